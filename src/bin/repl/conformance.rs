@@ -0,0 +1,289 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Self-test of the Annex A conformance classes against the sample data
+//! bundled w/ this crate.
+//!
+
+use crate::predicates::PREDICATES;
+use ogc_cql2::{
+    CSVDataSource, Context, Expression, MemoryDataSource, MyError, Q, Resource, tally_with,
+};
+use serde::{Deserialize, de::DeserializeOwned};
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Name of the environment variable that, when set, overrides the location
+/// of the sample data folder.
+const SAMPLES_ENV: &str = "CQL2_SAMPLES";
+
+/// Titles of the Annex A conformance classes, in order.
+const CLASSES: [&str; 14] = [
+    "CQL2 Text",
+    "CQL2 JSON",
+    "Basic-CQL2",
+    "Advanced Comparison Operators",
+    "Case-insensitive Comparison",
+    "Accent-insensitive Comparison",
+    "Basic Spatial Functions",
+    "Basic Spatial Functions with additional Spatial Literals",
+    "Spatial Functions",
+    "Temporal Functions",
+    "Array Functions",
+    "Property-Property Comparisons",
+    "Functions",
+    "Arithmetic Expressions",
+];
+
+/// Sample data sets the conformance test predicates are evaluated against.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum Data {
+    Countries,
+    Places,
+    Rivers,
+}
+
+impl Data {
+    // name of the CSV file holding this data set...
+    fn file_name(&self) -> &str {
+        match self {
+            Data::Countries => "ne_110m_admin_0_countries.csv",
+            Data::Places => "ne_110m_populated_places_simple.csv",
+            Data::Rivers => "ne_110m_rivers_lake_centerlines.csv",
+        }
+    }
+
+    // read all the rows of this data set, converted to Resources...
+    fn resources(&self) -> Result<Vec<Resource>, MyError> {
+        let path = sample_path("data").join(self.file_name());
+        let csv = CSVDataSource::from(&path.to_string_lossy());
+        match self {
+            Data::Countries => read::<Country>(&csv),
+            Data::Places => read::<Place>(&csv),
+            Data::Rivers => read::<River>(&csv),
+        }
+    }
+}
+
+/// Outcome of running the tests of a conformance class.
+#[derive(Debug, Default)]
+pub(crate) struct Report {
+    /// Title of the conformance class.
+    pub(crate) title: String,
+    /// Number of tests that passed.
+    pub(crate) passed: usize,
+    /// Description of the tests that failed.
+    pub(crate) failures: Vec<String>,
+}
+
+impl Report {
+    /// Return the total number of tests that ran.
+    pub(crate) fn total(&self) -> usize {
+        self.passed + self.failures.len()
+    }
+}
+
+/// Return the location of the sample data folder, or of one of its entries
+/// if `name` is not empty; e.g. `text` for the text-encoded samples.
+///
+/// It's the `tests/samples` folder of this crate unless the `CQL2_SAMPLES`
+/// environment variable is set, in which case that's used instead.
+pub(crate) fn sample_path(name: &str) -> PathBuf {
+    let root = match std::env::var_os(SAMPLES_ENV) {
+        Some(x) => PathBuf::from(x),
+        None => Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/samples"),
+    };
+    root.join(name)
+}
+
+/// Run the tests of the conformance class identified by `class`; e.g. `A.7`,
+/// or simply `7`.
+pub(crate) fn run(class: &str) -> Result<Report, MyError> {
+    let n = parse_class(class)?;
+    let title = format!("A.{n}. {}", CLASSES[usize::from(n) - 1]);
+    let mut report = match n {
+        1 => parse_samples("text", Expression::try_from_text)?,
+        2 => parse_samples("json", Expression::try_from_json)?,
+        _ => evaluate_predicates(n)?,
+    };
+    report.title = title;
+    Ok(report)
+}
+
+// map `A.n`, `a.n` or `n` to the number of a conformance class...
+fn parse_class(class: &str) -> Result<u8, MyError> {
+    let s = class.trim();
+    let s = s
+        .strip_prefix("A.")
+        .or_else(|| s.strip_prefix("a."))
+        .unwrap_or(s);
+    match s.parse::<u8>() {
+        Ok(n) if (1..=14).contains(&n) => Ok(n),
+        _ => Err(MyError::Runtime(
+            format!("Unknown conformance class '{class}'. Expected A.1 to A.14").into(),
+        )),
+    }
+}
+
+// parse every sample file in the given folder...
+fn parse_samples<F>(folder: &str, parse: F) -> Result<Report, MyError>
+where
+    F: Fn(&str) -> Result<Expression, MyError>,
+{
+    let mut paths = vec![];
+    for entry in fs::read_dir(sample_path(folder))? {
+        let path = entry?.path();
+        if path.is_file() && path.extension().is_some_and(|x| x == "txt" || x == "json") {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+
+    let mut report = Report::default();
+    for path in paths {
+        let src = fs::read_to_string(&path)?;
+        match parse(&src) {
+            Ok(_) => report.passed += 1,
+            Err(x) => report.failures.push(format!("{}: {x}", path.display())),
+        }
+    }
+    Ok(report)
+}
+
+// evaluate the predicates of the given class against the sample data...
+fn evaluate_predicates(n: u8) -> Result<Report, MyError> {
+    // NOTE (rsn) 20261016 - same as the tests, some predicates expect invalid
+    // coordinates to be caught, assuming EPSG:4326 is the implicit CRS...
+    let ctx = Context::try_with_crs("epsg:4326")?.freeze();
+    let mut report = Report::default();
+    for data in [Data::Countries, Data::Places, Data::Rivers] {
+        let predicates: Vec<_> = PREDICATES
+            .iter()
+            .filter(|(class, ds, _, _)| *class == n && *ds == data)
+            .collect();
+        if predicates.is_empty() {
+            continue;
+        }
+
+        let ds = MemoryDataSource::from(data.resources()?);
+        let exprs = predicates
+            .iter()
+            .map(|(_, _, src, _)| Expression::try_from_text(src))
+            .collect::<Result<Vec<_>, _>>()?;
+        let actual = tally_with(&ds, &exprs, ctx.clone())?;
+        for ((_, _, src, expected), count) in predicates.iter().zip(actual) {
+            if count == u64::from(*expected) {
+                report.passed += 1;
+            } else {
+                let src = src.split_whitespace().collect::<Vec<_>>().join(" ");
+                let msg = format!("{data:?}: {src} - actual/expected: {count} / {expected}");
+                report.failures.push(msg);
+            }
+        }
+    }
+    Ok(report)
+}
+
+// read the rows of a CSV data source as `T` _Features_ and convert them...
+fn read<T>(csv: &CSVDataSource) -> Result<Vec<Resource>, MyError>
+where
+    T: DeserializeOwned + TryInto<Resource, Error = MyError>,
+{
+    csv.reader()?
+        .into_deserialize::<T>()
+        .map(|x| x.map_err(MyError::from).and_then(T::try_into))
+        .collect()
+}
+
+// NOTE (rsn) 20261016 - the following mirror the _Feature_ types, and their
+// conversion to Resources, used by the tests...
+
+#[derive(Debug, Deserialize)]
+struct Country {
+    fid: i32,
+    geom: String,
+    #[serde(rename = "NAME")]
+    name: String,
+    #[serde(rename = "POP_EST")]
+    pop_est: f64,
+}
+
+impl TryFrom<Country> for Resource {
+    type Error = MyError;
+
+    fn try_from(value: Country) -> Result<Self, Self::Error> {
+        Ok(HashMap::from([
+            ("fid".into(), Q::try_from(value.fid)?),
+            ("geom".into(), Q::try_from_wkt(&value.geom)?),
+            ("NAME".into(), Q::new_plain_str(&value.name)),
+            ("POP_EST".into(), Q::from(value.pop_est)),
+        ]))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct Place {
+    fid: i32,
+    geom: String,
+    name: String,
+    nameascii: String,
+    pop_max: f64,
+    pop_min: f64,
+    pop_other: f64,
+    date: Option<String>,
+    start: Option<String>,
+    end: Option<String>,
+    boolean: Option<u8>,
+}
+
+impl TryFrom<Place> for Resource {
+    type Error = MyError;
+
+    fn try_from(value: Place) -> Result<Self, Self::Error> {
+        let mut map = HashMap::from([
+            ("fid".into(), Q::try_from(value.fid)?),
+            ("geom".into(), Q::try_from_wkt(&value.geom)?),
+            ("name".into(), Q::new_plain_str(&value.name)),
+            ("nameascii".into(), Q::new_plain_str(&value.nameascii)),
+            ("pop_max".into(), Q::from(value.pop_max)),
+            ("pop_min".into(), Q::from(value.pop_min)),
+            ("pop_other".into(), Q::from(value.pop_other)),
+        ]);
+        if let Some(x) = value.date {
+            map.insert("date".into(), Q::try_from_date_str(&x)?);
+        }
+        // time-stamp values lack a 'Z' suffix to be correctly recognized as timestamps...
+        if let Some(x) = value.start {
+            map.insert("start".into(), Q::try_from_timestamp_str(&format!("{x}Z"))?);
+        }
+        if let Some(x) = value.end {
+            map.insert("end".into(), Q::try_from_timestamp_str(&format!("{x}Z"))?);
+        }
+        // booleans, when present, are encoded as 1 and 0...
+        if let Some(x) = value.boolean {
+            map.insert("boolean".into(), Q::Bool(x != 0));
+        }
+        Ok(map)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct River {
+    fid: i32,
+    geom: String,
+    name: String,
+}
+
+impl TryFrom<River> for Resource {
+    type Error = MyError;
+
+    fn try_from(value: River) -> Result<Self, Self::Error> {
+        Ok(HashMap::from([
+            ("fid".into(), Q::try_from(value.fid)?),
+            ("geom".into(), Q::try_from_wkt(&value.geom)?),
+            ("name".into(), Q::new_plain_str(&value.name)),
+        ]))
+    }
+}
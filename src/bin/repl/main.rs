@@ -0,0 +1,269 @@
+// SPDX-License-Identifier: Apache-2.0
+
+#![warn(missing_docs)]
+#![doc = include_str!("../../../doc/REPL.md")]
+
+mod conformance;
+mod predicates;
+
+use ogc_cql2::{Context, Evaluator, ExEvaluator, Expression, MyError, Resource, ResourceExt};
+use std::{
+    collections::HashMap,
+    io::{self, Write},
+};
+
+#[doc(hidden)]
+const RED: &str = "\x1b[31m";
+#[doc(hidden)]
+const GREEN: &str = "\x1b[32m";
+#[doc(hidden)]
+const YELLOW: &str = "\x1b[33m";
+#[doc(hidden)]
+const RESET: &str = "\x1b[0m";
+#[doc(hidden)]
+const MULTILINE: &str = "~~";
+#[doc(hidden)]
+const EVAL: &str = ":eval";
+#[doc(hidden)]
+const AST: &str = ":ast";
+#[doc(hidden)]
+const JSON: &str = ":json";
+#[doc(hidden)]
+const TEXT: &str = ":text";
+#[doc(hidden)]
+const CONFORMANCE: &str = ":conformance";
+#[doc(hidden)]
+const FORMAT: &str = "--format";
+
+macro_rules! error {
+    ( $( $arg: tt )* ) => {
+        {
+            let msg = ::std::fmt::format(::core::format_args!($($arg)*));
+            eprintln!("{RED}{msg}{RESET}");
+        }
+    }
+}
+
+macro_rules! info {
+    ( $( $arg: tt )* ) => {
+        {
+            let msg = ::std::fmt::format(::core::format_args!($($arg)*));
+            println!("{YELLOW}{msg}{RESET}");
+        }
+    }
+}
+
+macro_rules! note {
+    ( $( $arg: tt )* ) => {
+        {
+            let msg = ::std::fmt::format(::core::format_args!($($arg)*));
+            println!("{GREEN}{msg}{RESET}");
+        }
+    }
+}
+
+#[doc(hidden)]
+fn prompt(s: &str) -> Result<(), MyError> {
+    print!("{GREEN}{s} {RESET}");
+    io::stdout().flush().map_err(MyError::IO)
+}
+
+// Return a fresh copy of `exp` in its text-encoded form...
+#[doc(hidden)]
+fn copy_of(exp: &Expression) -> Result<Expression, MyError> {
+    exp.bind(&HashMap::new())
+}
+
+// Evaluate `exp` against a Resource given as a JSON object...
+#[doc(hidden)]
+fn eval(exp: &Expression, json: &str) -> Result<(), MyError> {
+    let value = serde_json::from_str(json)?;
+    let resource = Resource::from_json_object(&value)?;
+    let mut ctx = Context::new();
+    ctx.register_builtins();
+    let mut evaluator = ExEvaluator::new(ctx.freeze());
+    evaluator.setup(copy_of(exp)?)?;
+    let outcome = evaluator.evaluate(&resource)?;
+    note!("Outcome: {outcome}");
+    Ok(())
+}
+
+// Print the intermediary representation of `exp`...
+#[doc(hidden)]
+fn ast(exp: &Expression) -> Result<(), MyError> {
+    let copy = copy_of(exp)?;
+    match copy.as_text_encoded() {
+        Some(x) => note!("{x:#?}"),
+        None => error!("Failed converting to TEXT"),
+    }
+    Ok(())
+}
+
+// Print the CQL2-JSON encoding of `exp`...
+#[doc(hidden)]
+fn json(exp: &Expression) -> Result<(), MyError> {
+    let value = exp.to_json()?;
+    let s = serde_json::to_string_pretty(&value)?;
+    note!("{s}");
+    Ok(())
+}
+
+// Print the CQL2-TEXT encoding of `exp`...
+#[doc(hidden)]
+fn text(exp: &Expression) -> Result<(), MyError> {
+    let s = copy_of(exp)?.to_string();
+    // NOTE (rsn) 20261016 - round-trip the result to ensure it's valid TEXT...
+    match Expression::try_from_text(&s) {
+        Ok(_) => note!("{s}"),
+        Err(x) => error!("Failed converting to TEXT: {x}"),
+    }
+    Ok(())
+}
+
+// Run the tests of a conformance class and print their outcome...
+#[doc(hidden)]
+fn conformance(class: &str) -> Result<(), MyError> {
+    let report = conformance::run(class)?;
+    for x in &report.failures {
+        error!("Failed: {x}");
+    }
+    let (passed, total) = (report.passed, report.total());
+    if total == 0 {
+        info!("{}: no tests to run", report.title);
+    } else if passed == total {
+        note!("{}: passed {passed} / {total}", report.title);
+    } else {
+        error!("{}: passed {passed} / {total}", report.title);
+    }
+    Ok(())
+}
+
+// Return TRUE if the command line includes `--format json`; FALSE otherwise.
+// Raise an error if the `--format` option is present w/ another value...
+#[doc(hidden)]
+fn json_format() -> Result<bool, MyError> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let mut result = false;
+    let mut it = args.iter();
+    while let Some(arg) = it.next() {
+        let value = match arg.strip_prefix(FORMAT) {
+            Some("") => it.next().map(String::as_str),
+            Some(x) => x.strip_prefix('='),
+            None => None,
+        };
+        match value {
+            Some("json") => result = true,
+            Some("text") => result = false,
+            _ => {
+                let msg = format!("Unsupported argument: {arg}. Expected `{FORMAT} json|text`");
+                return Err(MyError::Runtime(msg.into()));
+            }
+        }
+    }
+    Ok(result)
+}
+
+/// Executable main method.
+///
+/// Invoke it like so...
+/// ```bash
+/// cargo run --bin repl↵
+/// ```
+/// Add `-- --format json` to also print the CQL2-JSON encoding of every
+/// valid input.
+fn main() -> Result<(), MyError> {
+    let as_json = json_format()?;
+    note!("Enter a text or JSON CQL2 expression to verify.\nWhen done, hit Ctrl-D.");
+    note!("Use `{EVAL} {{...}}` to evaluate the last valid one against a JSON object,");
+    note!("`{AST}` to show how it was parsed, and `{JSON}` or `{TEXT}` to convert it.");
+    note!("Use `{CONFORMANCE} A.n` to run the tests of a conformance class.");
+    let stdin = io::stdin();
+    let mut current: Option<Expression> = None;
+    loop {
+        prompt("> ")?;
+        let mut line = String::new();
+        match stdin.read_line(&mut line) {
+            Ok(0) => {
+                note!("\nSee you later...");
+                break;
+            }
+
+            Ok(_) => {
+                let first = line.trim();
+                let input = if first == MULTILINE {
+                    info!("Enter multi-line mode. Exit w/ Ctrl-D");
+                    let mut lines = String::new();
+                    loop {
+                        prompt(">>")?;
+                        let mut next = String::new();
+                        match stdin.read_line(&mut next) {
+                            Ok(0) => break,
+                            Ok(_) => lines.push_str(&next),
+                            Err(x) => {
+                                error!("Failed Read: {}", x);
+                                break;
+                            }
+                        }
+                    }
+                    lines
+                } else {
+                    first.to_owned()
+                };
+
+                let input = input.trim();
+                if let Some(class) = input.strip_prefix(CONFORMANCE) {
+                    if let Err(x) = conformance(class) {
+                        error!("Failed: {}", x);
+                    }
+                    continue;
+                }
+
+                if input.starts_with(EVAL) || [AST, JSON, TEXT].contains(&input) {
+                    let Some(exp) = &current else {
+                        error!("No valid expression to work with yet");
+                        continue;
+                    };
+                    let result = match input.strip_prefix(EVAL) {
+                        Some(object) => eval(exp, object),
+                        None if input == JSON => json(exp),
+                        None if input == TEXT => text(exp),
+                        None => ast(exp),
+                    };
+                    if let Err(x) = result {
+                        error!("Failed: {}", x);
+                    }
+                    continue;
+                }
+
+                let expr = Expression::try_from_text(input);
+                match expr {
+                    Ok(x) => {
+                        note!("OK! {}", x);
+                        if as_json && let Err(x) = json(&x) {
+                            error!("Failed converting to JSON: {}", x);
+                        }
+                        current = Some(x);
+                    }
+                    Err(x) => {
+                        error!("Failed as TEXT: {}.", x);
+                        info!("Will try as JSON...");
+                        let expr = Expression::try_from_json(input);
+                        match expr {
+                            Ok(x) => {
+                                note!("OK! {}", x);
+                                current = Some(x);
+                            }
+                            Err(x) => error!("Failed as JSON: {}", x),
+                        }
+                    }
+                }
+            }
+            Err(x) => {
+                error!("Failed Read: {}", x);
+                break;
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,440 @@
+// SPDX-License-Identifier: Apache-2.0
+
+// Predicates, and the expected number of matching _Features_ in the bundled
+// sample data, of the Annex A conformance tests; i.e. those of the `tests/a*`
+// modules evaluated w/ the `harness()` function. keep the 2 in sync...
+
+use crate::conformance::Data::{self, Countries, Places, Rivers};
+
+#[rustfmt::skip]
+pub(crate) const PREDICATES: [(u8, Data, &str, u32); 366] = [
+    // A.3 - test_8 COUNTRIES_PREDICATES...
+    (3, Countries, r#"NAME='Luxembourg'"#, 1),
+    (3, Countries, r#"NAME>='Luxembourg'"#, 84),
+    (3, Countries, r#"NAME>'Luxembourg'"#, 83),
+    (3, Countries, r#"NAME<='Luxembourg'"#, 94),
+    (3, Countries, r#"NAME<'Luxembourg'"#, 93),
+    (3, Countries, r#"NAME<>'Luxembourg'"#, 176),
+    (3, Countries, r#"POP_EST=37589262"#, 1),
+    (3, Countries, r#"POP_EST>=37589262"#, 39),
+    (3, Countries, r#"POP_EST>37589262"#, 38),
+    (3, Countries, r#"POP_EST<=37589262"#, 139),
+    (3, Countries, r#"POP_EST<37589262"#, 138),
+    (3, Countries, r#"POP_EST<>37589262"#, 176),
+    // A.3 - test_8 PLACES_PREDICATES...
+    (3, Places, r#"name IS NOT NULL"#, 243),
+    (3, Places, r#"name IS NULL"#, 0),
+    (3, Places, r#"name='København'"#, 1),
+    (3, Places, r#"name>='København'"#, 137),
+    (3, Places, r#"name>'København'"#, 136),
+    (3, Places, r#"name<='København'"#, 107),
+    (3, Places, r#"name<'København'"#, 106),
+    (3, Places, r#"name<>'København'"#, 242),
+    (3, Places, r#"pop_other IS NOT NULL"#, 243),
+    (3, Places, r#"pop_other IS NULL"#, 0),
+    (3, Places, r#"pop_other=1038288"#, 1),
+    (3, Places, r#"pop_other>=1038288"#, 123),
+    (3, Places, r#"pop_other>1038288"#, 122),
+    (3, Places, r#"pop_other<=1038288"#, 121),
+    (3, Places, r#"pop_other<1038288"#, 120),
+    (3, Places, r#"pop_other<>1038288"#, 242),
+    (3, Places, r#""date" IS NOT NULL"#, 3),
+    (3, Places, r#""date" IS NULL"#, 240),
+    (3, Places, r#""date"=DATE('2022-04-16')"#, 1),
+    (3, Places, r#""date">=DATE('2022-04-16')"#, 2),
+    (3, Places, r#""date">DATE('2022-04-16')"#, 1),
+    (3, Places, r#""date"<=DATE('2022-04-16')"#, 2),
+    (3, Places, r#""date"<DATE('2022-04-16')"#, 1),
+    (3, Places, r#""date"<>DATE('2022-04-16')"#, 2),
+    (3, Places, r#"start IS NOT NULL"#, 3),
+    (3, Places, r#"start IS NULL"#, 240),
+    (3, Places, r#"start=TIMESTAMP('2022-04-16T10:13:19Z')"#, 1),
+    (3, Places, r#"start>=TIMESTAMP('2022-04-16T10:13:19Z')"#, 2),
+    (3, Places, r#"start>TIMESTAMP('2022-04-16T10:13:19Z')"#, 1),
+    (3, Places, r#"start<=TIMESTAMP('2022-04-16T10:13:19Z')"#, 2),
+    (3, Places, r#"start<TIMESTAMP('2022-04-16T10:13:19Z')"#, 1),
+    (3, Places, r#"start<>TIMESTAMP('2022-04-16T10:13:19Z')"#, 2),
+    (3, Places, r#"boolean IS NOT NULL"#, 3),
+    (3, Places, r#"boolean IS NULL"#, 240),
+    (3, Places, r#"boolean=true"#, 2),
+    (3, Places, r#"boolean=false"#, 1),
+    // A.4 - test_13 PREDICATES...
+    (4, Places, r#"name LIKE 'B_r%'"#, 3),
+    (4, Places, r#"name NOT LIKE 'B_r%'"#, 240),
+    (4, Places, r#"pop_other between 1000000 and 3000000"#, 75),
+    (4, Places, r#"pop_other not between 1000000 and 3000000"#, 168),
+    (4, Places, r#"name IN ('Kiev','kobenhavn','Berlin','athens','foo')"#, 2),
+    (4, Places, r#"name NOT IN ('Kiev','kobenhavn','Berlin','athens','foo')"#, 241),
+    (4, Places, r#"pop_other in (1038288,1611692,3013258,3013257,3013259)"#, 3),
+    (4, Places, r#"pop_other not in (1038288,1611692,3013258,3013257,3013259)"#, 240),
+    (4, Places, r#""date" in (DATE('2021-04-16'),DATE('2022-04-16'),DATE('2022-04-18'))"#, 2),
+    (4, Places, r#""date" not in (DATE('2021-04-16'),DATE('2022-04-16'),DATE('2022-04-18'))"#, 1),
+    (4, Places, r#"start in (TIMESTAMP('2022-04-16T10:13:19Z'))"#, 1),
+    (4, Places, r#"start not in (TIMESTAMP('2022-04-16T10:13:19Z'))"#, 2),
+    (4, Places, r#"boolean in (true)"#, 2),
+    (4, Places, r#"boolean not in (false)"#, 2),
+    // A.5 - test_17 PREDICATES...
+    (5, Places, r#"CASEI(name)=casei('KIEV')"#, 1),
+    (5, Places, r#"CASEI(name)=casei('kiev')"#, 1),
+    (5, Places, r#"CASEI(name)=casei('Kiev')"#, 1),
+    (5, Places, r#"CASEI(name)=casei('København')"#, 1),
+    (5, Places, r#"CASEI(name)=casei('københavn')"#, 1),
+    (5, Places, r#"CASEI(name)=casei('KØBENHAVN')"#, 1),
+    (5, Places, r#"CASEI(name) LIKE casei('B_r%')"#, 3),
+    (5, Places, r#"CASEI(name) LIKE casei('b_r%')"#, 3),
+    (5, Places, r#"CASEI(name) LIKE casei('B_R%')"#, 3),
+    (5, Places, r#"CASEI(name) IN (casei('Kiev'),
+        casei('kobenhavn'), casei('Berlin'), 
+        casei('athens'), casei('foo'))"#, 3),
+    // A.5 - test_17 MIXED_PREDICATES...
+    (5, Places, r#"CASEI(name)=accenti('sao paulo')"#, 1),
+    (5, Places, r#"name=accenti(casei('CHISINAU'))"#, 1),
+    (5, Places, r#"ACCENTI(name)<>casei('REYKJAVIK')"#, 242),
+    // A.6 - test_23 PREDICATES...
+    (6, Places, r#"ACCENTI(name)=accenti('Chișinău')"#, 1),
+    (6, Places, r#"ACCENTI(name)=accenti('Chisinau')"#, 1),
+    (6, Places, r#"ACCENTI(name)=accenti('Kiev')"#, 1),
+    (6, Places, r#"ACCENTI(CASEI(name))=accenti(casei('chișinău'))"#, 1),
+    (6, Places, r#"ACCENTI(CASEI(name))=accenti(casei('chisinau'))"#, 1),
+    (6, Places, r#"ACCENTI(CASEI(name))=accenti(casei('CHISINAU'))"#, 1),
+    (6, Places, r#"ACCENTI(CASEI(name))=accenti(casei('CHIȘINĂU'))"#, 1),
+    (6, Places, r#"ACCENTI(name) LIKE accenti('Ch%')"#, 3),
+    (6, Places, r#"ACCENTI(CASEI(name)) LIKE accenti(casei('Chiș%'))"#, 1),
+    (6, Places, r#"ACCENTI(CASEI(name)) LIKE accenti(casei('cHis%'))"#, 1),
+    (6, Places, r#"ACCENTI(CASEI(name)) IN (accenti(casei('Kiev')),
+     accenti(casei('chișinău')), accenti(casei('Berlin')),
+     accenti(casei('athens')), accenti(casei('foo')))"#, 4),
+    // A.7 - test_25 COUNTRIES_PREDICATES...
+    (7, Countries, r#"S_INTERSECTS(geom,BBOX(-180,-90,180,90))"#, 177),
+    (7, Countries, r#"S_INTERSECTS(geom,POINT(7.02 49.92))"#, 1),
+    (7, Countries, r#"S_INTERSECTS(geom,BBOX(-180,-90,-90,90)) 
+      AND S_INTERSECTS(geom,BBOX(90,-90,180,90))"#, 3),
+    // A.7 - test_25 PLACES_PREDICATES...
+    (7, Places, r#"S_INTERSECTS(geom,BBOX(-180,-90,180,90))"#, 243),
+    (7, Places, r#"S_INTERSECTS(geom,POINT(7.02 49.92))"#, 0),
+    (7, Places, r#"S_INTERSECTS(geom,BBOX(-180,-90,-90,90)) 
+      AND S_INTERSECTS(geom,BBOX(90,-90,180,90))"#, 0),
+    // A.7 - test_26 COUNTRIES_PREDICATES...
+    (7, Countries, r#"S_INTERSECTS(geom,BBOX(0,40,10,50))"#, 8),
+    (7, Countries, r#"S_INTERSECTS(geom,BBOX(150,-90,-150,90))"#, 10),
+    (7, Countries, r#"S_INTERSECTS(geom,POINT(7.02 49.92))"#, 1),
+    (7, Countries, r#"S_INTERSECTS(geom,BBOX(0,40,10,50)) 
+       and S_INTERSECTS(geom,BBOX(5,50,10,60))"#, 3),
+    (7, Countries, r#"S_INTERSECTS(geom,BBOX(0,40,10,50)) 
+       and not S_INTERSECTS(geom,BBOX(5,50,10,60))"#, 5),
+    (7, Countries, r#"S_INTERSECTS(geom,BBOX(0,40,10,50)) 
+       or S_INTERSECTS(geom,BBOX(-90,40,-60,50))"#, 10),
+    // A.7 - test_26 PLACES_PREDICATES...
+    (7, Places, r#"S_INTERSECTS(geom,BBOX(0,40,10,50))"#, 7),
+    // A.7 - test_26 RIVERS_PREDICATES...
+    (7, Rivers, r#"S_INTERSECTS(geom,BBOX(-180,-90,0,90))"#, 4),
+    // A.8 - test_29 PREDICATES...
+    (8, Countries, "S_INTERSECTS(geom,LINESTRING(-180 -45, 0 -45))", 2),
+    // A.9 - test_30 PREDICATES...
+    (9, Countries, "S_INTERSECTS(geom,BBOX(-180,-90,180,90))", 177),
+    (9, Countries, "S_INTERSECTS(geom,POLYGON((-180 -90,180 -90,180 90,-180 90,-180 -90)))", 177),
+    (9, Countries, "S_INTERSECTS(geom,LINESTRING(7 50, 10 51))", 1),
+    (9, Countries, "S_INTERSECTS(geom,POINT(7.02 49.92))", 1),
+    // A.9 - test_31 DISJOINT...
+    (9, Countries, "S_DISJOINT(geom,BBOX(-180,-90,180,90))", 0),
+    (9, Countries, "S_DISJOINT(geom,POLYGON((-180 -90,180 -90,180 90,-180 90,-180 -90)))", 0),
+    (9, Countries, "S_DISJOINT(geom,LINESTRING(7 50, 10 51))", 176),
+    (9, Countries, "S_DISJOINT(geom,POINT(7.02 49.92))", 176),
+    // A.9 - test_32 PREDICATES...
+    (9, Countries, "S_EQUALS(geom,POLYGON((-180 -90,180 -90,180 90,-180 90,-180 -90)))", 0),
+    (9, Countries, "S_EQUALS(geom,LINESTRING(7 50,10 51))", 0),
+    (9, Countries, "S_EQUALS(geom,POINT(7.02 49.92))", 0),
+    // A.9 - test_35 PREDICATES...
+    (9, Countries, "S_WITHIN(geom,BBOX(-180,-90,180,90))", 177),
+    (9, Countries, "S_WITHIN(geom,POLYGON((-180 -90,180 -90,180 90,-180 90,-180 -90)))", 177),
+    (9, Countries, "S_WITHIN(geom,LINESTRING(7 50,10 51))", 0),
+    (9, Countries, "S_WITHIN(geom,MULTIPOINT((7 50),(10 51)))", 0),
+    // A.9 - test_36 CONTAINS...
+    (9, Countries, "S_CONTAINS(geom,BBOX(-180,-90,180,90))", 0),
+    (9, Countries, "S_CONTAINS(geom,POLYGON((-180 -90,180 -90,180 90,-180 90,-180 -90)))", 0),
+    (9, Countries, "S_CONTAINS(geom,LINESTRING(7 50,10 51))", 1),
+    (9, Countries, "S_CONTAINS(geom,MULTIPOINT((7 50),(10 51)))", 1),
+    // A.9 - test_36 WITHIN...
+    (9, Countries, "S_WITHIN(geom,BBOX(-180,-90,180,90))", 177),
+    (9, Countries, "S_WITHIN(geom,POLYGON((-180 -90,180 -90,180 90,-180 90,-180 -90)))", 177),
+    // A.9 - test_37 POINT_PREDICATE...
+    (9, Places, "S_OVERLAPS(geom,MULTIPOINT(7 50,10 51))", 0),
+    // A.9 - test_37 LINE_PREDICATE...
+    (9, Rivers, "S_OVERLAPS(geom,LINESTRING(7 50,10 51))", 0),
+    // A.9 - test_37 POLYGON_PREDICATE...
+    (9, Countries, "S_OVERLAPS(geom,POLYGON((-180 -90,180 -90,180 90,-180 90,-180 -90)))", 0),
+    // A.9 - test_38 COUNTRIES_PREDICATES...
+    (9, Countries, "S_INTERSECTS(geom,POLYGON((0 40,10 40,10 50,0 50,0 40)))", 8),
+    (9, Countries, "S_INTERSECTS(geom,LINESTRING(0 40,10 50))", 4),
+    (9, Countries, "S_DISJOINT(geom,BBOX(0,40,10,50))", 169),
+    (9, Countries, "S_DISJOINT(geom,POLYGON((0 40,10 40,10 50,0 50,0 40)))", 169),
+    (9, Countries, "S_DISJOINT(geom,LINESTRING(0 40,10 50))", 173),
+    (9, Countries, "S_DISJOINT(geom,POINT(7.02 49.92))", 176),
+    (9, Countries, r#"S_TOUCHES(geom,POLYGON((
+        6.043073357781111 50.128051662794235,6.242751092156993 49.90222565367873,
+        6.186320428094177 49.463802802114515,5.897759230176348 49.44266714130711,
+        5.674051954784829 49.529483547557504,5.782417433300907 50.09032786722122,
+        6.043073357781111 50.128051662794235)))"#, 3),
+    (9, Countries, "S_TOUCHES(geom,POINT(6.043073357781111 50.128051662794235))", 3),
+    (9, Countries, "S_TOUCHES(geom,POINT(6.242751092156993 49.90222565367873))", 2),
+    (9, Countries, r#"S_TOUCHES(geom,LINESTRING(
+        6.043073357781111 50.128051662794235,
+        6.242751092156993 49.90222565367873))"#, 3),
+    (9, Countries, "S_WITHIN(geom,BBOX(-180,-90,0,90))", 44),
+    (9, Countries, "S_CONTAINS(geom,BBOX(7,50,8,51))", 1),
+    (9, Countries, "S_CONTAINS(geom,LINESTRING(7 50,8 51))", 1),
+    (9, Countries, "S_CONTAINS(geom,POINT(7.02 49.92))", 1),
+    (9, Countries, "S_OVERLAPS(geom,BBOX(-180,-90,0,90))", 11),
+    // A.9 - test_38 PLACES_PREDICATES...
+    (9, Places, "S_INTERSECTS(geom,POLYGON((0 40,10 40,10 50,0 50,0 40)))", 7),
+    (9, Places, "S_DISJOINT(geom,BBOX(0,40,10,50))", 236),
+    (9, Places, "S_DISJOINT(geom,POLYGON((0 40,10 40,10 50,0 50,0 40)))", 236),
+    (9, Places, "S_EQUALS(geom,POINT(6.1300028 49.6116604))", 1),
+    (9, Places, "S_WITHIN(geom,BBOX(-180,-90,0,90))", 74),
+    // A.9 - test_38 RIVERS_PREDICATES...
+    (9, Rivers, "S_INTERSECTS(geom,LINESTRING(-60 -90,-60 90))", 2),
+    (9, Rivers, "S_DISJOINT(geom,BBOX(-180,-90,0,90))", 9),
+    (9, Rivers, "S_DISJOINT(geom,LINESTRING(-60 -90,-60 90))", 11),
+    (9, Rivers, "S_CROSSES(geom,BBOX(0,40,10,50))", 1),
+    (9, Rivers, "S_CROSSES(geom,LINESTRING(-60 -90,-60 90))", 2),
+    (9, Rivers, "S_WITHIN(geom,BBOX(-180,-90,0,90))", 4),
+    // A.10 - test_40 TIMESTAMP_PREDICATES...
+    (10, Places, "T_AFTER(start,   TIMESTAMP(  '2022-04-24T07:59:57Z'))", 0),
+    (10, Places, "T_AFTER(start,   INTERVAL(   '2021-01-01T00:00:00Z','2021-12-31T23:59:59Z'))", 2),
+    (10, Places, "T_BEFORE(start,  TIMESTAMP(  '2022-04-24T07:59:57Z'))", 3),
+    (10, Places, "T_BEFORE(start,  INTERVAL(   '2021-01-01T00:00:00Z','2021-12-31T23:59:59Z'))", 0),
+    (10, Places, "T_DISJOINT(start,TIMESTAMP(  '2022-04-24T07:59:57Z'))", 3),
+    (10, Places, "T_DISJOINT(start,INTERVAL(   '2021-01-01T00:00:00Z','2021-12-31T23:59:59Z'))", 2),
+    (10, Places, "T_EQUALS(start,TIMESTAMP(    '2022-04-24T07:59:57Z'))", 0),
+    (10, Places, "T_EQUALS(start,INTERVAL(     '2021-01-01T00:00:00Z','2021-12-31T23:59:59Z'))", 0),
+    (10, Places, "T_INTERSECTS(start,TIMESTAMP('2022-04-24T07:59:57Z'))", 0),
+    (10, Places, "T_INTERSECTS(start,INTERVAL( '2021-01-01T00:00:00Z','2021-12-31T23:59:59Z'))", 1),
+    // A.10 - test_40 DATE_PREDICATES...
+    (10, Places, "T_AFTER(date,DATE(         '2022-04-24'))", 1),
+    (10, Places, "T_AFTER(date,INTERVAL(     '2021-01-01','2021-12-31'))", 2),
+    (10, Places, "T_BEFORE(date,DATE(        '2022-04-24'))", 2),
+    (10, Places, "T_BEFORE(date,INTERVAL(    '2021-01-01','2021-12-31'))", 0),
+    (10, Places, "T_DISJOINT(date,DATE(      '2022-04-24'))", 3),
+    (10, Places, "T_DISJOINT(date,INTERVAL(  '2021-01-01','2021-12-31'))", 2),
+    (10, Places, "T_EQUALS(date,DATE(        '2022-04-24'))", 0),
+    (10, Places, "T_EQUALS(date,INTERVAL(    '2021-01-01','2021-12-31'))", 0),
+    (10, Places, "T_INTERSECTS(date,DATE(    '2022-04-24'))", 0),
+    (10, Places, "T_INTERSECTS(date,INTERVAL('2021-01-01','2021-12-31'))", 1),
+    // A.10 - test_41 TIMESTAMP_PREDICATES...
+    (10, Places, "T_AFTER(INTERVAL(start,end),INTERVAL(       '2021-01-01T00:00:00Z','2021-12-31T23:59:59Z'))", 2),
+    (10, Places, "T_BEFORE(INTERVAL(start,end),INTERVAL(      '2021-01-01T00:00:00Z','2021-12-31T23:59:59Z'))", 0),
+    (10, Places, "T_DISJOINT(INTERVAL(start,end),INTERVAL(    '2021-01-01T00:00:00Z','2021-12-31T23:59:59Z'))", 2),
+    (10, Places, "T_EQUALS(INTERVAL(start,end),INTERVAL(      '2021-01-01T00:00:00Z','2021-12-31T23:59:59Z'))", 0),
+    (10, Places, "T_INTERSECTS(INTERVAL(start,end),INTERVAL(  '2021-01-01T00:00:00Z','2021-12-31T23:59:59Z'))", 1),
+    (10, Places, "T_CONTAINS(INTERVAL(start,end),INTERVAL(    '2021-01-01T00:00:00Z','2021-12-31T23:59:59Z'))", 0),
+    (10, Places, "T_DURING(INTERVAL(start,end),INTERVAL(      '2021-01-01T00:00:00Z','2021-12-31T23:59:59Z'))", 0),
+    (10, Places, "T_FINISHEDBY(INTERVAL(start,end),INTERVAL(  '2021-01-01T00:00:00Z','2021-12-31T23:59:59Z'))", 0),
+    (10, Places, "T_FINISHES(INTERVAL(start,end),INTERVAL(    '2021-01-01T00:00:00Z','2021-12-31T23:59:59Z'))", 0),
+    (10, Places, "T_MEETS(INTERVAL(start,end),INTERVAL(       '2021-01-01T00:00:00Z','2021-12-31T23:59:59Z'))", 0),
+    (10, Places, "T_METBY(INTERVAL(start,end),INTERVAL(       '2021-01-01T00:00:00Z','2021-12-31T23:59:59Z'))", 0),
+    (10, Places, "T_OVERLAPPEDBY(INTERVAL(start,end),INTERVAL('2021-01-01T00:00:00Z','2021-12-31T23:59:59Z'))", 1),
+    (10, Places, "T_OVERLAPS(INTERVAL(start,end),INTERVAL(    '2021-01-01T00:00:00Z','2021-12-31T23:59:59Z'))", 0),
+    (10, Places, "T_STARTEDBY(INTERVAL(start,end),INTERVAL(   '2021-01-01T00:00:00Z','2021-12-31T23:59:59Z'))", 0),
+    (10, Places, "T_STARTS(INTERVAL(start,end),INTERVAL(      '2021-01-01T00:00:00Z','2021-12-31T23:59:59Z'))", 0),
+    // A.10 - test_41 DATE_PREDICATES...
+    (10, Places, "T_AFTER(       INTERVAL(date,'..'),INTERVAL('2021-01-01','2021-12-31'))", 2),
+    (10, Places, "T_BEFORE(      INTERVAL(date,'..'),INTERVAL('2021-01-01','2021-12-31'))", 0),
+    (10, Places, "T_DISJOINT(    INTERVAL(date,'..'),INTERVAL('2021-01-01','2021-12-31'))", 2),
+    (10, Places, "T_EQUALS(      INTERVAL(date,'..'),INTERVAL('2021-01-01','2021-12-31'))", 0),
+    (10, Places, "T_INTERSECTS(  INTERVAL(date,'..'),INTERVAL('2021-01-01','2021-12-31'))", 1),
+    (10, Places, "T_CONTAINS(    INTERVAL(date,'..'),INTERVAL('2021-01-01','2021-12-31'))", 0),
+    (10, Places, "T_DURING(      INTERVAL(date,'..'),INTERVAL('2021-01-01','2021-12-31'))", 0),
+    (10, Places, "T_FINISHEDBY(  INTERVAL(date,'..'),INTERVAL('2021-01-01','2021-12-31'))", 0),
+    (10, Places, "T_FINISHES(    INTERVAL(date,'..'),INTERVAL('2021-01-01','2021-12-31'))", 0),
+    (10, Places, "T_MEETS(       INTERVAL(date,'..'),INTERVAL('2021-01-01','2021-12-31'))", 0),
+    (10, Places, "T_METBY(       INTERVAL(date,'..'),INTERVAL('2021-01-01','2021-12-31'))", 0),
+    (10, Places, "T_OVERLAPPEDBY(INTERVAL(date,'..'),INTERVAL('2021-01-01','2021-12-31'))", 1),
+    (10, Places, "T_OVERLAPS(    INTERVAL(date,'..'),INTERVAL('2021-01-01','2021-12-31'))", 0),
+    (10, Places, "T_STARTEDBY(   INTERVAL(date,'..'),INTERVAL('2021-01-01','2021-12-31'))", 0),
+    (10, Places, "T_STARTS(      INTERVAL(date,'..'),INTERVAL('2021-01-01','2021-12-31'))", 0),
+    // A.10 - test_41 DURING_PREDICATES...
+    (10, Places, "T_DURING(INTERVAL(start,end),INTERVAL('2021-01-01T00:00:00Z','2024-12-31T23:59:59Z'))", 3),
+    (10, Places, "T_DURING(INTERVAL(start,end),INTERVAL('2021-01-01','2022-12-31'))", 2),
+    // A.10 - test_42 PREDICATES...
+    (10, Places, r#"t_after("date",date('2022-04-16'))"#, 1),
+    (10, Places, r#"t_before("date",date('2022-04-16'))"#, 1),
+    (10, Places, r#"t_disjoint("date",date('2022-04-16'))"#, 2),
+    (10, Places, r#"t_equals("date",date('2022-04-16'))"#, 1),
+    (10, Places, r#"t_intersects("date",date('2022-04-16'))"#, 1),
+    (10, Places, r#"t_after("date",interval('2022-01-01','2022-12-31'))"#, 1),
+    (10, Places, r#"t_before("date",interval('2022-01-01','2022-12-31'))"#, 1),
+    (10, Places, r#"t_disjoint("date",interval('2022-01-01','2022-12-31'))"#, 2),
+    (10, Places, r#"t_equals("date",interval('2022-01-01','2022-12-31'))"#, 0),
+    (10, Places, r#"t_equals("date",interval('2022-04-16','2022-04-16'))"#, 1),
+    (10, Places, r#"t_intersects("date",interval('2022-01-01','2022-12-31'))"#, 1),
+    (10, Places, r#"t_after(start,timestamp('2022-04-16T10:13:19Z'))"#, 1),
+    (10, Places, r#"t_before(start,timestamp('2022-04-16T10:13:19Z'))"#, 1),
+    (10, Places, r#"t_disjoint(start,timestamp('2022-04-16T10:13:19Z'))"#, 2),
+    (10, Places, r#"t_equals(start,timestamp('2022-04-16T10:13:19Z'))"#, 1),
+    (10, Places, r#"t_intersects(start,timestamp('2022-04-16T10:13:19Z'))"#, 1),
+    (10, Places, r#"t_after(start,interval('2022-01-01T00:00:00Z','2022-12-31T23:59:59Z'))"#, 0),
+    (10, Places, r#"t_before(start,interval('2022-01-01T00:00:00Z','2022-12-31T23:59:59Z'))"#, 1),
+    (10, Places, r#"t_disjoint(start,interval('2022-01-01T00:00:00Z','2022-12-31T23:59:59Z'))"#, 1),
+    (10, Places, r#"t_equals(start,interval('2022-01-01T00:00:00Z','2022-12-31T23:59:59Z'))"#, 0),
+    (10, Places, r#"t_intersects(start,interval('2022-01-01T00:00:00Z','2022-12-31T23:59:59Z'))"#, 2),
+    (10, Places, r#"t_after(interval(start,end),interval('..','2022-04-16T10:13:19Z'))"#, 1),
+    (10, Places, r#"t_before(interval(start,end),interval('2023-01-01T00:00:00Z','..'))"#, 2),
+    (10, Places, r#"t_disjoint(interval(start,end),interval('2022-04-16T10:13:19Z','2022-04-16T10:15:09Z'))"#, 1),
+    (10, Places, r#"t_equals(interval(start,end),interval('2021-04-16T10:15:59Z','2022-04-16T10:16:06Z'))"#, 1),
+    (10, Places, r#"t_intersects(interval(start,end),interval('2022-04-16T10:13:19Z','2022-04-16T10:15:09Z'))"#, 2),
+    (10, Places, r#"T_CONTAINS(interval(start,end),interval('2022-04-16T10:13:19Z','2022-04-16T10:15:10Z'))"#, 1),
+    (10, Places, r#"T_DURING(interval(start,end),interval('2022-01-01T00:00:00Z','2022-12-31T23:59:59Z'))"#, 1),
+    (10, Places, r#"T_FINISHES(interval(start,end),interval('2020-04-16T10:13:19Z','2022-04-16T10:16:06Z'))"#, 1),
+    (10, Places, r#"T_FINISHEDBY(interval(start,end),interval('2022-04-16T10:13:19Z','2022-04-16T10:16:06Z'))"#, 1),
+    (10, Places, r#"T_MEETS(interval(start,end),interval('2022-04-16T10:13:19Z','2022-04-16T10:15:10Z'))"#, 0),
+    (10, Places, r#"T_METBY(interval(start,end),interval('2022-04-16T10:13:19Z','2022-04-16T10:15:10Z'))"#, 1),
+    (10, Places, r#"T_OVERLAPPEDBY(interval(start,end),interval('2020-04-16T10:13:19Z','2022-04-16T10:15:10Z'))"#, 2),
+    (10, Places, r#"T_OVERLAPS(interval(start,end),interval('2022-04-16T10:13:19Z','2023-04-16T10:15:10Z'))"#, 1),
+    (10, Places, r#"T_STARTEDBY(interval(start,end),interval('2022-04-16T10:13:19Z','2022-04-16T10:15:10Z'))"#, 1),
+    (10, Places, r#"T_STARTS(interval(start,end),interval('2022-04-16T10:13:19Z','2022-04-16T10:15:10Z'))"#, 0),
+    // A.12 - test_49 PLACES_PREDICATES...
+    (12, Places, "'København'=name", 1),
+    (12, Places, "'København'<=name", 137),
+    (12, Places, "'København'<name", 136),
+    (12, Places, "'København'>=name", 107),
+    (12, Places, "'København'>name", 106),
+    (12, Places, "'København'<>name", 242),
+    (12, Places, "name=nameascii", 230),
+    (12, Places, "name>=nameascii", 243),
+    (12, Places, "name>nameascii", 13),
+    (12, Places, "name<=nameascii", 230),
+    (12, Places, "name<nameascii", 0),
+    (12, Places, "name<>nameascii", 13),
+    (12, Places, "1038288=pop_other", 1),
+    (12, Places, "1038288<=pop_other", 123),
+    (12, Places, "1038288<pop_other", 122),
+    (12, Places, "1038288>=pop_other", 121),
+    (12, Places, "1038288>pop_other", 120),
+    (12, Places, "1038288<>pop_other", 242),
+    (12, Places, "pop_min=pop_max", 27),
+    (12, Places, "pop_min<=pop_max", 243),
+    (12, Places, "pop_min<pop_max", 216),
+    (12, Places, "pop_min>=pop_max", 27),
+    (12, Places, "pop_min>pop_max", 0),
+    (12, Places, "pop_min<>pop_max", 216),
+    (12, Places, "start=end", 0),
+    (12, Places, "start<=end", 3),
+    (12, Places, "start<end", 3),
+    (12, Places, "start>=end", 0),
+    (12, Places, "start>end", 0),
+    (12, Places, "start<>end", 3),
+    (12, Places, "'København' LIKE 'K_benhavn'", 243),
+    (12, Places, "'København' NOT LIKE 'K_benhavn'", 0),
+    (12, Places, "pop_other between pop_min and pop_max", 94),
+    (12, Places, "pop_other not between pop_min and pop_max", 149),
+    (12, Places, "S_INTERSECTS(BBOX(0,40,10,50),geom)", 7),
+    (12, Places, "S_INTERSECTS(POLYGON((0 40,10 40,10 50,0 50,0 40)),geom)", 7),
+    (12, Places, "S_DISJOINT(BBOX(0,40,10,50),geom)", 236),
+    (12, Places, "S_DISJOINT(POLYGON((0 40,10 40,10 50,0 50,0 40)),geom)", 236),
+    (12, Places, "S_EQUALS(POINT(6.1300028 49.6116604),geom)", 1),
+    (12, Places, "S_CONTAINS(BBOX(-180,-90,0,90),geom)", 74),
+    (12, Places, r#"t_after(date('2022-04-16'),"date")"#, 1),
+    (12, Places, r#"t_before(date('2022-04-16'),"date")"#, 1),
+    (12, Places, r#"t_disjoint(date('2022-04-16'),"date")"#, 2),
+    (12, Places, r#"t_equals(date('2022-04-16'),"date")"#, 1),
+    (12, Places, r#"t_intersects(date('2022-04-16'),"date")"#, 1),
+    (12, Places, r#"t_after(interval('2022-01-01','2022-12-31'),"date")"#, 1),
+    (12, Places, r#"t_before(interval('2022-01-01','2022-12-31'),"date")"#, 1),
+    (12, Places, r#"t_disjoint(interval('2022-01-01','2022-12-31'),"date")"#, 2),
+    (12, Places, r#"t_equals(interval('2022-01-01','2022-12-31'),"date")"#, 0),
+    (12, Places, r#"t_equals(interval('2022-04-16','2022-04-16'),"date")"#, 1),
+    (12, Places, r#"t_intersects(interval('2022-01-01','2022-12-31'),"date")"#, 1),
+    (12, Places, "t_after(timestamp('2022-04-16T10:13:19Z'),start)", 1),
+    (12, Places, "t_before(timestamp('2022-04-16T10:13:19Z'),start)", 1),
+    (12, Places, "t_disjoint(timestamp('2022-04-16T10:13:19Z'),start)", 2),
+    (12, Places, "t_equals(timestamp('2022-04-16T10:13:19Z'),start)", 1),
+    (12, Places, "t_intersects(timestamp('2022-04-16T10:13:19Z'),start)", 1),
+    (12, Places, "t_after(interval('2022-01-01T00:00:00Z','2022-12-31T23:59:59Z'),start)", 1),
+    (12, Places, "t_before(interval('2022-01-01T00:00:00Z','2022-12-31T23:59:59Z'),start)", 0),
+    (12, Places, "t_disjoint(interval('2022-01-01T00:00:00Z','2022-12-31T23:59:59Z'),start)", 1),
+    (12, Places, "t_equals(interval('2022-01-01T00:00:00Z','2022-12-31T23:59:59Z'),start)", 0),
+    (12, Places, "t_intersects(interval('2022-01-01T00:00:00Z','2022-12-31T23:59:59Z'),start)", 2),
+    (12, Places, "t_after(interval('2023-01-01T00:00:00Z','..'),interval(start,end))", 2),
+    (12, Places, "t_before(interval('..','2022-04-16T10:13:19Z'),interval(start,end))", 1),
+    (12, Places, "t_disjoint(interval('2022-04-16T10:13:19Z','2022-04-16T10:15:09Z'),interval(start,end))", 1),
+    (12, Places, "t_equals(interval('2021-04-16T10:15:59Z','2022-04-16T10:16:06Z'),interval(start,end))", 1),
+    (12, Places, "t_intersects(interval('2022-04-16T10:13:19Z','2022-04-16T10:15:09Z'),interval(start,end))", 2),
+    (12, Places, "T_CONTAINS(interval('2021-04-16T10:13:19Z','2023-04-16T10:15:10Z'),interval(start,end))", 2),
+    (12, Places, "T_DURING(interval('2022-07-01T00:00:00Z','2022-12-31T23:59:59Z'),interval(start,end))", 1),
+    (12, Places, "T_FINISHES(interval('2022-04-16T10:13:19Z','2022-04-16T10:16:06Z'),interval(start,end))", 1),
+    (12, Places, "T_FINISHEDBY(interval('2022-04-16T10:13:19Z','2022-04-16T10:16:06Z'),interval(start,end))", 0),
+    (12, Places, "T_MEETS(interval('2022-04-16T10:13:19Z','2022-04-16T10:15:10Z'),interval(start,end))", 1),
+    (12, Places, "T_METBY(interval('2022-04-16T10:13:19Z','2022-04-16T10:15:10Z'),interval(start,end))", 0),
+    (12, Places, "T_OVERLAPPEDBY(interval('2020-04-16T10:13:19Z','2022-04-16T10:15:10Z'),interval(start,end))", 0),
+    (12, Places, "T_OVERLAPS(interval('2022-04-16T10:13:19Z','2023-04-16T10:15:10Z'),interval(start,end))", 0),
+    (12, Places, "T_STARTEDBY(interval('2022-04-16T10:13:19Z','2022-04-16T10:15:10Z'),interval(start,end))", 0),
+    (12, Places, "T_STARTS(interval('2022-04-16T10:13:19Z','2022-04-16T10:15:10Z'),interval(start,end))", 1),
+    // A.12 - test_49 COUNTRIES_PREDICATES...
+    (12, Countries, "S_INTERSECTS(BBOX(0,40,10,50),geom)", 8),
+    (12, Countries, "S_INTERSECTS(BBOX(150,-90,-150,90),geom)", 10),
+    (12, Countries, "S_INTERSECTS(POINT(7.02 49.92),geom)", 1),
+    (12, Countries, "S_INTERSECTS(POLYGON((0 40,10 40,10 50,0 50,0 40)),geom)", 8),
+    (12, Countries, "S_INTERSECTS(LINESTRING(0 40,10 50),geom)", 4),
+    (12, Countries, "S_DISJOINT(BBOX(0,40,10,50),geom)", 169),
+    (12, Countries, "S_DISJOINT(POLYGON((0 40,10 40,10 50,0 50,0 40)),geom)", 169),
+    (12, Countries, "S_DISJOINT(LINESTRING(0 40,10 50),geom)", 173),
+    (12, Countries, "S_DISJOINT(POINT(7.02 49.92),geom)", 176),
+    (12, Countries, r#"S_TOUCHES(POLYGON((
+        6.043073357781111 50.128051662794235,
+        6.242751092156993 49.90222565367873,
+        6.186320428094177 49.463802802114515,
+        5.897759230176348 49.44266714130711,
+        5.674051954784829 49.529483547557504,
+        5.782417433300907 50.09032786722122,
+        6.043073357781111 50.128051662794235)),geom)"#, 3),
+    (12, Countries, "S_TOUCHES(POINT(6.043073357781111 50.128051662794235),geom)", 3),
+    (12, Countries, "S_TOUCHES(POINT(6.242751092156993 49.90222565367873),geom)", 2),
+    (12, Countries, r#"S_TOUCHES(LINESTRING(
+        6.043073357781111 50.128051662794235,
+        6.242751092156993 49.90222565367873),geom)"#, 3),
+    (12, Countries, "S_CONTAINS(BBOX(-180,-90,0,90),geom)", 44),
+    (12, Countries, "S_WITHIN(BBOX(7,50,8,51),geom)", 1),
+    (12, Countries, "S_WITHIN(LINESTRING(7 50,8 51),geom)", 1),
+    (12, Countries, "S_WITHIN(POINT(7.02 49.92),geom)", 1),
+    (12, Countries, "S_OVERLAPS(BBOX(-180,-90,0,90),geom)", 11),
+    // A.12 - test_49 RIVERS_PREDICATES...
+    (12, Rivers, "S_INTERSECTS(BBOX(-180,-90,0,90),geom)", 4),
+    (12, Rivers, "S_INTERSECTS(LINESTRING(-60 -90,-60 90),geom)", 2),
+    (12, Rivers, "S_DISJOINT(BBOX(-180,-90,0,90),geom)", 9),
+    (12, Rivers, "S_DISJOINT(LINESTRING(-60 -90,-60 90),geom)", 11),
+    (12, Rivers, "S_CROSSES(BBOX(0,40,10,50),geom)", 1),
+    (12, Rivers, "S_CROSSES(LINESTRING(-60 -90,-60 90),geom)", 2),
+    (12, Rivers, "S_CONTAINS(BBOX(-180,-90,0,90),geom)", 4),
+    // A.14 - test_52 PREDICATES...
+    (14, Countries, "POP_EST=25364300+7", 1),
+    (14, Countries, "25364300+7=POP_EST", 1),
+    (14, Countries, "POP_EST>=1038290-2*2^0", 157),
+    (14, Countries, "POP_EST>1038290-20/10", 157),
+    (14, Countries, "POP_EST>1038290-21 div 10", 157),
+    (14, Countries, "POP_EST>1038290-5%2", 157),
+    (14, Countries, "POP_EST<=1038200+8*11", 20),
+    (14, Countries, "POP_EST<1038280+2^3", 20),
+    (14, Countries, "POP_EST<>25364300+3^2-2", 176),
+    (14, Countries, "POP_EST between 4000000/4 and (3*(900000+100000))", 22),
+    (14, Countries, "POP_EST not between 4000000/4 and (3*(900000+100000))", 155),
+    (14, Countries, r#"POP_EST in (25364300+7,1000000+600000+11692,
+        3*1000000+13258,3*1000000+13257,30*100000+13259)"#, 1),
+    (14, Countries, r#"POP_EST not in (25364300+7,1000000+600000+11692,
+        3*1000000+13258,3*1000000+13257,30*100000+13259)"#, 176),
+    // A.14 - test_53 PREDICATES...
+    (14, Places, "pop_other=1038280+8", 1),
+    (14, Places, "pop_other>=1038290-2*2^0", 123),
+    (14, Places, "pop_other>1038290-20/10", 122),
+    (14, Places, "pop_other>1038290-21 div 10", 122),
+    (14, Places, "pop_other>1038290-5%2", 122),
+    (14, Places, "pop_other<=1038200+8*11", 121),
+    (14, Places, "pop_other<1038280+2^3", 120),
+    (14, Places, "pop_other<>1038290-2^1", 242),
+    (14, Places, "pop_other between 4000000/4 and (3*(900000+100000))", 75),
+    (14, Places, "pop_other not between 4000000/4 and (3*(900000+100000))", 168),
+    (14, Places, r#"pop_other in (1000000+38288,1000000+600000+11692,
+        3*1000000+13258,3*1000000+13257,30*100000+13259)"#, 3),
+    (14, Places, r#"pop_other not in (1000000+38288,1000000+600000+11692,
+        3*1000000+13258,3*1000000+13257,30*100000+13259)"#, 240),
+    (14, Places, "1038280+8=pop_other", 1),
+];
@@ -29,7 +29,7 @@ impl PartialEq for Bound {
             (Bound::Date(x), Bound::Date(y))
             | (Bound::Date(x), Bound::Timestamp(y))
             | (Bound::Timestamp(x), Bound::Date(y))
-            | (Bound::Timestamp(x), Bound::Timestamp(y)) => x == y,
+            | (Bound::Timestamp(x), Bound::Timestamp(y)) => x.timestamp() == y.timestamp(),
             _ => mem::discriminant(self) == mem::discriminant(other),
         }
     }
@@ -59,6 +59,50 @@ impl PartialOrd for Bound {
     }
 }
 
+/// A [Bound] in its role as either the start, or the end, of an _Interval_.
+///
+/// Unlike bare [Bound]s, where the unbounded variant always sorts first, an
+/// unbounded start compares as -infinity while an unbounded end compares as
+/// +infinity; e.g. `INTERVAL('..', '2020-01-01')` is `T_BEFORE` any interval
+/// starting after that date, while `INTERVAL('2020-01-01', '..')` is not.
+#[derive(Debug, Clone, Copy)]
+pub enum Limit<'a> {
+    /// The start, or lower limit, of an _Interval_.
+    Start(&'a Bound),
+    /// The end, or upper limit, of an _Interval_.
+    End(&'a Bound),
+}
+
+impl Limit<'_> {
+    fn bound(&self) -> &Bound {
+        match self {
+            Limit::Start(x) | Limit::End(x) => x,
+        }
+    }
+}
+
+impl PartialEq for Limit<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for Limit<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (Limit::Start(Bound::None), Limit::Start(Bound::None))
+            | (Limit::End(Bound::None), Limit::End(Bound::None)) => Some(Ordering::Equal),
+            // -infinity is less than anything else, and so is anything compared
+            // to +infinity...
+            (Limit::Start(Bound::None), _) | (_, Limit::End(Bound::None)) => Some(Ordering::Less),
+            (Limit::End(Bound::None), _) | (_, Limit::Start(Bound::None)) => {
+                Some(Ordering::Greater)
+            }
+            _ => self.bound().partial_cmp(other.bound()),
+        }
+    }
+}
+
 impl fmt::Display for Bound {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -114,6 +158,42 @@ impl Bound {
         Ok(Bound::Timestamp(z))
     }
 
+    /// Try creating a pair of Bounds, the start and end of an _Interval_, from
+    /// a range string of two instants separated by a `/`; e.g.
+    /// `2020-01-01/2021-12-31`. Each instant is either a well-formed RFC-3339
+    /// date or timestamp string, or `..` (or an empty string) when unbounded.
+    /// Return [MyError] if an error occurs, or the start is after the end.
+    ///
+    /// ```rust
+    /// use ogc_cql2::prelude::*;
+    /// # use std::error::Error;
+    /// # fn test() -> Result<(), Box<dyn Error>> {
+    ///     let (start, end) = Bound::try_new_range("2020-01-01/..")?;
+    ///     assert_eq!(start, Bound::try_new_date("2020-01-01")?);
+    ///     assert_eq!(end, Bound::None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_new_range(s: &str) -> Result<(Self, Self), MyError> {
+        let Some((x, y)) = s.split_once('/') else {
+            return Err(MyError::Runtime(
+                format!("Expected a '/' separated range; got '{s}'").into(),
+            ));
+        };
+        let bound = |x: &str| match x.trim() {
+            "" | ".." => Ok(Bound::None),
+            x if x.contains('T') || x.contains('t') => Bound::try_new_timestamp(x),
+            x => Bound::try_new_date(x),
+        };
+        let (start, end) = (bound(x)?, bound(y)?);
+        if start.as_start() > end.as_end() {
+            return Err(MyError::Runtime(
+                format!("Range '{s}' starts after it ends").into(),
+            ));
+        }
+        Ok((start, end))
+    }
+
     /// Return the inner value in `Some` if this is not the unbound variant.
     /// Return `None` otherwise.
     pub fn as_zoned(&self) -> Option<Zoned> {
@@ -136,10 +216,21 @@ impl Bound {
     }
 
     // Return TRUE if this is an unbound variant, FALSE otherwise.
-    #[cfg(test)]
     pub(crate) fn is_unbound(&self) -> bool {
         matches!(self, Bound::None)
     }
+
+    /// Return this as the start of an _Interval_ where, when unbounded, it
+    /// compares as -infinity.
+    pub fn as_start(&self) -> Limit<'_> {
+        Limit::Start(self)
+    }
+
+    /// Return this as the end of an _Interval_ where, when unbounded, it
+    /// compares as +infinity.
+    pub fn as_end(&self) -> Limit<'_> {
+        Limit::End(self)
+    }
 }
 
 #[cfg(test)]
@@ -170,4 +261,58 @@ mod tests {
         assert_eq!(z1, z2);
         assert!(z1 == z2);
     }
+
+    #[test]
+    fn test_range() -> Result<(), MyError> {
+        let d1 = Bound::try_new_date("2020-01-01")?;
+        let d2 = Bound::try_new_date("2021-12-31")?;
+        let t = Bound::try_new_timestamp("2020-01-01T12:00:00Z")?;
+
+        #[rustfmt::skip]
+        let tv = [
+            ("2020-01-01/2021-12-31",           (d1.clone(), d2.clone())),
+            ("../2021-12-31",                   (Bound::None, d2.clone())),
+            ("2020-01-01/..",                   (d1.clone(), Bound::None)),
+            ("2020-01-01/",                     (d1.clone(), Bound::None)),
+            ("../..",                           (Bound::None, Bound::None)),
+            ("2020-01-01T12:00:00Z/2021-12-31", (t, d2)),
+        ];
+        for (s, expected) in tv {
+            assert_eq!(Bound::try_new_range(s)?, expected, "{s}");
+        }
+
+        // no separator, start after end, and malformed instants are errors...
+        for s in [
+            "2020-01-01",
+            "2021-12-31/2020-01-01",
+            "2020-13-01/..",
+            "a/b",
+        ] {
+            assert!(Bound::try_new_range(s).is_err(), "{s}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_limits() -> Result<(), MyError> {
+        let d = Bound::try_new_date("2020-01-01")?;
+        let open = Bound::None;
+
+        // bare bounds: unbounded always sorts first...
+        assert!(open < d);
+        // but not as an end...
+        assert!(open.as_start() < d.as_start());
+        assert!(open.as_end() > d.as_end());
+        assert!(d.as_end() < open.as_end());
+        assert!(d.as_start() > open.as_start());
+        assert!(open.as_start() < open.as_end());
+        assert!(open.as_start() == open.as_start());
+        assert!(open.as_end() == open.as_end());
+        assert!(open.as_start() != open.as_end());
+        // bounded ones are compared regardless of their role...
+        assert!(d.as_start() == d.as_end());
+
+        Ok(())
+    }
 }
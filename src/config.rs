@@ -12,14 +12,26 @@
 //!    decimal point to output when rendering geometry coordinates in a WKT.
 //!    also used when generating SQL for certain ST functions.
 //!
+//! 3. _Rounding mode_: determine how geometry coordinates are rounded to the
+//!    _default precision_ when ingested, and to a given precision when
+//!    rendered in WKT output.
+//!
+//! 4. _Literals redaction_: determine if literal values in the SQL, and CQL2
+//!    expressions, recorded in data source traces are masked or not.
+//!
+//! 5. _Maximum nesting_: determine how deeply nested an input expression can
+//!    be before it's rejected, w/o being parsed, to protect the stack.
+//!
 
-use crate::{crs::CRS, srid::SRID};
+use crate::{RoundingMode, crs::CRS, srid::SRID};
 use dotenvy::var;
 use std::{sync::OnceLock, time::Duration};
 
 const DEFAULT_SRID: usize = 4326;
 const DEFAULT_PRECISION: &str = "7";
 const MAX_PRECISION: usize = 32;
+// same as `serde_json`'s own recursion limit...
+const DEFAULT_MAX_NESTING: &str = "128";
 
 #[derive(Debug)]
 pub(crate) struct Config {
@@ -27,6 +39,9 @@ pub(crate) struct Config {
     default_srid: SRID,
     default_crs: String,
     default_precision: usize,
+    rounding_mode: Option<RoundingMode>,
+    redact_literals: bool,
+    max_nesting: usize,
 
     // PostgreSQL parameters...
     pg_url: String,
@@ -64,6 +79,20 @@ impl Default for Config {
             panic!("Invalid ({value}) default precision. MUST be <= {MAX_PRECISION}");
         }
 
+        let rounding_mode = var("ROUNDING_MODE").ok().map(|x| {
+            x.parse::<RoundingMode>()
+                .expect("Failed parsing ROUNDING_MODE")
+        });
+
+        let redact_literals = var("REDACT_LITERALS")
+            .map(|x| x.parse::<bool>().expect("Failed parsing REDACT_LITERALS"))
+            .unwrap_or(false);
+
+        let max_nesting: usize = var("MAX_NESTING")
+            .unwrap_or(DEFAULT_MAX_NESTING.to_owned())
+            .parse()
+            .expect("Failed parsing MAX_NESTING");
+
         let pg_url = var("PG_URL").expect("Missing PG_URL");
         let pg_max_connections: u32 = var("PG_MAX_CONNECTIONS")
             .unwrap_or("8".to_string())
@@ -96,6 +125,9 @@ impl Default for Config {
             default_srid,
             default_crs,
             default_precision: value,
+            rounding_mode,
+            redact_literals,
+            max_nesting,
             pg_url,
             pg_max_connections,
             pg_min_connections,
@@ -127,6 +159,26 @@ impl Config {
         self.default_precision
     }
 
+    /// Return the configured mode to use when rounding geometry coordinates
+    /// if any.
+    pub(crate) fn rounding_mode(&self) -> Option<RoundingMode> {
+        self.rounding_mode
+    }
+
+    /// Return TRUE if literal values should be masked when tracing the SQL,
+    /// and CQL2 expressions, data sources use to filter their _Features_.
+    pub(crate) fn redact_literals(&self) -> bool {
+        self.redact_literals
+    }
+
+    /// Return the maximum nesting of input expressions; i.e. of brackets,
+    /// incl. those of function calls, in CQL2 text, or of objects and arrays
+    /// in CQL2 JSON (halved), beyond which they're rejected w/o being parsed.
+    /// Flat `AND` / `OR` chains don't count however long they are.
+    pub(crate) fn max_nesting(&self) -> usize {
+        self.max_nesting
+    }
+
     pub(crate) fn pg_url(&self) -> &str {
         &self.pg_url
     }
@@ -5,22 +5,70 @@
 //! Expressions evaluation context.
 //!
 
-use crate::{ExtDataType, FnInfo, MyError, add_builtins, crs::CRS};
+use crate::{
+    CaseFolding, ExtDataType, FnInfo, MyError, NormalizationForm, Q, ResourceRef, add_builtins,
+    config::config, crs::CRS,
+};
 use core::fmt;
-use std::{any::Any, collections::HashMap, rc::Rc};
+use icu_collator::{Collator, CollatorBorrowed, options::CollatorOptions};
+use icu_locale_core::Locale;
+use jiff::tz::TimeZone;
+use std::{any::Any, collections::HashMap, rc::Rc, time::Duration};
+use tracing::{error, warn};
+
+/// Default character used to escape wildcards in LIKE patterns.
+pub(crate) const DEFAULT_LIKE_ESCAPE: char = '\\';
+
+/// Type of a closure deriving the value of a _computed_ property from the
+/// other properties of a _Resource_. See [`Context::with_computed()`].
+pub type ComputedFn = dyn Fn(&dyn ResourceRef) -> Q + Send + Sync;
 
 /// A _Context_ object we will be handing to [evaluators][crate::Evaluator] so they are aware of
 /// external registered _Functions_.
 pub struct Context {
     crs: CRS,
+    filter_crs: Option<CRS>,
+    like_escape: char,
+    tz: TimeZone,
+    coercion: bool,
+    spatial_tolerance: Option<f64>,
+    numeric_epsilon: Option<f64>,
+    temporal_tolerance: Option<Duration>,
+    normalization: NormalizationForm,
+    case_folding: CaseFolding,
+    collation: Option<(String, CollatorBorrowed<'static>)>,
+    auto_repair: bool,
+    antimeridian: bool,
+    strict_properties: bool,
+    unchecked_coordinates: bool,
+    empty_as_null: bool,
+    max_depth: Option<usize>,
     pub(crate) functions: HashMap<String, FnInfo>,
+    computed: HashMap<String, Box<ComputedFn>>,
 }
 
 impl fmt::Debug for Context {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Context")
             .field("crs", &self.crs)
+            .field("filter_crs", &self.filter_crs)
+            .field("like_escape", &self.like_escape)
+            .field("tz", &self.tz)
+            .field("coercion", &self.coercion)
+            .field("spatial_tolerance", &self.spatial_tolerance)
+            .field("numeric_epsilon", &self.numeric_epsilon)
+            .field("temporal_tolerance", &self.temporal_tolerance)
+            .field("normalization", &self.normalization)
+            .field("case_folding", &self.case_folding)
+            .field("collation", &self.collation())
+            .field("auto_repair", &self.auto_repair)
+            .field("antimeridian", &self.antimeridian)
+            .field("strict_properties", &self.strict_properties)
+            .field("unchecked_coordinates", &self.unchecked_coordinates)
+            .field("empty_as_null", &self.empty_as_null)
+            .field("max_depth", &self.max_depth)
             .field("functions", &self.functions)
+            .field("computed", &self.computed.keys().collect::<Vec<_>>())
             .finish()
     }
 }
@@ -37,19 +85,278 @@ impl Context {
     pub fn new() -> Self {
         Context {
             crs: CRS::default(),
+            filter_crs: None,
+            like_escape: DEFAULT_LIKE_ESCAPE,
+            tz: TimeZone::UTC,
+            coercion: false,
+            spatial_tolerance: None,
+            numeric_epsilon: None,
+            temporal_tolerance: None,
+            normalization: NormalizationForm::default(),
+            case_folding: CaseFolding::default(),
+            collation: None,
+            auto_repair: false,
+            antimeridian: false,
+            strict_properties: false,
+            unchecked_coordinates: false,
+            empty_as_null: false,
+            max_depth: Some(2 * config().max_nesting()),
             functions: HashMap::with_capacity(5),
+            computed: HashMap::new(),
         }
     }
 
     /// Create a new instance w/ an implicit CRS specified by a given code.
     /// Use this method to override the global default CRS code configured by
     /// setting the environment variable `DEFAULT_CRS`.
+    ///
+    /// The CRS, and its extent of validity used for checking the coordinates
+    /// of geometries, are resolved here. An unknown or unsupported CRS code
+    /// fails immediately rather than later when evaluating expressions.
     pub fn try_with_crs(crs_code: &str) -> Result<Self, MyError> {
         let mut result = Self::new();
         result.crs = CRS::new(crs_code)?;
         Ok(result)
     }
 
+    /// Set the CRS in which the literal geometries of expressions are given;
+    /// e.g. from the `filter-crs` parameter of an _OGC API Features_ request,
+    /// when it differs from the one of the data (see [`crs()`][Self::crs()]).
+    ///
+    /// Literal geometries are then checked against the area-of-use of `crs`
+    /// and [transformed][crate::G::transform()] to the data CRS once, when
+    /// setting up an [Evaluator][crate::Evaluator] or compiling an
+    /// [Expression][crate::Expression]. Property geometries are used as they
+    /// are. IOW, the data CRS wins and spatial predicates are always evaluated
+    /// in that CRS. Both must then be EPSG codes.
+    pub fn with_filter_crs(mut self, crs: CRS) -> Self {
+        self.filter_crs = Some(crs);
+        self
+    }
+
+    /// Set the character to use in LIKE patterns to make the next wildcard
+    /// (`%` or `_`), or itself, a literal. Defaults to `\`.
+    ///
+    /// Return an error if `c` is itself a wildcard.
+    pub fn try_with_like_escape(mut self, c: char) -> Result<Self, MyError> {
+        if c == '%' || c == '_' {
+            let msg = format!("LIKE wildcard '{c}' cannot be used as an escape");
+            error!("Failed: {msg}");
+            return Err(MyError::Runtime(msg.into()));
+        }
+        self.like_escape = c;
+        Ok(self)
+    }
+
+    /// Set the time zone to use when a DATE is compared to a TIMESTAMP. In
+    /// that case the DATE is treated as the half-open interval covering that
+    /// whole civil day in `tz`. Defaults to UTC.
+    pub fn with_time_zone(mut self, tz: TimeZone) -> Self {
+        self.tz = tz;
+        self
+    }
+
+    /// Allow comparing a string to a literal of a different type by first
+    /// [casting][crate::Q::cast()] the string to that type; e.g. so that
+    /// `population > '1000'` compares 2 numbers.
+    ///
+    /// By default, comparisons are strict and such mismatches are errors.
+    pub fn with_implicit_coercion(mut self) -> Self {
+        self.coercion = true;
+        self
+    }
+
+    /// Compare geometries in `S_EQUALS` predicates coordinate by coordinate
+    /// allowing each pair of ordinates to differ by at most `epsilon`; e.g. to
+    /// absorb the floating-point noise introduced by a CRS transformation.
+    ///
+    /// Note that w/ a tolerance the 2 geometries must have the same structure
+    /// and vertices order to be considered equal. By default, there's no
+    /// tolerance and geometries are compared for exact topological equality.
+    ///
+    /// A negative, or non-finite, `epsilon` is ignored.
+    pub fn with_spatial_tolerance(mut self, epsilon: f64) -> Self {
+        if epsilon.is_finite() && epsilon >= 0.0 {
+            self.spatial_tolerance = Some(epsilon);
+        } else {
+            warn!("Ignore invalid spatial tolerance ({epsilon})");
+        }
+        self
+    }
+
+    /// Set the tolerance to use when comparing 2 numbers w/ `=` or `<>`; e.g.
+    /// so that `ratio = 0.1` holds when `ratio` is `0.09999999999999998`. 2
+    /// numbers are then equal if their difference is at most `epsilon`, either
+    /// in absolute terms, or relative to the larger of their magnitudes.
+    ///
+    /// The same tolerance applies when matching a number against the members
+    /// of a `[NOT] IN` list. Note that the other comparison operators (`<`,
+    /// `<=`, `>`, and `>=`) remain strict. By default, there's no tolerance and numbers are
+    /// compared for exact `f64` equality.
+    ///
+    /// A negative, or non-finite, `epsilon` is ignored.
+    pub fn with_numeric_epsilon(mut self, epsilon: f64) -> Self {
+        if epsilon.is_finite() && epsilon >= 0.0 {
+            self.numeric_epsilon = Some(epsilon);
+        } else {
+            warn!("Ignore invalid numeric epsilon ({epsilon})");
+        }
+        self
+    }
+
+    /// Set the tolerance to use when comparing 2 instants for equality; e.g.
+    /// so that `T_EQUALS` holds for `2010-02-10T05:29:20.073225Z` and the same
+    /// timestamp truncated to milliseconds. 2 instants are then equal if they
+    /// are at most `tolerance` apart.
+    ///
+    /// This applies to `T_EQUALS`, as well as to `T_INTERSECTS` and `T_DISJOINT`
+    /// when both arguments are instants. The other temporal predicates (e.g.
+    /// `T_BEFORE` and `T_AFTER`) remain strict. By default, there's no
+    /// tolerance and instants are compared exactly.
+    pub fn with_temporal_tolerance(mut self, tolerance: Duration) -> Self {
+        self.temporal_tolerance = Some(tolerance);
+        self
+    }
+
+    /// Set the Unicode normalization form to use when removing accents from
+    /// strings compared, or matched w/ LIKE, in an accent-insensitive way.
+    /// Defaults to [NFC][NormalizationForm::Nfc].
+    pub fn with_normalization(mut self, form: NormalizationForm) -> Self {
+        self.normalization = form;
+        self
+    }
+
+    /// Set the Unicode case folding to use when comparing strings, or matching
+    /// them w/ LIKE, in a case-insensitive way. Defaults to
+    /// [full][CaseFolding::Full] case folding; e.g. `CASEI(name) =
+    /// casei('STRASSE')` holds when `name` is `Straße`.
+    pub fn with_case_folding(mut self, folding: CaseFolding) -> Self {
+        self.case_folding = folding;
+        self
+    }
+
+    /// Order strings compared w/ `<`, `<=`, `>`, `>=` and `[NOT] BETWEEN`
+    /// according to the collation rules of the given locale, a BCP 47 language
+    /// tag; e.g. `sv` where `å`, `ä` and `ö` sort after `z`. A locale w/o its
+    /// own rules falls back to the root collation. By default, strings are
+    /// ordered by their Unicode code points which is deterministic but rarely
+    /// what end users expect.
+    ///
+    /// Return an error if `locale` is not a well-formed language tag.
+    ///
+    /// NOTE (rsn) 20261016 - only in-process evaluation honours this. SQL
+    /// translations keep using the database collation.
+    pub fn try_with_collation(mut self, locale: &str) -> Result<Self, MyError> {
+        let tag: Locale = locale.parse().map_err(|x| {
+            let msg = format!("Invalid locale '{locale}': {x}");
+            error!("Failed: {msg}");
+            MyError::Runtime(msg.into())
+        })?;
+        let collator = Collator::try_new(tag.into(), CollatorOptions::default()).map_err(|x| {
+            let msg = format!("No collation for locale '{locale}': {x}");
+            error!("Failed: {msg}");
+            MyError::Runtime(msg.into())
+        })?;
+        self.collation = Some((locale.to_owned(), collator));
+        Ok(self)
+    }
+
+    /// Repair invalid geometries, w/ [`G::make_valid()`][crate::G::make_valid()],
+    /// before evaluating spatial predicates; e.g. so that a single _Feature_
+    /// w/ a self-intersecting polygon does not abort a whole stream.
+    ///
+    /// By default, geometries are used as is.
+    pub fn with_auto_repair(mut self) -> Self {
+        self.auto_repair = true;
+        self
+    }
+
+    /// Interpret lines and polygons w/ 2 consecutive vertices more than 180
+    /// degrees of longitude apart as crossing the antimeridian; e.g. a line
+    /// from `170 0` to `-170 0` spans 20 degrees, not 340.
+    ///
+    /// When set, such geometries are split at ±180 before evaluating spatial
+    /// predicates. W/o it, they are handed to GEOS as is, which considers
+    /// them as spanning (most of) the globe. Each coordinate is always checked
+    /// independently against the area-of-use of the CRS.
+    ///
+    /// This only makes sense for geographic coordinates in degrees, w/
+    /// longitudes first. By default, geometries are used as is.
+    pub fn with_antimeridian(mut self) -> Self {
+        self.antimeridian = true;
+        self
+    }
+
+    /// Raise an error when evaluating a reference to a property that a
+    /// _Resource_ does not have; e.g. to catch a typo such as `populaton`
+    /// instead of `population`.
+    ///
+    /// By default, and as mandated by the specs, such a reference evaluates
+    /// to `NULL`. See also [`Expression::validate_against_schema()`][1] to
+    /// catch unknown properties before evaluating any _Resource_.
+    ///
+    /// [1]: crate::Expression::validate_against_schema()
+    pub fn with_strict_properties(mut self) -> Self {
+        self.strict_properties = true;
+        self
+    }
+
+    /// Skip checking that the coordinates of literal geometries fall w/in the
+    /// area-of-use of the CRS; e.g. when the filter is known to be valid and
+    /// the (per-evaluation) cost of that check is not worth paying.
+    ///
+    /// By default, and as mandated by the specs, out-of-range coordinates are
+    /// an error. W/ this set, such geometries are used as is; transforming
+    /// them to another CRS, or evaluating spatial predicates w/ them, may then
+    /// fail w/ a less helpful GEOS or PROJ error, or yield wrong results.
+    pub fn with_unchecked_coordinates(mut self) -> Self {
+        self.unchecked_coordinates = true;
+        self
+    }
+
+    /// Make `IS NULL` hold, and `IS NOT NULL` fail, for empty geometries and
+    /// arrays too; e.g. for data sets that use an empty geometry to mean _no
+    /// location_. See [`G::is_empty()`][crate::G::is_empty()] for what makes
+    /// a geometry empty. An array is empty when it has no items.
+    ///
+    /// By default, and as mandated by the specs, only `NULL` values are. Note
+    /// that this only affects in-process evaluation; i.e. not the SQL the
+    /// database-backed data sources translate filters to.
+    pub fn with_empty_as_null(mut self) -> Self {
+        self.empty_as_null = true;
+        self
+    }
+
+    /// Reject, when setting up an [Evaluator][crate::Evaluator] or compiling
+    /// an [Expression][crate::Expression], those nested more than `n` levels
+    /// deep; e.g. a generated filter w/ thousands of bracketed `AND` / `OR`
+    /// which would otherwise exhaust the stack while being evaluated.
+    ///
+    /// See [`Expression::depth()`][crate::Expression::depth()] for how depth
+    /// is measured. By default, it's twice the configured `MAX_NESTING` of
+    /// input expressions (i.e. 256) since predicates and function calls add
+    /// levels to those.
+    pub fn with_max_depth(mut self, n: usize) -> Self {
+        self.max_depth = Some(n);
+        self
+    }
+
+    /// Add a _computed_ property named `name` whose value is derived by the
+    /// given closure from the _Resource_ being evaluated; e.g. a precomputed
+    /// `distance_to_poi`. Filters can then reference it as any other property
+    /// w/o modifying the source data.
+    ///
+    /// The closure is only invoked when an expression references `name` and
+    /// the _Resource_ has no stored property w/ that name, which takes
+    /// precedence.
+    pub fn with_computed<F>(mut self, name: &str, closure: F) -> Self
+    where
+        F: Fn(&dyn ResourceRef) -> Q + Send + Sync + 'static,
+    {
+        self.computed.insert(name.to_owned(), Box::new(closure));
+        self
+    }
+
     /// Register a Function (Rust Closure) by name with expected argument(s)
     /// and result types.
     pub fn register<F>(
@@ -61,11 +368,30 @@ impl Context {
     ) where
         F: Fn(Vec<Box<dyn Any>>) -> Option<Box<dyn Any>> + Send + Sync + 'static,
     {
+        let required = arg_types.len();
+        self.register_with_optional(name, arg_types, required, result_type, closure);
+    }
+
+    /// Similar to [`register()`][Self::register()] but only the first `required`
+    /// arguments must be present in a call. The remaining ones are optional
+    /// and when omitted the closure is invoked w/ fewer arguments.
+    pub fn register_with_optional<F>(
+        &mut self,
+        name: &str,
+        arg_types: Vec<ExtDataType>,
+        required: usize,
+        result_type: ExtDataType,
+        closure: F,
+    ) where
+        F: Fn(Vec<Box<dyn Any>>) -> Option<Box<dyn Any>> + Send + Sync + 'static,
+    {
+        let required = required.min(arg_types.len());
         self.functions.insert(
             name.to_string(),
             FnInfo {
                 closure: Box::new(closure),
                 arg_types,
+                required,
                 result_type,
             },
         );
@@ -82,6 +408,108 @@ impl Context {
         &self.crs
     }
 
+    /// Return the CRS of the literal geometries in expressions if one was set
+    /// w/ [`with_filter_crs()`][Self::with_filter_crs()]; `None` otherwise.
+    pub fn filter_crs(&self) -> Option<&CRS> {
+        self.filter_crs.as_ref()
+    }
+
+    /// Return the character used in LIKE patterns to make the next wildcard
+    /// (`%` or `_`), or itself, a literal. Defaults to `\`.
+    pub fn like_escape(&self) -> char {
+        self.like_escape
+    }
+
+    /// Return the time zone used when comparing DATEs to TIMESTAMPs.
+    pub fn time_zone(&self) -> &TimeZone {
+        &self.tz
+    }
+
+    /// Return TRUE if strings are implicitly cast in mixed-type comparisons.
+    pub fn implicit_coercion(&self) -> bool {
+        self.coercion
+    }
+
+    /// Return the tolerance used when comparing geometries for equality if
+    /// one was set; `None` otherwise.
+    pub fn spatial_tolerance(&self) -> Option<f64> {
+        self.spatial_tolerance
+    }
+
+    /// Return the tolerance used when comparing numbers for equality if one
+    /// was set; `None` otherwise.
+    pub fn numeric_epsilon(&self) -> Option<f64> {
+        self.numeric_epsilon
+    }
+
+    /// Return the tolerance used when comparing instants for equality if one
+    /// was set; `None` otherwise.
+    pub fn temporal_tolerance(&self) -> Option<Duration> {
+        self.temporal_tolerance
+    }
+
+    /// Return the maximum depth of expressions accepted w/in this if any.
+    pub fn max_depth(&self) -> Option<usize> {
+        self.max_depth
+    }
+
+    /// Return the Unicode normalization form used when ignoring accents.
+    pub fn normalization(&self) -> NormalizationForm {
+        self.normalization
+    }
+
+    /// Return the Unicode case folding used when ignoring case.
+    pub fn case_folding(&self) -> CaseFolding {
+        self.case_folding
+    }
+
+    /// Return the locale whose collation rules order strings if one was set;
+    /// `None` otherwise.
+    pub fn collation(&self) -> Option<&str> {
+        self.collation.as_ref().map(|(locale, _)| locale.as_str())
+    }
+
+    // the collator to use when ordering strings if a collation was set...
+    pub(crate) fn collator(&self) -> Option<&CollatorBorrowed<'static>> {
+        self.collation.as_ref().map(|(_, collator)| collator)
+    }
+
+    /// Return TRUE if invalid geometries are repaired before evaluating spatial
+    /// predicates; FALSE otherwise.
+    pub fn auto_repair(&self) -> bool {
+        self.auto_repair
+    }
+
+    /// Return TRUE if geometries crossing the antimeridian are split at ±180
+    /// before evaluating spatial predicates; FALSE otherwise.
+    pub fn antimeridian(&self) -> bool {
+        self.antimeridian
+    }
+
+    /// Return TRUE if referencing a property unknown to a _Resource_ is an
+    /// error; FALSE otherwise.
+    pub fn strict_properties(&self) -> bool {
+        self.strict_properties
+    }
+
+    /// Return TRUE if the coordinates of literal geometries are checked against
+    /// the area-of-use of the CRS; FALSE otherwise.
+    pub fn coordinate_check(&self) -> bool {
+        !self.unchecked_coordinates
+    }
+
+    /// Return TRUE if empty geometries and arrays are considered `NULL` by
+    /// `IS NULL` and `IS NOT NULL`; FALSE otherwise.
+    pub fn empty_as_null(&self) -> bool {
+        self.empty_as_null
+    }
+
+    /// Return the closure deriving the computed property named `name` if one
+    /// was added; `None` otherwise.
+    pub fn computed(&self, name: &str) -> Option<&ComputedFn> {
+        self.computed.get(name).map(|x| x.as_ref())
+    }
+
     /// Return meta-information about a Function already registered in this.
     pub fn fn_info(&self, name: &str) -> Option<&FnInfo> {
         self.functions.get(name)
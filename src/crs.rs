@@ -6,11 +6,39 @@
 //! Coordinate Reference System (CRS) types and traits in this library.
 //!
 
-use crate::{MyError, config::config};
+use crate::{MyError, config::config, srid::SRID};
 use core::fmt;
+#[cfg(feature = "geos")]
 use proj::Proj;
-use std::{num::NonZero, ops::RangeInclusive};
-use tracing::info;
+use std::{
+    collections::HashMap,
+    num::NonZero,
+    ops::RangeInclusive,
+    sync::{Arc, Mutex, OnceLock},
+};
+#[cfg(feature = "geos")]
+use tracing::error;
+use tracing::{debug, info};
+
+// Process-wide cache of the extent of validity of already resolved CRSes
+// keyed by their (normalized) code.
+static EOV_CACHE: OnceLock<Mutex<HashMap<String, Arc<EoV>>>> = OnceLock::new();
+
+// Return the key of `code` in the cache. `AUTHORITY:CODE` pairs are
+// case-insensitive; other definitions; e.g. PROJ strings, are used as is...
+fn cache_key(code: &str) -> String {
+    let code = code.trim();
+    match code.split_once(':') {
+        Some((authority, id))
+            if !authority.is_empty()
+                && authority.chars().all(|c| c.is_ascii_alphanumeric())
+                && !id.contains(char::is_whitespace) =>
+        {
+            code.to_ascii_uppercase()
+        }
+        _ => code.to_owned(),
+    }
+}
 
 #[derive(Debug)]
 struct EoV {
@@ -20,13 +48,15 @@ struct EoV {
     y_range: RangeInclusive<f64>,
 }
 
-/// Representation of a Coordinate Reference System
-#[derive(Debug)]
+/// Representation of a Coordinate Reference System.
+///
+/// Cloning an instance is cheap. Its extent of validity is resolved once per
+/// process and shared by all instances constructed from the same code.
+#[derive(Debug, Clone)]
 #[allow(clippy::upper_case_acronyms)]
 pub struct CRS {
     definition: String,
-    inner: Proj,
-    extent_of_validity: EoV,
+    extent_of_validity: Arc<EoV>,
 }
 
 impl fmt::Display for CRS {
@@ -47,24 +77,75 @@ impl CRS {
     /// Try constructing a new instance and ensure that CRS has a non-trivial
     /// extent of validity which will be later used to validate geometry
     /// coordinates.
+    ///
+    /// The extent is resolved by Proj the first time a given `code` is used,
+    /// and cached for the lifetime of the process. `AUTHORITY:CODE` pairs are
+    /// case-insensitive; e.g. `epsg:4326` and `EPSG:4326` share the same
+    /// entry. Return an error if `code` is unknown to, or unsupported by,
+    /// Proj, or if it has no known area-of-use. Errors are not cached.
+    ///
+    /// W/o the `geos` feature Proj is not available; `code` is then accepted
+    /// as is and the extent of validity is unbounded.
     pub fn new(code: &str) -> Result<Self, MyError> {
-        let inner = Proj::new(code)?;
-        let definition = code.into();
+        let key = cache_key(code);
+        let cache = EOV_CACHE.get_or_init(Default::default);
+        let cached = cache
+            .lock()
+            .unwrap_or_else(|x| x.into_inner())
+            .get(&key)
+            .cloned();
+        let extent_of_validity = match cached {
+            Some(x) => x,
+            None => {
+                // NOTE (rsn) 20261016 - don't hold the lock while Proj does
+                // its thing.  a concurrent miss for the same code is harmless;
+                // first one in wins...
+                let eov = Arc::new(Self::resolve(code)?);
+                debug!("Caching extent of validity of CRS '{key}'");
+                cache
+                    .lock()
+                    .unwrap_or_else(|x| x.into_inner())
+                    .entry(key)
+                    .or_insert(eov)
+                    .clone()
+            }
+        };
+
+        Ok(CRS {
+            definition: code.into(),
+            extent_of_validity,
+        })
+    }
+
+    // Resolve the extent of validity of the CRS w/ the given `code`...
+    #[cfg(feature = "geos")]
+    fn resolve(code: &str) -> Result<EoV, MyError> {
+        let inner = Proj::new(code).inspect_err(|x| {
+            error!("Failed resolving CRS '{code}': {x}");
+        })?;
         let (mb_eov, _mb_def) = inner.area_of_use()?;
         // tracing::trace!("area-of-use for '{definition}' = {mb_eov:?}, '{_mb_def:?}'");
         // for now reject input w/ no known validity-extent bounds...
-        let eov = mb_eov.expect("CRSes w/ no known Area-of-Use are not supported. Abort");
-        let extent_of_validity = EoV {
+        let Some(eov) = mb_eov else {
+            return Err(MyError::Runtime(
+                format!("CRS '{code}' has no known Area-of-Use and is not supported").into(),
+            ));
+        };
+        Ok(EoV {
             x_range: RangeInclusive::new(eov.west, eov.east),
             y_range: RangeInclusive::new(eov.south, eov.north),
-        };
-        let crs = CRS {
-            definition,
-            inner,
-            extent_of_validity,
-        };
+        })
+    }
 
-        Ok(crs)
+    // w/o Proj there's nothing to resolve `code` against; accept it w/ an
+    // unbounded extent of validity...
+    #[cfg(not(feature = "geos"))]
+    fn resolve(code: &str) -> Result<EoV, MyError> {
+        debug!("Proj not available. Assume CRS '{code}' is unbounded");
+        Ok(EoV {
+            x_range: RangeInclusive::new(f64::NEG_INFINITY, f64::INFINITY),
+            y_range: RangeInclusive::new(f64::NEG_INFINITY, f64::INFINITY),
+        })
     }
 
     /// Construct a new instance from the given code assuming EPSG Authority
@@ -73,6 +154,32 @@ impl CRS {
         Self::new(&format!("EPSG:{code}"))
     }
 
+    /// Return the code, or definition, this was constructed from.
+    pub fn definition(&self) -> &str {
+        &self.definition
+    }
+
+    // Return the SRID of this if its definition is an EPSG code.
+    pub(crate) fn srid(&self) -> Result<SRID, MyError> {
+        let code = self
+            .definition
+            .split_once(':')
+            .filter(|(authority, _)| authority.eq_ignore_ascii_case("EPSG"))
+            .and_then(|(_, code)| code.trim().parse::<usize>().ok())
+            .ok_or_else(|| MyError::Runtime(format!("CRS '{self}' is not an EPSG code").into()))?;
+        SRID::try_from(code)
+    }
+
+    // Return a Proj pipeline transforming coordinates from this to `to`. axes
+    // are normalized for visualization; i.e. longitude/easting first...
+    #[cfg(feature = "geos")]
+    pub(crate) fn pipeline_to(&self, to: &CRS) -> Result<Proj, MyError> {
+        let it = Proj::new_known_crs(&self.definition, &to.definition, None).inspect_err(|x| {
+            error!("Failed creating '{self}' -> '{to}' pipeline: {x}");
+        })?;
+        Ok(it)
+    }
+
     /// Check if the given point coordinates are w/in the area-of-validity of this.
     pub fn check_point(&self, coord: &[f64]) -> Result<(), MyError> {
         // FIXME (rsn) 2250807 - so far we only handle 2D coordinates...
@@ -115,9 +222,51 @@ impl CRS {
 
 #[cfg(test)]
 mod tests {
-    use proj::Proj;
+    use super::*;
+    use crate::Context;
+
+    #[test]
+    #[cfg(feature = "geos")]
+    fn test_unsupported_crs() {
+        assert!(CRS::new("EPSG:4326").is_ok());
+        // unknown to Proj...
+        assert!(matches!(CRS::new("EPSG:999999"), Err(MyError::CRS(_))));
+        assert!(Context::try_with_crs("FOO:1234").is_err());
+        // known but w/o an Area-of-Use...
+        assert!(CRS::new("+proj=longlat +datum=WGS84 +no_defs").is_err());
+    }
+
+    #[test]
+    fn test_cache() -> Result<(), MyError> {
+        let a = CRS::new("EPSG:3857")?;
+        let b = CRS::new("epsg:3857")?;
+        let c = a.clone();
+        assert!(Arc::ptr_eq(&a.extent_of_validity, &b.extent_of_validity));
+        assert!(Arc::ptr_eq(&a.extent_of_validity, &c.extent_of_validity));
+        // the definition is kept as given...
+        assert_eq!(b.definition(), "epsg:3857");
+
+        let d = CRS::new("EPSG:32632")?;
+        assert!(!Arc::ptr_eq(&a.extent_of_validity, &d.extent_of_validity));
+
+        // errors are not cached...
+        #[cfg(feature = "geos")]
+        {
+            assert!(CRS::new("EPSG:999999").is_err());
+            assert!(CRS::new("EPSG:999999").is_err());
+        }
+
+        assert_eq!(cache_key(" epsg:4326 "), "EPSG:4326");
+        assert_eq!(
+            cache_key("+proj=longlat +datum=WGS84"),
+            "+proj=longlat +datum=WGS84"
+        );
+
+        Ok(())
+    }
 
     #[test]
+    #[cfg(feature = "geos")]
     fn test_name() {
         let epsg_4326 = Proj::new("EPSG:4326").unwrap();
         let (aou, _) = epsg_4326.area_of_use().unwrap();
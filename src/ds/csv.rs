@@ -5,44 +5,523 @@
 //! Geospatial data stored in CSV files.
 //!
 
-use crate::ds::DataSource;
-use std::path::PathBuf;
+use crate::{
+    Bound, Context, Expression, G, GTrait, MyError, Q, Resource,
+    config::config,
+    ds::{
+        ContextFactory, DataSource, filter_stream,
+        gpkg::{SQLiteSQL, double_quoted, sqlite_options},
+        trace::{redact_literals, traced},
+    },
+};
+use ::csv::{Reader, ReaderBuilder, StringRecord};
+use core::fmt;
+use futures::{
+    StreamExt,
+    stream::{self, BoxStream},
+};
+use serde::de::DeserializeOwned;
+use sqlx::{AssertSqlSafe, Pool, Sqlite, pool::PoolOptions};
+use std::{
+    borrow::Cow,
+    collections::BTreeSet,
+    fs::File,
+    io::{self, Read},
+    path::PathBuf,
+    sync::Mutex,
+};
+use tokio::sync::OnceCell;
+use tracing::{Instrument, debug, debug_span};
+
+/// Name of the column, or _Feature_ field, expected to hold a geometry's WKT.
+pub const DEFAULT_GEOM_COLUMN: &str = "geom";
+
+/// Name of the in-memory SQLite table holding a copy of a CSV file's rows.
+const SHIM_TABLE: &str = "csv";
+/// Name of the column holding the (0-based) index of a row in the CSV file.
+const SHIM_INDEX: &str = "_idx";
+
+// Factory of readers over the contents of a CSV data source; invoked every
+// time the rows are read...
+type OpenFn = dyn Fn() -> io::Result<Box<dyn Read + Send>> + Send + Sync;
 
 /// [`DataSource`] of _Features_ and [Resources][crate::Resource] mapped from CSV rows/records.
-#[derive(Debug)]
 pub struct CSVDataSource {
-    path: PathBuf,
+    path: Option<PathBuf>,
+    open: Box<OpenFn>,
+    delimiter: u8,
+    quote: u8,
+    geom_column: String,
+    srid: Option<u32>,
+    sql_shim: bool,
+    shim: OnceCell<SQLiteShim>,
+    context: ContextFactory,
+}
+
+// In-memory SQLite copy of a CSV file loaded once on first use.
+#[derive(Debug)]
+struct SQLiteShim {
+    pool: Pool<Sqlite>,
+    headers: StringRecord,
+    records: Vec<StringRecord>,
+    // names of the columns w/ at least one value stored as NULL for lack of
+    // an SQL equivalent...
+    nulled: BTreeSet<String>,
+}
+
+impl fmt::Debug for CSVDataSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CSVDataSource")
+            .field("path", &self.path)
+            .field("delimiter", &self.delimiter)
+            .field("quote", &self.quote)
+            .field("geom_column", &self.geom_column)
+            .field("srid", &self.srid)
+            .field("sql_shim", &self.sql_shim)
+            .field("shim", &self.shim)
+            .field("context", &self.context)
+            .finish_non_exhaustive()
+    }
 }
 
 impl DataSource for CSVDataSource {
     fn srid(&self) -> Option<u32> {
-        None
+        self.srid
     }
 }
 
 impl CSVDataSource {
     /// Constructor given the file system location of an accessible CSV file.
+    ///
+    /// Fields are assumed to be separated by commas, quoted w/ double-quotes,
+    /// and the geometry WKT to be in a column named `geom`.
     pub fn from(s: &str) -> Self {
-        Self { path: s.into() }
+        let path = PathBuf::from(s);
+        let p = path.clone();
+        let mut result = Self::from_fn(move || File::open(&p));
+        result.path = Some(path);
+        result
+    }
+
+    /// Constructor given a function that returns a new reader over the CSV
+    /// data every time it's called; e.g. one that opens a `gzip` compressed
+    /// file and wraps it in a decompressor.
+    ///
+    /// The same defaults as those of [`from()`][Self::from()] apply.
+    pub fn from_fn<F, R>(open: F) -> Self
+    where
+        F: Fn() -> io::Result<R> + Send + Sync + 'static,
+        R: Read + Send + 'static,
+    {
+        Self {
+            path: None,
+            open: Box::new(move || Ok(Box::new(open()?) as Box<dyn Read + Send>)),
+            delimiter: b',',
+            quote: b'"',
+            geom_column: DEFAULT_GEOM_COLUMN.into(),
+            srid: None,
+            sql_shim: false,
+            shim: OnceCell::new(),
+            context: ContextFactory::default(),
+        }
+    }
+
+    /// Constructor given a `reader` over the CSV data; e.g. an in-memory
+    /// buffer or a network stream.
+    ///
+    /// Note though that such reader can only be consumed once. Reading the
+    /// rows of this again fails unless this was configured w/
+    /// [`with_sql_shim()`][Self::with_sql_shim()], in which case they're read
+    /// once when loading the shim. Use [`from_fn()`][Self::from_fn()] instead
+    /// when the data can be read again from the start.
+    ///
+    /// The same defaults as those of [`from()`][Self::from()] apply.
+    pub fn from_reader<R: Read + Send + 'static>(reader: R) -> Self {
+        let reader = Mutex::new(Some(reader));
+        Self::from_fn(move || {
+            reader
+                .lock()
+                .map_err(|_| io::Error::other("Poisoned CSV reader"))?
+                .take()
+                .ok_or_else(|| io::Error::other("CSV reader already consumed"))
+        })
+    }
+
+    /// Use `delimiter` as the field separator; e.g. `b'\t'` for TSV files.
+    pub fn with_delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Use `quote` as the quoting character.
+    pub fn with_quote(mut self, quote: u8) -> Self {
+        self.quote = quote;
+        self
+    }
+
+    /// Use `name` as the column holding the geometry WKT.
+    ///
+    /// The column is presented to the _Feature_ deserializer as `geom` so
+    /// the same `TryFrom<Feature> for Resource` conversion logic works
+    /// regardless of how the column is actually named in the file.
+    pub fn with_geom_column(mut self, name: &str) -> Self {
+        self.geom_column = name.into();
+        self
+    }
+
+    /// Set the EPSG code of the CRS the geometries of this are in. When not
+    /// set, the configured default SRID is assumed.
+    pub fn with_srid(mut self, srid: u32) -> Self {
+        self.srid = Some(srid);
+        self
+    }
+
+    /// Delegate filtering to SQLite.
+    ///
+    /// When set, the first call to [`select_where()`][Self::select_where()]
+    /// converts all the rows to [Resources][Resource] and loads them into an
+    /// in-memory SQLite (+ `spatialite`) table, and this and every subsequent
+    /// call translate the filter [Expression] into an SQL _WHERE_ clause the
+    /// same way it's done for _GeoPackage_ data sources. This is a lot faster
+    /// when querying the same CSV file repeatedly.
+    ///
+    /// The table has a column per [Resource] key, so filters refer to the
+    /// same names whether they're evaluated in-process or not. Geometries are
+    /// stored as `spatialite` ones w/ the SRID of this, or the configured
+    /// default one. Temporal values are stored as ISO 8601 text, while arrays
+    /// and intervals, which have no SQL equivalent, are stored as `NULL`.
+    ///
+    /// Filters that cannot be translated to SQL, or that reference a column
+    /// holding any such array or interval, are evaluated in-process over the
+    /// rows already loaded into the shim.
+    pub fn with_sql_shim(mut self) -> Self {
+        self.sql_shim = true;
+        self
+    }
+
+    /// Evaluate filters in-process w/in a [Context] returned by `f` instead of
+    /// the default one; e.g. one w/ a different CRS, LIKE escape character,
+    /// or registered functions.
+    ///
+    /// `f` is called every time rows are filtered in-process.
+    pub fn with_context<F>(mut self, f: F) -> Self
+    where
+        F: Fn() -> Context + Send + Sync + 'static,
+    {
+        self.context = ContextFactory::new(f);
+        self
+    }
+
+    /// Return TRUE if filtering is delegated to an in-memory SQLite table.
+    pub fn uses_sql_shim(&self) -> bool {
+        self.sql_shim
+    }
+
+    /// Return this CSV data source path if it was constructed from one.
+    pub fn path(&self) -> Option<&PathBuf> {
+        self.path.as_ref()
+    }
+
+    /// Return the field separator of this.
+    pub fn delimiter(&self) -> u8 {
+        self.delimiter
+    }
+
+    /// Return the quoting character of this.
+    pub fn quote(&self) -> u8 {
+        self.quote
+    }
+
+    /// Return the name of the column holding the geometry WKT.
+    pub fn geom_column(&self) -> &str {
+        &self.geom_column
+    }
+
+    // return the SRID of this, or the configured default one...
+    fn srid_or_default(&self) -> Result<i32, MyError> {
+        match self.srid {
+            Some(x) => Ok(i32::try_from(x)?),
+            None => Ok(config().default_srid().into_inner()),
+        }
+    }
+
+    /// Return a CSV reader configured w/ this delimiter and quote characters,
+    /// and w/ the geometry column (if different) renamed to `geom`.
+    pub fn reader(&self) -> Result<Reader<Box<dyn Read + Send>>, MyError> {
+        let mut rdr = ReaderBuilder::new()
+            .delimiter(self.delimiter)
+            .quote(self.quote)
+            .from_reader((self.open)()?);
+        if self.geom_column != DEFAULT_GEOM_COLUMN {
+            let headers: StringRecord = rdr
+                .headers()?
+                .iter()
+                .map(|h| {
+                    if h == self.geom_column {
+                        DEFAULT_GEOM_COLUMN
+                    } else {
+                        h
+                    }
+                })
+                .collect();
+            rdr.set_headers(headers);
+        }
+        Ok(rdr)
     }
 
-    /// Return this CSV data source path.
-    pub fn path(&self) -> &PathBuf {
-        &self.path
+    /// Return a stream of the _Features_ of this satisfying a CQL2 filter
+    /// [Expression].
+    ///
+    /// Unless this was configured w/ [`with_sql_shim()`][Self::with_sql_shim()],
+    /// every row is deserialized and converted to a [Resource] to be evaluated
+    /// in-process w/in the [Context] set w/ [`with_context()`][Self::with_context()],
+    /// or the default one.
+    ///
+    /// Both paths are traced w/in a `select_where` span recording, once the
+    /// stream is exhausted or dropped, the number of rows matched and the time
+    /// it took.
+    pub async fn select_where<'a, F>(
+        &'a self,
+        exp: &Expression,
+    ) -> Result<BoxStream<'a, Result<F, MyError>>, MyError>
+    where
+        F: DeserializeOwned + TryInto<Resource, Error = MyError> + Send + 'a,
+    {
+        let span = debug_span!("select_where", cql = %redact_literals(&exp.to_string()));
+        let it = if self.sql_shim {
+            self.shim_select_where(exp).instrument(span.clone()).await?
+        } else {
+            span.in_scope(|| self.evaluate_where(exp))?
+        };
+        Ok(traced(span, it))
+    }
+
+    // filter the rows of this delegating to its SQLite shim...
+    async fn shim_select_where<'a, F>(
+        &'a self,
+        exp: &Expression,
+    ) -> Result<BoxStream<'a, Result<F, MyError>>, MyError>
+    where
+        F: DeserializeOwned + TryInto<Resource, Error = MyError> + Send + 'a,
+    {
+        let shim = self.shim.get_or_try_init(|| self.load_shim::<F>()).await?;
+        let where_clause = match SQLiteSQL::new(&self.context.make()).to_sql(exp) {
+            Ok(x) => x,
+            Err(MyError::Unsupported(x)) => {
+                debug!("Not delegating to SQLite shim: {x}");
+                return self.filter_records(exp, shim.headers.clone(), shim_records(shim));
+            }
+            Err(x) => return Err(x),
+        };
+        // NULL columns would yield wrong matches; e.g. `tags IS NULL`...
+        if let Some(name) = exp.properties().iter().find(|x| shim.nulled.contains(*x)) {
+            debug!("Not delegating to SQLite shim: '{name}' has no SQL equivalent");
+            return self.filter_records(exp, shim.headers.clone(), shim_records(shim));
+        }
+        let sql = format!(
+            r#"SELECT "{SHIM_INDEX}" FROM "{SHIM_TABLE}" WHERE {where_clause} ORDER BY "{SHIM_INDEX}""#
+        );
+        debug!(sql = %redact_literals(&sql), "Delegate to SQLite shim");
+        let it = sqlx::query_scalar::<_, i64>(AssertSqlSafe(sql))
+            .fetch(&shim.pool)
+            .map(move |x| -> Result<F, MyError> {
+                let ndx = usize::try_from(x?)?;
+                let record = shim
+                    .records
+                    .get(ndx)
+                    .ok_or_else(|| MyError::Runtime(format!("Unknown row #{ndx}").into()))?;
+                Ok(record.deserialize(Some(&shim.headers))?)
+            });
+        Ok(it.boxed())
+    }
+
+    // filter the rows of this in-process...
+    fn evaluate_where<'a, F>(
+        &'a self,
+        exp: &Expression,
+    ) -> Result<BoxStream<'a, Result<F, MyError>>, MyError>
+    where
+        F: DeserializeOwned + TryInto<Resource, Error = MyError> + Send + 'a,
+    {
+        let mut rdr = self.reader()?;
+        let headers = rdr.headers()?.clone();
+        let records = stream::iter(rdr.into_records().map(|x| x.map_err(MyError::from)));
+        self.filter_records(exp, headers, records.boxed())
+    }
+
+    // filter the given rows of this in-process...
+    fn filter_records<'a, F>(
+        &self,
+        exp: &Expression,
+        headers: StringRecord,
+        records: BoxStream<'a, Result<StringRecord, MyError>>,
+    ) -> Result<BoxStream<'a, Result<F, MyError>>, MyError>
+    where
+        F: DeserializeOwned + TryInto<Resource, Error = MyError> + Send + 'a,
+    {
+        debug!("Evaluate in-process");
+        let context = self.context.clone();
+        let h = headers.clone();
+        let matches = filter_stream(
+            exp,
+            move || context.make(),
+            records,
+            move |record| {
+                let feature: F = record.deserialize(Some(&h))?;
+                Ok(Cow::Owned(feature.try_into()?))
+            },
+        )?;
+        let it =
+            matches.map(move |x| -> Result<F, MyError> { Ok(x?.deserialize(Some(&headers))?) });
+        Ok(it.boxed())
+    }
+
+    // convert the rows of this to Resources and load them into an in-memory
+    // SQLite table w/ a column per Resource key...
+    async fn load_shim<F>(&self) -> Result<SQLiteShim, MyError>
+    where
+        F: DeserializeOwned + TryInto<Resource, Error = MyError>,
+    {
+        let mut rdr = self.reader()?;
+        let headers = rdr.headers()?.clone();
+        let records = rdr.records().collect::<Result<Vec<_>, _>>()?;
+        let resources = records
+            .iter()
+            .map(|x| x.deserialize::<F>(Some(&headers))?.try_into())
+            .collect::<Result<Vec<Resource>, MyError>>()?;
+        let keys: BTreeSet<&String> = resources.iter().flat_map(|x| x.keys()).collect();
+        let keys: Vec<(&String, bool)> = keys
+            .into_iter()
+            .map(|k| {
+                let is_geom = resources
+                    .iter()
+                    .any(|x| matches!(x.get(k), Some(Q::Geom(_))));
+                (k, is_geom)
+            })
+            .collect();
+        let nulled: BTreeSet<String> = keys
+            .iter()
+            .filter(|(k, _)| {
+                resources.iter().any(|x| {
+                    matches!(
+                        x.get(*k),
+                        Some(Q::Instant(Bound::None) | Q::Interval(..) | Q::List(_))
+                    )
+                })
+            })
+            .map(|(k, _)| k.to_string())
+            .collect();
+
+        // NOTE (rsn) 20261016 - every connection to `:memory:` gets its own
+        // database.  use exactly one that never expires...
+        let pool = PoolOptions::<Sqlite>::new()
+            .min_connections(1)
+            .max_connections(1)
+            .idle_timeout(None)
+            .max_lifetime(None)
+            .connect_with(sqlite_options("sqlite::memory:")?)
+            .await?;
+
+        let columns: Vec<String> = keys.iter().map(|(k, _)| double_quoted(k)).collect();
+        let sql = format!(
+            r#"CREATE TABLE "{SHIM_TABLE}" ("{SHIM_INDEX}" INTEGER PRIMARY KEY, {})"#,
+            columns.join(", ")
+        );
+        sqlx::query(AssertSqlSafe(sql)).execute(&pool).await?;
+
+        let srid = self.srid_or_default()?;
+        let params: Vec<String> = keys
+            .iter()
+            .map(|(_, is_geom)| match is_geom {
+                true => format!("ST_GeomFromText(?, {srid})"),
+                false => "?".into(),
+            })
+            .collect();
+        let sql = format!(
+            r#"INSERT INTO "{SHIM_TABLE}" ("{SHIM_INDEX}", {}) VALUES (?, {})"#,
+            columns.join(", "),
+            params.join(", ")
+        );
+        let mut tx = pool.begin().await?;
+        for (i, resource) in resources.iter().enumerate() {
+            let mut query = sqlx::query(AssertSqlSafe(sql.clone())).bind(i64::try_from(i)?);
+            for (k, _) in &keys {
+                query = match resource.get(*k) {
+                    None | Some(Q::Null) | Some(Q::Geom(G::Null)) => query.bind(None::<String>),
+                    Some(Q::Bool(x)) => query.bind(*x),
+                    Some(Q::Int(x)) => query.bind(*x),
+                    Some(Q::Num(x)) => query.bind(*x),
+                    Some(Q::Str(x)) => query.bind(x.as_str().to_owned()),
+                    Some(Q::Geom(x)) => query.bind(x.to_wkt()),
+                    Some(Q::Instant(Bound::Date(x))) => query.bind(x.date().to_string()),
+                    Some(Q::Instant(Bound::Timestamp(x))) => query.bind(x.datetime().to_string()),
+                    Some(Q::Bytes(x)) => query.bind(x.to_owned()),
+                    // no SQL equivalent...
+                    Some(Q::Instant(Bound::None) | Q::Interval(..) | Q::List(_)) => {
+                        query.bind(None::<String>)
+                    }
+                };
+            }
+            query.execute(&mut *tx).await?;
+        }
+        tx.commit().await?;
+        debug!("Loaded {} row(s) into SQLite shim", records.len());
+
+        Ok(SQLiteShim {
+            pool,
+            headers,
+            records,
+            nulled,
+        })
     }
 }
 
+// stream the rows already loaded into an SQLite shim...
+fn shim_records(shim: &SQLiteShim) -> BoxStream<'_, Result<StringRecord, MyError>> {
+    stream::iter(shim.records.iter().cloned().map(Ok)).boxed()
+}
+
 /// Macro to generate a concrete [CSVDataSource].
 ///
 /// Caller must provide the following parameters:
 /// * `$vis`: Visibility specifier of the generated artifacts; e.g. `pub`.
 /// * `$name`: Prefix of the concrete data source structure name to materialize.
 ///   The final name will have a 'CSV' suffix appended; eg. `Foo` -> `FooCSV`.
-/// * `$path`: Path to a readable CSV file.
+/// * `$path`: Path to a readable CSV file. Ignored when the data source is
+///   constructed w/ `from_reader()` or `from_fn()` instead of `new()`.
 /// * `$feature`: `serde` deserializable structure that maps rows to _Features_.
+///
+/// The generated data source implements both [`IterableDS`][crate::IterableDS]
+/// and [`StreamableDS`][crate::StreamableDS]. `$feature` must also be `Send`.
+///
+/// Optionally, the following 3 additional parameters can be given together:
+/// * `$delimiter`: Field separator byte; e.g. `b'\t'`. Defaults to `b','`.
+/// * `$quote`: Quoting character byte. Defaults to `b'"'`.
+/// * `$geom`: Name of the column holding the geometry WKT. Defaults to `geom`.
+///   When different, it's renamed `geom` before deserializing a _Feature_.
+///
+/// Rows are deserialized by header name, not position; i.e. the columns can
+/// be in any order and those w/o a matching field are ignored. Columns w/
+/// names that are not valid Rust identifiers, such as `"Feature ID"`, can be
+/// mapped w/ `serde` field attributes; e.g.
+/// ```rust,ignore
+/// #[derive(Deserialize)]
+/// struct Place {
+///     #[serde(rename = "Feature ID")]
+///     id: i32,
+///     geom: String,
+/// }
+/// ```
+/// The keys of the [Resources][crate::Resource] are whatever the `$feature`
+/// to _Resource_ conversion uses; e.g. the Rust field names. Filters refer to
+/// those keys whether or not [`with_sql_shim()`][CSVDataSource::with_sql_shim()]
+/// is used.
 #[macro_export]
 macro_rules! gen_csv_ds {
     ($vis:vis, $name:expr, $path:expr, $feature:expr) => {
+        $crate::gen_csv_ds!($vis, $name, $path, $feature, b',', b'"', "geom");
+    };
+    ($vis:vis, $name:expr, $path:expr, $feature:expr, $delimiter:expr, $quote:expr, $geom:expr) => {
         ::paste::paste! {
             /// Concrete data source.
             #[derive(Debug)]
@@ -51,19 +530,74 @@ macro_rules! gen_csv_ds {
             impl [<$name CSV>] {
                 /// Construct a new CSV data source.
                 $vis fn new() -> Self {
-                    Self(CSVDataSource::from($path))
+                    Self::configure(CSVDataSource::from($path))
+                }
+
+                /// Construct a new CSV data source reading its rows, only
+                /// once, from `reader`.
+                #[allow(dead_code)]
+                $vis fn from_reader<R>(reader: R) -> Self
+                where
+                    R: ::std::io::Read + Send + 'static,
+                {
+                    Self::configure(CSVDataSource::from_reader(reader))
+                }
+
+                /// Construct a new CSV data source reading its rows from a
+                /// new reader returned by `open` every time.
+                #[allow(dead_code)]
+                $vis fn from_fn<F, R>(open: F) -> Self
+                where
+                    F: Fn() -> ::std::io::Result<R> + Send + Sync + 'static,
+                    R: ::std::io::Read + Send + 'static,
+                {
+                    Self::configure(CSVDataSource::from_fn(open))
+                }
+
+                fn configure(ds: CSVDataSource) -> Self {
+                    Self(
+                        ds.with_delimiter($delimiter)
+                            .with_quote($quote)
+                            .with_geom_column($geom),
+                    )
+                }
+
+                /// Set the EPSG code of the CRS the geometries are in.
+                #[allow(dead_code)]
+                $vis fn with_srid(self, srid: u32) -> Self {
+                    Self(self.0.with_srid(srid))
+                }
+
+                /// Delegate filtering to an in-memory SQLite copy of the CSV file.
+                #[allow(dead_code)]
+                $vis fn with_sql_shim(self) -> Self {
+                    Self(self.0.with_sql_shim())
+                }
+
+                /// Evaluate filters in-process w/in a Context returned by `f`.
+                #[allow(dead_code)]
+                $vis fn with_context<F>(self, f: F) -> Self
+                where
+                    F: Fn() -> $crate::Context + Send + Sync + 'static,
+                {
+                    Self(self.0.with_context(f))
                 }
 
-                /// Return a file reader that deserializes rows into features.
-                $vis fn reader(&self) -> Result<::csv::Reader<::std::fs::File>, MyError> {
-                    let file = ::std::fs::File::open(self.0.path())?;
-                    Ok(::csv::Reader::from_reader(file))
+                /// Return a CSV reader that deserializes rows into features.
+                #[allow(dead_code)]
+                $vis fn reader(
+                    &self,
+                ) -> Result<::csv::Reader<Box<dyn ::std::io::Read + Send>>, MyError> {
+                    self.0.reader()
                 }
             }
 
             impl ::core::fmt::Display for [<$name CSV>] {
                 fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
-                    write!(f, "{}CSV({})", $name, $path)
+                    match self.0.path() {
+                        Some(_) => write!(f, "{}CSV({})", $name, $path),
+                        None => write!(f, "{}CSV(<reader>)", $name),
+                    }
                 }
             }
 
@@ -72,12 +606,329 @@ macro_rules! gen_csv_ds {
                 type Err = MyError;
 
                 fn iter(&self) -> Result<impl Iterator<Item = Result<$feature, Self::Err>>, Self::Err> {
-                    let file = ::std::fs::File::open(&self.0.path())?;
-                    let rdr = ::csv::Reader::from_reader(file);
+                    let rdr = self.0.reader()?;
                     let it = rdr.into_deserialize().map(|res| res.map_err(MyError::from));
                     Ok(it)
                 }
             }
+
+            #[::async_trait::async_trait]
+            impl $crate::StreamableDS for [<$name CSV>] {
+                type Item = $feature;
+                type Err = MyError;
+
+                async fn fetch(
+                    &self
+                ) -> Result<::futures::stream::BoxStream<'_, Result<$feature, MyError>>, MyError> {
+                    let rdr = self.0.reader()?;
+                    let it = rdr.into_deserialize().map(|res| res.map_err(MyError::from));
+                    Ok(Box::pin(::futures::stream::iter(it)))
+                }
+
+                async fn stream(
+                    &self
+                ) -> Result<::futures::stream::BoxStream<'_, Result<Resource, MyError>>, MyError> {
+                    let rdr = self.0.reader()?;
+                    let it = rdr
+                        .into_deserialize::<$feature>()
+                        .map(|res| res.map_err(MyError::from).and_then(Resource::try_from));
+                    Ok(Box::pin(::futures::stream::iter(it)))
+                }
+
+                async fn fetch_where(
+                    &self,
+                    exp: &$crate::Expression,
+                ) -> Result<::futures::stream::BoxStream<'_, Result<$feature, MyError>>, MyError> {
+                    self.0.select_where::<$feature>(exp).await
+                }
+
+                async fn stream_where(
+                    &self,
+                    exp: &$crate::Expression,
+                ) -> Result<::futures::stream::BoxStream<'_, Result<Resource, MyError>>, MyError> {
+                    use ::futures::StreamExt;
+
+                    let features = self.0.select_where::<$feature>(exp).await?;
+                    let it = features.map(|res| res.and_then(Resource::try_from));
+                    Ok(Box::pin(it))
+                }
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Expression, GTrait, IterableDS, Q, Resource, StreamableDS};
+    use futures::TryStreamExt;
+    use serde::Deserialize;
+    use std::{collections::HashMap, error::Error, io::Cursor};
+
+    const TSV: &str = "./tests/samples/data/sample.tsv";
+    const RENAMED_CSV: &str = "./tests/samples/data/renamed.csv";
+
+    #[derive(Debug, Deserialize)]
+    struct Feature {
+        id: i32,
+        geom: String,
+        name: String,
+    }
+
+    impl TryFrom<Feature> for Resource {
+        type Error = MyError;
+
+        fn try_from(value: Feature) -> Result<Self, Self::Error> {
+            Ok(HashMap::from([
+                ("id".into(), Q::try_from(value.id)?),
+                ("geom".into(), Q::try_from_wkt(&value.geom)?),
+                ("name".into(), Q::new_plain_str(&value.name)),
+            ]))
+        }
+    }
+
+    gen_csv_ds!(, "Test", TSV, Feature, b'\t', b'\'', "wkt_geometry");
+
+    // columns in a different order than the fields, w/ spaces and non-ASCII
+    // characters in their names...
+    #[derive(Debug, Deserialize)]
+    struct City {
+        geom: String,
+        #[serde(rename = "Größe (km²)")]
+        area: f64,
+        #[serde(rename = "Nom de la ville")]
+        name: String,
+        #[serde(rename = "Feature ID")]
+        id: i32,
+    }
+
+    impl TryFrom<City> for Resource {
+        type Error = MyError;
+
+        fn try_from(value: City) -> Result<Self, Self::Error> {
+            Ok(HashMap::from([
+                ("id".into(), Q::try_from(value.id)?),
+                ("geom".into(), Q::try_from_wkt(&value.geom)?),
+                ("name".into(), Q::new_plain_str(&value.name)),
+                ("area".into(), Q::from(value.area)),
+            ]))
+        }
+    }
+
+    gen_csv_ds!(, "City", RENAMED_CSV, City);
+
+    // same rows as `Feature` w/ an array-valued property the SQLite shim
+    // stores as NULL...
+    #[derive(Debug, Deserialize)]
+    struct Tagged {
+        id: i32,
+        geom: String,
+        name: String,
+    }
+
+    impl TryFrom<Tagged> for Resource {
+        type Error = MyError;
+
+        fn try_from(value: Tagged) -> Result<Self, Self::Error> {
+            let tags = value.name.split('\t').map(Q::new_plain_str).collect();
+            Ok(HashMap::from([
+                ("id".into(), Q::try_from(value.id)?),
+                ("geom".into(), Q::try_from_wkt(&value.geom)?),
+                ("name".into(), Q::new_plain_str(&value.name)),
+                ("tags".into(), Q::List(tags)),
+            ]))
+        }
+    }
+
+    gen_csv_ds!(, "Tagged", TSV, Tagged, b'\t', b'\'', "wkt_geometry");
+
+    #[test]
+    fn test_tsv() -> Result<(), Box<dyn Error>> {
+        let tsv = TestCSV::new();
+        assert_eq!(tsv.0.delimiter(), b'\t');
+        assert_eq!(tsv.0.geom_column(), "wkt_geometry");
+        let headers = tsv.reader()?.headers()?.clone();
+        assert_eq!(headers.get(1), Some("geom"));
+
+        let mut count = 0;
+        for x in tsv.iter()? {
+            let res = Resource::try_from(x?)?;
+            let name = res.get("name").expect("Missing name").to_str()?;
+            let geom = res.get("geom").expect("Missing geom").to_geom()?;
+            match count {
+                0 => {
+                    assert_eq!(name.as_str(), "Foo");
+                    assert_eq!(geom.type_(), "Point");
+                }
+                _ => {
+                    assert_eq!(name.as_str(), "Bar\tBaz");
+                    assert_eq!(geom.type_(), "LineString");
+                }
+            }
+            count += 1;
+        }
+        assert_eq!(count, 2);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_renamed_columns() -> Result<(), Box<dyn Error>> {
+        let csv = CityCSV::new();
+        let cities = csv.iter()?.collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(cities.len(), 3);
+        assert_eq!(cities[0].id, 1);
+        assert_eq!(cities[0].name, "Paris");
+        assert_eq!(cities[1].area, 891.1);
+
+        let exp = Expression::try_from_text("area > 101 AND name <> 'Berlin'")?;
+        let ids: Vec<i32> = csv
+            .fetch_where(&exp)
+            .await?
+            .map_ok(|x| x.id)
+            .try_collect()
+            .await?;
+        assert_eq!(ids, [1]);
+
+        // the shim's columns are the same Resource keys...
+        let shim = CityCSV::new().with_sql_shim();
+        let ids: Vec<i32> = shim
+            .fetch_where(&exp)
+            .await?
+            .map_ok(|x| x.id)
+            .try_collect()
+            .await?;
+        assert_eq!(ids, [1]);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_sql_shim() -> Result<(), Box<dyn Error>> {
+        #[rustfmt::skip]
+        const TV: [(&str, &[i32]); 4] = [
+            ("id > 1",                                          &[2]),
+            ("name = 'Foo'",                                    &[1]),
+            ("S_INTERSECTS(geom, BBOX(0.5, 1.5, 1.5, 2.5))",    &[1]),
+            ("S_INTERSECTS(geom, BBOX(-1, -1, 5, 5))",          &[1, 2]),
+        ];
+
+        let plain = TestCSV::new();
+        let shim = TestCSV::new().with_sql_shim();
+        assert!(!plain.0.uses_sql_shim());
+        assert!(shim.0.uses_sql_shim());
+
+        for (cql, expected) in TV {
+            let exp = Expression::try_from_text(cql)?;
+            for ds in [&plain, &shim] {
+                let ids: Vec<i32> = ds
+                    .fetch_where(&exp)
+                    .await?
+                    .map_ok(|x| x.id)
+                    .try_collect()
+                    .await?;
+                assert_eq!(ids, expected, "{cql}");
+            }
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_sql_shim_fallback() -> Result<(), Box<dyn Error>> {
+        #[rustfmt::skip]
+        const TV: [(&str, &[i32]); 4] = [
+            // has no SQL equivalent...
+            ("'Baz' IN tags",                   &[2]),
+            ("'Baz' NOT IN tags",               &[1]),
+            // references a column stored as NULL...
+            ("tags IS NULL",                    &[]),
+            ("id > 0 AND tags IS NOT NULL",     &[1, 2]),
+        ];
+
+        let plain = TaggedCSV::new();
+        let shim = TaggedCSV::new().with_sql_shim();
+        for (cql, expected) in TV {
+            let exp = Expression::try_from_text(cql)?;
+            for ds in [&plain, &shim] {
+                let ids: Vec<i32> = ds
+                    .fetch_where(&exp)
+                    .await?
+                    .map_ok(|x| x.id)
+                    .try_collect()
+                    .await?;
+                assert_eq!(ids, expected, "{cql}");
+            }
+        }
+        assert!(logs_contain("Not delegating to SQLite shim: [NOT] IN"));
+        assert!(logs_contain("Not delegating to SQLite shim: 'tags'"));
+        assert!(!logs_contain("Delegate to SQLite shim"));
+
+        // other filters are still delegated...
+        let exp = Expression::try_from_text("name = 'Foo'")?;
+        let ids: Vec<i32> = shim
+            .fetch_where(&exp)
+            .await?
+            .map_ok(|x| x.id)
+            .try_collect()
+            .await?;
+        assert_eq!(ids, [1]);
+        assert!(logs_contain("Delegate to SQLite shim"));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_traced_select_where() -> Result<(), Box<dyn Error>> {
+        let exp = Expression::try_from_text("name = 'Foo'")?;
+        for ds in [TestCSV::new(), TestCSV::new().with_sql_shim()] {
+            let features: Vec<Feature> = ds.0.select_where(&exp).await?.try_collect().await?;
+            assert_eq!(features.len(), 1);
         }
+        assert!(logs_contain("matched=1 errors=0"));
+        assert!(logs_contain("Evaluate in-process"));
+        assert!(logs_contain("Delegate to SQLite shim"));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_from_reader() -> Result<(), Box<dyn Error>> {
+        let bytes = std::fs::read(TSV)?;
+
+        // a one-shot reader can only be iterated once...
+        let ds = TestCSV::from_reader(Cursor::new(bytes.clone()));
+        assert!(ds.0.path().is_none());
+        assert_eq!(ds.to_string(), "TestCSV(<reader>)");
+        let ids: Vec<i32> = ds
+            .iter()?
+            .map(|x| x.map(|f| f.id))
+            .collect::<Result<_, _>>()?;
+        assert_eq!(ids, [1, 2]);
+        assert!(ds.iter().is_err());
+
+        // ...unless its rows are loaded once in an SQLite shim...
+        let ds = TestCSV::from_reader(Cursor::new(bytes.clone())).with_sql_shim();
+        for _ in 0..2 {
+            let exp = Expression::try_from_text("name = 'Bar\tBaz'")?;
+            let ids: Vec<i32> = ds
+                .fetch_where(&exp)
+                .await?
+                .map_ok(|x| x.id)
+                .try_collect()
+                .await?;
+            assert_eq!(ids, [2]);
+        }
+
+        // ...while a reader factory can be iterated as often as needed...
+        let ds = TestCSV::from_fn(move || Ok(Cursor::new(bytes.clone())));
+        for _ in 0..2 {
+            let lens: Vec<usize> = ds.stream().await?.map_ok(|x| x.len()).try_collect().await?;
+            assert_eq!(lens, [3, 3]);
+        }
+
+        Ok(())
     }
 }
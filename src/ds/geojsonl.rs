@@ -0,0 +1,306 @@
+// SPDX-License-Identifier: Apache-2.0
+
+#![warn(missing_docs)]
+
+//! Geospatial data stored in newline-delimited GeoJSON (GeoJSONL) files.
+//!
+
+use crate::{
+    Context, EPSG_4326, Expression, G, MyError, Q, Resource,
+    ds::{
+        ContextFactory, DEFAULT_GEOM_COLUMN, DataSource, IterableDS, StreamableDS, filter_stream,
+    },
+};
+use async_trait::async_trait;
+use futures::{
+    StreamExt,
+    stream::{self, BoxStream},
+};
+use serde::Deserialize;
+use serde_json::{Map, Value};
+use std::{
+    borrow::Cow,
+    fs::File,
+    io::{BufRead, BufReader, ErrorKind},
+    path::PathBuf,
+};
+use tokio::io::{AsyncBufReadExt, BufReader as AsyncBufReader, Lines};
+
+/// A GeoJSON _Feature_ read from a single line of a GeoJSONL file.
+#[derive(Debug, Clone, Deserialize)]
+pub struct GeoJsonFeature {
+    #[serde(rename = "type")]
+    type_: String,
+    #[serde(default)]
+    id: Option<Value>,
+    #[serde(default)]
+    geometry: Option<Value>,
+    #[serde(default)]
+    properties: Option<Map<String, Value>>,
+}
+
+impl GeoJsonFeature {
+    /// Try parsing a line of text as a GeoJSON _Feature_.
+    pub fn try_from_line(line: &str) -> Result<Self, MyError> {
+        let result: GeoJsonFeature = serde_json::from_str(line)?;
+        if result.type_ != "Feature" {
+            return Err(MyError::Runtime(
+                format!("Expected a 'Feature' but found '{}'", result.type_).into(),
+            ));
+        }
+        Ok(result)
+    }
+
+    /// Return the optional identifier of this.
+    pub fn id(&self) -> Option<&Value> {
+        self.id.as_ref()
+    }
+
+    /// Return the optional GeoJSON geometry of this.
+    pub fn geometry(&self) -> Option<&Value> {
+        self.geometry.as_ref()
+    }
+
+    /// Return the optional properties of this.
+    pub fn properties(&self) -> Option<&Map<String, Value>> {
+        self.properties.as_ref()
+    }
+}
+
+/// Properties are mapped as described in [`Q::try_from_json()`], the geometry,
+/// if any, is stored under `geom`, and the identifier, if any, under `id`
+/// unless a property by that name already exists.
+impl TryFrom<GeoJsonFeature> for Resource {
+    type Error = MyError;
+
+    fn try_from(value: GeoJsonFeature) -> Result<Self, Self::Error> {
+        let properties = value.properties.unwrap_or_default();
+        let mut result = Resource::with_capacity(properties.len() + 2);
+        for (k, v) in &properties {
+            result.insert(k.to_owned(), Q::try_from_json(v)?);
+        }
+        if let Some(id) = value.id
+            && !result.contains_key("id")
+        {
+            result.insert("id".into(), Q::try_from_json(&id)?);
+        }
+        let geom = match value.geometry {
+            Some(Value::Null) | None => Q::Null,
+            Some(x) => Q::Geom(G::try_from(&x)?),
+        };
+        result.insert(DEFAULT_GEOM_COLUMN.into(), geom);
+        Ok(result)
+    }
+}
+
+/// [`DataSource`] of _Features_ and [Resources][Resource] read line by line
+/// from a newline-delimited GeoJSON file.
+///
+/// Blank lines are skipped, while a line that cannot be parsed as a GeoJSON
+/// _Feature_ yields an `Err` item w/o ending the iteration or stream.
+#[derive(Debug)]
+pub struct GeoJsonLDataSource {
+    path: PathBuf,
+    context: ContextFactory,
+}
+
+impl DataSource for GeoJsonLDataSource {
+    // RFC 7946 mandates WGS 84 coordinates...
+    fn srid(&self) -> Option<u32> {
+        u32::try_from(EPSG_4326.into_inner()).ok()
+    }
+}
+
+impl GeoJsonLDataSource {
+    /// Constructor given the file system location of an accessible GeoJSONL file.
+    pub fn from(s: &str) -> Self {
+        Self {
+            path: s.into(),
+            context: ContextFactory::default(),
+        }
+    }
+
+    /// Evaluate filters w/in a [Context] returned by `f` instead of the
+    /// default one. `f` is called every time _Features_ are filtered.
+    pub fn with_context<F>(mut self, f: F) -> Self
+    where
+        F: Fn() -> Context + Send + Sync + 'static,
+    {
+        self.context = ContextFactory::new(f);
+        self
+    }
+
+    /// Return this GeoJSONL data source path.
+    pub fn path(&self) -> &PathBuf {
+        &self.path
+    }
+}
+
+impl IterableDS for GeoJsonLDataSource {
+    type Item = GeoJsonFeature;
+    type Err = MyError;
+
+    fn iter(&self) -> Result<impl Iterator<Item = Result<GeoJsonFeature, MyError>>, MyError> {
+        let file = File::open(&self.path)?;
+        let it = BufReader::new(file)
+            .lines()
+            .filter(|line| !matches!(line, Ok(x) if x.trim().is_empty()))
+            .map(|line| GeoJsonFeature::try_from_line(&line?));
+        Ok(it)
+    }
+}
+
+#[async_trait]
+impl StreamableDS for GeoJsonLDataSource {
+    type Item = GeoJsonFeature;
+    type Err = MyError;
+
+    async fn fetch(&self) -> Result<BoxStream<'_, Result<GeoJsonFeature, MyError>>, MyError> {
+        let file = tokio::fs::File::open(&self.path).await?;
+        let lines = AsyncBufReader::new(file).lines();
+        Ok(stream::unfold(Some(lines), next_feature).boxed())
+    }
+
+    async fn stream(&self) -> Result<BoxStream<'_, Result<Resource, MyError>>, MyError> {
+        let features = self.fetch().await?;
+        let it = features.map(|x| x.and_then(Resource::try_from));
+        Ok(it.boxed())
+    }
+
+    // NOTE (rsn) 20261016 - there's no DB engine to delegate filtering to.
+    // the features, read line by line, are evaluated in-process and only the
+    // matching ones, as well as the errors, are kept...
+    async fn fetch_where(
+        &self,
+        exp: &Expression,
+    ) -> Result<BoxStream<'_, Result<GeoJsonFeature, MyError>>, MyError> {
+        let features = self.fetch().await?;
+        filter_stream(
+            exp,
+            || self.context.make(),
+            features,
+            |x| Ok(Cow::Owned(Resource::try_from(x.clone())?)),
+        )
+    }
+
+    async fn stream_where(
+        &self,
+        exp: &Expression,
+    ) -> Result<BoxStream<'_, Result<Resource, MyError>>, MyError> {
+        let resources = self.stream().await?;
+        filter_stream(
+            exp,
+            || self.context.make(),
+            resources,
+            |x| Ok(Cow::Borrowed(x)),
+        )
+    }
+}
+
+type AsyncLines = Lines<AsyncBufReader<tokio::fs::File>>;
+
+// read the next non-blank line and parse it as a feature. a line that is not
+// valid UTF-8 is consumed and reported like a malformed one, while any other
+// I/O error ends the stream after being reported...
+async fn next_feature(
+    lines: Option<AsyncLines>,
+) -> Option<(Result<GeoJsonFeature, MyError>, Option<AsyncLines>)> {
+    let mut lines = lines?;
+    loop {
+        match lines.next_line().await {
+            Ok(Some(line)) if line.trim().is_empty() => continue,
+            Ok(Some(line)) => return Some((GeoJsonFeature::try_from_line(&line), Some(lines))),
+            Ok(None) => return None,
+            Err(x) if x.kind() == ErrorKind::InvalidData => {
+                return Some((Err(MyError::IO(x)), Some(lines)));
+            }
+            Err(x) => return Some((Err(MyError::IO(x)), None)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error;
+
+    const GEOJSONL: &str = "./tests/samples/data/sample.geojsonl";
+
+    #[test]
+    fn test_iter() -> Result<(), Box<dyn Error>> {
+        let ds = GeoJsonLDataSource::from(GEOJSONL);
+        let items: Vec<_> = ds.iter()?.collect();
+        // 4 non-blank lines, 1 of which is malformed...
+        assert_eq!(items.len(), 4);
+        assert!(items[2].is_err());
+
+        let res = Resource::try_from(items[0].as_ref().expect("Failed").clone())?;
+        assert_eq!(res["name"].to_str()?.inner(), "Foo");
+        assert_eq!(res["id"].to_num()?, 1.0);
+        assert!(res["geom"].to_geom().is_ok());
+
+        // the properties' `id` wins over the feature's...
+        let res = Resource::try_from(items[1].as_ref().expect("Failed").clone())?;
+        assert_eq!(res["id"].to_num()?, 20.0);
+
+        // no geometry...
+        let res = Resource::try_from(items[3].as_ref().expect("Failed").clone())?;
+        assert!(res["geom"].is_null());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_stream() -> Result<(), Box<dyn Error>> {
+        let ds = GeoJsonLDataSource::from(GEOJSONL);
+        let items: Vec<_> = ds.stream().await?.collect().await;
+        assert_eq!(items.len(), 4);
+        assert_eq!(items.iter().filter(|x| x.is_err()).count(), 1);
+
+        // the malformed line is still reported...
+        let exp = Expression::try_from_text("S_INTERSECTS(geom, BBOX(0, 0, 5, 5))")?;
+        let items: Vec<_> = ds.stream_where(&exp).await?.collect().await;
+        assert_eq!(items.len(), 2);
+        let names: Vec<String> = items
+            .into_iter()
+            .filter_map(|x| x.ok())
+            .map(|x| x["name"].to_str().expect("Not a string").inner().to_owned())
+            .collect();
+        assert_eq!(names, ["Foo"]);
+
+        // an invalid filter is raised upfront...
+        let exp = Expression::try_from_text("S_INTERSECTS(geom, POINT(1000 1000))")?;
+        assert!(ds.fetch_where(&exp).await.is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_invalid_utf8() -> Result<(), Box<dyn Error>> {
+        const FOO: &str = r#"{"type":"Feature","geometry":null,"properties":{"name":"Foo"}}"#;
+        const BAR: &str = r#"{"type":"Feature","geometry":null,"properties":{"name":"Bar"}}"#;
+
+        let path = std::env::temp_dir().join("ogc_cql2_test_invalid_utf8.geojsonl");
+        let mut bytes = format!("{FOO}\n").into_bytes();
+        bytes.extend_from_slice(b"{\"name\":\"\xff\xfe\"}\n");
+        bytes.extend_from_slice(format!("{BAR}\n").as_bytes());
+        std::fs::write(&path, bytes)?;
+
+        // the bad line is reported w/o ending the iteration...
+        let ds = GeoJsonLDataSource::from(path.to_str().expect("Invalid path"));
+        let items: Vec<_> = ds.iter()?.collect();
+        assert_eq!(items.len(), 3);
+        assert!(matches!(items[1], Err(MyError::IO(_))));
+        assert!(items[2].is_ok());
+
+        // ...or the stream...
+        let items: Vec<_> = ds.stream().await?.collect().await;
+        assert_eq!(items.len(), 3);
+        assert!(matches!(items[1], Err(MyError::IO(_))));
+        let res = items[2].as_ref().expect("Failed");
+        assert_eq!(res["name"].to_str()?.inner(), "Bar");
+
+        std::fs::remove_file(&path)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,473 @@
+// SPDX-License-Identifier: Apache-2.0
+
+#![warn(missing_docs)]
+
+//! Geospatial data stored in [GeoParquet][1] files.
+//!
+//! The geometry column name, its encoding, and its CRS are discovered from
+//! the `geo` entry of the Parquet file key-value metadata. Only the `WKB`
+//! encoding of the primary geometry column is supported.
+//!
+//! [1]: https://geoparquet.org/releases/v1.1.0/
+//!
+
+use crate::{
+    Context, Expression, G, MyError, Q, QString, Resource, SRID,
+    config::config,
+    ds::{ContextFactory, DEFAULT_GEOM_COLUMN, DataSource, IterableDS, StreamableDS, filter_where},
+    wkb::WellKnownBinary,
+};
+use async_trait::async_trait;
+use futures::{
+    StreamExt,
+    stream::{self, BoxStream},
+};
+use parquet::{
+    file::reader::{FileReader, RowGroupReader, SerializedFileReader},
+    record::{Field, Row, reader::RowIter},
+};
+use serde_json::Value;
+use std::{borrow::Cow, fs::File, path::PathBuf};
+use tracing::{debug, warn};
+
+/// Key of the GeoParquet metadata in the Parquet file footer.
+const GEO_METADATA_KEY: &str = "geo";
+/// Number of nanoseconds in a day.
+const NANOS_PER_DAY: i128 = 86_400_000_000_000;
+
+/// A _Feature_ read from a GeoParquet file; i.e. the decoded geometry of the
+/// primary geometry column, and the other columns of the same row.
+#[derive(Debug, Clone)]
+pub struct GeoParquetFeature {
+    geom: G,
+    properties: Resource,
+}
+
+impl GeoParquetFeature {
+    /// Return the geometry of this; [`G::Null`] if it was `null`.
+    pub fn geom(&self) -> &G {
+        &self.geom
+    }
+
+    /// Return the non-geometry columns of this.
+    pub fn properties(&self) -> &Resource {
+        &self.properties
+    }
+}
+
+/// Columns are mapped as is while the geometry, if any, is stored under
+/// `geom`.
+impl TryFrom<GeoParquetFeature> for Resource {
+    type Error = MyError;
+
+    fn try_from(value: GeoParquetFeature) -> Result<Self, Self::Error> {
+        let mut result = value.properties;
+        let geom = match value.geom {
+            G::Null => Q::Null,
+            x => Q::Geom(x),
+        };
+        result.insert(DEFAULT_GEOM_COLUMN.into(), geom);
+        Ok(result)
+    }
+}
+
+/// Read-only [`DataSource`] of _Features_ and [Resources][Resource] read from
+/// a GeoParquet file.
+///
+/// Only 2D geometries are supported. Numeric columns are mapped to numbers,
+/// UTF-8 ones to strings, `DATE` and `TIMESTAMP` (milli- and microseconds)
+/// ones to instants, and lists to lists. Columns of other types; e.g.
+/// `DECIMAL`, `TIME` or nested groups, are mapped to `NULL`.
+///
+/// Rows are read one row group at a time when streamed.
+#[derive(Debug)]
+pub struct GeoParquetDataSource {
+    path: PathBuf,
+    geom_column: String,
+    srid: Option<u32>,
+    context: ContextFactory,
+}
+
+impl DataSource for GeoParquetDataSource {
+    fn srid(&self) -> Option<u32> {
+        self.srid
+    }
+}
+
+impl GeoParquetDataSource {
+    /// Constructor given the file system location of an accessible GeoParquet
+    /// file.
+    ///
+    /// Return an error if the file is not a Parquet one, it lacks the `geo`
+    /// metadata, or its primary geometry column is not `WKB` encoded.
+    pub fn from(s: &str) -> Result<Self, MyError> {
+        let path = PathBuf::from(s);
+        let reader = SerializedFileReader::new(File::open(&path)?)?;
+        let geo = reader
+            .metadata()
+            .file_metadata()
+            .key_value_metadata()
+            .and_then(|x| x.iter().find(|kv| kv.key == GEO_METADATA_KEY))
+            .and_then(|kv| kv.value.as_deref())
+            .ok_or_else(|| {
+                MyError::Runtime(format!("{path:?} has no '{GEO_METADATA_KEY}' metadata").into())
+            })?;
+        let (geom_column, srid) = parse_geo_metadata(geo)?;
+        debug!("geom_column = '{geom_column}', srid = {srid:?}");
+        Ok(Self {
+            path,
+            geom_column,
+            srid,
+            context: ContextFactory::default(),
+        })
+    }
+
+    /// Evaluate filters w/in a [Context] returned by `f` instead of the
+    /// default one. `f` is called every time _Features_ are filtered.
+    pub fn with_context<F>(mut self, f: F) -> Self
+    where
+        F: Fn() -> Context + Send + Sync + 'static,
+    {
+        self.context = ContextFactory::new(f);
+        self
+    }
+
+    /// Return this GeoParquet data source path.
+    pub fn path(&self) -> &PathBuf {
+        &self.path
+    }
+
+    /// Return the name of the primary geometry column.
+    pub fn geom_column(&self) -> &str {
+        &self.geom_column
+    }
+
+    fn reader(&self) -> Result<SerializedFileReader<File>, MyError> {
+        Ok(SerializedFileReader::new(File::open(&self.path)?)?)
+    }
+
+    fn srid_or_default(&self) -> Result<SRID, MyError> {
+        match self.srid {
+            Some(x) => SRID::try_from(i32::try_from(x)?),
+            None => Ok(*config().default_srid()),
+        }
+    }
+
+    // filter the features of this in-process.  rows that fail decoding, and
+    // features that fail to evaluate, are kept as errors...
+    #[allow(clippy::type_complexity)]
+    fn evaluate_where(
+        &self,
+        exp: &Expression,
+    ) -> Result<Vec<Result<GeoParquetFeature, MyError>>, MyError> {
+        let it = filter_where(exp, self.context.make(), self.iter()?, |x| {
+            Ok(Cow::Owned(Resource::try_from(x.clone())?))
+        })?;
+        Ok(it.collect())
+    }
+}
+
+impl IterableDS for GeoParquetDataSource {
+    type Item = GeoParquetFeature;
+    type Err = MyError;
+
+    fn iter(&self) -> Result<impl Iterator<Item = Result<GeoParquetFeature, MyError>>, MyError> {
+        let reader = self.reader()?;
+        let srid = self.srid_or_default()?;
+        let geom_column = self.geom_column.to_owned();
+        let it = RowIter::from_file_into(Box::new(reader))
+            .map(move |x| to_feature(&x?, &geom_column, srid));
+        Ok(it)
+    }
+}
+
+#[async_trait]
+impl StreamableDS for GeoParquetDataSource {
+    type Item = GeoParquetFeature;
+    type Err = MyError;
+
+    async fn fetch(&self) -> Result<BoxStream<'_, Result<GeoParquetFeature, MyError>>, MyError> {
+        let reader = self.reader()?;
+        let srid = self.srid_or_default()?;
+        let geom_column = self.geom_column.to_owned();
+        // NOTE (rsn) 20261016 - only one row group at a time is materialized.
+        // the (not `Send`) row iterators never outlive a single poll...
+        let it = stream::iter(0..reader.num_row_groups())
+            .map(move |i| {
+                let rows =
+                    read_row_group(&reader, i, &geom_column, srid).unwrap_or_else(|x| vec![Err(x)]);
+                stream::iter(rows)
+            })
+            .flatten();
+        Ok(it.boxed())
+    }
+
+    async fn stream(&self) -> Result<BoxStream<'_, Result<Resource, MyError>>, MyError> {
+        let features = self.fetch().await?;
+        let it = features.map(|x| x.and_then(Resource::try_from));
+        Ok(it.boxed())
+    }
+
+    // NOTE (rsn) 20261016 - same as GeoJSONL there's no DB engine to delegate
+    // filtering to...
+    async fn fetch_where(
+        &self,
+        exp: &Expression,
+    ) -> Result<BoxStream<'_, Result<GeoParquetFeature, MyError>>, MyError> {
+        let features = self.evaluate_where(exp)?;
+        Ok(stream::iter(features).boxed())
+    }
+
+    async fn stream_where(
+        &self,
+        exp: &Expression,
+    ) -> Result<BoxStream<'_, Result<Resource, MyError>>, MyError> {
+        let features = self.evaluate_where(exp)?;
+        let it = features.into_iter().map(|x| x.and_then(Resource::try_from));
+        Ok(stream::iter(it).boxed())
+    }
+}
+
+// decode all the rows of the row group at index `ndx`...
+#[allow(clippy::type_complexity)]
+fn read_row_group(
+    reader: &SerializedFileReader<File>,
+    ndx: usize,
+    geom_column: &str,
+    srid: SRID,
+) -> Result<Vec<Result<GeoParquetFeature, MyError>>, MyError> {
+    let group = reader.get_row_group(ndx)?;
+    let rows = group.get_row_iter(None)?;
+    let result = rows.map(|x| to_feature(&x?, geom_column, srid)).collect();
+    Ok(result)
+}
+
+fn to_feature(row: &Row, geom_column: &str, srid: SRID) -> Result<GeoParquetFeature, MyError> {
+    let mut geom = G::Null;
+    let mut properties = Resource::with_capacity(row.len());
+    for (name, field) in row.get_column_iter() {
+        if name == geom_column {
+            geom = match field {
+                Field::Null => G::Null,
+                Field::Bytes(x) => WellKnownBinary::try_from_bytes(x.data(), srid)?.geom(),
+                x => {
+                    return Err(MyError::Runtime(
+                        format!("Expected WKB bytes in '{name}' but found {x}").into(),
+                    ));
+                }
+            };
+        } else {
+            properties.insert(name.to_owned(), field_to_q(field)?);
+        }
+    }
+    Ok(GeoParquetFeature { geom, properties })
+}
+
+fn field_to_q(field: &Field) -> Result<Q, MyError> {
+    let result = match field {
+        Field::Bool(x) => Q::Bool(*x),
+        Field::Byte(x) => Q::Int(i64::from(*x)),
+        Field::Short(x) => Q::Int(i64::from(*x)),
+        Field::Int(x) => Q::Int(i64::from(*x)),
+        Field::Long(x) => Q::Int(*x),
+        Field::UByte(x) => Q::Int(i64::from(*x)),
+        Field::UShort(x) => Q::Int(i64::from(*x)),
+        Field::UInt(x) => Q::Int(i64::from(*x)),
+        Field::ULong(x) => i64::try_from(*x).map_or(Q::Num(*x as f64), Q::Int),
+        Field::Float16(x) => Q::Num(x.to_f64()),
+        Field::Float(x) => Q::Num(f64::from(*x)),
+        Field::Double(x) => Q::Num(*x),
+        Field::Str(x) => Q::Str(QString::plain(x)),
+        // days since the Unix epoch...
+        Field::Date(x) => Q::try_from_date_ns(i128::from(*x) * NANOS_PER_DAY)?,
+        Field::TimestampMillis(x) => Q::try_from_timestamp_ns(i128::from(*x) * 1_000_000)?,
+        Field::TimestampMicros(x) => Q::try_from_timestamp_ns(i128::from(*x) * 1_000)?,
+        Field::ListInternal(x) => {
+            let items: Result<Vec<Q>, MyError> = x.elements().iter().map(field_to_q).collect();
+            Q::List(items?)
+        }
+        Field::Null => Q::Null,
+        x => {
+            debug!("Unsupported Parquet field {x:?}. Use NULL");
+            Q::Null
+        }
+    };
+    Ok(result)
+}
+
+// Return the name of the primary geometry column and its SRID, if known, from
+// the JSON `geo` metadata of a GeoParquet file.
+fn parse_geo_metadata(s: &str) -> Result<(String, Option<u32>), MyError> {
+    let geo: Value = serde_json::from_str(s)?;
+    let name = geo["primary_column"]
+        .as_str()
+        .ok_or_else(|| MyError::Runtime("Missing 'primary_column' in 'geo' metadata".into()))?;
+    let column = geo["columns"]
+        .get(name)
+        .ok_or_else(|| MyError::Runtime(format!("Missing '{name}' column metadata").into()))?;
+    let encoding = column["encoding"].as_str().unwrap_or_default();
+    if !encoding.eq_ignore_ascii_case("WKB") {
+        return Err(MyError::Runtime(
+            format!("Unsupported '{encoding}' geometry encoding").into(),
+        ));
+    }
+    let srid = match column.get("crs") {
+        // an absent CRS means OGC:CRS84...
+        None => Some(4326),
+        // while a `null` one means undefined...
+        Some(Value::Null) => None,
+        Some(x) => srid_from_crs(x),
+    };
+    Ok((name.to_owned(), srid))
+}
+
+// Return the EPSG code of a PROJJSON CRS if we can tell; `None` otherwise.
+fn srid_from_crs(crs: &Value) -> Option<u32> {
+    // some writers use an authority:code string instead of PROJJSON...
+    let (authority, code) = match crs {
+        Value::String(x) => x
+            .split_once(':')
+            .map(|(a, c)| (a.to_owned(), c.to_owned()))?,
+        _ => {
+            let id = &crs["id"];
+            let code = match &id["code"] {
+                Value::Number(x) => x.to_string(),
+                Value::String(x) => x.to_owned(),
+                _ => String::new(),
+            };
+            (
+                id["authority"].as_str().unwrap_or_default().to_owned(),
+                code,
+            )
+        }
+    };
+    match (authority.to_uppercase().as_str(), code.as_str()) {
+        ("OGC", "CRS84") => Some(4326),
+        ("EPSG", x) => x.parse().ok(),
+        _ => {
+            warn!("Unable to tell the SRID of {crs}");
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GTrait;
+    use parquet::{
+        data_type::{ByteArray, ByteArrayType, DoubleType, Int32Type},
+        file::{metadata::KeyValue, properties::WriterProperties, writer::SerializedFileWriter},
+        schema::parser::parse_message_type,
+    };
+    use std::{error::Error, sync::Arc};
+
+    const SCHEMA: &str = "message test {
+        REQUIRED INT32 fid;
+        REQUIRED BYTE_ARRAY name (UTF8);
+        REQUIRED DOUBLE pop;
+        REQUIRED BYTE_ARRAY geometry;
+    }";
+
+    // little-endian WKB of POINT (x y)...
+    fn wkb_point(x: f64, y: f64) -> ByteArray {
+        let mut result = vec![0x01, 0x01, 0x00, 0x00, 0x00];
+        result.extend(x.to_le_bytes());
+        result.extend(y.to_le_bytes());
+        ByteArray::from(result)
+    }
+
+    // write 2 row groups of 2 rows each...
+    fn write_sample(path: &PathBuf, geo: &str) -> Result<(), Box<dyn Error>> {
+        let schema = Arc::new(parse_message_type(SCHEMA)?);
+        let kv = KeyValue::new(GEO_METADATA_KEY.into(), geo.to_owned());
+        let props = WriterProperties::builder()
+            .set_key_value_metadata(Some(vec![kv]))
+            .build();
+        let mut writer = SerializedFileWriter::new(File::create(path)?, schema, Arc::new(props))?;
+        for g in 0..2 {
+            let fids = [2 * g + 1, 2 * g + 2];
+            let names: Vec<ByteArray> = fids.iter().map(|x| format!("f{x}").into()).collect();
+            let pops: Vec<f64> = fids.iter().map(|x| f64::from(*x) * 1000.0).collect();
+            let geoms: Vec<ByteArray> = fids
+                .iter()
+                .map(|x| wkb_point(f64::from(*x), -f64::from(*x)))
+                .collect();
+
+            let mut group = writer.next_row_group()?;
+            let mut col = group.next_column()?.expect("Missing fid");
+            col.typed::<Int32Type>().write_batch(&fids, None, None)?;
+            col.close()?;
+            let mut col = group.next_column()?.expect("Missing name");
+            col.typed::<ByteArrayType>()
+                .write_batch(&names, None, None)?;
+            col.close()?;
+            let mut col = group.next_column()?.expect("Missing pop");
+            col.typed::<DoubleType>().write_batch(&pops, None, None)?;
+            col.close()?;
+            let mut col = group.next_column()?.expect("Missing geometry");
+            col.typed::<ByteArrayType>()
+                .write_batch(&geoms, None, None)?;
+            col.close()?;
+            group.close()?;
+        }
+        writer.close()?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_geo_metadata() -> Result<(), Box<dyn Error>> {
+        const GEO: &str = r#"{"version":"1.1.0","primary_column":"geometry",
+            "columns":{"geometry":{"encoding":"WKB","geometry_types":[]}}}"#;
+        assert_eq!(parse_geo_metadata(GEO)?, ("geometry".into(), Some(4326)));
+
+        const GEO_NULL: &str = r#"{"primary_column":"g",
+            "columns":{"g":{"encoding":"WKB","crs":null}}}"#;
+        assert_eq!(parse_geo_metadata(GEO_NULL)?, ("g".into(), None));
+
+        const GEO_EPSG: &str = r#"{"primary_column":"g",
+            "columns":{"g":{"encoding":"WKB","crs":{"id":{"authority":"EPSG","code":3857}}}}}"#;
+        assert_eq!(parse_geo_metadata(GEO_EPSG)?, ("g".into(), Some(3857)));
+
+        const GEO_ARROW: &str = r#"{"primary_column":"g",
+            "columns":{"g":{"encoding":"point"}}}"#;
+        assert!(parse_geo_metadata(GEO_ARROW).is_err());
+
+        const GEO_MISSING: &str = r#"{"primary_column":"g","columns":{}}"#;
+        assert!(parse_geo_metadata(GEO_MISSING).is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_geoparquet() -> Result<(), Box<dyn Error>> {
+        const GEO: &str = r#"{"version":"1.1.0","primary_column":"geometry",
+            "columns":{"geometry":{"encoding":"WKB","crs":{"id":{"authority":"EPSG","code":4326}}}}}"#;
+
+        let path = std::env::temp_dir().join("ogc_cql2_test_geoparquet.parquet");
+        write_sample(&path, GEO)?;
+
+        let ds = GeoParquetDataSource::from(path.to_str().expect("Invalid path"))?;
+        assert_eq!(ds.geom_column(), "geometry");
+        assert_eq!(ds.srid(), Some(4326));
+
+        let features: Vec<_> = ds.iter()?.collect::<Result<_, _>>()?;
+        assert_eq!(features.len(), 4);
+        let f3 = &features[2];
+        assert_eq!(f3.properties()["fid"], Q::Num(3.0));
+        assert_eq!(f3.properties()["name"], Q::new_plain_str("f3"));
+        assert_eq!(f3.geom().to_wkt_fmt(0), "POINT (3 -3)");
+        assert_eq!(f3.geom().srid().into_inner(), 4326);
+
+        let resources: Vec<_> = ds.stream().await?.collect().await;
+        assert_eq!(resources.len(), 4);
+        assert!(resources.iter().all(|x| x.is_ok()));
+
+        let exp = Expression::try_from_text("pop > 1500 AND S_INTERSECTS(geom, BBOX(0,-5,5,0))")?;
+        let matches: Vec<_> = ds.stream_where(&exp).await?.collect().await;
+        assert_eq!(matches.len(), 3);
+
+        std::fs::remove_file(&path)?;
+        Ok(())
+    }
+}
@@ -7,19 +7,23 @@
 //!
 
 use crate::{
-    CRS, E, Expression, MyError, QString,
+    CRS, Context, E, Expression, G, MyError, Q, QString, Resource,
     config::config,
-    ds::{DataSource, sql::MIN_DATE_SQL},
+    context::DEFAULT_LIKE_ESCAPE,
+    ds::{DataSource, SortDir, sql::MIN_DATE_SQL, trace::redact_literals},
+    expr::CONCAT,
     op::Op,
+    srid::SRID,
 };
 use sqlx::{AssertSqlSafe, FromRow, Pool, Sqlite, pool::PoolOptions, sqlite::SqliteConnectOptions};
 use std::{cmp::Ordering, str::FromStr};
-use tracing::{debug, info};
+use tracing::{debug, info, warn};
 use unicode_normalization::{UnicodeNormalization, char::is_combining_mark};
 
 const GPKG_APPLICATION_ID: i32 = 0x47504B47;
 const FIND_TABLE: &str = "SELECT * FROM gpkg_contents WHERE table_name = $1";
 const FIND_SRS: &str = "SELECT * FROM gpkg_spatial_ref_sys WHERE srs_id = $1";
+const FIND_GEOM_COLUMN: &str = "SELECT * FROM gpkg_geometry_columns WHERE table_name = $1";
 const EPSG_AUTH: &str = "EPSG";
 
 /// Name of a collation that is case-insensitive.
@@ -28,6 +32,9 @@ const CQL2_CI: &str = "CQL2_CI";
 const CQL2_AI: &str = "CQL2_AI";
 /// Name of a collation that is both case- and accent-insensitive.
 const CQL2_CAI: &str = "CQL2_CI_AI";
+/// `strftime()` format to which dates and timestamps are normalized before
+/// being compared.
+const INSTANT_FMT: &str = "%Y-%m-%dT%H:%M:%f";
 
 // structure to read back a textual PRAGMA value.
 #[derive(Debug, FromRow)]
@@ -45,6 +52,13 @@ struct TSpatialRefSys {
     organization_coordsys_id: i32,
 }
 
+// Partial representation of a `gpkg_geometry_columns` table row.
+#[derive(Debug, FromRow)]
+struct TGeometryColumns {
+    column_name: String,
+    srs_id: i32,
+}
+
 // Partial representation of a GeoPackage `gpkg_contents` table row.
 #[allow(dead_code)]
 #[derive(Debug, FromRow)]
@@ -62,6 +76,11 @@ pub struct GPkgDataSource {
     layer: String,
     pool: Pool<Sqlite>,
     srid: Option<u32>,
+    geom_column: Option<String>,
+    // cached SRID to assign to decoded geometries...
+    geom_srid: Option<SRID>,
+    // translator of filters to SQL...
+    sql: SQLiteSQL,
 }
 
 impl DataSource for GPkgDataSource {
@@ -75,28 +94,7 @@ impl GPkgDataSource {
     pub async fn from(gpkg_url: &str, layer_name: &str) -> Result<Self, MyError> {
         // FIXME (rsn) 20251023 - allow configuring the pool from environment
         // variables.
-
-        // closure for case-insesnitive string comparisons.
-        // let collate_ci = |a: &str, b: &str| QString::cmp_ci(a, b);
-        let collate_ci = |a: &str, b: &str| cmp_ci(a, b);
-
-        // closure for accent-insensitive string comparisons.
-        let collate_ai = |a: &str, b: &str| cmp_ai(a, b);
-
-        // closure for accent- and case-insensitive string comparisons.
-        let collate_aci = |a: &str, b: &str| cmp_aci(a, b);
-
-        // IMPORTANT - this is UNSAFE but i have no control over how to do it
-        // differently since handling GeoPackage data sources is a no go w/o
-        // `spatialite`...
-        let pool_opts = unsafe {
-            SqliteConnectOptions::from_str(gpkg_url)?
-                .extension("mod_spatialite")
-                .collation(CQL2_CI, collate_ci)
-                .collation(CQL2_AI, collate_ai)
-                .collation(CQL2_CAI, collate_aci)
-        };
-
+        let pool_opts = sqlite_options(gpkg_url)?;
         let pool = PoolOptions::new().connect_with(pool_opts).await?;
 
         // GeoPackage SQLite DB files are expected to have 0x47504B47 (or 1196444487)
@@ -143,7 +141,18 @@ impl GPkgDataSource {
         let safe_sql = AssertSqlSafe(sql);
         sqlx::query(safe_sql).execute(&pool).await?;
 
-        let srid = match layer.srs_id {
+        // NOTE (rsn) 20261016 - the authoritative SRS of a layer's geometries
+        // is the one of its geometry column.  only fall back to the layer's
+        // `gpkg_contents` one if it has none...
+        let geom_column = sqlx::query_as::<_, TGeometryColumns>(FIND_GEOM_COLUMN)
+            .bind(layer_name)
+            .fetch_optional(&pool)
+            .await?;
+        let srs_id = match &geom_column {
+            Some(x) => Some(x.srs_id),
+            None => layer.srs_id,
+        };
+        let srid = match srs_id {
             // NOTE (rsn) 20251021 - the specs mandate the support for at least
             // 3 values: `4326`, `-1`, and `0` w/ the last 2 to indicate an
             // "undefined" cartesian or geographic system respectively.  ensure
@@ -176,7 +185,7 @@ impl GPkgDataSource {
                     }
 
                     let it = srs.organization_coordsys_id;
-                    let epsg_code = format!("{authority}:{x}");
+                    let epsg_code = format!("{authority}:{it}");
                     // raise an error if Proj cannot handle it...
                     let _ = CRS::new(&epsg_code)?;
                     Some(u32::try_from(it)?)
@@ -184,15 +193,53 @@ impl GPkgDataSource {
             },
             None => None,
         };
-        debug!("srid = {srid:?}");
+        let geom_column = geom_column.map(|x| x.column_name);
+        debug!("geom_column = {geom_column:?}, srid = {srid:?}");
+        let geom_srid = match srid {
+            Some(x) => Some(SRID::try_from(i32::try_from(x)?)?),
+            None => None,
+        };
 
         Ok(Self {
             layer: layer_name.to_owned(),
             pool,
             srid,
+            geom_column,
+            geom_srid,
+            sql: SQLiteSQL::default(),
         })
     }
 
+    /// Translate filters to SQL w/ the settings of the given [Context]; i.e.
+    /// its LIKE escape character.
+    pub fn with_context(mut self, ctx: &Context) -> Self {
+        self.sql = SQLiteSQL::new(ctx);
+        self
+    }
+
+    /// Return the name of this layer's geometry column as recorded in the
+    /// `gpkg_geometry_columns` table if any.
+    pub fn geom_column(&self) -> Option<&str> {
+        self.geom_column.as_deref()
+    }
+
+    /// Assign the SRID discovered when this data source was opened to every
+    /// geometry value in the given [Resource].
+    ///
+    /// This is a no-op if the layer uses one of the undefined SRSes.
+    pub fn set_srid(&self, resource: &mut Resource) {
+        let Some(srid) = &self.geom_srid else {
+            return;
+        };
+        resource
+            .values_mut()
+            .filter_map(|x| match x {
+                Q::Geom(g) if !matches!(g, G::Null) => Some(g),
+                _ => None,
+            })
+            .for_each(|g| g.set_srid_unchecked(srid));
+    }
+
     /// Return a reference to the connection pool.
     pub fn pool(&self) -> &Pool<Sqlite> {
         &self.pool
@@ -210,10 +257,76 @@ impl GPkgDataSource {
     /// Transform given [Expression] to an SQL _WHERE_ clause that can be used
     /// for selecting a subset of this data source items.
     pub fn to_sql(&self, exp: &Expression) -> Result<String, MyError> {
+        self.sql.to_sql(exp)
+    }
+
+    /// Return the SQL _SELECT_ statement, w/o executing it, that data sources
+    /// generated by [`gen_gpkg_ds!`][crate::gen_gpkg_ds] run to fetch the rows
+    /// of this layer that match the given [Expression].
+    ///
+    /// The _WHERE_ clause is the one returned by [`to_sql()`][Self::to_sql].
+    /// It has no bind parameters; i.e. literal values are inlined. Limits,
+    /// offsets and _ORDER BY_ clauses are appended to this statement when
+    /// requested.
+    pub fn to_sql_where(&self, exp: &Expression) -> Result<String, MyError> {
+        let where_clause = self.to_sql(exp)?;
+        Ok(format!(
+            r#"SELECT * FROM "{}" WHERE {}"#,
+            self.vtable(),
+            where_clause
+        ))
+    }
+
+    /// Return an SQL _ORDER BY_ clause, incl. a leading space, sorting rows by
+    /// the given columns each in its own [direction][SortDir]. Return an empty
+    /// string if there are none.
+    pub fn to_order_by_sql(&self, order_by: &[(String, SortDir)]) -> String {
+        if order_by.is_empty() {
+            return String::new();
+        }
+
+        let columns: Vec<_> = order_by
+            .iter()
+            .map(|(name, dir)| format!("{} {}", double_quoted(name), dir.to_sql()))
+            .collect();
+        format!(" ORDER BY {}", columns.join(", "))
+    }
+}
+
+/// Translator of [Expression]s to SQLite + `spatialite` SQL _WHERE_ clauses.
+///
+/// Shared by the data sources that delegate filtering to an SQLite database
+/// whose connections were configured w/ [`sqlite_options()`].
+#[derive(Debug)]
+pub(crate) struct SQLiteSQL {
+    like_escape: char,
+}
+
+impl Default for SQLiteSQL {
+    fn default() -> Self {
+        Self {
+            like_escape: DEFAULT_LIKE_ESCAPE,
+        }
+    }
+}
+
+impl SQLiteSQL {
+    /// Create a new instance using the LIKE escape character of `ctx`.
+    pub(crate) fn new(ctx: &Context) -> Self {
+        Self {
+            like_escape: ctx.like_escape(),
+        }
+    }
+
+    /// Transform given [Expression] to an SQL _WHERE_ clause.
+    pub(crate) fn to_sql(&self, exp: &Expression) -> Result<String, MyError> {
         let mut e = exp.to_inner()?;
         let it = E::reduce(&mut e)?;
         let res = self.to_sql_impl(it);
-        debug!("to_sql: {res:?}");
+        match &res {
+            Ok(x) => debug!("to_sql: {}", redact_literals(x)),
+            Err(x) => debug!("to_sql: {x}"),
+        }
         res
     }
 
@@ -224,11 +337,15 @@ impl GPkgDataSource {
             E::Bool(true) => Ok("TRUE".to_owned()),
             E::Bool(false) => Ok("FALSE".to_owned()),
             E::Num(x) => Ok(x.to_string()),
+            E::Int(x) => Ok(x.to_string()),
             E::Str(x) => qstr_to_sql(x),
             E::Date(x) => Ok(format!("'{}'", x.date())),
             E::Timestamp(x) => Ok(format!("'{}'", x.datetime())),
             E::Spatial(x) => Ok(x.to_sql()?),
             E::Id(x) => Ok(x.to_owned()),
+            E::Param(x) => Err(MyError::Runtime(
+                format!("Parameter '${{{x}}}' is not bound").into(),
+            )),
             // some work need to be done when handling these options...
             E::Monadic(op, x) if op.nullable() => {
                 let is_literal = x.is_literal_or_id();
@@ -273,6 +390,11 @@ impl GPkgDataSource {
                 },
                 x => unreachable!("Unexpected ({x}) monadic operator"),
             },
+            E::Dyadic(op, _, b) if matches!(op, Op::IsInList | Op::IsNotInList) && b.is_id() => {
+                Err(MyError::Unsupported(
+                    format!("[NOT] IN an array-valued property ({b}) has no SQL equivalent").into(),
+                ))
+            }
             E::Dyadic(op, a, b)
                 if matches!(op, Op::IsBetween) || matches!(op, Op::IsNotBetween) =>
             {
@@ -322,14 +444,28 @@ impl GPkgDataSource {
                 let rhs = self.to_sql_impl(*b)?;
                 Ok(format!("{lhs} {z_op} {rhs}"))
             }
+            E::Dyadic(op, a, b)
+                if matches!(op, Op::IsLike | Op::IsNotLike)
+                    && (is_insensitive(&a) || is_insensitive(&b)) =>
+            {
+                self.like_insensitive_sql(op, *a, *b)
+            }
+            E::Dyadic(op, a, b)
+                if matches!(op, Op::Eq | Op::Neq) && (is_insensitive(&a) || is_insensitive(&b)) =>
+            {
+                self.eq_insensitive_sql(op, *a, *b)
+            }
             E::Dyadic(op, a, b) if matches!(op, Op::IsLike) || matches!(op, Op::IsNotLike) => {
                 let a_is_literal = a.is_literal_or_id();
                 let lhs = self.to_sql_impl(*a)?;
                 let rhs = self.to_sql_impl(*b)?;
                 let z_op = op.to_sql();
+                // NOTE (rsn) 20261016 - unlike PostgreSQL, SQLite has no default
+                // LIKE escape character.  use the same one as our Context's...
+                let escape = quoted(&self.like_escape.to_string());
                 match a_is_literal {
-                    true => Ok(format!("{lhs} {z_op} ({rhs})")),
-                    false => Ok(format!("({lhs}) {z_op} ({rhs})")),
+                    true => Ok(format!("{lhs} {z_op} ({rhs}) ESCAPE '{escape}'")),
+                    false => Ok(format!("({lhs}) {z_op} ({rhs}) ESCAPE '{escape}'")),
                 }
             }
             E::Dyadic(op, a, b) => {
@@ -349,6 +485,11 @@ impl GPkgDataSource {
                 let params: Result<Vec<String>, MyError> =
                     x.params.into_iter().map(|x| self.to_sql_impl(x)).collect();
                 let params_ = params?;
+                // `||` yields NULL if any operand is NULL as `concat()` does
+                // when evaluated in-process...
+                if x.name == CONCAT {
+                    return Ok(format!("({})", params_.join(" || ")));
+                }
                 Ok(format!("{}({})", x.name, params_.join(", ")))
             }
             // NOTE (rsn) 20251105 - SQLite does not accept array elements w/in
@@ -363,6 +504,84 @@ impl GPkgDataSource {
         }
     }
 
+    // NOTE (rsn) 20261016 - SQLite's LIKE ignores collations altogether and
+    // only folds the case of ASCII characters. when either side ignores case
+    // and/or accents we apply the same collation to both the column and the
+    // pattern by rewriting the LIKE as an equality whenever the pattern allows
+    // it; i.e. it has no wildcards, or only a single leading or trailing '%'.
+    // other patterns keep using LIKE, w/ the collation on both sides, knowing
+    // that SQLite will not honour it...
+    fn like_insensitive_sql(&self, op: Op, a: E, b: E) -> Result<String, MyError> {
+        let (a, a_flags) = strip_insensitive(a);
+        let (b, b_flags) = strip_insensitive(b);
+        let collation = collation_for(a_flags | b_flags)?;
+        let lhs = if a.is_literal_or_id() {
+            self.to_sql_impl(a)?
+        } else {
+            format!("({})", self.to_sql_impl(a)?)
+        };
+        let pattern = match &b {
+            E::Str(x) => LikePattern::from(x.as_str(), self.like_escape),
+            _ => LikePattern::Other,
+        };
+        let sql = match pattern {
+            LikePattern::Exact(x) => {
+                format!(
+                    "{lhs} COLLATE {collation} = '{}' COLLATE {collation}",
+                    quoted(&x)
+                )
+            }
+            LikePattern::Prefix(x) => format!(
+                "substr({lhs}, 1, {}) COLLATE {collation} = '{}' COLLATE {collation}",
+                x.chars().count(),
+                quoted(&x)
+            ),
+            LikePattern::Suffix(x) => format!(
+                "substr({lhs}, -{}) COLLATE {collation} = '{}' COLLATE {collation}",
+                x.chars().count(),
+                quoted(&x)
+            ),
+            LikePattern::Other => {
+                warn!("SQLite LIKE w/ a '{b}' pattern ignores the {collation} collation");
+                let rhs = self.to_sql_impl(b)?;
+                let escape = quoted(&self.like_escape.to_string());
+                format!(
+                    "{lhs} COLLATE {collation} LIKE {rhs} COLLATE {collation} ESCAPE '{escape}'"
+                )
+            }
+        };
+        if matches!(op, Op::IsLike) {
+            Ok(sql)
+        } else {
+            Ok(format!("NOT ({sql})"))
+        }
+    }
+
+    // NOTE (rsn) 20261016 - when the operands of a comparison carry different
+    // explicit collations, SQLite only honours the left-most one; e.g. in
+    // `x COLLATE CQL2_CI = 'y' COLLATE CQL2_AI` accents are not ignored. when
+    // evaluated in-process the flags of both sides are combined. do the same
+    // by applying the one collation matching all of them to both operands...
+    fn eq_insensitive_sql(&self, op: Op, a: E, b: E) -> Result<String, MyError> {
+        let (a, a_flags) = strip_insensitive(a);
+        let (b, b_flags) = strip_insensitive(b);
+        let collation = collation_for(a_flags | b_flags)?;
+        let lhs = if a.is_literal_or_id() {
+            self.to_sql_impl(a)?
+        } else {
+            format!("({})", self.to_sql_impl(a)?)
+        };
+        let rhs = if b.is_literal_or_id() {
+            self.to_sql_impl(b)?
+        } else {
+            format!("({})", self.to_sql_impl(b)?)
+        };
+        let z_op = op.to_sql();
+        Ok(format!(
+            "{lhs} COLLATE {collation} {z_op} {rhs} COLLATE {collation}"
+        ))
+    }
+
     // NOTE (rsn) 20251120 - Some spatial functions (i.e. `ST_Within`, `ST_Covers`,
     // and `ST_Touches`) w/ GeoPackage data sources do NOT yield same results to
     // those obtained when directly using GEOS, when one of the arguments is a table
@@ -404,28 +623,43 @@ impl GPkgDataSource {
         Ok(it)
     }
 
+    // NOTE (rsn) 20261016 - GeoPackage stores dates and timestamps as TEXT;
+    // the latter w/ or w/o fractional seconds and a trailing 'Z'. comparing
+    // those as strings is wrong when 2 instants are written differently, so
+    // columns and literals are normalized to the same (millisecond precision)
+    // format. unbounded interval starts are left as is; i.e. before any...
+    fn instant_sql(&self, x: E) -> Result<String, MyError> {
+        match x {
+            E::Id(_) | E::Date(_) | E::Timestamp(_) => Ok(format!(
+                "strftime('{INSTANT_FMT}', {})",
+                self.to_sql_impl(x)?
+            )),
+            _ => self.to_sql_impl(x),
+        }
+    }
+
     // mixed (instant and interval) arguments...
     fn t_after_sql(&self, a: E, b: E) -> Result<String, MyError> {
         let (a_is_interval, b_is_interval, e0, e1, e2, e3) = crate::unfold_expressions!(a, b);
         match (a_is_interval, b_is_interval) {
             (false, false) => Ok(format!(
                 "{} > {}",
-                self.to_sql_impl(e0)?,
-                self.to_sql_impl(e2)?
+                self.instant_sql(e0)?,
+                self.instant_sql(e2)?
             )),
             // w/ the remaining cases, we may need additional xxx IS NOT NULL fragments...
             (false, true) => {
-                let base = format!("{} > {}", self.to_sql_impl(e0)?, self.to_sql_impl(e3)?);
+                let base = format!("{} > {}", self.instant_sql(e0)?, self.instant_sql(e3)?);
                 let sql = crate::check_ids!(e2, base);
                 Ok(sql)
             }
             (true, false) => {
-                let base = format!("{} > {}", self.to_sql_impl(e0)?, self.to_sql_impl(e2)?);
+                let base = format!("{} > {}", self.instant_sql(e0)?, self.instant_sql(e2)?);
                 let sql = crate::check_ids!(e1, base);
                 Ok(sql)
             }
             (true, true) => {
-                let base = format!("{} > {}", self.to_sql_impl(e0)?, self.to_sql_impl(e3)?);
+                let base = format!("{} > {}", self.instant_sql(e0)?, self.instant_sql(e3)?);
                 let sql = crate::check_ids!(e1, e2, base);
                 Ok(sql)
             }
@@ -437,21 +671,21 @@ impl GPkgDataSource {
         match (a_is_interval, b_is_interval) {
             (false, false) => Ok(format!(
                 "{} < {}",
-                self.to_sql_impl(e0)?,
-                self.to_sql_impl(e2)?
+                self.instant_sql(e0)?,
+                self.instant_sql(e2)?
             )),
             (false, true) => {
-                let base = format!("{} < {}", self.to_sql_impl(e0)?, self.to_sql_impl(e2)?);
+                let base = format!("{} < {}", self.instant_sql(e0)?, self.instant_sql(e2)?);
                 let sql = crate::check_ids!(e3, base);
                 Ok(sql)
             }
             (true, false) => {
-                let base = format!("{} < {}", self.to_sql_impl(e1)?, self.to_sql_impl(e2)?);
+                let base = format!("{} < {}", self.instant_sql(e1)?, self.instant_sql(e2)?);
                 let sql = crate::check_ids!(e0, base);
                 Ok(sql)
             }
             (true, true) => {
-                let base = format!("{} < {}", self.to_sql_impl(e1)?, self.to_sql_impl(e2)?);
+                let base = format!("{} < {}", self.instant_sql(e1)?, self.instant_sql(e2)?);
                 let sql = crate::check_ids!(e0, e3, base);
                 Ok(sql)
             }
@@ -463,15 +697,15 @@ impl GPkgDataSource {
         match (a_is_interval, b_is_interval) {
             (false, false) => Ok(format!(
                 "{} != {}",
-                self.to_sql_impl(e0)?,
-                self.to_sql_impl(e2)?
+                self.instant_sql(e0)?,
+                self.instant_sql(e2)?
             )),
             (false, true) => {
                 let e2_ = e2.clone();
                 let e3_ = e3.clone();
-                let s0 = self.to_sql_impl(e0)?;
-                let s2 = self.to_sql_impl(e2)?;
-                let s3 = self.to_sql_impl(e3)?;
+                let s0 = self.instant_sql(e0)?;
+                let s2 = self.instant_sql(e2)?;
+                let s3 = self.instant_sql(e3)?;
                 let base1 = format!("{s0} < {s2}");
                 let sql1 = crate::check_ids!(e3_, base1);
                 let base2 = format!("{s0} > {s3}");
@@ -481,9 +715,9 @@ impl GPkgDataSource {
             (true, false) => {
                 let e0_ = e0.clone();
                 let e1_ = e1.clone();
-                let s0 = self.to_sql_impl(e0)?;
-                let s1 = self.to_sql_impl(e1)?;
-                let s2 = self.to_sql_impl(e2)?;
+                let s0 = self.instant_sql(e0)?;
+                let s1 = self.instant_sql(e1)?;
+                let s2 = self.instant_sql(e2)?;
                 let base1 = format!("{s1} < {s2}");
                 let sql1 = crate::check_ids!(e0_, base1);
                 let base2 = format!("{s0} > {s2}");
@@ -495,10 +729,10 @@ impl GPkgDataSource {
                 let e1_ = e1.clone();
                 let e2_ = e2.clone();
                 let e3_ = e3.clone();
-                let s0 = self.to_sql_impl(e0)?;
-                let s1 = self.to_sql_impl(e1)?;
-                let s2 = self.to_sql_impl(e2)?;
-                let s3 = self.to_sql_impl(e3)?;
+                let s0 = self.instant_sql(e0)?;
+                let s1 = self.instant_sql(e1)?;
+                let s2 = self.instant_sql(e2)?;
+                let s3 = self.instant_sql(e3)?;
                 let base1 = format!("{s1} < {s2}");
                 let sql1 = crate::check_ids!(e0_, e3_, base1);
                 let base2 = format!("{s0} > {s3}");
@@ -513,27 +747,27 @@ impl GPkgDataSource {
         match (a_is_interval, b_is_interval) {
             (false, false) => Ok(format!(
                 "{} = {}",
-                self.to_sql_impl(e0)?,
-                self.to_sql_impl(e2)?
+                self.instant_sql(e0)?,
+                self.instant_sql(e2)?
             )),
             (false, true) => Ok(format!(
                 "({0} = {1}) AND ({0} = {2})",
-                self.to_sql_impl(e0)?,
-                self.to_sql_impl(e2)?,
-                self.to_sql_impl(e3)?
+                self.instant_sql(e0)?,
+                self.instant_sql(e2)?,
+                self.instant_sql(e3)?
             )),
             (true, false) => Ok(format!(
                 "({0} = {2}) AND ({1} = {2})",
-                self.to_sql_impl(e0)?,
-                self.to_sql_impl(e1)?,
-                self.to_sql_impl(e2)?
+                self.instant_sql(e0)?,
+                self.instant_sql(e1)?,
+                self.instant_sql(e2)?
             )),
             (true, true) => Ok(format!(
                 "({0} = {2}) AND ({1} = {3})",
-                self.to_sql_impl(e0)?,
-                self.to_sql_impl(e1)?,
-                self.to_sql_impl(e2)?,
-                self.to_sql_impl(e3)?
+                self.instant_sql(e0)?,
+                self.instant_sql(e1)?,
+                self.instant_sql(e2)?,
+                self.instant_sql(e3)?
             )),
         }
     }
@@ -543,27 +777,27 @@ impl GPkgDataSource {
         match (a_is_interval, b_is_interval) {
             (false, false) => Ok(format!(
                 "{} = {}",
-                self.to_sql_impl(e0)?,
-                self.to_sql_impl(e2)?
+                self.instant_sql(e0)?,
+                self.instant_sql(e2)?
             )),
             (false, true) => Ok(format!(
                 "NOT(({0} < {1}) OR ({0} > {2}))",
-                self.to_sql_impl(e0)?,
-                self.to_sql_impl(e2)?,
-                self.to_sql_impl(e3)?
+                self.instant_sql(e0)?,
+                self.instant_sql(e2)?,
+                self.instant_sql(e3)?
             )),
             (true, false) => Ok(format!(
                 "NOT(({1} < {2}) OR ({0} > {2}))",
-                self.to_sql_impl(e0)?,
-                self.to_sql_impl(e1)?,
-                self.to_sql_impl(e2)?
+                self.instant_sql(e0)?,
+                self.instant_sql(e1)?,
+                self.instant_sql(e2)?
             )),
             (true, true) => Ok(format!(
                 "NOT(({1} < {2}) OR ({0} > {3}))",
-                self.to_sql_impl(e0)?,
-                self.to_sql_impl(e1)?,
-                self.to_sql_impl(e2)?,
-                self.to_sql_impl(e3)?
+                self.instant_sql(e0)?,
+                self.instant_sql(e1)?,
+                self.instant_sql(e2)?,
+                self.instant_sql(e3)?
             )),
         }
     }
@@ -573,10 +807,10 @@ impl GPkgDataSource {
         let (e0, e1, e2, e3) = crate::unfold_intervals!(a, b);
         Ok(format!(
             "({0} < {2}) AND ({1} > {3})",
-            self.to_sql_impl(e0)?,
-            self.to_sql_impl(e1)?,
-            self.to_sql_impl(e2)?,
-            self.to_sql_impl(e3)?
+            self.instant_sql(e0)?,
+            self.instant_sql(e1)?,
+            self.instant_sql(e2)?,
+            self.instant_sql(e3)?
         ))
     }
 
@@ -584,10 +818,10 @@ impl GPkgDataSource {
         let (e0, e1, e2, e3) = crate::unfold_intervals!(a, b);
         Ok(format!(
             "({0} > {2}) AND ({1} < {3})",
-            self.to_sql_impl(e0)?,
-            self.to_sql_impl(e1)?,
-            self.to_sql_impl(e2)?,
-            self.to_sql_impl(e3)?
+            self.instant_sql(e0)?,
+            self.instant_sql(e1)?,
+            self.instant_sql(e2)?,
+            self.instant_sql(e3)?
         ))
     }
 
@@ -595,10 +829,10 @@ impl GPkgDataSource {
         let (e0, e1, e2, e3) = crate::unfold_intervals!(a, b);
         Ok(format!(
             "({0} < {2}) AND ({1} = {3})",
-            self.to_sql_impl(e0)?,
-            self.to_sql_impl(e1)?,
-            self.to_sql_impl(e2)?,
-            self.to_sql_impl(e3)?
+            self.instant_sql(e0)?,
+            self.instant_sql(e1)?,
+            self.instant_sql(e2)?,
+            self.instant_sql(e3)?
         ))
     }
 
@@ -606,23 +840,23 @@ impl GPkgDataSource {
         let (e0, e1, e2, e3) = crate::unfold_intervals!(a, b);
         Ok(format!(
             "({0} > {2}) AND ({1} = {3})",
-            self.to_sql_impl(e0)?,
-            self.to_sql_impl(e1)?,
-            self.to_sql_impl(e2)?,
-            self.to_sql_impl(e3)?
+            self.instant_sql(e0)?,
+            self.instant_sql(e1)?,
+            self.instant_sql(e2)?,
+            self.instant_sql(e3)?
         ))
     }
 
     fn t_meets_sql(&self, a: E, b: E) -> Result<String, MyError> {
         let (e0, e1, e2, e3) = crate::unfold_intervals!(a, b);
-        let base = format!("{0} = {1}", self.to_sql_impl(e1)?, self.to_sql_impl(e2)?);
+        let base = format!("{0} = {1}", self.instant_sql(e1)?, self.instant_sql(e2)?);
         let sql = crate::check_ids!(e0, e3, base);
         Ok(sql)
     }
 
     fn t_met_by_sql(&self, a: E, b: E) -> Result<String, MyError> {
         let (e0, e1, e2, e3) = crate::unfold_intervals!(a, b);
-        let base = format!("{0} = {1}", self.to_sql_impl(e0)?, self.to_sql_impl(e3)?);
+        let base = format!("{0} = {1}", self.instant_sql(e0)?, self.instant_sql(e3)?);
         let sql = crate::check_ids!(e1, e2, base);
         Ok(sql)
     }
@@ -631,10 +865,10 @@ impl GPkgDataSource {
         let (e0, e1, e2, e3) = crate::unfold_intervals!(a, b);
         Ok(format!(
             "({0} > {2}) AND ({0} < {3}) AND ({1} > {3})",
-            self.to_sql_impl(e0)?,
-            self.to_sql_impl(e1)?,
-            self.to_sql_impl(e2)?,
-            self.to_sql_impl(e3)?
+            self.instant_sql(e0)?,
+            self.instant_sql(e1)?,
+            self.instant_sql(e2)?,
+            self.instant_sql(e3)?
         ))
     }
 
@@ -642,10 +876,10 @@ impl GPkgDataSource {
         let (e0, e1, e2, e3) = crate::unfold_intervals!(a, b);
         Ok(format!(
             "({0} < {2}) AND ({1} > {2}) AND ({1} < {3})",
-            self.to_sql_impl(e0)?,
-            self.to_sql_impl(e1)?,
-            self.to_sql_impl(e2)?,
-            self.to_sql_impl(e3)?
+            self.instant_sql(e0)?,
+            self.instant_sql(e1)?,
+            self.instant_sql(e2)?,
+            self.instant_sql(e3)?
         ))
     }
 
@@ -653,10 +887,10 @@ impl GPkgDataSource {
         let (e0, e1, e2, e3) = crate::unfold_intervals!(a, b);
         Ok(format!(
             "({0} = {2}) AND ({1} > {3})",
-            self.to_sql_impl(e0)?,
-            self.to_sql_impl(e1)?,
-            self.to_sql_impl(e2)?,
-            self.to_sql_impl(e3)?
+            self.instant_sql(e0)?,
+            self.instant_sql(e1)?,
+            self.instant_sql(e2)?,
+            self.instant_sql(e3)?
         ))
     }
 
@@ -664,14 +898,39 @@ impl GPkgDataSource {
         let (e0, e1, e2, e3) = crate::unfold_intervals!(a, b);
         Ok(format!(
             "({0} = {2}) AND ({1} < {3})",
-            self.to_sql_impl(e0)?,
-            self.to_sql_impl(e1)?,
-            self.to_sql_impl(e2)?,
-            self.to_sql_impl(e3)?
+            self.instant_sql(e0)?,
+            self.instant_sql(e1)?,
+            self.instant_sql(e2)?,
+            self.instant_sql(e3)?
         ))
     }
 }
 
+/// Return SQLite connection options for the given database URL w/ `spatialite`
+/// loaded, and the collations used by [`SQLiteSQL`] registered.
+pub(crate) fn sqlite_options(url: &str) -> Result<SqliteConnectOptions, MyError> {
+    // closure for case-insesnitive string comparisons.
+    let collate_ci = |a: &str, b: &str| cmp_ci(a, b);
+
+    // closure for accent-insensitive string comparisons.
+    let collate_ai = |a: &str, b: &str| cmp_ai(a, b);
+
+    // closure for accent- and case-insensitive string comparisons.
+    let collate_aci = |a: &str, b: &str| cmp_aci(a, b);
+
+    // IMPORTANT - this is UNSAFE but i have no control over how to do it
+    // differently since handling GeoPackage data sources is a no go w/o
+    // `spatialite`...
+    let result = unsafe {
+        SqliteConnectOptions::from_str(url)?
+            .extension("mod_spatialite")
+            .collation(CQL2_CI, collate_ci)
+            .collation(CQL2_AI, collate_ai)
+            .collation(CQL2_CAI, collate_aci)
+    };
+    Ok(result)
+}
+
 /// Return the [Ordering] when comparing `a` to `b` ignoring case.
 fn cmp_ci(a: &str, b: &str) -> Ordering {
     a.to_lowercase().cmp(&b.to_lowercase())
@@ -694,6 +953,101 @@ fn cmp_aci(a: &str, b: &str) -> Ordering {
     lhs.cmp(rhs)
 }
 
+// Return TRUE if `e` ignores case and/or accents; FALSE otherwise.
+fn is_insensitive(e: &E) -> bool {
+    match e {
+        E::Monadic(Op::CaseI | Op::AccentI, _) => true,
+        E::Str(x) => x.flags() != 0,
+        _ => false,
+    }
+}
+
+// Return the expression stripped of its CASEI and ACCENTI wrappers, or flags
+// in the case of a string literal, + the flags of what was stripped.
+fn strip_insensitive(e: E) -> (E, u8) {
+    match e {
+        E::Monadic(Op::CaseI, x) => {
+            let (y, flags) = strip_insensitive(*x);
+            (y, flags | 1)
+        }
+        E::Monadic(Op::AccentI, x) => {
+            let (y, flags) = strip_insensitive(*x);
+            (y, flags | 2)
+        }
+        E::Str(x) => {
+            let flags = x.flags();
+            (E::Str(QString::plain(x.as_str())), flags)
+        }
+        x => (x, 0),
+    }
+}
+
+// Return the name of the collation matching the given QString-like flags.
+fn collation_for(flags: u8) -> Result<&'static str, MyError> {
+    match flags {
+        1 => Ok(CQL2_CI),
+        2 => Ok(CQL2_AI),
+        3 => Ok(CQL2_CAI),
+        x => {
+            let msg = format!("There's no collation for '{x}' flags");
+            Err(MyError::Unsupported(msg.into()))
+        }
+    }
+}
+
+// Double the single-quotes in `s` so it can be used as an SQL string literal.
+fn quoted(s: &str) -> String {
+    s.replace('\'', "''")
+}
+
+// Surround `s` w/ double-quotes, doubling the ones in it, so it can be used as
+// an SQL identifier.
+pub(crate) fn double_quoted(s: &str) -> String {
+    format!("\"{}\"", s.replace('"', "\"\""))
+}
+
+// Shape of a LIKE pattern w/ its escaped characters replaced by themselves.
+#[derive(Debug, PartialEq)]
+enum LikePattern {
+    // no wildcards at all...
+    Exact(String),
+    // a single trailing '%'...
+    Prefix(String),
+    // a single leading '%'...
+    Suffix(String),
+    // anything else...
+    Other,
+}
+
+impl LikePattern {
+    fn from(pattern: &str, escape: char) -> Self {
+        let mut literal = String::new();
+        // positions, in `literal`, of the '%' wildcards...
+        let mut percents = vec![];
+        let mut chars = pattern.chars();
+        while let Some(c) = chars.next() {
+            if c == escape {
+                match chars.next() {
+                    Some(x) => literal.push(x),
+                    None => return LikePattern::Other,
+                }
+            } else if c == '_' {
+                return LikePattern::Other;
+            } else if c == '%' {
+                percents.push(literal.len());
+            } else {
+                literal.push(c);
+            }
+        }
+        match percents[..] {
+            [] => LikePattern::Exact(literal),
+            [x] if x == literal.len() => LikePattern::Prefix(literal),
+            [0] => LikePattern::Suffix(literal),
+            _ => LikePattern::Other,
+        }
+    }
+}
+
 /// Generate a string that can be used in composing an SQL WHERE clause.
 fn qstr_to_sql(qs: QString) -> Result<String, MyError> {
     match qs.flags() {
@@ -703,7 +1057,7 @@ fn qstr_to_sql(qs: QString) -> Result<String, MyError> {
         3 => Ok(format!("'{}' COLLATE {CQL2_CAI}", qs.inner())),
         x => {
             let msg = format!("String w/ '{x}' flags has NO direct SQL representation");
-            Err(MyError::Runtime(msg.into()))
+            Err(MyError::Unsupported(msg.into()))
         }
     }
 }
@@ -719,6 +1073,10 @@ fn qstr_to_sql(qs: QString) -> Result<String, MyError> {
 /// * `$layer`: Name of the table/layer containing the features' data.
 /// * `$feature`: `sqlx` _FromRow_ convertible structure to map database layer
 ///   table rows to _Features_.
+///
+/// Geometries of streamed [Resources][crate::Resource] are assigned the SRID
+/// of the layer's geometry column as recorded in the GeoPackage itself. See
+/// [`GPkgDataSource::set_srid()`].
 #[macro_export]
 macro_rules! gen_gpkg_ds {
     ($vis:vis, $name:expr, $gpkg_url:expr, $layer:expr, $feature:expr) => {
@@ -744,6 +1102,12 @@ macro_rules! gen_gpkg_ds {
                     self.0.vtable()
                 }
 
+                /// Translate filters to SQL w/ the settings of the given Context.
+                #[allow(dead_code)]
+                $vis fn with_context(self, ctx: &$crate::Context) -> Self {
+                    Self(self.0.with_context(ctx))
+                }
+
                 /// Return a reference to the inner model data source.
                 $vis fn inner(&self) -> &GPkgDataSource {
                     &self.0
@@ -765,11 +1129,12 @@ macro_rules! gen_gpkg_ds {
                     &self
                 ) -> Result<::futures::stream::BoxStream<'_, Result<$feature, MyError>>, MyError> {
                     let sql = format!("SELECT * FROM {}", $layer);
+                    let span = ::tracing::debug_span!("fetch", ds = %self, sql = %sql);
                     let safe_sql = ::sqlx::AssertSqlSafe(sql);
                     let it = sqlx::query_as::<_, $feature>(safe_sql)
                         .fetch(self.0.pool())
                         .map_err(MyError::SQL);
-                    Ok(Box::pin(it))
+                    Ok($crate::traced(span, Box::pin(it)))
                 }
 
                 async fn stream(
@@ -777,12 +1142,60 @@ macro_rules! gen_gpkg_ds {
                 ) -> Result<::futures::stream::BoxStream<'_, Result<Resource, MyError>>, MyError> {
                     let rows = self.fetch().await?;
                     let resources = rows
-                        .try_filter_map(|row| async move {
-                            match Resource::try_from(row) {
-                                Ok(x) => Ok(Some(x)),
-                                Err(x) => Err(x),
+                        .try_filter_map(move |row| async move {
+                            let mut x = Resource::try_from(row)?;
+                            self.0.set_srid(&mut x);
+                            Ok(Some(x))
+                        })
+                        .boxed();
+                    Ok(resources)
+                }
+
+                async fn stream_with(
+                    &self,
+                    cfg: &StreamConfig,
+                ) -> Result<::futures::stream::BoxStream<'_, Result<Resource, MyError>>, MyError> {
+                    let Some(n) = cfg.chunk_size() else {
+                        return self.stream().await;
+                    };
+                    // NOTE (rsn) 20261016 - every chunk is fetched w/ its own
+                    // query, in `rowid` order so pages neither overlap nor skip
+                    // rows, until an empty one or an error...
+                    let chunks = ::futures::stream::iter((0_usize..).step_by(n))
+                        .map(move |offset| {
+                            let sql = format!(
+                                "SELECT * FROM {} ORDER BY rowid LIMIT {} OFFSET {}",
+                                $layer, n, offset
+                            );
+                            async move {
+                                let safe_sql = ::sqlx::AssertSqlSafe(sql);
+                                sqlx::query_as::<_, $feature>(safe_sql)
+                                    .fetch_all(self.0.pool())
+                                    .await
+                                    .map_err(MyError::SQL)
                             }
                         })
+                        .buffered(cfg.prefetch())
+                        .scan(false, |failed, x| {
+                            let it = match x {
+                                _ if *failed => None,
+                                Ok(rows) if rows.is_empty() => None,
+                                Ok(rows) => Some(Ok(rows)),
+                                Err(x) => {
+                                    *failed = true;
+                                    Some(Err(x))
+                                }
+                            };
+                            ::futures::future::ready(it)
+                        });
+                    let resources = chunks
+                        .map_ok(|rows| ::futures::stream::iter(rows.into_iter().map(Ok::<_, MyError>)))
+                        .try_flatten()
+                        .try_filter_map(move |row| async move {
+                            let mut x = Resource::try_from(row)?;
+                            self.0.set_srid(&mut x);
+                            Ok(Some(x))
+                        })
                         .boxed();
                     Ok(resources)
                 }
@@ -791,13 +1204,17 @@ macro_rules! gen_gpkg_ds {
                     &self,
                     exp: &Expression,
                 ) -> Result<::futures::stream::BoxStream<'_, Result<$feature, MyError>>, MyError> {
-                    let where_clause = self.0.to_sql(exp)?;
-                    let sql = format!(r#"SELECT * FROM "{}" WHERE {}"#, self.vtable(), where_clause);
+                    let sql = self.0.to_sql_where(exp)?;
+                    let span = ::tracing::debug_span!(
+                        "fetch_where",
+                        ds = %self,
+                        sql = %$crate::redact_literals(&sql)
+                    );
                     let safe_sql = ::sqlx::AssertSqlSafe(sql);
                     let it = sqlx::query_as::<_, $feature>(safe_sql)
                         .fetch(self.0.pool())
                         .map_err(MyError::SQL);
-                    Ok(Box::pin(it))
+                    Ok($crate::traced(span, Box::pin(it)))
                 }
 
                 async fn stream_where(
@@ -806,11 +1223,77 @@ macro_rules! gen_gpkg_ds {
                 ) -> Result<::futures::stream::BoxStream<'_, Result<Resource, MyError>>, MyError> {
                     let rows = self.fetch_where(exp).await?;
                     let resources = rows
-                        .try_filter_map(|row| async move {
-                            match Resource::try_from(row) {
-                                Ok(x) => Ok(Some(x)),
-                                Err(x) => Err(x),
-                            }
+                        .try_filter_map(move |row| async move {
+                            let mut x = Resource::try_from(row)?;
+                            self.0.set_srid(&mut x);
+                            Ok(Some(x))
+                        })
+                        .boxed();
+                    Ok(resources)
+                }
+
+                async fn fetch_where_limit(
+                    &self,
+                    exp: &Expression,
+                    limit: usize,
+                    offset: usize,
+                ) -> Result<::futures::stream::BoxStream<'_, Result<$feature, MyError>>, MyError> {
+                    let sql = format!(
+                        "{} LIMIT {} OFFSET {}",
+                        self.0.to_sql_where(exp)?, limit, offset
+                    );
+                    let span = ::tracing::debug_span!(
+                        "fetch_where_limit",
+                        ds = %self,
+                        sql = %$crate::redact_literals(&sql)
+                    );
+                    let safe_sql = ::sqlx::AssertSqlSafe(sql);
+                    let it = sqlx::query_as::<_, $feature>(safe_sql)
+                        .fetch(self.0.pool())
+                        .map_err(MyError::SQL);
+                    Ok($crate::traced(span, Box::pin(it)))
+                }
+
+                async fn stream_where_limit(
+                    &self,
+                    exp: &Expression,
+                    limit: usize,
+                    offset: usize,
+                ) -> Result<::futures::stream::BoxStream<'_, Result<Resource, MyError>>, MyError> {
+                    let rows = self.fetch_where_limit(exp, limit, offset).await?;
+                    let resources = rows
+                        .try_filter_map(move |row| async move {
+                            let mut x = Resource::try_from(row)?;
+                            self.0.set_srid(&mut x);
+                            Ok(Some(x))
+                        })
+                        .boxed();
+                    Ok(resources)
+                }
+
+                async fn stream_where_ordered(
+                    &self,
+                    exp: &Expression,
+                    order_by: &[(String, SortDir)],
+                ) -> Result<::futures::stream::BoxStream<'_, Result<Resource, MyError>>, MyError> {
+                    let sql = format!(
+                        "{}{}",
+                        self.0.to_sql_where(exp)?, self.0.to_order_by_sql(order_by)
+                    );
+                    let span = ::tracing::debug_span!(
+                        "stream_where_ordered",
+                        ds = %self,
+                        sql = %$crate::redact_literals(&sql)
+                    );
+                    let safe_sql = ::sqlx::AssertSqlSafe(sql);
+                    let rows = sqlx::query_as::<_, $feature>(safe_sql)
+                        .fetch(self.0.pool())
+                        .map_err(MyError::SQL);
+                    let resources = $crate::traced(span, Box::pin(rows))
+                        .try_filter_map(move |row| async move {
+                            let mut x = Resource::try_from(row)?;
+                            self.0.set_srid(&mut x);
+                            Ok(Some(x))
                         })
                         .boxed();
                     Ok(resources)
@@ -856,4 +1339,158 @@ mod tests {
         let eq = cmp_aci("ábc", "ABÇ");
         assert_eq!(eq, Ordering::Equal);
     }
+
+    #[test]
+    fn test_like_pattern() {
+        const ESC: char = DEFAULT_LIKE_ESCAPE;
+        assert_eq!(
+            LikePattern::from("abc", ESC),
+            LikePattern::Exact("abc".into())
+        );
+        assert_eq!(
+            LikePattern::from("ab%", ESC),
+            LikePattern::Prefix("ab".into())
+        );
+        assert_eq!(
+            LikePattern::from("%bc", ESC),
+            LikePattern::Suffix("bc".into())
+        );
+        assert_eq!(LikePattern::from("%", ESC), LikePattern::Prefix("".into()));
+        assert_eq!(
+            LikePattern::from(r"a\%c", ESC),
+            LikePattern::Exact("a%c".into())
+        );
+        assert_eq!(
+            LikePattern::from(r"a\_%", ESC),
+            LikePattern::Prefix("a_".into())
+        );
+        assert_eq!(LikePattern::from("a_c", ESC), LikePattern::Other);
+        assert_eq!(LikePattern::from("%b%", ESC), LikePattern::Other);
+        assert_eq!(LikePattern::from("a%c", ESC), LikePattern::Other);
+    }
+
+    #[test]
+    fn test_like_collation_sql() -> Result<(), MyError> {
+        #[rustfmt::skip]
+        const TV: [(&str, &str); 5] = [
+            (
+                r#"ACCENTI(CASEI("name")) LIKE accenti(casei('chis%'))"#,
+                r#"substr("name", 1, 4) COLLATE CQL2_CI_AI = 'chis' COLLATE CQL2_CI_AI"#,
+            ),
+            (
+                "CASEI(name) NOT LIKE casei('%Town')",
+                "NOT (substr(name, -4) COLLATE CQL2_CI = 'Town' COLLATE CQL2_CI)",
+            ),
+            // the pattern's folding applies to the column too...
+            (
+                "name LIKE accenti('Chișinău')",
+                "name COLLATE CQL2_AI = 'Chișinău' COLLATE CQL2_AI",
+            ),
+            // ...and vice-versa...
+            (
+                "ACCENTI(name) LIKE casei('Ch%')",
+                "substr(name, 1, 2) COLLATE CQL2_CI_AI = 'Ch' COLLATE CQL2_CI_AI",
+            ),
+            (
+                "CASEI(name) LIKE casei('B_r%')",
+                r"name COLLATE CQL2_CI LIKE 'B_r%' COLLATE CQL2_CI ESCAPE '\'",
+            ),
+        ];
+        for (src, expected) in TV {
+            let exp = Expression::try_from_text(src)?;
+            let actual = SQLiteSQL::default().to_sql(&exp)?;
+            assert_eq!(actual, expected, "{src}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_like_escape_sql() -> Result<(), MyError> {
+        let ctx = Context::new().try_with_like_escape('!')?;
+        let sql = SQLiteSQL::new(&ctx);
+
+        let exp = Expression::try_from_text("name LIKE '100!%'")?;
+        assert_eq!(sql.to_sql(&exp)?, "name LIKE ('100!%') ESCAPE '!'");
+        // the pattern is split on the same escape...
+        let exp = Expression::try_from_text("CASEI(name) LIKE casei('a!_%')")?;
+        assert_eq!(
+            sql.to_sql(&exp)?,
+            "substr(name, 1, 2) COLLATE CQL2_CI = 'a_' COLLATE CQL2_CI"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_eq_collation_sql() -> Result<(), MyError> {
+        #[rustfmt::skip]
+        const TV: [(&str, &str); 4] = [
+            (
+                r#"CASEI("name") = casei('foo')"#,
+                r#""name" COLLATE CQL2_CI = 'foo' COLLATE CQL2_CI"#,
+            ),
+            (
+                "ACCENTI(name) <> accenti('Chișinău')",
+                "name COLLATE CQL2_AI <> 'Chișinău' COLLATE CQL2_AI",
+            ),
+            // the folding of either side applies to both...
+            (
+                "CASEI(name) = accenti('kobenhavn')",
+                "name COLLATE CQL2_CI_AI = 'kobenhavn' COLLATE CQL2_CI_AI",
+            ),
+            (
+                "name = casei('Kiev')",
+                "name COLLATE CQL2_CI = 'Kiev' COLLATE CQL2_CI",
+            ),
+        ];
+        for (src, expected) in TV {
+            let exp = Expression::try_from_text(src)?;
+            let actual = SQLiteSQL::default().to_sql(&exp)?;
+            assert_eq!(actual, expected, "{src}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_temporal_sql() -> Result<(), MyError> {
+        #[rustfmt::skip]
+        const TV: [(&str, &str); 3] = [
+            (
+                "T_AFTER(start, TIMESTAMP('2022-04-24T07:59:57Z'))",
+                "strftime('%Y-%m-%dT%H:%M:%f', start) > strftime('%Y-%m-%dT%H:%M:%f', '2022-04-24T07:59:57')",
+            ),
+            (
+                "T_DURING(INTERVAL(start, end), INTERVAL('2021-01-01', '2021-12-31'))",
+                "(strftime('%Y-%m-%dT%H:%M:%f', start) > strftime('%Y-%m-%dT%H:%M:%f', '2021-01-01')) \
+                AND (strftime('%Y-%m-%dT%H:%M:%f', end) < strftime('%Y-%m-%dT%H:%M:%f', '2021-12-31'))",
+            ),
+            // unbounded starts are not normalized...
+            (
+                "T_INTERSECTS(start, INTERVAL('..', '2021-12-31'))",
+                "NOT((strftime('%Y-%m-%dT%H:%M:%f', start) < '-2021-01-01') \
+                OR (strftime('%Y-%m-%dT%H:%M:%f', start) > strftime('%Y-%m-%dT%H:%M:%f', '2021-12-31')))",
+            ),
+        ];
+        for (src, expected) in TV {
+            let exp = Expression::try_from_text(src)?;
+            let actual = SQLiteSQL::default().to_sql(&exp)?;
+            assert_eq!(actual, expected, "{src}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat_sql() -> Result<(), MyError> {
+        #[rustfmt::skip]
+        const TV: [(&str, &str); 2] = [
+            ("concat(first, ' ', last) = 'John Smith'", "((first || ' ' || last)) = 'John Smith'"),
+            ("upper(concat(code, 1))", "upper((code || 1))"),
+        ];
+        for (src, expected) in TV {
+            let exp = Expression::try_from_text(src)?;
+            let actual = SQLiteSQL::default().to_sql(&exp)?;
+            assert_eq!(actual, expected, "{src}");
+        }
+        Ok(())
+    }
 }
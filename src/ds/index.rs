@@ -0,0 +1,183 @@
+// SPDX-License-Identifier: Apache-2.0
+
+#![warn(missing_docs)]
+
+//! Spatial index over the geometries of a data source's _Features_.
+//!
+
+use crate::{G, MyError, Q, Resource, ds::IterableDS};
+use geos::{STRtree, SpatialIndex as _};
+
+// number of entries per node of the GEOS tree; same as the GEOS default...
+const NODE_CAPACITY: usize = 10;
+
+/// Spatial index, backed by a GEOS [STRtree][1], over the envelopes of the
+/// geometries of a data source's _Features_.
+///
+/// It's meant to speed up evaluating the same spatial predicate against many
+/// _Features_: [`candidates()`][Self::candidates()] quickly narrows those down
+/// to the ones whose envelope intersects that of the query geometry, which
+/// the caller then refines w/ the exact predicate; e.g. `S_INTERSECTS`.
+///
+/// _Features_ are identified by their (zero-based) position in the sequence
+/// returned by [`IterableDS::iter()`]. Every geometry property of a _Feature_
+/// is indexed; those w/o any are never candidates.
+///
+/// NOTE (rsn) 20261016 - coordinates are compared as is. the query geometry is
+/// expected to be in the same CRS as the indexed ones.
+///
+/// [1]: https://libgeos.org/doxygen/classgeos_1_1index_1_1strtree_1_1TemplateSTRtree.html
+pub struct SpatialIndex {
+    tree: STRtree<usize>,
+    len: usize,
+}
+
+impl std::fmt::Debug for SpatialIndex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SpatialIndex")
+            .field("len", &self.len)
+            .finish_non_exhaustive()
+    }
+}
+
+impl SpatialIndex {
+    /// Try building a new instance from the _Features_ of the given data
+    /// source; iterating over them once.
+    ///
+    /// Return an error if a _Feature_ cannot be read, or converted to a
+    /// [`Resource`], or if one of its geometries cannot be indexed.
+    pub fn try_from_ds<T>(ds: &T) -> Result<Self, MyError>
+    where
+        T: IterableDS,
+        MyError: From<T::Err>,
+    {
+        let mut result = Self::new()?;
+        for x in ds.iter()? {
+            let resource: Resource = x?.try_into()?;
+            result.insert(&resource)?;
+        }
+        Ok(result)
+    }
+
+    /// Return the number of _Features_ seen while building this, including
+    /// those w/o a geometry.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Return TRUE if no _Features_ were seen while building this; FALSE
+    /// otherwise.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Return, in ascending order, the indices of the _Features_ w/ at least
+    /// one geometry whose envelope intersects that of `geom`.
+    ///
+    /// The result is a superset of the _Features_ that satisfy any spatial
+    /// predicate that implies an intersection; it's empty if `geom` is
+    /// [`G::Null`].
+    pub fn candidates(&self, geom: &G) -> Result<Vec<usize>, MyError> {
+        if matches!(geom, G::Null) {
+            return Ok(vec![]);
+        }
+
+        let g = geom.to_geos()?;
+        let mut result = vec![];
+        self.tree.query(&g, |ndx: &usize| result.push(*ndx));
+        // a feature w/ more than one geometry may be visited more than once...
+        result.sort_unstable();
+        result.dedup();
+        Ok(result)
+    }
+
+    fn new() -> Result<Self, MyError> {
+        let tree = STRtree::with_capacity(NODE_CAPACITY)?;
+        Ok(Self { tree, len: 0 })
+    }
+
+    // index the geometries of the next feature...
+    fn insert(&mut self, resource: &Resource) -> Result<(), MyError> {
+        let ndx = self.len;
+        self.len += 1;
+        for x in resource.values() {
+            if let Q::Geom(g) = x
+                && !matches!(g, G::Null)
+            {
+                self.tree.insert(&g.to_geos()?, ndx);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ds::MemoryDataSource;
+    use std::error::Error;
+
+    fn parcels() -> Result<MemoryDataSource, MyError> {
+        let it = [
+            ("a", Some("POLYGON((0 0, 2 0, 2 2, 0 2, 0 0))")),
+            ("b", None),
+            ("c", Some("POINT(5 5)")),
+            ("d", Some("LINESTRING(1 3, 3 1)")),
+            ("e", Some("POLYGON((10 10, 12 10, 12 12, 10 12, 10 10))")),
+        ]
+        .into_iter()
+        .map(|(id, wkt)| {
+            let mut resource = Resource::from([("id".into(), Q::new_plain_str(id))]);
+            if let Some(wkt) = wkt {
+                resource.insert("geom".into(), Q::try_from_wkt(wkt)?);
+            }
+            Ok(resource)
+        })
+        .collect::<Result<Vec<_>, MyError>>()?;
+        Ok(MemoryDataSource::from(it))
+    }
+
+    #[test]
+    fn test_candidates() -> Result<(), Box<dyn Error>> {
+        let ds = parcels()?;
+        let index = SpatialIndex::try_from_ds(&ds)?;
+        assert_eq!(index.len(), 5);
+        assert!(!index.is_empty());
+
+        let q = G::try_from("POLYGON((1 1, 6 1, 6 6, 1 6, 1 1))")?;
+        assert_eq!(index.candidates(&q)?, vec![0, 2, 3]);
+
+        // 'd' envelope intersects the query but the line itself does not...
+        let q = G::try_from("POINT(2.9 2.9)")?;
+        let candidates = index.candidates(&q)?;
+        assert_eq!(candidates, vec![3]);
+        let resources = ds.resources();
+        let hits: Vec<_> = candidates
+            .into_iter()
+            .filter(|&i| match &resources[i]["geom"] {
+                Q::Geom(g) => g.intersects(&q).unwrap(),
+                _ => false,
+            })
+            .collect();
+        assert!(hits.is_empty());
+
+        let q = G::try_from("POINT(100 100)")?;
+        assert!(index.candidates(&q)?.is_empty());
+        assert!(index.candidates(&G::Null)?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_spatial_candidates() -> Result<(), Box<dyn Error>> {
+        let ds = parcels()?;
+        let q = G::try_from("BBOX(9, 9, 11, 11)")?;
+        assert_eq!(ds.spatial_candidates(&q)?, vec![4]);
+
+        let index = SpatialIndex::try_from_ds(&MemoryDataSource::default())?;
+        assert!(index.is_empty());
+        assert!(index.candidates(&q)?.is_empty());
+
+        Ok(())
+    }
+}
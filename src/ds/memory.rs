@@ -0,0 +1,296 @@
+// SPDX-License-Identifier: Apache-2.0
+
+#![warn(missing_docs)]
+
+//! In-memory collection of [Resources][Resource]; e.g. for testing filters w/o
+//! a GeoPackage, nor a CSV file.
+//!
+
+use crate::{
+    Context, Expression, MyError, Resource,
+    ds::{ContextFactory, DataSource, IterableDS, StreamableDS, filter_where},
+};
+use async_trait::async_trait;
+use futures::{
+    StreamExt,
+    stream::{self, BoxStream},
+};
+use std::{borrow::Cow, ops::Deref};
+
+/// A _Feature_ of a [`MemoryDataSource`]; i.e. a clone of one of its
+/// [Resources][Resource].
+///
+/// NOTE (rsn) 20261016 - this wrapper only exists b/c the conversion of a
+/// [`Resource`] into itself is infallible (`Error = Infallible`) while our
+/// data source traits expect it to fail w/ the same error type as theirs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MemoryFeature(pub Resource);
+
+impl Deref for MemoryFeature {
+    type Target = Resource;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl TryFrom<MemoryFeature> for Resource {
+    type Error = MyError;
+
+    fn try_from(value: MemoryFeature) -> Result<Self, Self::Error> {
+        Ok(value.0)
+    }
+}
+
+/// [`DataSource`] of [Resources][Resource] held in memory.
+///
+/// Both iterating over, and streaming, its _Features_ yield clones of those
+/// [Resources][Resource] in their original order. Filtering is always done
+/// in-process.
+#[derive(Debug, Default, Clone)]
+pub struct MemoryDataSource {
+    resources: Vec<Resource>,
+    context: ContextFactory,
+}
+
+impl DataSource for MemoryDataSource {
+    // geometries carry their own SRID...
+    fn srid(&self) -> Option<u32> {
+        None
+    }
+}
+
+impl From<Vec<Resource>> for MemoryDataSource {
+    fn from(resources: Vec<Resource>) -> Self {
+        Self {
+            resources,
+            context: ContextFactory::default(),
+        }
+    }
+}
+
+impl FromIterator<Resource> for MemoryDataSource {
+    fn from_iter<T: IntoIterator<Item = Resource>>(iter: T) -> Self {
+        Self {
+            resources: iter.into_iter().collect(),
+            context: ContextFactory::default(),
+        }
+    }
+}
+
+impl MemoryDataSource {
+    /// Evaluate filters w/in a [Context] returned by `f` instead of the
+    /// default one. `f` is called every time [Resources][Resource] are
+    /// filtered.
+    pub fn with_context<F>(mut self, f: F) -> Self
+    where
+        F: Fn() -> Context + Send + Sync + 'static,
+    {
+        self.context = ContextFactory::new(f);
+        self
+    }
+
+    /// Return the number of [Resources][Resource] in this.
+    pub fn len(&self) -> usize {
+        self.resources.len()
+    }
+
+    /// Return TRUE if this has no [Resources][Resource]; FALSE otherwise.
+    pub fn is_empty(&self) -> bool {
+        self.resources.is_empty()
+    }
+
+    /// Return the [Resources][Resource] of this.
+    pub fn resources(&self) -> &[Resource] {
+        &self.resources
+    }
+
+    // return clones of the resources of this satisfying `exp`. resources that
+    // fail to evaluate are kept as errors...
+    #[allow(clippy::type_complexity)]
+    fn evaluate_where(&self, exp: &Expression) -> Result<Vec<Result<Resource, MyError>>, MyError> {
+        let resources = self.resources.iter().map(Ok);
+        let it = filter_where(exp, self.context.make(), resources, |x| {
+            Ok(Cow::Borrowed(*x))
+        })?;
+        Ok(it.map(|x| x.cloned()).collect())
+    }
+}
+
+impl IterableDS for MemoryDataSource {
+    type Item = MemoryFeature;
+    type Err = MyError;
+
+    fn iter(&self) -> Result<impl Iterator<Item = Result<MemoryFeature, MyError>>, MyError> {
+        let it = self
+            .resources
+            .iter()
+            .map(|x| Ok(MemoryFeature(x.to_owned())));
+        Ok(it)
+    }
+}
+
+#[async_trait]
+impl StreamableDS for MemoryDataSource {
+    type Item = MemoryFeature;
+    type Err = MyError;
+
+    async fn fetch(&self) -> Result<BoxStream<'_, Result<MemoryFeature, MyError>>, MyError> {
+        let it = self.iter()?;
+        Ok(stream::iter(it).boxed())
+    }
+
+    async fn stream(&self) -> Result<BoxStream<'_, Result<Resource, MyError>>, MyError> {
+        let it = self.resources.iter().map(|x| Ok(x.to_owned()));
+        Ok(stream::iter(it).boxed())
+    }
+
+    async fn fetch_where(
+        &self,
+        exp: &Expression,
+    ) -> Result<BoxStream<'_, Result<MemoryFeature, MyError>>, MyError> {
+        let resources = self.evaluate_where(exp)?;
+        let it = resources.into_iter().map(|x| x.map(MemoryFeature));
+        Ok(stream::iter(it).boxed())
+    }
+
+    async fn stream_where(
+        &self,
+        exp: &Expression,
+    ) -> Result<BoxStream<'_, Result<Resource, MyError>>, MyError> {
+        let resources = self.evaluate_where(exp)?;
+        Ok(stream::iter(resources).boxed())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Outcome, Q, SortDir, tally};
+    use futures::TryStreamExt;
+    use std::error::Error;
+
+    fn cities() -> Result<MemoryDataSource, MyError> {
+        let it = [
+            ("Paris", 2_102_650.0, "POINT(2.35 48.86)"),
+            ("Lyon", 522_250.0, "POINT(4.83 45.76)"),
+        ]
+        .into_iter()
+        .map(|(name, pop, wkt)| {
+            Ok(Resource::from([
+                ("name".into(), Q::new_plain_str(name)),
+                ("pop".into(), Q::Num(pop)),
+                ("geom".into(), Q::try_from_wkt(wkt)?),
+            ]))
+        })
+        .collect::<Result<Vec<_>, MyError>>()?;
+        Ok(MemoryDataSource::from(it))
+    }
+
+    #[test]
+    fn test_iter() -> Result<(), Box<dyn Error>> {
+        let ds = cities()?;
+        assert_eq!(ds.len(), 2);
+        assert!(ds.srid().is_none());
+
+        let mut names = vec![];
+        for x in ds.iter()? {
+            let resource = Resource::try_from(x?)?;
+            names.push(resource["name"].to_str()?.as_str().to_owned());
+        }
+        assert_eq!(names, vec!["Paris", "Lyon"]);
+
+        let ds2: MemoryDataSource = ds.resources().iter().rev().cloned().collect();
+        assert_eq!(ds2.resources()[0]["name"], Q::new_plain_str("Lyon"));
+
+        let exprs = [
+            Expression::try_from_text("pop > 1000000")?,
+            Expression::try_from_text("S_WITHIN(geom, BBOX(0, 40, 10, 50))")?,
+        ];
+        assert_eq!(tally(&ds, &exprs)?, vec![1, 2]);
+        // and an empty one...
+        assert_eq!(tally(&MemoryDataSource::default(), &exprs)?, vec![0, 0]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_evaluate_all() -> Result<(), Box<dyn Error>> {
+        let mut resources = cities()?.resources().to_vec();
+        let nowhere = Q::new_plain_str("Nowhere");
+        resources.push(Resource::from([("name".into(), nowhere)]));
+        let ds = MemoryDataSource::from(resources);
+
+        let exp = Expression::try_from_text("pop > 1000000")?;
+        let ctx = Context::new();
+        let mut verdicts = vec![];
+        for x in ds.evaluate_all(&exp, &ctx)? {
+            let (feature, outcome) = x?;
+            verdicts.push((feature["name"].to_str()?.as_str().to_owned(), outcome));
+        }
+        assert_eq!(
+            verdicts,
+            vec![
+                ("Paris".to_owned(), Outcome::T),
+                ("Lyon".to_owned(), Outcome::F),
+                ("Nowhere".to_owned(), Outcome::N),
+            ]
+        );
+
+        // compilation errors are raised upfront...
+        let exp = Expression::try_from_text("S_WITHIN(geom, POINT(1000 1000))")?;
+        assert!(ds.evaluate_all(&exp, &ctx).is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_stream() -> Result<(), Box<dyn Error>> {
+        let ds = cities()?;
+        let items: Vec<_> = ds.stream().await?.collect().await;
+        assert_eq!(items.len(), 2);
+
+        let exp = Expression::try_from_text("name LIKE 'L%'")?;
+        let items: Vec<_> = ds.fetch_where(&exp).await?.collect().await;
+        assert_eq!(items.len(), 1);
+        let feature = items.into_iter().next().expect("a feature")?;
+        assert_eq!(feature["name"], Q::new_plain_str("Lyon"));
+
+        let items: Vec<_> = ds.stream_where_limit(&exp, 10, 1).await?.collect().await;
+        assert!(items.is_empty());
+
+        let exp = Expression::try_from_text("pop > 0")?;
+        let order_by = [("pop".to_owned(), SortDir::Asc)];
+        let items: Vec<_> = ds
+            .stream_where_ordered(&exp, &order_by)
+            .await?
+            .try_collect()
+            .await?;
+        assert_eq!(items[0]["name"], Q::new_plain_str("Lyon"));
+        assert_eq!(items[1]["name"], Q::new_plain_str("Paris"));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_with_context() -> Result<(), Box<dyn Error>> {
+        let exp = Expression::try_from_text("density > 1000")?;
+
+        // w/ the default context `density` is missing...
+        let ds = cities()?;
+        let items: Vec<_> = ds.stream_where(&exp).await?.try_collect().await?;
+        assert!(items.is_empty());
+
+        let ds = cities()?.with_context(|| {
+            Context::new().with_computed("density", |x| match x.get("pop") {
+                Some(pop) => pop.to_num().map_or(Q::Null, |x| Q::Num(x / 100.0)),
+                None => Q::Null,
+            })
+        });
+        let items: Vec<_> = ds.stream_where(&exp).await?.try_collect().await?;
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0]["name"], Q::new_plain_str("Paris"));
+
+        Ok(())
+    }
+}
@@ -3,28 +3,258 @@
 #![warn(missing_docs)]
 
 //! Groups artifacts providing Features and Resources from known types used in
-//! the geospatial ecosystem such as GeoPackage files.
+//! the geospatial ecosystem such as GeoPackage, GeoJSONL, and Shapefiles.
 //!
 
 mod csv;
+mod geojsonl;
+#[cfg(feature = "geoparquet")]
+mod geoparquet;
 mod gpkg;
+#[cfg(feature = "geos")]
+mod index;
+mod memory;
 mod pg;
+mod shp;
 mod sql;
+mod trace;
 mod types;
 
 pub use csv::*;
+pub use geojsonl::*;
+#[cfg(feature = "geoparquet")]
+pub use geoparquet::*;
 pub use gpkg::*;
+#[cfg(feature = "geos")]
+pub use index::*;
+pub use memory::*;
 pub use pg::*;
+pub use shp::*;
+pub use trace::*;
 pub use types::*;
 
-use crate::{Expression, Q};
+use crate::{Context, Evaluator, ExEvaluator, Expression, MyError, Outcome, Q, SharedContext};
 use async_trait::async_trait;
-use futures::stream::BoxStream;
-use std::collections::HashMap;
+use futures::{
+    StreamExt,
+    stream::{self, BoxStream},
+};
+use serde_json::{Map, Value, json};
+use std::{borrow::Cow, cmp::Ordering, collections::HashMap, fmt, sync::Arc};
+use tracing::warn;
 
 /// A dictionary of queryable property names (strings) to [`Queryable`][Q] values.
 pub type Resource = HashMap<String, Q>;
 
+/// Convenience methods for working w/ [`Resource`]s.
+pub trait ResourceExt: Sized {
+    /// Try creating a new instance from a JSON object mapping each of its
+    /// members to a [`Queryable`][Q] as described in [`Q::try_from_json()`].
+    ///
+    /// Return an error if `value` is not an object or one of its members is
+    /// itself a (non-GeoJSON geometry) object.
+    fn from_json_object(value: &Value) -> Result<Self, MyError>;
+
+    /// Return an [RFC 7946][1] GeoJSON _Feature_ object w/ the value of the
+    /// property named `geometry` as its geometry, and every other property,
+    /// rendered w/ [`Q::to_geojson()`], as its properties.
+    ///
+    /// The _Feature_ geometry is `null` if that property is missing or is not
+    /// a geometry; in the latter case it's kept w/ the other properties.
+    ///
+    /// [1]: https://datatracker.ietf.org/doc/html/rfc7946#section-3.2
+    fn to_geojson_feature(&self, geometry: &str) -> Value;
+
+    /// Return a copy of this w/ the property named `key` set to `value`,
+    /// replacing any existing one; e.g. to inject a derived value before
+    /// evaluating a filter. See also [`Context::with_computed()`] to derive
+    /// such values lazily instead.
+    fn with(&self, key: &str, value: Q) -> Self;
+}
+
+impl ResourceExt for Resource {
+    fn from_json_object(value: &Value) -> Result<Self, MyError> {
+        let obj = value
+            .as_object()
+            .ok_or_else(|| MyError::Runtime("Expected a JSON object".into()))?;
+        let mut result = Resource::with_capacity(obj.len());
+        for (k, v) in obj {
+            result.insert(k.to_owned(), Q::try_from_json(v)?);
+        }
+        Ok(result)
+    }
+
+    fn to_geojson_feature(&self, geometry: &str) -> Value {
+        let mut properties = Map::with_capacity(self.len());
+        let mut geom = Value::Null;
+        for (k, v) in self {
+            match v {
+                Q::Geom(x) if k == geometry => geom = x.to_geojson(),
+                x => {
+                    properties.insert(k.to_owned(), x.to_geojson());
+                }
+            }
+        }
+        json!({ "type": "Feature", "geometry": geom, "properties": properties })
+    }
+
+    fn with(&self, key: &str, value: Q) -> Self {
+        let mut result = self.clone();
+        result.insert(key.to_owned(), value);
+        result
+    }
+}
+
+/// Read-only access, by name, to the properties of a _Resource_.
+///
+/// [Evaluators][crate::Evaluator] only need this to evaluate an [Expression]
+/// against a _Feature_. Implementing it directly for a _Feature_ type, instead
+/// of converting every _Feature_ into a [`Resource`], avoids allocating a map
+/// per _Feature_, and allows materializing lazily only the properties that
+/// are actually referenced (see [`Expression::properties()`]); e.g. skipping
+/// parsing a geometry when the filter only involves other attributes.
+pub trait ResourceRef {
+    /// Return the value of the property named `name` if it's set; `None`
+    /// otherwise.
+    fn get(&self, name: &str) -> Option<Cow<'_, Q>>;
+}
+
+impl ResourceRef for Resource {
+    fn get(&self, name: &str) -> Option<Cow<'_, Q>> {
+        HashMap::get(self, name).map(Cow::Borrowed)
+    }
+}
+
+/// Direction in which to order [Resources][Resource] by the values of one of
+/// their properties; e.g. when calling [`StreamableDS::stream_where_ordered()`].
+///
+/// `NULL`s, as well as missing values, come last unless one of the
+/// `...NullsFirst` variants is used.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SortDir {
+    /// Ascending order w/ `NULL`s last.
+    #[default]
+    Asc,
+    /// Descending order w/ `NULL`s last.
+    Desc,
+    /// Ascending order w/ `NULL`s first.
+    AscNullsFirst,
+    /// Descending order w/ `NULL`s first.
+    DescNullsFirst,
+}
+
+impl SortDir {
+    /// Return TRUE if values are sorted in descending order; FALSE otherwise.
+    pub fn is_desc(&self) -> bool {
+        matches!(self, SortDir::Desc | SortDir::DescNullsFirst)
+    }
+
+    /// Return TRUE if `NULL`s come before other values; FALSE otherwise.
+    pub fn nulls_first(&self) -> bool {
+        matches!(self, SortDir::AscNullsFirst | SortDir::DescNullsFirst)
+    }
+
+    /// Return the same direction but w/ `NULL`s placed at the other end.
+    pub fn reverse_nulls(self) -> Self {
+        match self {
+            SortDir::Asc => SortDir::AscNullsFirst,
+            SortDir::Desc => SortDir::DescNullsFirst,
+            SortDir::AscNullsFirst => SortDir::Asc,
+            SortDir::DescNullsFirst => SortDir::Desc,
+        }
+    }
+
+    // SQL keywords to append to a column name in an `ORDER BY` clause...
+    pub(crate) fn to_sql(self) -> &'static str {
+        match self {
+            SortDir::Asc => "ASC NULLS LAST",
+            SortDir::Desc => "DESC NULLS LAST",
+            SortDir::AscNullsFirst => "ASC NULLS FIRST",
+            SortDir::DescNullsFirst => "DESC NULLS FIRST",
+        }
+    }
+}
+
+/// Sort, in place, the given [Resources][Resource] by the values of the named
+/// properties, each in its own [direction][SortDir], using [`Q`]'s `PartialOrd`.
+///
+/// The sort is stable. Values that cannot be compared (e.g. geometries, or ones
+/// of different types) are considered equal and left in their original order.
+pub fn sort_resources(resources: &mut [Resource], order_by: &[(String, SortDir)]) {
+    if order_by.is_empty() {
+        return;
+    }
+
+    resources.sort_by(|a, b| {
+        for (name, dir) in order_by {
+            let x = a.get(name).filter(|x| !x.is_null());
+            let y = b.get(name).filter(|y| !y.is_null());
+            let it = match (x, y) {
+                (None, None) => Ordering::Equal,
+                (None, Some(_)) if dir.nulls_first() => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (Some(_), None) if dir.nulls_first() => Ordering::Greater,
+                (Some(_), None) => Ordering::Less,
+                (Some(x), Some(y)) => {
+                    let it = x.partial_cmp(y).unwrap_or(Ordering::Equal);
+                    if dir.is_desc() { it.reverse() } else { it }
+                }
+            };
+            if it != Ordering::Equal {
+                return it;
+            }
+        }
+        Ordering::Equal
+    });
+}
+
+/// Configuration of how [`StreamableDS::stream_with()`] pulls _Resources_
+/// from a data source.
+///
+/// The default values produce the same stream as [`StreamableDS::stream()`].
+/// Data sources that cannot fetch a chunk on their own ignore it altogether.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StreamConfig {
+    chunk_size: Option<usize>,
+    prefetch: usize,
+}
+
+impl Default for StreamConfig {
+    fn default() -> Self {
+        Self {
+            chunk_size: None,
+            prefetch: 1,
+        }
+    }
+}
+
+impl StreamConfig {
+    /// Pull at most `chunk_size` _Resources_ at a time. A `0` is treated
+    /// as `1`.
+    pub fn with_chunk_size(mut self, chunk_size: usize) -> Self {
+        self.chunk_size = Some(chunk_size.max(1));
+        self
+    }
+
+    /// Set how many chunks may be fetched ahead of the one being consumed.
+    /// A `0` is treated as `1`. Only meaningful when a chunk size is set.
+    pub fn with_prefetch(mut self, prefetch: usize) -> Self {
+        self.prefetch = prefetch.max(1);
+        self
+    }
+
+    /// Return the maximum number of _Resources_ pulled at a time if set;
+    /// `None` otherwise.
+    pub fn chunk_size(&self) -> Option<usize> {
+        self.chunk_size
+    }
+
+    /// Return the number of chunks that may be fetched ahead.
+    pub fn prefetch(&self) -> usize {
+        self.prefetch
+    }
+}
+
 /// Trait for a type that can act as a data source provider of _Features_
 /// and [`Resource`]s, including a _Geometry_ attribute, in the context of
 /// processing CQL2 filter expressions.
@@ -45,6 +275,54 @@ pub trait IterableDS {
 
     /// Return an iterator over this data source _Features_.
     fn iter(&self) -> Result<impl Iterator<Item = Result<Self::Item, Self::Err>>, Self::Err>;
+
+    /// Return, in ascending order, the (zero-based) positions in the sequence
+    /// returned by [`iter()`][Self::iter()] of the _Features_ w/ a geometry
+    /// whose envelope intersects that of `geom`. Those are the only ones that
+    /// may satisfy an intersecting spatial predicate w/ `geom` as an operand;
+    /// the caller is expected to refine them w/ the exact predicate.
+    ///
+    /// The default implementation builds a [`SpatialIndex`] and discards it
+    /// after one query. When querying the same data source more than once,
+    /// build one w/ [`SpatialIndex::try_from_ds()`] and reuse it instead.
+    ///
+    /// Only available w/ the `geos` feature.
+    #[cfg(feature = "geos")]
+    fn spatial_candidates(&self, geom: &crate::G) -> Result<Vec<usize>, MyError>
+    where
+        Self: Sized,
+        MyError: From<Self::Err>,
+    {
+        SpatialIndex::try_from_ds(self)?.candidates(geom)
+    }
+
+    /// Return an iterator over every one of this data source _Features_ paired
+    /// w/ the [`Outcome`] of evaluating the given [Expression], w/in `ctx`,
+    /// against it; i.e. including the ones that do not satisfy it. This is
+    /// useful for counting `T`, `F` and `N` verdicts separately, or logging
+    /// the rejected _Features_.
+    ///
+    /// The [Expression] is [compiled][Expression::compile()] once, and an error
+    /// is returned if that fails. An item is an error if its _Feature_ cannot
+    /// be read, converted to a [`Resource`], or evaluated.
+    fn evaluate_all<'a>(
+        &'a self,
+        exp: &Expression,
+        ctx: &'a Context,
+    ) -> Result<impl Iterator<Item = Result<(Self::Item, Outcome), MyError>> + 'a, MyError>
+    where
+        Self::Item: Clone,
+        MyError: From<Self::Err>,
+    {
+        let compiled = exp.compile(ctx)?;
+        let it = self.iter()?.map(move |x| {
+            let feature = x?;
+            let resource: Resource = feature.clone().try_into()?;
+            let outcome = compiled.evaluate(&resource)?;
+            Ok((feature, outcome))
+        });
+        Ok(it)
+    }
 }
 
 /// Capability of a [`DataSource`] to asynchronously stream _Features_ or
@@ -62,6 +340,26 @@ pub trait StreamableDS {
     /// Return an unfiltered stream of all data source _Resources_.
     async fn stream(&self) -> Result<BoxStream<'_, Result<Resource, Self::Err>>, Self::Err>;
 
+    /// Return an unfiltered stream of all data source _Resources_ pulled in
+    /// bounded batches as set in the given [`StreamConfig`].
+    ///
+    /// The default implementation ignores `cfg` and returns the stream from
+    /// [`stream()`][Self::stream()] as is. That stream is already pulled one
+    /// _Resource_ at a time, and w/o a way of fetching a chunk on its own
+    /// there's nothing to bound or prefetch. GeoPackage sources generated by
+    /// [`gen_gpkg_ds!`][crate::gen_gpkg_ds] override it to fetch every chunk
+    /// w/ its own `LIMIT`ed query, w/ up to `prefetch` of them in flight, so
+    /// that a huge layer is never held in memory all at once.
+    async fn stream_with(
+        &self,
+        _cfg: &StreamConfig,
+    ) -> Result<BoxStream<'_, Result<Resource, Self::Err>>, Self::Err>
+    where
+        Self::Err: Send,
+    {
+        self.stream().await
+    }
+
     /// Return a filtered stream of _Features_ satisfying a CQL2 filter [Expression].
     async fn fetch_where(
         &self,
@@ -73,4 +371,617 @@ pub trait StreamableDS {
         &self,
         exp: &Expression,
     ) -> Result<BoxStream<'_, Result<Resource, Self::Err>>, Self::Err>;
+
+    /// Return a filtered stream of at most `limit` _Features_ satisfying a CQL2
+    /// filter [Expression] after skipping the first `offset` ones.
+    ///
+    /// The default implementation skips and takes from the stream returned
+    /// by [`fetch_where()`][Self::fetch_where()]. SQL-backed sources override
+    /// it to push `LIMIT` and `OFFSET` down to the database.
+    async fn fetch_where_limit(
+        &self,
+        exp: &Expression,
+        limit: usize,
+        offset: usize,
+    ) -> Result<BoxStream<'_, Result<Self::Item, Self::Err>>, Self::Err> {
+        let it = self.fetch_where(exp).await?;
+        Ok(it.skip(offset).take(limit).boxed())
+    }
+
+    /// Return a filtered stream of at most `limit` _Resources_ satisfying a
+    /// CQL2 filter [Expression] after skipping the first `offset` ones.
+    ///
+    /// Similar to [`fetch_where_limit()`][Self::fetch_where_limit()] the default
+    /// implementation skips and takes from [`stream_where()`][Self::stream_where()].
+    async fn stream_where_limit(
+        &self,
+        exp: &Expression,
+        limit: usize,
+        offset: usize,
+    ) -> Result<BoxStream<'_, Result<Resource, Self::Err>>, Self::Err> {
+        let it = self.stream_where(exp).await?;
+        Ok(it.skip(offset).take(limit).boxed())
+    }
+
+    /// Return a filtered stream of _Resources_ satisfying a CQL2 filter
+    /// [Expression] ordered by the values of the named properties, each in its
+    /// own [direction][SortDir]; e.g. to ensure a stable order when paginating.
+    ///
+    /// IMPORTANT - The default implementation buffers **all** the _Resources_
+    /// returned by [`stream_where()`][Self::stream_where()] in memory before
+    /// sorting them w/ [`sort_resources()`], and raises the first error found
+    /// in that stream. SQL-backed sources override it to push an `ORDER BY`
+    /// clause down to the database instead.
+    async fn stream_where_ordered(
+        &self,
+        exp: &Expression,
+        order_by: &[(String, SortDir)],
+    ) -> Result<BoxStream<'_, Result<Resource, Self::Err>>, Self::Err>
+    where
+        Self::Err: Send,
+    {
+        let mut it = self.stream_where(exp).await?;
+        let mut resources = vec![];
+        while let Some(x) = it.next().await {
+            resources.push(x?);
+        }
+        sort_resources(&mut resources, order_by);
+        Ok(stream::iter(resources.into_iter().map(Ok)).boxed())
+    }
+}
+
+/// Return a stream of the values of the `id_field` property of a data source's
+/// [Resources][Resource] that satisfy a CQL2 filter [Expression].
+///
+/// The filtering is delegated to the data source's [`StreamableDS::stream_where()`],
+/// which for SQL-backed sources translates the [Expression] to SQL. If that
+/// fails w/ [`MyError::Unsupported`] (e.g. b/c the [Expression] has no SQL
+/// equivalent), all the _Resources_ are streamed and filtered in-process, in
+/// chunks, w/in a [Context] returned by `make_ctx`. Any other error is yielded
+/// as the stream's sole item.
+///
+/// A matching _Resource_ w/o an `id_field` property yields an `Err` item, as
+/// does a _Resource_ that could not be read or evaluated.
+pub async fn evaluate_stream<'a, T, F>(
+    ds: &'a T,
+    exp: &Expression,
+    id_field: &str,
+    make_ctx: F,
+) -> BoxStream<'a, Result<Q, MyError>>
+where
+    T: StreamableDS<Err = MyError> + Sync,
+    F: Fn() -> Context + Send + 'a,
+{
+    let id_field = id_field.to_owned();
+    let project = move |x: Result<Resource, MyError>| {
+        x.and_then(|mut res| {
+            res.remove(&id_field)
+                .ok_or_else(|| MyError::Runtime(format!("Missing '{id_field}' property").into()))
+        })
+    };
+    match ds.stream_where(exp).await {
+        Ok(resources) => resources.map(project).boxed(),
+        Err(MyError::Unsupported(x)) => {
+            warn!("Failed delegating filter ({x}). Evaluate in-process...");
+            let resources = match ds.stream().await {
+                Ok(it) => it,
+                Err(x) => return stream::iter([Err(x)]).boxed(),
+            };
+            let matches = filter_stream(
+                exp,
+                move || make_ctx().freeze(),
+                resources,
+                |x| Ok(Cow::Borrowed(x)),
+            );
+            match matches {
+                Ok(it) => it.map(project).boxed(),
+                Err(x) => stream::iter([Err(x)]).boxed(),
+            }
+        }
+        Err(x) => stream::iter([Err(x)]).boxed(),
+    }
+}
+
+/// Return, for each of the given CQL2 filter [Expressions][Expression], the
+/// number of a data source's _Features_ that satisfy it; i.e. for which it
+/// evaluates to [`Outcome::T`].
+///
+/// The data source is iterated over once, evaluating each _Feature_ against
+/// every [Expression] in turn, w/ a default [`Context`]. An error is raised as
+/// soon as a _Feature_ cannot be read, converted to a [`Resource`], or
+/// evaluated.
+pub fn tally<T>(ds: &T, exprs: &[Expression]) -> Result<Vec<u64>, MyError>
+where
+    T: IterableDS<Err = MyError>,
+{
+    tally_with(ds, exprs, Context::new().freeze())
+}
+
+/// Similar to [`tally()`] but evaluates the _Features_ in the given, shared,
+/// [`Context`]; e.g. one w/ a CRS other than the default.
+pub fn tally_with<T>(ds: &T, exprs: &[Expression], ctx: SharedContext) -> Result<Vec<u64>, MyError>
+where
+    T: IterableDS<Err = MyError>,
+{
+    let mut evaluators = Vec::with_capacity(exprs.len());
+    for exp in exprs {
+        let mut evaluator = ExEvaluator::new(ctx.clone());
+        evaluator.setup(exp.to_owned())?;
+        evaluators.push(evaluator);
+    }
+
+    let mut result = vec![0; exprs.len()];
+    for x in ds.iter()? {
+        let resource: Resource = x?.try_into()?;
+        for (count, evaluator) in result.iter_mut().zip(&evaluators) {
+            if evaluator.evaluate(&resource)? == Outcome::T {
+                *count += 1;
+            }
+        }
+    }
+    Ok(result)
+}
+
+// max number of streamed items filtered w/ the same evaluator...
+const FILTER_CHUNK: usize = 1024;
+
+// Factory of the Context w/in which data sources w/o a DB engine to delegate
+// filtering to evaluate filter Expressions in-process...
+type ContextFn = dyn Fn() -> Context + Send + Sync;
+
+// Optional factory of the Context in-process filtering is done w/in. it's a
+// factory b/c a Context is neither `Send` nor `Clone`.  when not set, the
+// default Context is used...
+#[derive(Clone, Default)]
+pub(crate) struct ContextFactory(Option<Arc<ContextFn>>);
+
+impl fmt::Debug for ContextFactory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(_) => write!(f, "ContextFactory(<fn>)"),
+            None => write!(f, "ContextFactory(default)"),
+        }
+    }
+}
+
+impl ContextFactory {
+    pub(crate) fn new<F>(f: F) -> Self
+    where
+        F: Fn() -> Context + Send + Sync + 'static,
+    {
+        Self(Some(Arc::new(f)))
+    }
+
+    pub(crate) fn make(&self) -> SharedContext {
+        match &self.0 {
+            Some(f) => f().freeze(),
+            None => Context::new().freeze(),
+        }
+    }
+}
+
+// evaluate `exp`, w/in `ctx`, against every item converted to a Resource by
+// `to_resource`, lazily yielding the matching items, as well as the errors,
+// in their original order...
+pub(crate) fn filter_where<'a, T, I, F>(
+    exp: &Expression,
+    ctx: SharedContext,
+    items: I,
+    to_resource: F,
+) -> Result<impl Iterator<Item = Result<T, MyError>> + 'a, MyError>
+where
+    I: IntoIterator<Item = Result<T, MyError>>,
+    I::IntoIter: 'a,
+    F: Fn(&T) -> Result<Cow<'_, Resource>, MyError> + 'a,
+{
+    let mut evaluator = ExEvaluator::new(ctx);
+    evaluator.setup(exp.to_owned())?;
+
+    let it = items.into_iter().filter_map(move |x| {
+        let x = match x {
+            Ok(x) => x,
+            Err(x) => return Some(Err(x)),
+        };
+        let outcome = to_resource(&x).and_then(|r| evaluator.evaluate(&r));
+        match outcome {
+            Ok(Outcome::T) => Some(Ok(x)),
+            Ok(_) => None,
+            Err(x) => Some(Err(x)),
+        }
+    });
+    Ok(it)
+}
+
+// same as filter_where() but over a stream of items. b/c evaluators are not
+// `Send`, one is set up anew, w/in a Context returned by `make_ctx`, for every
+// chunk of the already available items...
+pub(crate) fn filter_stream<'a, T, M, F>(
+    exp: &Expression,
+    make_ctx: M,
+    items: BoxStream<'a, Result<T, MyError>>,
+    to_resource: F,
+) -> Result<BoxStream<'a, Result<T, MyError>>, MyError>
+where
+    T: Send + 'a,
+    M: Fn() -> SharedContext + Send + 'a,
+    F: Fn(&T) -> Result<Cow<'_, Resource>, MyError> + Send + 'a,
+{
+    // raise an invalid `exp` upfront instead of once per chunk...
+    let _ = filter_where(exp, make_ctx(), [], &to_resource)?;
+
+    let exp = exp.to_owned();
+    let it = items.ready_chunks(FILTER_CHUNK).flat_map(move |chunk| {
+        let matches: Vec<_> = match filter_where(&exp, make_ctx(), chunk, &to_resource) {
+            Ok(it) => it.collect(),
+            Err(x) => vec![Err(x)],
+        };
+        stream::iter(matches)
+    });
+    Ok(it.boxed())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{cell::Cell, error::Error};
+
+    const GEOJSONL: &str = "./tests/samples/data/sample.geojsonl";
+
+    // a data source that cannot filter its own features...
+    struct NoFilterDS(GeoJsonLDataSource);
+
+    // a data source that fails filtering its own features...
+    struct BrokenDS(GeoJsonLDataSource);
+
+    #[async_trait]
+    impl StreamableDS for NoFilterDS {
+        type Item = GeoJsonFeature;
+        type Err = MyError;
+
+        async fn fetch(&self) -> Result<BoxStream<'_, Result<GeoJsonFeature, MyError>>, MyError> {
+            self.0.fetch().await
+        }
+
+        async fn stream(&self) -> Result<BoxStream<'_, Result<Resource, MyError>>, MyError> {
+            self.0.stream().await
+        }
+
+        async fn fetch_where(
+            &self,
+            _: &Expression,
+        ) -> Result<BoxStream<'_, Result<GeoJsonFeature, MyError>>, MyError> {
+            Err(MyError::Unsupported("Not supported".into()))
+        }
+
+        async fn stream_where(
+            &self,
+            _: &Expression,
+        ) -> Result<BoxStream<'_, Result<Resource, MyError>>, MyError> {
+            Err(MyError::Unsupported("Not supported".into()))
+        }
+    }
+
+    #[async_trait]
+    impl StreamableDS for BrokenDS {
+        type Item = GeoJsonFeature;
+        type Err = MyError;
+
+        async fn fetch(&self) -> Result<BoxStream<'_, Result<GeoJsonFeature, MyError>>, MyError> {
+            self.0.fetch().await
+        }
+
+        async fn stream(&self) -> Result<BoxStream<'_, Result<Resource, MyError>>, MyError> {
+            self.0.stream().await
+        }
+
+        async fn fetch_where(
+            &self,
+            _: &Expression,
+        ) -> Result<BoxStream<'_, Result<GeoJsonFeature, MyError>>, MyError> {
+            Err(MyError::Runtime("Boom".into()))
+        }
+
+        async fn stream_where(
+            &self,
+            _: &Expression,
+        ) -> Result<BoxStream<'_, Result<Resource, MyError>>, MyError> {
+            Err(MyError::Runtime("Boom".into()))
+        }
+    }
+
+    // a data source that skips malformed lines...
+    struct WellFormedDS(GeoJsonLDataSource);
+
+    impl IterableDS for WellFormedDS {
+        type Item = GeoJsonFeature;
+        type Err = MyError;
+
+        fn iter(&self) -> Result<impl Iterator<Item = Result<GeoJsonFeature, MyError>>, MyError> {
+            Ok(self.0.iter()?.filter(|x| x.is_ok()))
+        }
+    }
+
+    #[test]
+    fn test_tally() -> Result<(), Box<dyn Error>> {
+        let exprs = [
+            Expression::try_from_text("name = 'Foo'")?,
+            Expression::try_from_text("S_INTERSECTS(geom, BBOX(0, 0, 5, 5))")?,
+            Expression::try_from_text("name IS NOT NULL")?,
+            Expression::try_from_text("name = 'Qux'")?,
+        ];
+
+        let ds = WellFormedDS(GeoJsonLDataSource::from(GEOJSONL));
+        assert_eq!(tally(&ds, &exprs)?, vec![1, 1, 3, 0]);
+        assert!(tally(&ds, &[])?.is_empty());
+
+        // the malformed line is an error...
+        let ds = GeoJsonLDataSource::from(GEOJSONL);
+        assert!(tally(&ds, &exprs).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_json_object() -> Result<(), Box<dyn Error>> {
+        let doc = json!({
+            "name": "Paris",
+            "pop": 2_102_650,
+            "capital": true,
+            "mayor": null,
+            "tags": ["city", 1],
+            "geom": { "type": "Point", "coordinates": [2.35, 48.86] }
+        });
+
+        let res = Resource::from_json_object(&doc)?;
+        assert_eq!(res.len(), 6);
+        assert_eq!(res["pop"].to_num()?, 2_102_650.0);
+        assert!(res["capital"].to_bool()?);
+        assert!(res["mayor"].is_null());
+        assert_eq!(res["tags"].to_list()?.len(), 2);
+        assert!(res["geom"].to_geom().is_ok());
+
+        let shared_ctx = Context::try_with_crs("epsg:4326")?.freeze();
+        let mut evaluator = ExEvaluator::new(shared_ctx);
+        let exp = Expression::try_from_text(
+            "name = 'Paris' AND pop > 1000000 AND S_WITHIN(geom, BBOX(2, 48, 3, 49))",
+        )?;
+        evaluator.setup(exp)?;
+        assert_eq!(evaluator.evaluate(&res)?, Outcome::T);
+
+        // nested objects, and non objects, are errors...
+        assert!(Resource::from_json_object(&json!({ "a": { "b": 1 } })).is_err());
+        assert!(Resource::from_json_object(&json!([1, 2])).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_geojson_feature() -> Result<(), Box<dyn Error>> {
+        let res = Resource::from([
+            ("name".into(), Q::new_plain_str("Paris")),
+            ("pop".into(), Q::Num(2_102_650.0)),
+            ("geom".into(), Q::try_from_wkt("POINT(2.35 48.86)")?),
+        ]);
+        let feature = res.to_geojson_feature("geom");
+        assert_eq!(
+            feature,
+            json!({
+                "type": "Feature",
+                "geometry": { "type": "Point", "coordinates": [2.35, 48.86] },
+                "properties": { "name": "Paris", "pop": 2_102_650.0 }
+            })
+        );
+
+        // a round-trip through JSON preserves properties and the geometry...
+        let mut props = Resource::from_json_object(&feature["properties"])?;
+        props.insert("geom".into(), Q::try_from_json(&feature["geometry"])?);
+        assert_eq!(props, res);
+
+        // a missing geometry is null...
+        let feature = res.to_geojson_feature("location");
+        assert!(feature["geometry"].is_null());
+        assert_eq!(feature["properties"].as_object().map(Map::len), Some(3));
+
+        Ok(())
+    }
+
+    // a Feature that only parses its geometry when asked for it...
+    struct LazyFeature {
+        name: &'static str,
+        wkt: &'static str,
+        parsed: Cell<bool>,
+    }
+
+    impl ResourceRef for LazyFeature {
+        fn get(&self, name: &str) -> Option<Cow<'_, Q>> {
+            match name {
+                "name" => Some(Cow::Owned(Q::new_plain_str(self.name))),
+                "geom" => {
+                    self.parsed.set(true);
+                    Q::try_from_wkt(self.wkt).ok().map(Cow::Owned)
+                }
+                _ => None,
+            }
+        }
+    }
+
+    #[test]
+    fn test_resource_ref() -> Result<(), Box<dyn Error>> {
+        let feature = LazyFeature {
+            name: "Paris",
+            wkt: "POINT(2.35 48.86)",
+            parsed: Cell::new(false),
+        };
+        let shared_ctx = Context::new().freeze();
+
+        let mut evaluator = ExEvaluator::new(shared_ctx.clone());
+        evaluator.setup(Expression::try_from_text("name = 'Paris' AND pop IS NULL")?)?;
+        assert_eq!(evaluator.evaluate_ref(&feature)?, Outcome::T);
+        assert!(!feature.parsed.get());
+
+        let mut evaluator = ExEvaluator::new(shared_ctx);
+        evaluator.setup(Expression::try_from_text(
+            "S_WITHIN(geom, BBOX(2, 48, 3, 49))",
+        )?)?;
+        assert_eq!(evaluator.evaluate_ref(&feature)?, Outcome::T);
+        assert!(feature.parsed.get());
+
+        // a Resource is a ResourceRef too...
+        let res = Resource::from([("name".into(), Q::new_plain_str("Paris"))]);
+        assert_eq!(
+            ResourceRef::get(&res, "name"),
+            Some(Cow::Borrowed(&res["name"]))
+        );
+        assert_eq!(ResourceRef::get(&res, "geom"), None);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_evaluate_stream() -> Result<(), Box<dyn Error>> {
+        let exp = Expression::try_from_text("S_INTERSECTS(geom, BBOX(0, 0, 5, 5))")?;
+
+        // delegated...
+        let ds = GeoJsonLDataSource::from(GEOJSONL);
+        let ids: Vec<_> = evaluate_stream(&ds, &exp, "name", Context::new)
+            .await
+            .collect()
+            .await;
+        // the malformed line is still reported...
+        assert_eq!(ids.len(), 2);
+        assert_eq!(ids[0].as_ref().expect("Failed").to_str()?.inner(), "Foo");
+        assert!(ids[1].is_err());
+
+        // in-process...
+        let ds = NoFilterDS(GeoJsonLDataSource::from(GEOJSONL));
+        let ids: Vec<_> = evaluate_stream(&ds, &exp, "id", Context::new)
+            .await
+            .collect()
+            .await;
+        assert_eq!(ids.len(), 2);
+        assert_eq!(ids[0].as_ref().expect("Failed").to_num()?, 1.0);
+        assert!(ids[1].is_err());
+
+        // ...w/in the caller's Context...
+        let lenient = Expression::try_from_text("id < '10'")?;
+        let ids: Vec<_> = evaluate_stream(&ds, &lenient, "id", || {
+            Context::new().with_implicit_coercion()
+        })
+        .await
+        .collect()
+        .await;
+        assert_eq!(ids.len(), 2);
+        assert_eq!(ids[0].as_ref().expect("Failed").to_num()?, 1.0);
+        assert!(ids[1].is_err());
+
+        // missing property...
+        let ids: Vec<_> = evaluate_stream(&ds, &exp, "fid", Context::new)
+            .await
+            .collect()
+            .await;
+        assert!(ids.iter().all(|x| x.is_err()));
+
+        // other errors are not masked by an in-process evaluation...
+        let ds = BrokenDS(GeoJsonLDataSource::from(GEOJSONL));
+        let ids: Vec<_> = evaluate_stream(&ds, &exp, "id", Context::new)
+            .await
+            .collect()
+            .await;
+        assert_eq!(ids.len(), 1);
+        assert!(matches!(ids[0], Err(MyError::Runtime(_))));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_where_limit() -> Result<(), Box<dyn Error>> {
+        let ds = GeoJsonLDataSource::from(GEOJSONL);
+        // matches Foo, Bar and Baz + the malformed line...
+        let exp = Expression::try_from_text("name <> 'Qux'")?;
+        let all: Vec<_> = ds.stream_where(&exp).await?.collect().await;
+        assert_eq!(all.len(), 4);
+
+        let page: Vec<_> = ds.stream_where_limit(&exp, 2, 1).await?.collect().await;
+        assert_eq!(page.len(), 2);
+        let res = page[0].as_ref().expect("Failed");
+        assert_eq!(res["name"].to_str()?.inner(), "Bar");
+        assert!(page[1].is_err());
+
+        let page: Vec<_> = ds.fetch_where_limit(&exp, 10, 3).await?.collect().await;
+        assert_eq!(page.len(), 1);
+        let res = Resource::try_from(page[0].as_ref().expect("Failed").clone())?;
+        assert_eq!(res["name"].to_str()?.inner(), "Baz");
+
+        let page: Vec<_> = ds.stream_where_limit(&exp, 0, 0).await?.collect().await;
+        assert!(page.is_empty());
+
+        // ordering buffers the lot and fails on the malformed line...
+        let order_by = [("name".to_owned(), SortDir::Asc)];
+        assert!(ds.stream_where_ordered(&exp, &order_by).await.is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_stream_with() -> Result<(), Box<dyn Error>> {
+        let ds = GeoJsonLDataSource::from(GEOJSONL);
+        let names = |x: &[Result<Resource, MyError>]| -> Vec<Option<String>> {
+            x.iter()
+                .map(|r| r.as_ref().ok().map(|r| r["name"].to_string()))
+                .collect()
+        };
+
+        let expected: Vec<_> = ds.stream().await?.collect().await;
+        let cfg = StreamConfig::default();
+        assert_eq!(cfg.chunk_size(), None);
+        let actual: Vec<_> = ds.stream_with(&cfg).await?.collect().await;
+        assert_eq!(names(&actual), names(&expected));
+
+        // passed through as is; same items, malformed line included, in the
+        // same order...
+        for n in [0, 1, 2, 100] {
+            let cfg = StreamConfig::default().with_chunk_size(n).with_prefetch(0);
+            assert_eq!(cfg.prefetch(), 1);
+            let actual: Vec<_> = ds.stream_with(&cfg).await?.collect().await;
+            assert_eq!(names(&actual), names(&expected));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_sort_resources() {
+        let mut resources: Vec<_> = [(1.0, 3.0), (2.0, f64::NAN), (3.0, 1.0), (4.0, 3.0)]
+            .into_iter()
+            .map(|(id, x)| {
+                let x = if x.is_nan() { Q::Null } else { Q::Num(x) };
+                Resource::from([("id".into(), Q::Num(id)), ("x".into(), x)])
+            })
+            .collect();
+        // and one w/o an 'x'...
+        resources.push(Resource::from([("id".into(), Q::Num(5.0))]));
+
+        let ids = |resources: &[Resource]| -> Vec<f64> {
+            resources
+                .iter()
+                .map(|x| x["id"].to_num().expect("Not a number"))
+                .collect()
+        };
+
+        sort_resources(&mut resources, &[("x".into(), SortDir::Asc)]);
+        assert_eq!(ids(&resources), [3.0, 1.0, 4.0, 2.0, 5.0]);
+
+        sort_resources(&mut resources, &[("x".into(), SortDir::Desc)]);
+        assert_eq!(ids(&resources), [1.0, 4.0, 3.0, 2.0, 5.0]);
+
+        let order_by = [
+            ("x".into(), SortDir::Desc.reverse_nulls()),
+            ("id".into(), SortDir::Desc),
+        ];
+        sort_resources(&mut resources, &order_by);
+        assert_eq!(ids(&resources), [5.0, 2.0, 4.0, 1.0, 3.0]);
+
+        assert!(SortDir::default() == SortDir::Asc);
+        assert!(SortDir::AscNullsFirst.reverse_nulls() == SortDir::Asc);
+    }
 }
@@ -7,7 +7,11 @@
 //!
 
 use crate::{
-    DataSource, Expression, MyError, QString, config::config, ds::sql::MIN_DATE_SQL, expr::E,
+    Context, DataSource, Expression, MyError, QString,
+    config::config,
+    context::DEFAULT_LIKE_ESCAPE,
+    ds::sql::MIN_DATE_SQL,
+    expr::{CONCAT, E},
     op::Op,
 };
 use sqlx::{
@@ -53,6 +57,7 @@ pub struct PGDataSource {
     table: String,
     pool: PgPool,
     srid: u32,
+    like_escape: char,
 }
 
 impl DataSource for PGDataSource {
@@ -128,9 +133,17 @@ impl PGDataSource {
             table: table.to_owned(),
             pool,
             srid,
+            like_escape: DEFAULT_LIKE_ESCAPE,
         })
     }
 
+    /// Translate filters to SQL w/ the settings of the given [Context]; i.e.
+    /// its LIKE escape character.
+    pub fn with_context(mut self, ctx: &Context) -> Self {
+        self.like_escape = ctx.like_escape();
+        self
+    }
+
     /// Return this pool.
     pub fn pool(&self) -> &PgPool {
         &self.pool
@@ -156,11 +169,15 @@ impl PGDataSource {
             E::Bool(true) => Ok("TRUE".to_owned()),
             E::Bool(false) => Ok("FALSE".to_owned()),
             E::Num(x) => Ok(x.to_string()),
+            E::Int(x) => Ok(x.to_string()),
             E::Str(x) => qstr_to_sql(x),
             E::Date(x) => Ok(format!("'{}'", x.date())),
             E::Timestamp(x) => Ok(format!("'{}'", x.datetime())),
             E::Spatial(x) => Ok(x.to_sql()?),
             E::Id(x) => Ok(double_quoted(x)),
+            E::Param(x) => Err(MyError::Runtime(
+                format!("Parameter '${{{x}}}' is not bound").into(),
+            )),
             // some work need to be done when handling these options...
             E::Monadic(op, x) if op.nullable() => {
                 let is_literal = x.is_literal_or_id();
@@ -205,6 +222,11 @@ impl PGDataSource {
                 },
                 x => unreachable!("Unexpected ({x}) monadic operator"),
             },
+            E::Dyadic(op, _, b) if matches!(op, Op::IsInList | Op::IsNotInList) && b.is_id() => {
+                Err(MyError::Unsupported(
+                    format!("[NOT] IN an array-valued property ({b}) has no SQL equivalent").into(),
+                ))
+            }
             E::Dyadic(op, a, b)
                 if matches!(op, Op::IsBetween) || matches!(op, Op::IsNotBetween) =>
             {
@@ -259,9 +281,10 @@ impl PGDataSource {
                 let lhs = self.to_sql_impl(*a)?;
                 let rhs = self.to_sql_impl(*b)?;
                 let z_op = op.to_sql();
+                let escape = self.like_escape.to_string().replace('\'', "''");
                 match a_is_literal {
-                    true => Ok(format!("{lhs} {z_op} ({rhs})")),
-                    false => Ok(format!("({lhs}) {z_op} ({rhs})")),
+                    true => Ok(format!("{lhs} {z_op} ({rhs}) ESCAPE '{escape}'")),
+                    false => Ok(format!("({lhs}) {z_op} ({rhs}) ESCAPE '{escape}'")),
                 }
             }
             E::Dyadic(op, a, b) => {
@@ -281,6 +304,11 @@ impl PGDataSource {
                 let params: Result<Vec<String>, MyError> =
                     x.params.into_iter().map(|x| self.to_sql_impl(x)).collect();
                 let params_ = params?;
+                // `||` yields NULL if any operand is NULL as `concat()` does
+                // when evaluated in-process...
+                if x.name == CONCAT {
+                    return Ok(format!("({})", params_.join(" || ")));
+                }
                 Ok(format!("{}({})", x.name, params_.join(", ")))
             }
             E::Array(x) => {
@@ -612,7 +640,7 @@ fn qstr_to_sql(qs: QString) -> Result<String, MyError> {
         3 => Ok(format!(r#"'{}' COLLATE "{CQL2_CAI}""#, qs.inner())),
         x => {
             let msg = format!("String w/ '{x}' flags has NO direct SQL representation");
-            Err(MyError::Runtime(msg.into()))
+            Err(MyError::Unsupported(msg.into()))
         }
     }
 }
@@ -653,6 +681,12 @@ macro_rules! gen_pg_ds {
                     self.0.table()
                 }
 
+                /// Translate filters to SQL w/ the settings of the given Context.
+                #[allow(dead_code)]
+                $vis fn with_context(self, ctx: &$crate::Context) -> Self {
+                    Self(self.0.with_context(ctx))
+                }
+
                 /// Return a reference to the inner model data source.
                 $vis fn inner(&self) -> &PGDataSource {
                     &self.0
@@ -674,11 +708,12 @@ macro_rules! gen_pg_ds {
                     &self
                 ) -> Result<::futures::stream::BoxStream<'_, Result<$feature, MyError>>, MyError> {
                     let sql = format!("SELECT * FROM {};", $table);
+                    let span = ::tracing::debug_span!("fetch", ds = %self, sql = %sql);
                     let safe_sql = ::sqlx::AssertSqlSafe(sql);
                     let it = sqlx::query_as::<_, $feature>(safe_sql)
                         .fetch(self.0.pool())
                         .map_err(MyError::SQL);
-                    Ok(Box::pin(it))
+                    Ok($crate::traced(span, Box::pin(it)))
                 }
 
                 async fn stream(
@@ -702,12 +737,16 @@ macro_rules! gen_pg_ds {
                 ) -> Result<::futures::stream::BoxStream<'_, Result<$feature, MyError>>, MyError> {
                     let where_clause = self.0.to_sql(exp)?;
                     let sql = format!(r#"SELECT * FROM "{}" WHERE {};"#, self.table(), where_clause);
-                    tracing::debug!("-- sql = {sql}");
+                    let span = ::tracing::debug_span!(
+                        "fetch_where",
+                        ds = %self,
+                        sql = %$crate::redact_literals(&sql)
+                    );
                     let safe_sql = ::sqlx::AssertSqlSafe(sql);
                     let it = sqlx::query_as::<_, $feature>(safe_sql)
                         .fetch(self.0.pool())
                         .map_err(MyError::SQL);
-                    Ok(Box::pin(it))
+                    Ok($crate::traced(span, Box::pin(it)))
                 }
 
                 async fn stream_where(
@@ -725,6 +764,47 @@ macro_rules! gen_pg_ds {
                         .boxed();
                     Ok(resources)
                 }
+
+                async fn fetch_where_limit(
+                    &self,
+                    exp: &Expression,
+                    limit: usize,
+                    offset: usize,
+                ) -> Result<::futures::stream::BoxStream<'_, Result<$feature, MyError>>, MyError> {
+                    let where_clause = self.0.to_sql(exp)?;
+                    let sql = format!(
+                        r#"SELECT * FROM "{}" WHERE {} LIMIT {} OFFSET {};"#,
+                        self.table(), where_clause, limit, offset
+                    );
+                    let span = ::tracing::debug_span!(
+                        "fetch_where_limit",
+                        ds = %self,
+                        sql = %$crate::redact_literals(&sql)
+                    );
+                    let safe_sql = ::sqlx::AssertSqlSafe(sql);
+                    let it = sqlx::query_as::<_, $feature>(safe_sql)
+                        .fetch(self.0.pool())
+                        .map_err(MyError::SQL);
+                    Ok($crate::traced(span, Box::pin(it)))
+                }
+
+                async fn stream_where_limit(
+                    &self,
+                    exp: &Expression,
+                    limit: usize,
+                    offset: usize,
+                ) -> Result<::futures::stream::BoxStream<'_, Result<Resource, MyError>>, MyError> {
+                    let rows = self.fetch_where_limit(exp, limit, offset).await?;
+                    let resources = rows
+                        .try_filter_map(|row| async move {
+                            match Resource::try_from(row) {
+                                Ok(x) => Ok(Some(x)),
+                                Err(x) => Err(x),
+                            }
+                        })
+                        .boxed();
+                    Ok(resources)
+                }
             }
         }
     };
@@ -0,0 +1,657 @@
+// SPDX-License-Identifier: Apache-2.0
+
+#![warn(missing_docs)]
+
+//! Geospatial data stored in Esri Shapefiles.
+//!
+//! Geometries are decoded from the `.shp` file as described in the [ESRI
+//! Shapefile Technical Description][1], while attributes are read from the
+//! companion dBASE (`.dbf`) file.
+//!
+//! [1]: https://www.esri.com/content/dam/esrisites/sitecore-archive/Files/Pdfs/library/whitepapers/pdfs/shapefile.pdf
+//!
+
+use crate::{
+    Bound, G, GTrait, Line, Lines, MyError, Point, Points, Polygon, Polygons, Q, Resource, SRID,
+    config::config,
+    ds::{DEFAULT_GEOM_COLUMN, DataSource, IterableDS},
+    geom::{XY2V, XY3V},
+};
+use jiff::civil::Date;
+use serde_json::{Map, Value};
+use std::{
+    fs::{self, File},
+    io::{BufReader, ErrorKind, Read},
+    path::PathBuf,
+};
+use tracing::{debug, warn};
+
+/// Expected value of the first 4 bytes of a `.shp` file.
+const SHP_FILE_CODE: i32 = 9994;
+/// Size in bytes of a `.shp` file header.
+const SHP_HEADER_LEN: usize = 100;
+/// Size in bytes of a dBASE file header, or field descriptor.
+const DBF_BLOCK_LEN: usize = 32;
+/// Marker of the end of the dBASE field descriptors.
+const DBF_TERMINATOR: u8 = 0x0D;
+/// Marker of a deleted dBASE record.
+const DBF_DELETED: u8 = b'*';
+
+/// A _Feature_ read from a Shapefile; i.e. a geometry from the `.shp` file
+/// and the attributes of the corresponding `.dbf` record.
+#[derive(Debug, Clone)]
+pub struct ShpFeature {
+    geom: G,
+    properties: Resource,
+}
+
+impl ShpFeature {
+    /// Return the geometry of this; [`G::Null`] for a _Null_ shape.
+    pub fn geom(&self) -> &G {
+        &self.geom
+    }
+
+    /// Return the attributes of this.
+    pub fn properties(&self) -> &Resource {
+        &self.properties
+    }
+
+    /// Return a JSON object w/ the attributes of this, and the WKT of its
+    /// geometry under `geom`, suitable for deserializing into a _Feature_
+    /// type w/ `serde`. Dates are rendered as `YYYY-MM-DD` strings.
+    pub fn to_json(&self) -> Value {
+        let mut result = Map::with_capacity(self.properties.len() + 1);
+        for (k, v) in &self.properties {
+            result.insert(k.to_owned(), q_to_json(v));
+        }
+        let geom = match &self.geom {
+            G::Null => Value::Null,
+            x => Value::String(x.to_wkt()),
+        };
+        result.insert(DEFAULT_GEOM_COLUMN.into(), geom);
+        Value::Object(result)
+    }
+}
+
+/// Attributes are mapped as is while the geometry, if any, is stored under
+/// `geom`.
+impl TryFrom<ShpFeature> for Resource {
+    type Error = MyError;
+
+    fn try_from(value: ShpFeature) -> Result<Self, Self::Error> {
+        let mut result = value.properties;
+        let geom = match value.geom {
+            G::Null => Q::Null,
+            x => Q::Geom(x),
+        };
+        result.insert(DEFAULT_GEOM_COLUMN.into(), geom);
+        Ok(result)
+    }
+}
+
+/// [`DataSource`] of _Features_ and [Resources][Resource] read from an Esri
+/// Shapefile.
+///
+/// Only 2D geometries are produced: the _Z_ and _M_ values of `PointZ`,
+/// `PolyLineM`, etc... shapes are ignored. _MultiPatch_ shapes are not
+/// supported and yield an `Err` item w/o ending the iteration.
+///
+/// Records marked as deleted in the `.dbf` file are skipped. Character
+/// attributes are decoded as UTF-8, and blank values are mapped to `NULL`.
+#[derive(Debug)]
+pub struct ShapefileDataSource {
+    path: PathBuf,
+    srid: Option<u32>,
+}
+
+impl DataSource for ShapefileDataSource {
+    fn srid(&self) -> Option<u32> {
+        self.srid
+    }
+}
+
+impl ShapefileDataSource {
+    /// Constructor given the file system location of an accessible `.shp`
+    /// file. The `.dbf` file w/ the same name must exist alongside it.
+    ///
+    /// If a `.prj` file w/ the same name exists, the SRID it specifies is
+    /// assigned to all the geometries. Otherwise they get the configured
+    /// default SRID.
+    pub fn from(s: &str) -> Self {
+        let path = PathBuf::from(s);
+        let srid = fs::read_to_string(path.with_extension("prj"))
+            .ok()
+            .and_then(|x| srid_from_prj(&x));
+        debug!("srid = {srid:?}");
+        Self { path, srid }
+    }
+
+    /// Return this Shapefile data source `.shp` path.
+    pub fn path(&self) -> &PathBuf {
+        &self.path
+    }
+
+    /// Return this Shapefile data source `.dbf` path.
+    pub fn dbf_path(&self) -> PathBuf {
+        self.path.with_extension("dbf")
+    }
+}
+
+impl IterableDS for ShapefileDataSource {
+    type Item = ShpFeature;
+    type Err = MyError;
+
+    fn iter(&self) -> Result<impl Iterator<Item = Result<ShpFeature, MyError>>, MyError> {
+        let srid = match self.srid {
+            Some(x) => SRID::try_from(i32::try_from(x)?)?,
+            None => *config().default_srid(),
+        };
+        ShpIter::new(self, srid)
+    }
+}
+
+// dBASE field descriptor.
+#[derive(Debug)]
+struct DbfField {
+    name: String,
+    type_: u8,
+    len: usize,
+}
+
+// raw bytes of a shape and its matching dBASE record...
+type RawRecord = (Vec<u8>, Vec<u8>);
+
+// Iterator over the records of the `.shp` + `.dbf` file pair.
+struct ShpIter {
+    shp: BufReader<File>,
+    dbf: BufReader<File>,
+    fields: Vec<DbfField>,
+    record_len: usize,
+    remaining: u32,
+    srid: SRID,
+}
+
+impl ShpIter {
+    fn new(ds: &ShapefileDataSource, srid: SRID) -> Result<Self, MyError> {
+        let mut shp = BufReader::new(File::open(&ds.path)?);
+        let mut header = [0_u8; SHP_HEADER_LEN];
+        shp.read_exact(&mut header)?;
+        let code = i32::from_be_bytes(header[..4].try_into()?);
+        if code != SHP_FILE_CODE {
+            return Err(MyError::Runtime(
+                format!("{:?} is not a Shapefile", ds.path).into(),
+            ));
+        }
+
+        let mut dbf = BufReader::new(File::open(ds.dbf_path())?);
+        let mut header = [0_u8; DBF_BLOCK_LEN];
+        dbf.read_exact(&mut header)?;
+        let remaining = u32::from_le_bytes(header[4..8].try_into()?);
+        let header_len = usize::from(u16::from_le_bytes(header[8..10].try_into()?));
+        let record_len = usize::from(u16::from_le_bytes(header[10..12].try_into()?));
+        let mut descriptors = vec![0_u8; header_len.saturating_sub(DBF_BLOCK_LEN)];
+        dbf.read_exact(&mut descriptors)?;
+        let fields = descriptors
+            .chunks_exact(DBF_BLOCK_LEN)
+            .take_while(|x| x[0] != DBF_TERMINATOR)
+            .map(|x| {
+                let name = x[..11].split(|b| *b == 0).next().unwrap_or_default();
+                DbfField {
+                    name: String::from_utf8_lossy(name).trim().to_owned(),
+                    type_: x[11],
+                    len: usize::from(x[16]),
+                }
+            })
+            .collect();
+
+        Ok(Self {
+            shp,
+            dbf,
+            fields,
+            record_len,
+            remaining,
+            srid,
+        })
+    }
+
+    // read the next pair of records. return `None` when exhausted...
+    fn next_record(&mut self) -> Result<Option<RawRecord>, MyError> {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+        let mut header = [0_u8; 8];
+        match self.shp.read_exact(&mut header) {
+            Ok(_) => (),
+            Err(x) if x.kind() == ErrorKind::UnexpectedEof => return Ok(None),
+            Err(x) => return Err(MyError::IO(x)),
+        }
+        // content length is in 16-bit words...
+        let len = i32::from_be_bytes(header[4..].try_into()?);
+        let mut shape = vec![0_u8; 2 * usize::try_from(len)?];
+        self.shp.read_exact(&mut shape)?;
+
+        let mut record = vec![0_u8; self.record_len];
+        self.dbf.read_exact(&mut record)?;
+        self.remaining -= 1;
+        Ok(Some((shape, record)))
+    }
+
+    fn to_feature(&self, shape: &[u8], record: &[u8]) -> Result<ShpFeature, MyError> {
+        let geom = decode_shape(shape, self.srid)?;
+        let mut properties = Resource::with_capacity(self.fields.len() + 1);
+        // skip the deletion flag...
+        let mut offset = 1;
+        for field in &self.fields {
+            let raw = record
+                .get(offset..offset + field.len)
+                .ok_or_else(|| MyError::Runtime("Truncated dBASE record".into()))?;
+            properties.insert(field.name.to_owned(), decode_value(field.type_, raw)?);
+            offset += field.len;
+        }
+        Ok(ShpFeature { geom, properties })
+    }
+}
+
+impl Iterator for ShpIter {
+    type Item = Result<ShpFeature, MyError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.next_record() {
+                Ok(Some((_, record))) if record.first() == Some(&DBF_DELETED) => continue,
+                Ok(Some((shape, record))) => return Some(self.to_feature(&shape, &record)),
+                Ok(None) => return None,
+                Err(x) => {
+                    // the files are out of sync; stop here...
+                    self.remaining = 0;
+                    return Some(Err(x));
+                }
+            }
+        }
+    }
+}
+
+// Return the EPSG code specified in the WKT of a `.prj` file if we can tell;
+// `None` otherwise.
+fn srid_from_prj(wkt: &str) -> Option<u32> {
+    // the outermost CRS authority, if any, comes last. only fold the case of
+    // ASCII letters so the index is also valid in `wkt`...
+    const AUTHORITY: &str = "AUTHORITY[\"EPSG\",\"";
+    let wkt = wkt.trim();
+    if let Some(ndx) = wkt.to_ascii_uppercase().rfind(AUTHORITY) {
+        let code: String = wkt[ndx + AUTHORITY.len()..]
+            .chars()
+            .take_while(char::is_ascii_digit)
+            .collect();
+        return code.parse().ok();
+    }
+    // ESRI flavoured WKT carries no authority...
+    if wkt.starts_with("GEOGCS[") && (wkt.contains("WGS_1984") || wkt.contains("WGS 84")) {
+        return Some(4326);
+    }
+    warn!("Unable to tell the SRID of '{wkt}'");
+    None
+}
+
+fn le_i32(buf: &[u8], at: usize) -> Result<i32, MyError> {
+    let bytes = buf
+        .get(at..at + 4)
+        .ok_or_else(|| MyError::Runtime("Truncated shape record".into()))?;
+    Ok(i32::from_le_bytes(bytes.try_into()?))
+}
+
+fn le_f64(buf: &[u8], at: usize) -> Result<f64, MyError> {
+    let bytes = buf
+        .get(at..at + 8)
+        .ok_or_else(|| MyError::Runtime("Truncated shape record".into()))?;
+    Ok(f64::from_le_bytes(bytes.try_into()?))
+}
+
+// read `count` consecutive XY pairs starting at offset `at`...
+fn read_points(buf: &[u8], at: usize, count: usize) -> Result<XY2V, MyError> {
+    (0..count)
+        .map(|i| {
+            let offset = at + 16 * i;
+            Ok(vec![le_f64(buf, offset)?, le_f64(buf, offset + 8)?])
+        })
+        .collect()
+}
+
+// read the parts of a PolyLine or Polygon shape as sequences of XY pairs...
+fn read_parts(buf: &[u8]) -> Result<XY3V, MyError> {
+    // skip the shape type + bounding box...
+    let num_parts = usize::try_from(le_i32(buf, 36)?)?;
+    let num_points = usize::try_from(le_i32(buf, 40)?)?;
+    let mut starts = (0..num_parts)
+        .map(|i| Ok(usize::try_from(le_i32(buf, 44 + 4 * i)?)?))
+        .collect::<Result<Vec<_>, MyError>>()?;
+    let points = read_points(buf, 44 + 4 * num_parts, num_points)?;
+    starts.push(num_points);
+    starts
+        .windows(2)
+        .map(|x| {
+            points
+                .get(x[0]..x[1])
+                .map(|p| p.to_vec())
+                .ok_or_else(|| MyError::Runtime("Invalid shape part index".into()))
+        })
+        .collect()
+}
+
+// Return twice the signed area of a ring. it's negative when the ring is
+// clockwise.
+fn signed_area(ring: &[Vec<f64>]) -> f64 {
+    ring.windows(2)
+        .map(|x| x[0][0] * x[1][1] - x[1][0] * x[0][1])
+        .sum()
+}
+
+fn decode_shape(buf: &[u8], srid: SRID) -> Result<G, MyError> {
+    let shape_type = le_i32(buf, 0)?;
+    match shape_type {
+        0 => Ok(G::Null),
+        // Point, PointZ, PointM...
+        1 | 11 | 21 => {
+            let xy = vec![le_f64(buf, 4)?, le_f64(buf, 12)?];
+            Ok(G::Point(Point::from_xy_and_srid(xy, srid)))
+        }
+        // MultiPoint, MultiPointZ, MultiPointM...
+        8 | 18 | 28 => {
+            let count = usize::try_from(le_i32(buf, 36)?)?;
+            match read_points(buf, 40, count)? {
+                x if x.is_empty() => Ok(G::Null),
+                x => Ok(G::Points(Points::from_xy_and_srid(x, srid))),
+            }
+        }
+        // PolyLine, PolyLineZ, PolyLineM...
+        3 | 13 | 23 => {
+            let mut lines = read_parts(buf)?;
+            match lines.len() {
+                0 => Ok(G::Null),
+                1 => Ok(G::Line(Line::from_xy_and_srid(lines.remove(0), srid))),
+                _ => Ok(G::Lines(Lines::from_xy_and_srid(lines, srid))),
+            }
+        }
+        // Polygon, PolygonZ, PolygonM...
+        5 | 15 | 25 => {
+            // NOTE (rsn) 20261016 - outer rings are clockwise while holes are
+            // counter-clockwise. holes are assumed to follow the outer ring
+            // they belong to...
+            let mut polygons: Vec<XY3V> = vec![];
+            for ring in read_parts(buf)? {
+                if signed_area(&ring) > 0.0
+                    && let Some(x) = polygons.last_mut()
+                {
+                    x.push(ring)
+                } else {
+                    polygons.push(vec![ring])
+                }
+            }
+            match polygons.len() {
+                0 => Ok(G::Null),
+                1 => Ok(G::Polygon(Polygon::from_xy_and_srid(
+                    polygons.remove(0),
+                    srid,
+                ))),
+                _ => Ok(G::Polygons(Polygons::from_xy_and_srid(polygons, srid))),
+            }
+        }
+        x => Err(MyError::Runtime(
+            format!("Unsupported shape type ({x})").into(),
+        )),
+    }
+}
+
+fn decode_value(type_: u8, raw: &[u8]) -> Result<Q, MyError> {
+    let text = String::from_utf8_lossy(raw);
+    let s = text.trim();
+    if s.is_empty() {
+        return Ok(Q::Null);
+    }
+    match type_ {
+        // numeric; asterisks signal an overflow...
+        b'N' | b'F' if s.starts_with('*') => Ok(Q::Null),
+        b'N' | b'F' => match s.parse::<f64>() {
+            Ok(x) => Ok(Q::Num(x)),
+            Err(_) => Err(MyError::Runtime(
+                format!("Invalid dBASE number '{s}'").into(),
+            )),
+        },
+        b'L' => match s {
+            "T" | "t" | "Y" | "y" => Ok(Q::Bool(true)),
+            "F" | "f" | "N" | "n" => Ok(Q::Bool(false)),
+            _ => Ok(Q::Null),
+        },
+        // YYYYMMDD...
+        b'D' if s.len() == 8 && s != "00000000" => {
+            // slicing a non-ASCII string may panic...
+            if !s.is_ascii() {
+                return Err(MyError::Runtime(format!("Invalid dBASE date '{s}'").into()));
+            }
+            let y: i16 = s[..4].parse()?;
+            let m: i8 = s[4..6].parse()?;
+            let d: i8 = s[6..].parse()?;
+            Q::try_from_date(&Date::new(y, m, d)?)
+        }
+        b'D' => Ok(Q::Null),
+        // character, memo, etc...
+        _ => Ok(Q::new_plain_str(text.trim_end())),
+    }
+}
+
+// Map a Queryable decoded from a Shapefile to JSON. integral numbers are
+// mapped to JSON integers so they can be deserialized as such...
+fn q_to_json(value: &Q) -> Value {
+    match value {
+        Q::Bool(x) => Value::Bool(*x),
+        Q::Num(x) if x.fract() == 0.0 && x.abs() < i64::MAX as f64 => Value::from(*x as i64),
+        Q::Num(x) => serde_json::Number::from_f64(*x).map_or(Value::Null, Value::Number),
+        Q::Int(x) => Value::from(*x),
+        Q::Str(x) => Value::String(x.as_str().to_owned()),
+        Q::Instant(Bound::Date(x)) => Value::String(x.date().to_string()),
+        Q::Geom(x) => Value::String(x.to_wkt()),
+        _ => Value::Null,
+    }
+}
+
+/// Macro to generate a concrete [ShapefileDataSource].
+///
+/// Caller must provide the following parameters:
+/// * `$vis`: Visibility specifier of the generated artifacts; e.g. `pub`.
+/// * `$name`: Prefix of the concrete data source structure name to materialize.
+///   The final name will have a 'Shp' suffix appended; eg. `Foo` -> `FooShp`.
+/// * `$path`: Path to a readable `.shp` file.
+/// * `$feature`: `serde` deserializable structure that maps records to
+///   _Features_. Attribute names are used as is, and the geometry WKT is
+///   provided under `geom`.
+///
+/// The generated data source implements [`IterableDS`][crate::IterableDS].
+#[macro_export]
+macro_rules! gen_shp_ds {
+    ($vis:vis, $name:expr, $path:expr, $feature:expr) => {
+        ::paste::paste! {
+            /// Concrete Shapefile data source.
+            #[derive(Debug)]
+            $vis struct [<$name Shp>](ShapefileDataSource);
+
+            impl [<$name Shp>] {
+                /// Construct a new Shapefile data source.
+                $vis fn new() -> Self {
+                    Self(ShapefileDataSource::from($path))
+                }
+
+                /// Return a reference to the inner model data source.
+                $vis fn inner(&self) -> &ShapefileDataSource {
+                    &self.0
+                }
+            }
+
+            impl ::core::fmt::Display for [<$name Shp>] {
+                fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                    write!(f, "{}Shp({})", $name, $path)
+                }
+            }
+
+            impl IterableDS for [<$name Shp>] {
+                type Item = $feature;
+                type Err = MyError;
+
+                fn iter(&self) -> Result<impl Iterator<Item = Result<$feature, Self::Err>>, Self::Err> {
+                    let it = self.0.iter()?.map(|res| {
+                        res.and_then(|x| Ok(::serde_json::from_value::<$feature>(x.to_json())?))
+                    });
+                    Ok(it)
+                }
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Context, EPSG_4326, Expression, ds::filter_where};
+    use serde::Deserialize;
+    use std::{borrow::Cow, collections::HashMap, error::Error};
+
+    const SHP: &str = "./tests/samples/data/sample.shp";
+
+    #[derive(Debug, Deserialize)]
+    struct Feature {
+        #[serde(rename = "NAME")]
+        name: String,
+        #[serde(rename = "POP")]
+        population: Option<i32>,
+        geom: Option<String>,
+    }
+
+    impl TryFrom<Feature> for Resource {
+        type Error = MyError;
+
+        fn try_from(value: Feature) -> Result<Self, Self::Error> {
+            let geom = match value.geom {
+                Some(wkt) => Q::try_from_wkt(&wkt)?,
+                None => Q::Null,
+            };
+            Ok(HashMap::from([
+                ("NAME".into(), Q::new_plain_str(&value.name)),
+                (
+                    "POP".into(),
+                    value.population.map_or(Q::Null, |x| Q::from(x as f64)),
+                ),
+                ("geom".into(), geom),
+            ]))
+        }
+    }
+
+    gen_shp_ds!(, "Test", SHP, Feature);
+
+    #[test]
+    fn test_srid_from_prj() {
+        const ESRI: &str = r#"GEOGCS["GCS_WGS_1984",DATUM["D_WGS_1984",SPHEROID["WGS_1984",6378137.0,298.257223563]]]"#;
+        const OGC: &str = r#"PROJCS["WGS 84 / Pseudo-Mercator",GEOGCS["WGS 84",AUTHORITY["EPSG","4326"]],AUTHORITY["EPSG","3857"]]"#;
+        const NON_ASCII: &str = r#"PROJCS["Straße ﬀ",GEOGCS["WGS 84",AUTHORITY["EPSG","4326"]],AUTHORITY["EPSG","31467"]]"#;
+
+        assert_eq!(srid_from_prj(ESRI), Some(4326));
+        assert_eq!(srid_from_prj(OGC), Some(3857));
+        assert_eq!(srid_from_prj("LOCAL_CS[\"foo\"]"), None);
+        // non-ASCII characters may change length when upper-cased...
+        assert_eq!(srid_from_prj(NON_ASCII), Some(31467));
+    }
+
+    #[test]
+    fn test_decode_date() -> Result<(), MyError> {
+        let expected = Q::try_from_date(&Date::new(2024, 2, 29)?)?;
+        assert_eq!(decode_value(b'D', b"20240229")?, expected);
+        assert!(decode_value(b'D', b"00000000")?.is_null());
+        // 8 bytes but not 8 ASCII digits...
+        assert!(decode_value(b'D', "2024é02".as_bytes()).is_err());
+        assert!(decode_value(b'D', b"20240\xff").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_iter() -> Result<(), Box<dyn Error>> {
+        let ds = ShapefileDataSource::from(SHP);
+        assert_eq!(ds.srid(), Some(4326));
+
+        let features = ds.iter()?.collect::<Result<Vec<_>, _>>()?;
+        // 6 records, 1 of which is deleted...
+        assert_eq!(features.len(), 5);
+
+        let names: Vec<_> = features
+            .iter()
+            .map(|x| {
+                x.properties()["NAME"]
+                    .to_str()
+                    .expect("Not a string")
+                    .inner()
+                    .to_owned()
+            })
+            .collect();
+        assert_eq!(names, ["Foo", "Bar", "Baz", "Qux", "Nil"]);
+
+        let wkts: Vec<_> = features.iter().map(|x| x.geom().to_wkt_fmt(0)).collect();
+        assert_eq!(wkts[0], "POINT (1 2)");
+        assert_eq!(wkts[1], "MULTILINESTRING ((0 0, 1 1), (2 2, 3 3, 4 4))");
+        assert_eq!(
+            wkts[2],
+            "POLYGON ((0 0, 0 10, 10 10, 10 0, 0 0), (2 2, 4 2, 4 4, 2 4, 2 2))"
+        );
+        // Z is ignored...
+        assert_eq!(wkts[3], "POINT (5 6)");
+        assert_eq!(features[4].geom(), &G::Null);
+        assert_eq!(features[0].geom().srid(), EPSG_4326);
+
+        let foo = features[0].properties();
+        assert_eq!(foo["POP"], Q::Num(100.0));
+        assert_eq!(foo["AREA"], Q::Num(12.5));
+        assert_eq!(foo["ACTIVE"], Q::Bool(true));
+        assert_eq!(foo["FOUNDED"], Q::try_from_date_str("2020-01-15")?);
+
+        let bar = features[1].properties();
+        assert!(bar["POP"].is_null());
+        assert_eq!(bar["ACTIVE"], Q::Bool(false));
+        assert!(bar["FOUNDED"].is_null());
+        assert!(features[2].properties()["ACTIVE"].is_null());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_filter() -> Result<(), Box<dyn Error>> {
+        let ds = ShapefileDataSource::from(SHP);
+        let exp =
+            Expression::try_from_text("POP >= 300 AND S_INTERSECTS(geom, BBOX(0, 0, 10, 10))")?;
+        let matches = filter_where(&exp, Context::new().freeze(), ds.iter()?, |x| {
+            Ok(Cow::Owned(Resource::try_from(x.clone())?))
+        })?;
+
+        let mut names = vec![];
+        for feature in matches {
+            names.push(feature?.properties()["NAME"].to_str()?.inner().to_owned());
+        }
+        assert_eq!(names, ["Baz", "Qux"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_macro() -> Result<(), Box<dyn Error>> {
+        let ds = TestShp::new();
+        assert_eq!(ds.inner().srid(), Some(4326));
+        let features = ds.iter()?.collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(features.len(), 5);
+        assert_eq!(features[0].name, "Foo");
+        assert_eq!(features[0].population, Some(100));
+        assert_eq!(
+            features[0].geom.as_deref(),
+            Some("POINT (1.0000000 2.0000000)")
+        );
+        assert!(features[1].population.is_none());
+        assert!(features[4].geom.is_none());
+
+        Ok(())
+    }
+}
@@ -5,12 +5,40 @@
 //! SQL generation capability for use by a data source.
 //!
 
+use crate::expr::E;
+use jiff::{
+    civil::{Date, date},
+    tz::TimeZone,
+};
+use tracing::error;
+
 /// SQL string to use in lieue of unbounded interval limits.
 /// NOTE (rsn) 2025112 - although PostgreSQL documentation states that that
 /// "low" value is `4713 BC` this seems to be the lowest value i can use w/o
 /// PostgreSQL 18.beta-1 throwing a tantrum.  it also works fine w/ SQLite.
 pub(crate) const MIN_DATE_SQL: &str = "'-2021-01-01'";
 
+/// Date to use in lieu of unbounded interval ends. it's the latest one that
+/// can be converted to a UTC zoned date/time.
+const MAX_DATE: Date = date(9999, 12, 30);
+
+// Return the given interval end expression as is unless it's unbounded in
+// which case return a date after any other. `E::Unbounded` alone translates
+// to `MIN_DATE_SQL` which is only right for interval starts...
+pub(crate) fn unbounded_end(exp: E) -> E {
+    match exp {
+        E::Unbounded => match MAX_DATE.to_zoned(TimeZone::UTC) {
+            Ok(z) => E::Date(z),
+            // should not happen...
+            Err(x) => {
+                error!("Failed converting {MAX_DATE} to a zoned date: {x}");
+                E::Unbounded
+            }
+        },
+        x => x,
+    }
+}
+
 // Given two _Expressions_ `$a` and `$b`, check whether they're _Intervals_ or
 // not and compute a tuple that represents the result along w/ four expressions
 // representing the entities to use in formulating comparison predicates that
@@ -25,16 +53,20 @@ macro_rules! unfold_expressions {
             (false, false) => (false, false, $a, E::Null, $b, E::Null),
             (false, true) => {
                 let t2 = $b.as_interval().expect("2nd argument is NOT an interval");
-                (false, true, $a, E::Null, t2.0, t2.1)
+                let t2_end = $crate::ds::sql::unbounded_end(t2.1);
+                (false, true, $a, E::Null, t2.0, t2_end)
             }
             (true, false) => {
                 let t1 = $a.as_interval().expect("1st argument is NOT an interval");
-                (true, false, t1.0, t1.1, $b, E::Null)
+                let t1_end = $crate::ds::sql::unbounded_end(t1.1);
+                (true, false, t1.0, t1_end, $b, E::Null)
             }
             (true, true) => {
                 let t1 = $a.as_interval().expect("1st argument is NOT an interval");
                 let t2 = $b.as_interval().expect("2nd argument is NOT an interval");
-                (true, true, t1.0, t1.1, t2.0, t2.1)
+                let t1_end = $crate::ds::sql::unbounded_end(t1.1);
+                let t2_end = $crate::ds::sql::unbounded_end(t2.1);
+                (true, true, t1.0, t1_end, t2.0, t2_end)
             }
         }
     }};
@@ -48,7 +80,9 @@ macro_rules! unfold_intervals {
     ( $a: expr, $b: expr ) => {{
         let t1 = $a.as_interval().expect("1st argument is NOT an interval");
         let t2 = $b.as_interval().expect("2nd argument is NOT an interval");
-        (t1.0, t1.1, t2.0, t2.1)
+        let t1_end = $crate::ds::sql::unbounded_end(t1.1);
+        let t2_end = $crate::ds::sql::unbounded_end(t2.1);
+        (t1.0, t1_end, t2.0, t2_end)
     }};
 }
 
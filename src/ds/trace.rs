@@ -0,0 +1,179 @@
+// SPDX-License-Identifier: Apache-2.0
+
+#![warn(missing_docs)]
+
+//! Helpers used by data sources, and the macros generating them, to trace the
+//! queries they issue and the streams of _Features_ they return.
+//!
+
+use crate::{MyError, config::config};
+use futures::{StreamExt, stream::BoxStream};
+use std::{borrow::Cow, time::Instant};
+use tracing::{Span, debug};
+
+/// Return the given SQL, or CQL2 text, as it should be recorded in a trace;
+/// i.e. w/ its literal values replaced by `?` when the `REDACT_LITERALS`
+/// configuration parameter is set.
+#[doc(hidden)]
+pub fn redact_literals(s: &str) -> Cow<'_, str> {
+    if config().redact_literals() {
+        Cow::Owned(redact(s))
+    } else {
+        Cow::Borrowed(s)
+    }
+}
+
+/// Wrap a stream of _Features_ so that, once exhausted or dropped, the number
+/// of rows it yielded, the number of errors, and the time elapsed since this
+/// was called are recorded as a `debug` event w/in the given `span`.
+#[doc(hidden)]
+pub fn traced<'a, T: Send + 'a>(
+    span: Span,
+    stream: BoxStream<'a, Result<T, MyError>>,
+) -> BoxStream<'a, Result<T, MyError>> {
+    let mut tally = Tally {
+        span,
+        start: Instant::now(),
+        rows: 0,
+        errors: 0,
+    };
+    // NOTE (rsn) 20261016 - borrow the whole `tally` so the closure owns it,
+    // and drops it w/ the stream, instead of capturing copies of its counts...
+    stream
+        .inspect(move |x| {
+            let tally = &mut tally;
+            match x {
+                Ok(_) => tally.rows += 1,
+                Err(_) => tally.errors += 1,
+            }
+        })
+        .boxed()
+}
+
+// running counts of a traced stream reported when dropped...
+struct Tally {
+    span: Span,
+    start: Instant,
+    rows: usize,
+    errors: usize,
+}
+
+impl Drop for Tally {
+    fn drop(&mut self) {
+        debug!(
+            parent: &self.span,
+            matched = self.rows,
+            errors = self.errors,
+            elapsed = ?self.start.elapsed(),
+            "Stream done"
+        );
+    }
+}
+
+// replace the string and numeric literals in `s` by `?` leaving everything
+// else, incl. double-quoted identifiers, as is...
+fn redact(s: &str) -> String {
+    let mut result = String::with_capacity(s.len());
+    let mut it = s.chars().peekable();
+    while let Some(c) = it.next() {
+        match c {
+            '\'' => {
+                // a quote is escaped by doubling it...
+                while let Some(c) = it.next() {
+                    if c == '\'' && it.next_if_eq(&'\'').is_none() {
+                        break;
+                    }
+                }
+                result.push('?');
+            }
+            '"' => {
+                result.push(c);
+                for c in it.by_ref() {
+                    result.push(c);
+                    if c == '"' {
+                        break;
+                    }
+                }
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                result.push(c);
+                while let Some(c) = it.next_if(|x| x.is_alphanumeric() || *x == '_') {
+                    result.push(c);
+                }
+            }
+            c if c.is_ascii_digit()
+                || (c == '.' && it.peek().is_some_and(char::is_ascii_digit)) =>
+            {
+                while it.next_if(|x| x.is_ascii_digit() || *x == '.').is_some() {}
+                if it.next_if(|x| *x == 'e' || *x == 'E').is_some() {
+                    it.next_if(|x| *x == '+' || *x == '-');
+                    while it.next_if(char::is_ascii_digit).is_some() {}
+                }
+                result.push('?');
+            }
+            _ => result.push(c),
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::{TryStreamExt, stream};
+
+    #[test]
+    fn test_redact() {
+        #[rustfmt::skip]
+        const TV: [(&str, &str); 6] = [
+            (
+                r#"SELECT * FROM "ne_110m" WHERE "NAME" = 'Côte d''Ivoire'"#,
+                r#"SELECT * FROM "ne_110m" WHERE "NAME" = ?"#,
+            ),
+            (
+                "pop_2020 > 1000 AND pop_2020 < -2.5e+3",
+                "pop_2020 > ? AND pop_2020 < -?",
+            ),
+            (
+                "ST_Intersects(geom, ST_GeomFromText('POINT(7.02 49.92)', 4326))",
+                "ST_Intersects(geom, ST_GeomFromText(?, ?))",
+            ),
+            (
+                "S_INTERSECTS(geom, POINT(.5 49))",
+                "S_INTERSECTS(geom, POINT(? ?))",
+            ),
+            (
+                "T_AFTER(updated, TIMESTAMP('2017-06-11T10:30:00Z'))",
+                "T_AFTER(updated, TIMESTAMP(?))",
+            ),
+            (
+                r#""col 1" IS NULL LIMIT 10 OFFSET 20"#,
+                r#""col 1" IS NULL LIMIT ? OFFSET ?"#,
+            ),
+        ];
+
+        for (input, expected) in TV {
+            assert_eq!(redact(input), expected);
+        }
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_traced() -> Result<(), MyError> {
+        let items = vec![Ok(1), Err(MyError::Runtime("Oops".into())), Ok(3)];
+        let span = tracing::debug_span!("test_traced");
+        let it = traced(span, stream::iter(items).boxed());
+        let actual: Vec<_> = it.filter_map(|x| async { x.ok() }).collect().await;
+        assert_eq!(actual, [1, 3]);
+        assert!(logs_contain("matched=2 errors=1"));
+
+        // a partially consumed stream is reported when dropped...
+        let span = tracing::debug_span!("test_traced");
+        let mut it = traced(span, stream::iter([Ok(1), Ok(2), Ok(3)]).boxed());
+        assert_eq!(it.try_next().await?, Some(1));
+        drop(it);
+        assert!(logs_contain("matched=1 errors=0"));
+
+        Ok(())
+    }
+}
@@ -5,6 +5,7 @@
 //! Errors raised from this library.
 //!
 
+use core::fmt;
 use peg::{error::ParseError, str::LineCol};
 use std::{
     array::TryFromSliceError,
@@ -13,6 +14,74 @@ use std::{
 };
 use thiserror::Error;
 
+/// Details of where, and why, parsing a text-encoded input failed.
+///
+/// Its `Display` renders the `peg` message followed by the offending line of
+/// the input and a caret pointing at the failing position; e.g.
+///
+/// ```text
+/// error at 1:5: expected one of ...
+/// foo ===
+///     ^
+/// ```
+#[derive(Debug)]
+pub struct SyntaxError {
+    line_text: String,
+    inner: ParseError<LineCol>,
+}
+
+impl SyntaxError {
+    pub(crate) fn new(input: &str, inner: ParseError<LineCol>) -> Self {
+        let line_text = input
+            .lines()
+            .nth(inner.location.line.saturating_sub(1))
+            .unwrap_or_default()
+            .to_owned();
+        Self { line_text, inner }
+    }
+
+    /// Return the 0-based byte offset, in the input, of the failing position.
+    pub fn offset(&self) -> usize {
+        self.inner.location.offset
+    }
+
+    /// Return the 1-based line number of the failing position.
+    pub fn line(&self) -> usize {
+        self.inner.location.line
+    }
+
+    /// Return the 1-based column, in characters, of the failing position.
+    pub fn column(&self) -> usize {
+        self.inner.location.column
+    }
+
+    /// Return the, alphabetically sorted, tokens the parser expected to find
+    /// at the failing position.
+    pub fn expected(&self) -> Vec<&'static str> {
+        let mut result: Vec<_> = self.inner.expected.tokens().collect();
+        result.sort_unstable();
+        result
+    }
+
+    /// Return the line of the input where parsing failed.
+    pub fn line_text(&self) -> &str {
+        &self.line_text
+    }
+}
+
+impl fmt::Display for SyntaxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let caret = " ".repeat(self.column().saturating_sub(1));
+        write!(f, "{}\n{}\n{caret}^", self.inner, self.line_text)
+    }
+}
+
+impl std::error::Error for SyntaxError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.inner)
+    }
+}
+
 /// Variants of error raised from this library.
 #[derive(Debug, Error)]
 pub enum MyError {
@@ -25,14 +94,15 @@ pub enum MyError {
     Time(#[from] jiff::Error),
 
     /// Text-encoding (`peg`) related error.
-    #[error("PEG error: {0:?}")]
-    Text(ParseError<LineCol>),
+    #[error("PEG error: {0}")]
+    Text(#[source] SyntaxError),
 
     /// JSON-encoding (`serde`) related error
     #[error("Json [Try]From error: {0}")]
     Json(#[from] serde_json::Error),
 
     /// GEOS related error.
+    #[cfg(feature = "geos")]
     #[error("Geos error: {0}")]
     Geos(#[from] geos::Error),
 
@@ -41,17 +111,35 @@ pub enum MyError {
     PrecisionLoss(Cow<'static, str>),
 
     /// CRS construction error.
+    #[cfg(feature = "geos")]
     #[error("CRS creation error: {0}")]
     CRS(#[from] proj::ProjCreateError),
 
     /// Coordinate transformation (`proj`) related error.
+    #[cfg(feature = "geos")]
     #[error("Proj error: {0}")]
     Proj(#[from] proj::ProjError),
 
     /// Runtime error.
+    ///
+    /// Raised when an internal invariant does not hold or an input is invalid
+    /// in a way that does not originate from another library. Errors raised
+    /// by a dependency are wrapped in a dedicated variant instead, and are
+    /// accessible through [`source()`][std::error::Error::source()].
     #[error("Runtime error: {0}")]
     Runtime(Cow<'static, str>),
 
+    /// Unsupported operation error.
+    ///
+    /// Raised when a data source cannot handle an [Expression][crate::Expression]
+    /// natively; e.g. b/c it has no SQL equivalent. Such an [Expression][crate::Expression]
+    /// can still be evaluated in-process.
+    ///
+    /// Also raised by spatial functions and CRS transformations when this
+    /// library is built w/o the `geos` feature.
+    #[error("Unsupported: {0}")]
+    Unsupported(Cow<'static, str>),
+
     /// CSV error.
     #[error("CSV error: {0}")]
     CSV(#[from] csv::Error),
@@ -71,4 +159,58 @@ pub enum MyError {
     /// Byte-array to float conversion error.
     #[error("Conversion (slice) error: {0}")]
     Slice(#[from] TryFromSliceError),
+
+    /// Parquet file (`parquet`) related error.
+    #[cfg(feature = "geoparquet")]
+    #[error("Parquet error: {0}")]
+    Parquet(#[from] parquet::errors::ParquetError),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Expression, G};
+    use std::error::Error;
+
+    #[test]
+    fn test_source() {
+        let x = Expression::try_from_text("foo ===").expect_err("Expected a parse error");
+        assert!(matches!(x, MyError::Text(_)));
+        assert!(x.source().is_some());
+
+        let x = Expression::try_from_json("{").expect_err("Expected a JSON error");
+        assert!(matches!(x, MyError::Json(_)));
+        assert!(x.source().is_some());
+
+        let x = MyError::from(std::io::Error::other("boom"));
+        assert_eq!(x.source().map(|e| e.to_string()), Some("boom".into()));
+
+        let x = G::try_from("LINESTRING (0 0, 1 1)")
+            .and_then(|g| g.get_x())
+            .expect_err("Expected a runtime error");
+        assert!(matches!(x, MyError::Runtime(_)));
+        assert!(x.source().is_none());
+    }
+
+    #[test]
+    fn test_syntax_error() {
+        let x = Expression::try_from_text("pop > 10 AND\n  name === 'x'")
+            .expect_err("Expected a parse error");
+        let MyError::Text(x) = x else {
+            panic!("Expected a text error");
+        };
+        assert_eq!(x.line(), 2);
+        assert_eq!(x.column(), 9);
+        assert_eq!(x.offset(), 21);
+        assert_eq!(x.line_text(), "  name === 'x'");
+        assert!(!x.expected().is_empty());
+        assert!(x.expected().is_sorted());
+
+        let s = x.to_string();
+        let lines: Vec<_> = s.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].contains("2:9"), "{s}");
+        assert_eq!(lines[1], "  name === 'x'");
+        assert_eq!(lines[2], "        ^");
+    }
 }
@@ -6,8 +6,9 @@
 //!
 
 use crate::{
-    E, Expression, GTrait, MyError, Outcome, Q, Resource, SharedContext, text::cql2::expression,
+    Context, E, Expression, GTrait, MyError, Outcome, Q, Resource, ResourceRef, SharedContext,
 };
+use std::{borrow::Cow, cell::OnceCell};
 use tracing::{debug, error};
 
 /// Capability of processing OGC CQL2 [expressions][Expression], both text- and json-encoded.
@@ -25,10 +26,12 @@ pub trait Evaluator {
 /// database engine endowed w/ spatial and other operators.
 #[derive(Debug)]
 pub struct ExEvaluator {
-    /// Runtime context w/in which [Resource]s will be evaluated.
+    /// Runtime context w/in which [Resource][crate::Resource]s will be evaluated.
     shared_ctx: SharedContext,
     /// Valid/parsed OGC CQL2 expression.
     exp: E,
+    /// Names of the properties referenced more than once in `exp`.
+    memoized: Vec<String>,
 }
 
 impl ExEvaluator {
@@ -37,25 +40,31 @@ impl ExEvaluator {
         Self {
             shared_ctx: ctx,
             exp: E::default(),
+            memoized: vec![],
         }
     }
+
+    /// Same as [`evaluate()`][Evaluator::evaluate()] except that the
+    /// properties are read from any type that provides access to them by
+    /// name; e.g. one that decodes them lazily.
+    pub fn evaluate_ref<R: ResourceRef + ?Sized>(&self, feature: &R) -> Result<Outcome, MyError> {
+        evaluate(&self.shared_ctx, &self.exp, &self.memoized, feature)
+    }
 }
 
 impl Evaluator for ExEvaluator {
     fn setup(&mut self, input: Expression) -> Result<(), MyError> {
         // if we're JSON-encoded, convert to Text-encoded.
-        let mut exp = match input {
+        let exp = match input {
             Expression::Text(text) => text.0,
-            Expression::Json(json) => {
-                // attempt parsing the to_string() output of inner object...
-                let text = json.0.to_string();
-                debug!("About to parse '{text}'");
-                expression(&text).map_err(MyError::Text)?
+            Expression::Json(_) => {
+                debug!("About to parse '{input}'");
+                input.to_inner()?
             }
         };
-        let it = E::reduce(&mut exp)?;
-        tracing::trace!("setup (redux): {it}");
-        self.exp = it;
+        let (exp, memoized) = prepare(&self.shared_ctx, exp)?;
+        self.exp = exp;
+        self.memoized = memoized;
         Ok(())
     }
 
@@ -63,38 +72,426 @@ impl Evaluator for ExEvaluator {
     fn evaluate(&self, feature: &Resource) -> Result<Outcome, MyError> {
         // let _ev_ = tracing::span!(tracing::Level::DEBUG, "L2").entered();
         // let _ev_start = _ev_.enter();
+        self.evaluate_ref(feature)
+    }
+}
 
-        let ctx = &self.shared_ctx;
-        match self.exp.eval(ctx, feature)? {
-            Q::Null => Ok(Outcome::N),
-            Q::Bool(x) => match x {
-                true => Ok(Outcome::T),
-                false => Ok(Outcome::F),
-            },
-            Q::Num(x) => {
-                error!("Unexpected number: {x}");
-                Ok(Outcome::N)
-            }
-            Q::Str(x) => {
-                error!("Unexpected string: '{}'", &x);
-                Ok(Outcome::N)
-            }
-            Q::Geom(x) => {
-                error!("Unexpected geometry: {}", x.to_wkt());
-                Ok(Outcome::N)
-            }
-            Q::Instant(x) => {
-                error!("Unexpected instant: {x}");
-                Ok(Outcome::N)
-            }
-            Q::Interval(x, y) => {
-                error!("Unexpected interval: [{x}..{y}]");
-                Ok(Outcome::N)
-            }
-            Q::List(x) => {
-                error!("Unexpected list: {x:?}");
-                Ok(Outcome::N)
+/// An [Expression] parsed, reduced and checked once w/in a given [Context],
+/// ready to be [evaluated][CompiledExpression::evaluate()] against any number
+/// of [Resource][crate::Resource]s.
+///
+/// Instances are obtained by calling [Expression::compile()].
+#[derive(Debug)]
+pub struct CompiledExpression<'a> {
+    ctx: &'a Context,
+    exp: E,
+    memoized: Vec<String>,
+}
+
+impl<'a> CompiledExpression<'a> {
+    pub(crate) fn new(ctx: &'a Context, input: &Expression) -> Result<Self, MyError> {
+        let (exp, memoized) = prepare(ctx, input.to_inner()?)?;
+        // fail early, rather than once per Resource, when a literal geometry
+        // has coordinates outside the CRS's area-of-use. w/ a filter CRS that
+        // was done when preparing it...
+        let failed = OnceCell::new();
+        if ctx.filter_crs().is_none() && ctx.coordinate_check() {
+            exp.any(&|x| match x {
+                E::Spatial(g) => g
+                    .check_coordinates(ctx.crs())
+                    .map_err(|e| failed.set(e))
+                    .is_err(),
+                _ => false,
+            });
+        }
+        match failed.into_inner() {
+            Some(x) => Err(x),
+            None => Ok(Self { ctx, exp, memoized }),
+        }
+    }
+
+    /// Evaluate a given [Resource][crate::Resource], or any other type that
+    /// provides access to its properties by name, returning an [Outcome], or
+    /// raise a [MyError] if an unexpected error occurs in the process.
+    pub fn evaluate<R: ResourceRef + ?Sized>(&self, resource: &R) -> Result<Outcome, MyError> {
+        evaluate(self.ctx, &self.exp, &self.memoized, resource)
+    }
+}
+
+// check an expression is not nested deeper than allowed by `ctx`, reduce it,
+// transform its literal geometries from the filter CRS if any, and find the
+// names of the properties it references more than once...
+fn prepare(ctx: &Context, mut exp: E) -> Result<(E, Vec<String>), MyError> {
+    // NOTE (rsn) 20261016 - both reducing and evaluating are recursive. check
+    // the depth, iteratively, before either gets a chance to blow the stack...
+    if let Some(max) = ctx.max_depth() {
+        let depth = exp.depth();
+        if depth > max {
+            let msg = format!("Expression depth ({depth}) exceeds maximum ({max})");
+            error!("Failed: {msg}");
+            return Err(MyError::Runtime(msg.into()));
+        }
+    }
+    let it = E::reduce(&mut exp)?;
+    let it = match ctx.filter_crs() {
+        Some(crs) => it.transform_literals(crs, ctx.crs(), ctx.coordinate_check())?,
+        None => it,
+    };
+    tracing::trace!("setup (redux): {it}");
+    let memoized = it
+        .count_properties()
+        .into_iter()
+        .filter_map(|(k, v)| (v > 1).then_some(k))
+        .collect();
+    Ok((it, memoized))
+}
+
+fn evaluate<R: ResourceRef + ?Sized>(
+    ctx: &Context,
+    exp: &E,
+    memoized: &[String],
+    feature: &R,
+) -> Result<Outcome, MyError> {
+    // NOTE (rsn) 20261016 - a property referenced by more than one predicate
+    // is looked up, and possibly computed (e.g. a geometry decoded from WKB),
+    // only once per _Resource_...
+    let outcome = if memoized.is_empty() {
+        exp.eval(ctx, feature)?
+    } else {
+        let memoized = Memoized::new(feature, memoized);
+        exp.eval(ctx, &memoized)?
+    };
+    match outcome {
+        Q::Null => Ok(Outcome::N),
+        Q::Bool(x) => match x {
+            true => Ok(Outcome::T),
+            false => Ok(Outcome::F),
+        },
+        Q::Num(x) => {
+            error!("Unexpected number: {x}");
+            Ok(Outcome::N)
+        }
+        Q::Int(x) => {
+            error!("Unexpected number: {x}");
+            Ok(Outcome::N)
+        }
+        Q::Str(x) => {
+            error!("Unexpected string: '{}'", &x);
+            Ok(Outcome::N)
+        }
+        Q::Geom(x) => {
+            error!("Unexpected geometry: {}", x.to_wkt());
+            Ok(Outcome::N)
+        }
+        Q::Instant(x) => {
+            error!("Unexpected instant: {x}");
+            Ok(Outcome::N)
+        }
+        Q::Interval(x, y) => {
+            error!("Unexpected interval: [{x}..{y}]");
+            Ok(Outcome::N)
+        }
+        Q::List(x) => {
+            error!("Unexpected list: {x:?}");
+            Ok(Outcome::N)
+        }
+        Q::Bytes(x) => {
+            error!("Unexpected binary value ({} bytes)", x.len());
+            Ok(Outcome::N)
+        }
+    }
+}
+
+// a ResourceRef wrapping another one for the duration of a single evaluation,
+// caching the value of each of a known set of properties the 1st time it's
+// looked up. other properties are delegated as is...
+struct Memoized<'a, R: ?Sized> {
+    inner: &'a R,
+    names: &'a [String],
+    values: Vec<OnceCell<Option<Cow<'a, Q>>>>,
+}
+
+impl<'a, R: ResourceRef + ?Sized> Memoized<'a, R> {
+    fn new(inner: &'a R, names: &'a [String]) -> Self {
+        Self {
+            inner,
+            names,
+            values: names.iter().map(|_| OnceCell::new()).collect(),
+        }
+    }
+}
+
+impl<R: ResourceRef + ?Sized> ResourceRef for Memoized<'_, R> {
+    fn get(&self, name: &str) -> Option<Cow<'_, Q>> {
+        match self.names.iter().position(|x| x == name) {
+            Some(i) => self.values[i]
+                .get_or_init(|| self.inner.get(name))
+                .as_ref()
+                .map(|x| Cow::Borrowed(&**x)),
+            None => self.inner.get(name),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CRS, Context, Outcome};
+    use serde_json::json;
+    use std::{cell::Cell, error::Error};
+
+    // a feature counting how many times its geometry is decoded...
+    struct CountingFeature {
+        wkt: &'static str,
+        decoded: Cell<usize>,
+    }
+
+    impl ResourceRef for CountingFeature {
+        fn get(&self, name: &str) -> Option<Cow<'_, Q>> {
+            match name {
+                "geom" => {
+                    self.decoded.set(self.decoded.get() + 1);
+                    Q::try_from_wkt(self.wkt).ok().map(Cow::Owned)
+                }
+                "name" => Some(Cow::Owned(Q::new_plain_str("Paris"))),
+                _ => None,
             }
         }
     }
+
+    #[test]
+    fn test_memoized_properties() -> Result<(), Box<dyn Error>> {
+        const CQL: &str = r#"S_INTERSECTS(geom, BBOX(2, 48, 3, 49))
+            AND NOT S_WITHIN("geom", POINT(0 0))
+            AND S_DISJOINT(geom, POINT(10 10))
+            AND name = 'Paris'"#;
+
+        let mut evaluator = ExEvaluator::new(Context::new().freeze());
+        evaluator.setup(Expression::try_from_text(CQL)?)?;
+        assert_eq!(evaluator.memoized, vec!["geom".to_string()]);
+
+        let feature = CountingFeature {
+            wkt: "POINT(2.35 48.86)",
+            decoded: Cell::new(0),
+        };
+        assert_eq!(evaluator.evaluate_ref(&feature)?, Outcome::T);
+        assert_eq!(feature.decoded.get(), 1);
+        // the cache is scoped to a single evaluation...
+        assert_eq!(evaluator.evaluate_ref(&feature)?, Outcome::T);
+        assert_eq!(feature.decoded.get(), 2);
+
+        let feature = CountingFeature {
+            wkt: "POINT(20 20)",
+            decoded: Cell::new(0),
+        };
+        assert_eq!(evaluator.evaluate_ref(&feature)?, Outcome::F);
+        assert_eq!(feature.decoded.get(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_compiled_expression() -> Result<(), Box<dyn Error>> {
+        let ctx = Context::new();
+        let exp =
+            Expression::try_from_text("S_INTERSECTS(geom, BBOX(2, 48, 3, 49)) AND 1 + 1 = 2")?;
+        let compiled = exp.compile(&ctx)?;
+        // literals were reduced when compiling...
+        assert!(matches!(compiled.exp, E::Dyadic(_, _, _)));
+
+        for (wkt, expected) in [
+            ("POINT(2.35 48.86)", Outcome::T),
+            ("POINT(20 20)", Outcome::F),
+        ] {
+            let feature = CountingFeature {
+                wkt,
+                decoded: Cell::new(0),
+            };
+            assert_eq!(compiled.evaluate(&feature)?, expected);
+        }
+
+        // JSON-encoded expressions compile too...
+        let exp = Expression::try_from_json(
+            r#"{ "op": "=", "args": [{ "property": "name" }, "Paris"] }"#,
+        )?;
+        let feature = CountingFeature {
+            wkt: "POINT(0 0)",
+            decoded: Cell::new(0),
+        };
+        assert_eq!(exp.compile(&ctx)?.evaluate(&feature)?, Outcome::T);
+
+        // literal geometries are checked against the CRS once...
+        let exp = Expression::try_from_text("S_INTERSECTS(geom, POINT(200 0))")?;
+        assert!(exp.compile(&ctx).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_filter_crs() -> Result<(), Box<dyn Error>> {
+        // stored in Web Mercator, that's (10, 50) in WGS 84...
+        let feature = CountingFeature {
+            wkt: "POINT(1113194.9079327 6446275.8410172)",
+            decoded: Cell::new(0),
+        };
+        let inside = Expression::try_from_text("S_INTERSECTS(geom, BBOX(9, 49, 11, 51))")?;
+        let outside =
+            Expression::try_from_text("S_WITHIN(geom, POLYGON((0 40, 5 40, 5 45, 0 45, 0 40)))")?;
+
+        let ctx = Context::try_with_crs("EPSG:3857")?.with_filter_crs(CRS::new("EPSG:4326")?);
+        assert_eq!(inside.compile(&ctx)?.evaluate(&feature)?, Outcome::T);
+        assert_eq!(outside.compile(&ctx)?.evaluate(&feature)?, Outcome::F);
+
+        let mut evaluator = ExEvaluator::new(ctx.freeze());
+        evaluator.setup(inside.clone())?;
+        assert_eq!(evaluator.evaluate_ref(&feature)?, Outcome::T);
+
+        // w/o it, literals are taken as Web Mercator metres...
+        let ctx = Context::try_with_crs("EPSG:3857")?;
+        assert_eq!(inside.compile(&ctx)?.evaluate(&feature)?, Outcome::F);
+
+        // literals are still checked against the filter CRS area-of-use...
+        let ctx = Context::try_with_crs("EPSG:3857")?.with_filter_crs(CRS::new("EPSG:4326")?);
+        let exp = Expression::try_from_text("S_INTERSECTS(geom, POINT(200 0))")?;
+        assert!(exp.compile(&ctx).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_unchecked_coordinates() -> Result<(), Box<dyn Error>> {
+        let feature = CountingFeature {
+            wkt: "POINT(0 0)",
+            decoded: Cell::new(0),
+        };
+        let exp = Expression::try_from_text("S_INTERSECTS(geom, POINT(200 0))")?;
+
+        let ctx = Context::new();
+        assert!(ctx.coordinate_check());
+        assert!(exp.compile(&ctx).is_err());
+
+        let ctx = Context::new().with_unchecked_coordinates();
+        assert!(!ctx.coordinate_check());
+        assert_eq!(exp.compile(&ctx)?.evaluate(&feature)?, Outcome::F);
+
+        let mut evaluator = ExEvaluator::new(ctx.freeze());
+        evaluator.setup(exp)?;
+        assert_eq!(evaluator.evaluate_ref(&feature)?, Outcome::F);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_max_depth() -> Result<(), Box<dyn Error>> {
+        #[rustfmt::skip]
+        let cases = [
+            ("TRUE",                                1),
+            ("name = 'Paris'",                      2),
+            ("NOT (name = 'Paris')",                3),
+            ("name = 'Paris' AND (a = 1 OR b = 2)", 4),
+            ("abs(a + 1) > 0",                      4),
+        ];
+        for (cql, expected) in cases {
+            assert_eq!(Expression::try_from_text(cql)?.depth(), expected, "{cql}");
+        }
+
+        let cql = (0..50).fold("name = 'Paris'".to_owned(), |acc, i| {
+            format!("({acc} OR name = '{i}')")
+        });
+        let exp = Expression::try_from_text(&cql)?;
+        assert_eq!(exp.depth(), 52);
+
+        let feature = CountingFeature {
+            wkt: "POINT(0 0)",
+            decoded: Cell::new(0),
+        };
+        let ctx = Context::new().with_max_depth(52);
+        assert_eq!(exp.compile(&ctx)?.evaluate(&feature)?, Outcome::T);
+
+        let ctx = Context::new().with_max_depth(51);
+        assert!(exp.compile(&ctx).is_err());
+        let mut evaluator = ExEvaluator::new(ctx.freeze());
+        assert!(evaluator.setup(exp).is_err());
+
+        // guarded by default...
+        assert!(Context::new().max_depth().is_some());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_max_nesting() -> Result<(), Box<dyn Error>> {
+        // way deeper than what the stack can take while parsing...
+        const N: usize = 100_000;
+
+        let cql = format!("{}TRUE{}", "(".repeat(N), ")".repeat(N));
+        assert!(Expression::try_from_text(&cql).is_err());
+        let cql = format!("{}a{}", "abs(".repeat(N), ")".repeat(N));
+        assert!(Expression::try_from_text(&cql).is_err());
+
+        let json = format!(
+            "{}true{}",
+            r#"{"op":"not","args":["#.repeat(N),
+            "]}".repeat(N)
+        );
+        assert!(Expression::try_from_json(&json).is_err());
+
+        // brackets in literals don't count...
+        let cql = format!("name = '{}' AND a = 1", "(OR ".repeat(N));
+        assert!(Expression::try_from_text(&cql).is_ok());
+
+        // a (shallow enough) JSON value deeper than allowed...
+        let mut value = json!(true);
+        for _ in 0..200 {
+            value = json!({ "op": "not", "args": [value] });
+        }
+        assert!(Expression::try_from_json_value(&value).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_flat_chains() -> Result<(), Box<dyn Error>> {
+        const N: usize = 100_000;
+        // way more than the default maximum depth...
+        const M: usize = 1_000;
+
+        // flat chains, however long, are neither too nested nor too deep...
+        let ctx = Context::new();
+        let feature = Resource::from([("a".into(), Q::Int(42))]);
+        let cql = (0..N)
+            .map(|i| format!("a = {i}"))
+            .collect::<Vec<_>>()
+            .join(" OR ");
+        let exp = Expression::try_from_text(&cql)?;
+        assert!(exp.depth() < 32);
+        assert_eq!(exp.compile(&ctx)?.evaluate(&feature)?, Outcome::T);
+
+        // ...whatever their encoding...
+        let args: Vec<_> = (0..M)
+            .map(|i| json!({ "op": "=", "args": [{ "property": "a" }, i] }))
+            .collect();
+        let exp = Expression::try_from_json_value(&json!({ "op": "or", "args": args }))?;
+        assert!(exp.depth() < 32);
+        assert_eq!(exp.compile(&ctx)?.evaluate(&feature)?, Outcome::T);
+
+        // AND binds tighter than OR, and connectives nested in JSON stay so...
+        #[rustfmt::skip]
+        let cases = [
+            ("FALSE AND FALSE OR TRUE",     Outcome::T),
+            ("TRUE OR FALSE AND FALSE",     Outcome::T),
+            ("(TRUE OR FALSE) AND FALSE",   Outcome::F),
+        ];
+        for (cql, expected) in cases {
+            let exp = Expression::try_from_text(cql)?;
+            assert_eq!(exp.compile(&ctx)?.evaluate(&feature)?, expected, "{cql}");
+        }
+        let exp = Expression::try_from_json_value(&json!({
+            "op": "and",
+            "args": [{ "op": "or", "args": [true, false] }, false]
+        }))?;
+        assert_eq!(exp.compile(&ctx)?.evaluate(&feature)?, Outcome::F);
+
+        Ok(())
+    }
 }
@@ -6,16 +6,61 @@
 //!
 
 use crate::{
-    Bound, Context, ExtDataType, MyError, Q, Resource,
+    Bound, CRS, Context, ExtDataType, MyError, Q, ResourceRef,
     geom::{G, GTrait},
     op::Op,
     qstring::QString,
+    queryable::to_hex,
 };
 use core::fmt;
-use jiff::Zoned;
-use std::{any::Any, mem};
+use jiff::{Zoned, civil::Date, tz::TimeZone};
+use serde_json::{Value, json};
+use std::{
+    any::Any,
+    borrow::Cow,
+    cmp::Ordering,
+    collections::{BTreeSet, HashMap},
+    mem,
+    time::Duration,
+};
 use tracing::{debug, error};
 
+// Wrapper to hand a possibly unsized resource to computed properties' closures
+// as a trait object...
+struct DynResource<'a, R: ?Sized>(&'a R);
+
+impl<R: ResourceRef + ?Sized> ResourceRef for DynResource<'_, R> {
+    fn get(&self, name: &str) -> Option<Cow<'_, Q>> {
+        self.0.get(name)
+    }
+}
+
+// names of the intrinsic array functions...
+const ARRAY_LENGTH: &str = "array_length";
+const NTH: &str = "nth";
+const ARRAY_FNS: [&str; 2] = [ARRAY_LENGTH, NTH];
+// name of the intrinsic variadic string concatenation function...
+pub(crate) const CONCAT: &str = "concat";
+// name of the intrinsic function parsing a range string into an interval...
+const INTERVAL_FROM_STR: &str = "interval_from_str";
+// name of the intrinsic function rendering a binary value in hexadecimal...
+const HEX: &str = "hex";
+// name of the builtin function also measuring binary values...
+const LENGTH: &str = "length";
+// names of the builtin overlay functions whose empty result is kept as an
+// empty geometry rather than a NULL...
+const OVERLAY_FNS: [&str; 4] = ["union", "intersection", "difference", "sym_difference"];
+// names of the builtin functions backed by GEOS which can't be evaluated when
+// built w/o the `geos` feature...
+#[cfg(not(feature = "geos"))]
+#[rustfmt::skip]
+const GEOS_FNS: [&str; 18] = [
+    "boundary", "buffer", "envelope", "bbox_minx", "bbox_miny", "bbox_maxx", "bbox_maxy",
+    "centroid", "convex_hull", "point_on_surface", "simplify",
+    "union", "intersection", "difference", "sym_difference",
+    "area", LENGTH, "distance",
+];
+
 /// Expression variants...
 #[derive(Clone, Debug, Default, PartialEq)]
 pub(crate) enum E {
@@ -25,11 +70,13 @@ pub(crate) enum E {
     Unbounded,
     Bool(bool),
     Num(f64),
+    Int(i64),
     Str(QString),
     Date(Zoned),
     Timestamp(Zoned),
     Spatial(G),
     Id(String),
+    Param(String),
     Monadic(Op, Box<E>),
     Dyadic(Op, Box<E>, Box<E>),
     Function(Call),
@@ -44,11 +91,13 @@ impl fmt::Display for E {
             E::Unbounded => write!(f, ".."),
             E::Bool(x) => write!(f, "{}", if *x { "TRUE" } else { "FALSE" }),
             E::Num(x) => write!(f, "{x}"),
+            E::Int(x) => write!(f, "{x}"),
             E::Str(x) => write!(f, "'{x}'"),
             E::Date(x) => write!(f, "{}", x.date()),
             E::Timestamp(x) => write!(f, "{}", x.datetime()),
             E::Spatial(x) => write!(f, "{x}"),
             E::Id(x) => write!(f, "{x}"),
+            E::Param(x) => write!(f, "${{{x}}}"),
             E::Monadic(op, x) if op.nullable() => write!(f, "{x} {op}"),
             E::Monadic(op, x) => write!(f, "{op}({x})"),
             E::Dyadic(op, a, b)
@@ -67,6 +116,25 @@ impl fmt::Display for E {
             E::Dyadic(op, a, b) if op.array() || op.spatial() || op.temporal() => {
                 write!(f, "{op}({a}, {b})")
             }
+            E::Dyadic(op, _, _) if matches!(op, Op::And | Op::Or) => {
+                // the 1st operand is only surrounded by parens when it's the
+                // other connective; e.g. `(a OR b) AND c`...
+                let items = self.chain_items(op);
+                let zitems: Vec<_> = items
+                    .iter()
+                    .enumerate()
+                    .map(|(i, x)| match (i, x) {
+                        (0, E::Dyadic(Op::And | Op::Or, _, _)) => format!("({x})"),
+                        (0, _) => x.to_string(),
+                        (_, x) if x.is_literal() => x.to_string(),
+                        (_, x) => format!("({x})"),
+                    })
+                    .collect();
+                write!(f, "{}", zitems.join(&format!(" {op} ")))
+            }
+            E::Dyadic(op, a, b) if matches!(op, Op::IsInList | Op::IsNotInList) && b.is_id() => {
+                write!(f, "{a} {op} {b}")
+            }
             E::Dyadic(op, a, b) => {
                 // if 'b' is a literal, use as is; otherwise surround w/ parens...
                 if b.is_literal() {
@@ -88,13 +156,52 @@ impl fmt::Display for E {
     }
 }
 
+impl From<Bound> for E {
+    fn from(value: Bound) -> Self {
+        match value {
+            Bound::None => E::Unbounded,
+            Bound::Date(z) => E::Date(z),
+            Bound::Timestamp(z) => E::Timestamp(z),
+        }
+    }
+}
+
+impl TryFrom<Q> for E {
+    type Error = MyError;
+
+    fn try_from(value: Q) -> Result<Self, Self::Error> {
+        let it = match value {
+            Q::Null => E::Null,
+            Q::Bool(x) => E::Bool(x),
+            Q::Num(x) => E::Num(x),
+            Q::Int(x) => E::Int(x),
+            Q::Str(x) => E::Str(x),
+            Q::Geom(x) => E::Spatial(x),
+            Q::Instant(x) => E::from(x),
+            Q::Interval(x, y) => E::Interval(Box::new(E::from(x)), Box::new(E::from(y))),
+            Q::List(x) => E::Array(x.into_iter().map(E::try_from).collect::<Result<_, _>>()?),
+            // CQL2 has no binary literals...
+            Q::Bytes(_) => {
+                return Err(MyError::Runtime(
+                    "Binary values have no CQL2 literal representation".into(),
+                ));
+            }
+        };
+        Ok(it)
+    }
+}
+
 impl E {
     /// Return TRUE if this is a literal value; FALSE otherwise.
     pub(crate) fn is_literal(&self) -> bool {
         match self {
-            E::Bool(_) | E::Num(_) | E::Str(_) | E::Date(_) | E::Timestamp(_) | E::Spatial(_) => {
-                true
-            }
+            E::Bool(_)
+            | E::Num(_)
+            | E::Int(_)
+            | E::Str(_)
+            | E::Date(_)
+            | E::Timestamp(_)
+            | E::Spatial(_) => true,
             E::Array(x) => x.iter().all(|y| y.is_literal()),
             _ => false,
         }
@@ -123,6 +230,7 @@ impl E {
             E::Unbounded => Some(Q::Instant(Bound::None)),
             E::Bool(x) => Some(Q::Bool(*x)),
             E::Num(x) => Some(Q::Num(*x)),
+            E::Int(x) => Some(Q::Int(*x)),
             E::Str(s) => Some(Q::Str(s.to_owned())),
             E::Date(z) => Some(Q::Instant(Bound::Date(z.to_owned()))),
             E::Timestamp(z) => Some(Q::Instant(Bound::Timestamp(z.to_owned()))),
@@ -152,6 +260,524 @@ impl E {
         }
     }
 
+    // Return a copy of this w/ every named parameter found in `params`
+    // replaced by its bound value. Unbound ones are kept as they are. Fail if
+    // a bound value has no literal representation; e.g. a binary one.
+    pub(crate) fn bind(&self, params: &HashMap<String, Q>) -> Result<E, MyError> {
+        let it = match self {
+            E::Param(x) => match params.get(x) {
+                Some(q) => E::try_from(q.to_owned()).map_err(|_| {
+                    let msg = format!("Parameter '${{{x}}}' has no CQL2 literal representation");
+                    error!("Failed: {}", msg);
+                    MyError::Runtime(msg.into())
+                })?,
+                None => self.to_owned(),
+            },
+            E::Monadic(op, x) => E::Monadic(op.to_owned(), Box::new(x.bind(params)?)),
+            E::Dyadic(op, x, y) => E::Dyadic(
+                op.to_owned(),
+                Box::new(x.bind(params)?),
+                Box::new(y.bind(params)?),
+            ),
+            E::Function(x) => E::Function(Call {
+                name: x.name.to_owned(),
+                params: x
+                    .params
+                    .iter()
+                    .map(|p| p.bind(params))
+                    .collect::<Result<_, _>>()?,
+            }),
+            E::Array(x) => E::Array(x.iter().map(|y| y.bind(params)).collect::<Result<_, _>>()?),
+            E::Interval(x, y) => E::Interval(Box::new(x.bind(params)?), Box::new(y.bind(params)?)),
+            _ => self.to_owned(),
+        };
+        Ok(it)
+    }
+
+    // Return a copy of this w/ every literal geometry, checked against the
+    // area-of-use of `from` unless `check` is FALSE, transformed to the `to`
+    // CRS.
+    pub(crate) fn transform_literals(
+        &self,
+        from: &CRS,
+        to: &CRS,
+        check: bool,
+    ) -> Result<E, MyError> {
+        let it = match self {
+            E::Spatial(x) => {
+                if check {
+                    x.check_coordinates(from)?;
+                }
+                if from.definition() == to.definition() {
+                    self.to_owned()
+                } else {
+                    E::Spatial(x.transform(from, to)?)
+                }
+            }
+            E::Monadic(op, x) => E::Monadic(
+                op.to_owned(),
+                Box::new(x.transform_literals(from, to, check)?),
+            ),
+            E::Dyadic(op, x, y) => E::Dyadic(
+                op.to_owned(),
+                Box::new(x.transform_literals(from, to, check)?),
+                Box::new(y.transform_literals(from, to, check)?),
+            ),
+            E::Function(x) => E::Function(Call {
+                name: x.name.to_owned(),
+                params: x
+                    .params
+                    .iter()
+                    .map(|p| p.transform_literals(from, to, check))
+                    .collect::<Result<_, _>>()?,
+            }),
+            E::Array(x) => E::Array(
+                x.iter()
+                    .map(|y| y.transform_literals(from, to, check))
+                    .collect::<Result<_, _>>()?,
+            ),
+            E::Interval(x, y) => E::Interval(
+                Box::new(x.transform_literals(from, to, check)?),
+                Box::new(y.transform_literals(from, to, check)?),
+            ),
+            _ => self.to_owned(),
+        };
+        Ok(it)
+    }
+
+    // Add to `acc` the name of every property referenced in this, incl. in
+    // function arguments, w/o the surrounding double-quotes if any; i.e. as
+    // they'd be looked up in a _Resource_ when evaluated.
+    pub(crate) fn collect_properties(&self, acc: &mut BTreeSet<String>) {
+        self.visit_properties(&mut |x| {
+            acc.insert(x.to_owned());
+        });
+    }
+
+    // Return the number of times each property is referenced in this. names
+    // are the same as those collected by `collect_properties()`.
+    pub(crate) fn count_properties(&self) -> HashMap<String, usize> {
+        let mut result = HashMap::new();
+        self.visit_properties(&mut |x| *result.entry(x.to_owned()).or_insert(0) += 1);
+        result
+    }
+
+    // call `f` w/ the unquoted name of every property reference in this.
+    fn visit_properties<F>(&self, f: &mut F)
+    where
+        F: FnMut(&str),
+    {
+        match self {
+            E::Id(x) => match x.strip_prefix('"').and_then(|y| y.strip_suffix('"')) {
+                Some(y) => f(y),
+                None => f(x),
+            },
+            E::Monadic(_, x) => x.visit_properties(f),
+            E::Dyadic(_, x, y) | E::Interval(x, y) => {
+                x.visit_properties(f);
+                y.visit_properties(f);
+            }
+            E::Function(x) => x.params.iter().for_each(|p| p.visit_properties(f)),
+            E::Array(x) => x.iter().for_each(|y| y.visit_properties(f)),
+            _ => (),
+        }
+    }
+
+    // return TRUE as soon as `f` holds for this or any of its sub-expressions,
+    // incl. function arguments, array items and interval bounds.
+    pub(crate) fn any<F>(&self, f: &F) -> bool
+    where
+        F: Fn(&E) -> bool,
+    {
+        if f(self) {
+            return true;
+        }
+        match self {
+            E::Monadic(_, x) => x.any(f),
+            E::Dyadic(_, x, y) | E::Interval(x, y) => x.any(f) || y.any(f),
+            E::Function(x) => x.params.iter().any(|p| p.any(f)),
+            E::Array(x) => x.iter().any(|y| y.any(f)),
+            _ => false,
+        }
+    }
+
+    // join `x` and the items of `rest`, in order, w/ `op` (AND or OR) pairwise
+    // into a balanced tree so that a chain of N operands is only log2(N) deep.
+    // both connectives being associative, this doesn't change the outcome...
+    pub(crate) fn chain(op: Op, x: E, rest: Vec<E>) -> E {
+        let mut items = vec![x];
+        items.extend(rest);
+        while items.len() > 1 {
+            let mut it = items.into_iter();
+            let mut pairs = Vec::with_capacity(it.len().div_ceil(2));
+            while let Some(a) = it.next() {
+                pairs.push(match it.next() {
+                    Some(b) => E::Dyadic(op.clone(), Box::new(a), Box::new(b)),
+                    None => a,
+                });
+            }
+            items = pairs;
+        }
+        items.remove(0)
+    }
+
+    // return, in order, the operands of the chain of `op` (AND or OR) rooted
+    // at this, however it's nested; e.g. `a`, `b` and `c` for `(a OR b) OR c`
+    // as well as for `a OR (b OR c)`...
+    fn chain_items(&self, op: &Op) -> Vec<&E> {
+        let mut result = vec![];
+        let mut stack = vec![self];
+        while let Some(x) = stack.pop() {
+            match x {
+                E::Dyadic(y, a, b) if y == op => {
+                    stack.push(b);
+                    stack.push(a);
+                }
+                _ => result.push(x),
+            }
+        }
+        result
+    }
+
+    // return the number of nodes on the longest path from this to a leaf; i.e.
+    // 1 for a literal or a property name. iterative so it can be called on
+    // expressions too deeply nested to safely recurse into...
+    pub(crate) fn depth(&self) -> usize {
+        let mut result = 0;
+        let mut stack = vec![(self, 1)];
+        while let Some((x, level)) = stack.pop() {
+            result = result.max(level);
+            match x {
+                E::Monadic(_, y) => stack.push((y, level + 1)),
+                E::Dyadic(_, y, z) | E::Interval(y, z) => {
+                    stack.push((y, level + 1));
+                    stack.push((z, level + 1));
+                }
+                E::Function(y) => stack.extend(y.params.iter().map(|p| (p, level + 1))),
+                E::Array(y) => stack.extend(y.iter().map(|p| (p, level + 1))),
+                _ => (),
+            }
+        }
+        result
+    }
+
+    // return TRUE if this, or any of its sub-expressions, uses an operator
+    // satisfying `f`.
+    pub(crate) fn has_op(&self, f: fn(&Op) -> bool) -> bool {
+        self.any(&|x| matches!(x, E::Monadic(op, _) | E::Dyadic(op, _, _) if f(op)))
+    }
+
+    // Return a canonical text-encoded CQL2 representation of this that can be
+    // parsed back into an equal instance, where the operands of AND / OR
+    // chains are placed on separate lines indented by `indent` spaces per
+    // nesting level.
+    pub(crate) fn to_pretty(&self, indent: usize) -> String {
+        self.pretty(indent, 0)
+    }
+
+    fn pretty(&self, indent: usize, level: usize) -> String {
+        match self {
+            E::Null => "NULL".into(),
+            E::Unbounded => "'..'".into(),
+            E::Bool(x) => (if *x { "TRUE" } else { "FALSE" }).into(),
+            E::Num(x) => format!("{x}"),
+            E::Int(x) => format!("{x}"),
+            E::Str(x) => format!("'{}'", x.as_str().replace('\'', "''")),
+            E::Date(x) => format!("DATE('{}')", x.date()),
+            E::Timestamp(x) => format!("TIMESTAMP('{}')", x.timestamp()),
+            E::Spatial(x) => x.to_wkt(),
+            E::Id(x) => x.to_owned(),
+            E::Param(x) => format!("${{{x}}}"),
+            E::Monadic(Op::Minus, x) => format!("-{}", x.arith_operand(indent, level, 5)),
+            E::Monadic(Op::Neg, x) => match x.as_ref() {
+                E::Bool(_) | E::Function(_) => format!("NOT {}", x.pretty(indent, level)),
+                E::Dyadic(op, _, _) if op.spatial() || op.temporal() || op.array() => {
+                    format!("NOT {}", x.pretty(indent, level))
+                }
+                _ => format!("NOT ({})", x.pretty(indent, level)),
+            },
+            E::Monadic(op @ (Op::IsNull | Op::IsNotNull), x) => {
+                format!("{} {op}", x.argument(indent, level))
+            }
+            E::Monadic(op, x) => format!("{op}({})", x.pretty(indent, level)),
+            E::Dyadic(op, a, b) if op.arithmetic() => {
+                // left-associative, except for `^` which only accepts factors...
+                let (lhs, rhs) = match E::precedence_of(op) {
+                    3 => (4, 4),
+                    p => (p, p + 1),
+                };
+                format!(
+                    "{} {op} {}",
+                    a.arith_operand(indent, level, lhs),
+                    b.arith_operand(indent, level, rhs)
+                )
+            }
+            E::Dyadic(op, _, _) if matches!(op, Op::And | Op::Or) => {
+                let keyword = if matches!(op, Op::And) { "AND" } else { "OR" };
+                let items = self.chain_items(op);
+                let separator = format!("\n{}{keyword} ", " ".repeat(indent * (level + 1)));
+                let items: Vec<_> = items
+                    .iter()
+                    .map(|x| match x {
+                        E::Dyadic(Op::And | Op::Or, _, _) => {
+                            format!("({})", x.pretty(indent, level + 1))
+                        }
+                        _ => x.pretty(indent, level + 1),
+                    })
+                    .collect();
+                items.join(&separator)
+            }
+            E::Dyadic(op, a, b) if op.comparison() => {
+                let op = match op {
+                    Op::Eq => "=".into(),
+                    Op::Neq => "<>".into(),
+                    _ => op.to_string(),
+                };
+                format!(
+                    "{} {op} {}",
+                    a.pretty(indent, level),
+                    b.pretty(indent, level)
+                )
+            }
+            E::Dyadic(op @ (Op::IsBetween | Op::IsNotBetween), a, b) => match b.as_ref() {
+                E::Array(x) if x.len() == 2 => format!(
+                    "{} {op} {} AND {}",
+                    a.pretty(indent, level),
+                    x[0].pretty(indent, level),
+                    x[1].pretty(indent, level)
+                ),
+                _ => self.to_string(),
+            },
+            E::Dyadic(op, a, b) if op.xtd_comparison() => {
+                format!(
+                    "{} {op} {}",
+                    a.pretty(indent, level),
+                    b.pretty(indent, level)
+                )
+            }
+            E::Dyadic(op, a, b) => format!(
+                "{op}({}, {})",
+                a.argument(indent, level),
+                b.argument(indent, level)
+            ),
+            E::Function(x) => {
+                let params: Vec<_> = x.params.iter().map(|p| p.argument(indent, level)).collect();
+                format!("{}({})", x.name, params.join(", "))
+            }
+            E::Array(x) => {
+                let items: Vec<_> = x.iter().map(|y| y.argument(indent, level)).collect();
+                format!("({})", items.join(", "))
+            }
+            E::Interval(x, y) => format!(
+                "INTERVAL({}, {})",
+                x.instant_parameter(indent, level),
+                y.instant_parameter(indent, level)
+            ),
+        }
+    }
+
+    // an arithmetic operand, surrounded by parens if its precedence is less
+    // than `min`...
+    fn arith_operand(&self, indent: usize, level: usize, min: u8) -> String {
+        let precedence = match self {
+            E::Dyadic(op, _, _) if op.arithmetic() => E::precedence_of(op),
+            E::Monadic(Op::Minus, _) => 4,
+            E::Num(x) if x.is_sign_negative() => 4,
+            E::Int(x) if x.is_negative() => 4,
+            _ => 5,
+        };
+        if precedence < min {
+            format!("({})", self.pretty(indent, level))
+        } else {
+            self.pretty(indent, level)
+        }
+    }
+
+    fn precedence_of(op: &Op) -> u8 {
+        match op {
+            Op::Plus | Op::Minus => 1,
+            Op::Exp => 3,
+            _ => 2,
+        }
+    }
+
+    // a function argument, or an array item. predicates are surrounded by
+    // parens to stop the parser from mistaking their LHS for the whole...
+    fn argument(&self, indent: usize, level: usize) -> String {
+        match self {
+            E::Monadic(Op::Neg | Op::IsNull | Op::IsNotNull, _) => {
+                format!("({})", self.pretty(indent, level))
+            }
+            E::Dyadic(op, _, _) if op.comparison() || op.xtd_comparison() => {
+                format!("({})", self.pretty(indent, level))
+            }
+            E::Dyadic(Op::And | Op::Or, _, _) => format!("({})", self.pretty(indent, level + 1)),
+            _ => self.pretty(indent, level),
+        }
+    }
+
+    // instants inside an INTERVAL are plain quoted strings...
+    fn instant_parameter(&self, indent: usize, level: usize) -> String {
+        match self {
+            E::Date(x) => format!("'{}'", x.date()),
+            E::Timestamp(x) => format!("'{}'", x.timestamp()),
+            _ => self.pretty(indent, level),
+        }
+    }
+
+    // Return the CQL2-JSON encoding of this. negated predicates, which have no
+    // dedicated operator in that encoding, become a `not` wrapping their
+    // positive counterpart. named parameters have no JSON representation.
+    pub(crate) fn to_json(&self) -> Result<Value, MyError> {
+        let op = |name: &str, args: Vec<Value>| json!({ "op": name, "args": args });
+        let it = match self {
+            E::Null => Value::Null,
+            E::Unbounded => json!(".."),
+            E::Bool(x) => json!(x),
+            E::Num(x) => json!(x),
+            E::Int(x) => json!(x),
+            E::Str(x) => {
+                let mut it = json!(x.as_str());
+                if x.is_icase() {
+                    it = op("casei", vec![it]);
+                }
+                if x.is_iaccent() {
+                    it = op("accenti", vec![it]);
+                }
+                it
+            }
+            E::Date(x) => json!({ "date": x.date().to_string() }),
+            E::Timestamp(x) => json!({ "timestamp": x.timestamp().to_string() }),
+            E::Spatial(G::BBox(x)) => json!({ "bbox": x.coordinates() }),
+            E::Spatial(x) => x.to_geojson(),
+            E::Id(x) => {
+                let x = x
+                    .strip_prefix('"')
+                    .and_then(|y| y.strip_suffix('"'))
+                    .unwrap_or(x);
+                json!({ "property": x })
+            }
+            E::Param(x) => {
+                return Err(MyError::Runtime(
+                    format!("Parameter '${{{x}}}' has no JSON representation").into(),
+                ));
+            }
+            E::Monadic(Op::Minus, x) => match x.as_ref() {
+                E::Num(y) => json!(-y),
+                E::Int(y) if *y != i64::MIN => json!(-y),
+                _ => op("*", vec![json!(-1), x.to_json()?]),
+            },
+            E::Monadic(x, y) => match x.negated() {
+                Some(z) => op("not", vec![E::Monadic(z, y.clone()).to_json()?]),
+                None => op(json_op(x)?, vec![y.to_json()?]),
+            },
+            E::Dyadic(x, _, _) if matches!(x, Op::And | Op::Or) => {
+                let args = self
+                    .chain_items(x)
+                    .iter()
+                    .map(|y| y.to_json())
+                    .collect::<Result<Vec<_>, _>>()?;
+                op(json_op(x)?, args)
+            }
+            E::Dyadic(x, a, b) => match x.negated() {
+                Some(y) => op("not", vec![E::Dyadic(y, a.clone(), b.clone()).to_json()?]),
+                None => match (x, b.as_ref()) {
+                    (Op::IsBetween, E::Array(y)) if y.len() == 2 => op(
+                        "between",
+                        vec![a.to_json()?, y[0].to_json()?, y[1].to_json()?],
+                    ),
+                    _ => op(json_op(x)?, vec![a.to_json()?, b.to_json()?]),
+                },
+            },
+            E::Function(x) => {
+                let args = x.params.iter().map(E::to_json).collect::<Result<_, _>>()?;
+                op(&x.name, args)
+            }
+            E::Array(x) => Value::Array(x.iter().map(E::to_json).collect::<Result<_, _>>()?),
+            E::Interval(x, y) => {
+                // instants inside an interval are plain strings...
+                let bound = |z: &E| match z {
+                    E::Date(t) => Ok(json!(t.date().to_string())),
+                    E::Timestamp(t) => Ok(json!(t.timestamp().to_string())),
+                    _ => z.to_json(),
+                };
+                json!({ "interval": [bound(x)?, bound(y)?] })
+            }
+        };
+        Ok(it)
+    }
+
+    // Return a copy of this w/ every NOT wrapping a LIKE, BETWEEN, IN, or IS
+    // NULL predicate folded into the negated operator; e.g. `NOT (a LIKE 'x')`
+    // becomes `a NOT LIKE 'x'`. this is how the text encoding represents them
+    // while the JSON one has no such operators and uses a `not` instead.
+    pub(crate) fn fold_not(self) -> E {
+        match self {
+            E::Monadic(Op::Neg, x) => match x.fold_not() {
+                E::Dyadic(Op::IsLike, a, b) => E::Dyadic(Op::IsNotLike, a, b),
+                E::Dyadic(Op::IsBetween, a, b) => E::Dyadic(Op::IsNotBetween, a, b),
+                E::Dyadic(Op::IsInList, a, b) => E::Dyadic(Op::IsNotInList, a, b),
+                E::Monadic(Op::IsNull, a) => E::Monadic(Op::IsNotNull, a),
+                y => E::Monadic(Op::Neg, Box::new(y)),
+            },
+            E::Monadic(op, x) => E::Monadic(op, Box::new(x.fold_not())),
+            E::Dyadic(op, x, y) => E::Dyadic(op, Box::new(x.fold_not()), Box::new(y.fold_not())),
+            E::Function(x) => E::Function(Call {
+                name: x.name,
+                params: x.params.into_iter().map(E::fold_not).collect(),
+            }),
+            E::Array(x) => E::Array(x.into_iter().map(E::fold_not).collect()),
+            E::Interval(x, y) => E::Interval(Box::new(x.fold_not()), Box::new(y.fold_not())),
+            x => x,
+        }
+    }
+
+    // Return a copy of this w/ every integral number that `f64` represents
+    // exactly, incl. `-0.0`, as an `Int`; e.g. `10.0` and `1e1` become `10`...
+    pub(crate) fn int_numbers(self) -> E {
+        const MAX_EXACT: f64 = ((1_i64 << 53) - 1) as f64;
+        match self {
+            E::Num(x) if x.fract() == 0.0 && x.abs() <= MAX_EXACT => E::Int(x as i64),
+            E::Monadic(op, x) => E::Monadic(op, Box::new(x.int_numbers())),
+            E::Dyadic(op, x, y) => {
+                E::Dyadic(op, Box::new(x.int_numbers()), Box::new(y.int_numbers()))
+            }
+            E::Function(x) => E::Function(Call {
+                name: x.name,
+                params: x.params.into_iter().map(E::int_numbers).collect(),
+            }),
+            E::Array(x) => E::Array(x.into_iter().map(E::int_numbers).collect()),
+            E::Interval(x, y) => E::Interval(Box::new(x.int_numbers()), Box::new(y.int_numbers())),
+            x => x,
+        }
+    }
+
+    // Return the logical complement of this predicate pushing the negation
+    // inward where cheap; i.e. De Morgan on AND and OR, flipping comparison,
+    // LIKE, BETWEEN, IN and IS NULL operators, and collapsing double negations.
+    // anything else is wrapped in a NOT...
+    pub(crate) fn negate(self) -> E {
+        match self {
+            E::Bool(x) => E::Bool(!x),
+            E::Monadic(Op::Neg, x) => *x,
+            E::Monadic(op, x) if op.nullable() => match op.complement() {
+                Some(op) => E::Monadic(op, x),
+                None => E::Monadic(Op::Neg, Box::new(E::Monadic(op, x))),
+            },
+            E::Dyadic(Op::And, a, b) => {
+                E::Dyadic(Op::Or, Box::new(a.negate()), Box::new(b.negate()))
+            }
+            E::Dyadic(Op::Or, a, b) => {
+                E::Dyadic(Op::And, Box::new(a.negate()), Box::new(b.negate()))
+            }
+            E::Dyadic(op, a, b) => match op.complement() {
+                Some(op) => E::Dyadic(op, a, b),
+                None => E::Monadic(Op::Neg, Box::new(E::Dyadic(op, a, b))),
+            },
+            x => E::Monadic(Op::Neg, Box::new(x)),
+        }
+    }
+
     // Possible outcome values when evaluating an [Expression] against an
     // individual _Resource_ from a collection.
     //
@@ -180,23 +806,34 @@ impl E {
     // ```
     // [1]: https://docs.ogc.org/is/21-065r2/21-065r2.html
     // #[tracing::instrument(level = "trace", skip(ctx, f), ret)]
-    pub(crate) fn eval(&self, ctx: &Context, feature: &Resource) -> Result<Q, MyError> {
+    pub(crate) fn eval<R: ResourceRef + ?Sized>(
+        &self,
+        ctx: &Context,
+        feature: &R,
+    ) -> Result<Q, MyError> {
         match self {
             E::Null => Ok(Q::Null),
             E::Unbounded => Ok(Q::Instant(Bound::None)),
             E::Bool(x) => Ok(Q::Bool(*x)),
             E::Num(x) => Ok(Q::Num(*x)),
+            E::Int(x) => Ok(Q::Int(*x)),
             E::Str(x) => Ok(Q::Str(x.to_owned())),
             E::Date(x) => Ok(Q::Instant(Bound::Date(x.to_owned()))),
             E::Timestamp(x) => Ok(Q::Instant(Bound::Timestamp(x.to_owned()))),
             E::Spatial(x) => {
                 // ensure geometry has valid coordinates w/in the configured
-                // CRS's area-of-use...
-                x.check_coordinates(ctx.crs())?;
+                // CRS's area-of-use. w/ a filter CRS that was done when it
+                // was transformed to the configured one...
+                if ctx.filter_crs().is_none() && ctx.coordinate_check() {
+                    x.check_coordinates(ctx.crs())?;
+                }
                 Ok(Q::Geom(x.to_owned()))
             }
+            E::Param(x) => Err(MyError::Runtime(
+                format!("Parameter '${{{x}}}' is not bound").into(),
+            )),
             E::Id(x) => match feature.get(x) {
-                Some(y) => Ok(y.clone()),
+                Some(y) => Ok(y.into_owned()),
                 None => {
                     // from <https://docs.ogc.org/is/21-065r2/21-065r2.html#basic-cql2_property>:
                     // "The property name reference SHALL evaluate to its
@@ -206,9 +843,18 @@ impl E {
                     if x.starts_with('"') && x.ends_with('"') {
                         let x_ = &x[1..x.len() - 1];
                         if let Some(y) = feature.get(x_) {
-                            return Ok(y.clone());
+                            return Ok(y.into_owned());
                         } // else it's not in resource...
                     }
+                    // NOTE (rsn) 20261016 - computed properties are only ever
+                    // derived when referenced and not stored...
+                    let name = x.strip_prefix('"').and_then(|y| y.strip_suffix('"'));
+                    if let Some(f) = ctx.computed(name.unwrap_or(x)) {
+                        return Ok(f(&DynResource(feature)));
+                    }
+                    if ctx.strict_properties() {
+                        return Err(MyError::Runtime(format!("Unknown property '{x}'").into()));
+                    }
                     debug!("No queryable named '{x}' in Resource");
                     Ok(Q::Null)
                 }
@@ -221,6 +867,7 @@ impl E {
                         match zx {
                             Q::Null => Ok(Q::Null),
                             Q::Num(x) => Ok(Q::Num(-x)),
+                            Q::Int(x) => Ok(x.checked_neg().map_or(Q::Num(-(x as f64)), Q::Int)),
                             _ => Err(MyError::Runtime(
                                 format!("Expected a number: {zx:?}. Abort").into(),
                             )),
@@ -248,14 +895,8 @@ impl E {
                             format!("Expected a string: {zx:?}. Abort").into(),
                         )),
                     },
-                    Op::IsNull => match zx {
-                        Q::Null => Ok(Q::Bool(true)),
-                        _ => Ok(Q::Bool(false)),
-                    },
-                    Op::IsNotNull => match zx {
-                        Q::Null => Ok(Q::Bool(false)),
-                        _ => Ok(Q::Bool(true)),
-                    },
+                    Op::IsNull => Ok(Q::Bool(is_null_in(ctx, &zx))),
+                    Op::IsNotNull => Ok(Q::Bool(!is_null_in(ctx, &zx))),
                     _ => Err(MyError::Runtime(
                         format!("Unexpected ({op:?} w/ {zx:?}). Abort").into(),
                     )),
@@ -304,6 +945,11 @@ impl E {
             E::Dyadic(op, x, y) if op.comparison() => {
                 let zx = x.eval(ctx, feature)?;
                 let zy = y.eval(ctx, feature)?;
+                let (zx, zy) = if ctx.implicit_coercion() {
+                    Q::coerce(zx, zy)?
+                } else {
+                    (zx, zy)
+                };
                 // from Requirement #3C
                 // https://docs.ogc.org/is/21-065r2/21-065r2.html#basic-cql2_comparison-predicates
                 // "If either scalar expression (rule scalarExpression) of the
@@ -317,12 +963,14 @@ impl E {
                     // binaryComparisonPredicate SHALL evaluate to the same type
                     // of literal."
                     match op {
-                        Op::Eq => Ok(Q::Bool(zx.eq(&zy))),
-                        Op::Neq => Ok(Q::Bool(!zx.eq(&zy))),
-                        Op::Lt => Ok(Q::Bool(zx.lt(&zy))),
-                        Op::Gt => Ok(Q::Bool(zx.gt(&zy))),
-                        Op::Lte => Ok(Q::Bool(zx.le(&zy))),
-                        Op::Gte => Ok(Q::Bool(zx.ge(&zy))),
+                        Op::Eq | Op::Neq => {
+                            let eq = eq_in(ctx, &zx, &zy)?;
+                            Ok(Q::Bool(if matches!(op, Op::Eq) { eq } else { !eq }))
+                        }
+                        Op::Lt => Ok(Q::Bool(cmp_in(ctx, &zx, &zy).is_some_and(Ordering::is_lt))),
+                        Op::Gt => Ok(Q::Bool(cmp_in(ctx, &zx, &zy).is_some_and(Ordering::is_gt))),
+                        Op::Lte => Ok(Q::Bool(cmp_in(ctx, &zx, &zy).is_some_and(Ordering::is_le))),
+                        Op::Gte => Ok(Q::Bool(cmp_in(ctx, &zx, &zy).is_some_and(Ordering::is_ge))),
                         _ => Err(MyError::Runtime(
                             format!("Unexpected comparison operator: {op:?}. Abort").into(),
                         )),
@@ -344,50 +992,27 @@ impl E {
                         Op::IsLike | Op::IsNotLike => {
                             let input = zx.to_str()?;
                             let pattern = zy.to_str()?;
+                            let matched = QString::like_with(
+                                &input,
+                                &pattern,
+                                ctx.like_escape(),
+                                ctx.normalization(),
+                                ctx.case_folding(),
+                            )?;
                             if matches!(op, Op::IsLike) {
-                                Ok(Q::Bool(QString::like(&input, &pattern)))
+                                Ok(Q::Bool(matched))
                             } else {
-                                Ok(Q::Bool(!QString::like(&input, &pattern)))
+                                Ok(Q::Bool(!matched))
                             }
                         }
                         Op::IsBetween | Op::IsNotBetween => {
-                            let a = zx.to_num()?;
                             let bounds = zy.to_list()?;
-                            let (b0, b1) = (&bounds[0], &bounds[1]);
-                            if b0.is_null() || b1.is_null() {
-                                Ok(Q::Null)
-                            } else {
-                                let lo = b0.to_num()?;
-                                let hi = b1.to_num()?;
-                                let range = if lo <= hi { lo..=hi } else { hi..=lo };
-                                if matches!(op, Op::IsBetween) {
-                                    Ok(Q::Bool(range.contains(&a)))
-                                } else {
-                                    Ok(Q::Bool(!range.contains(&a)))
-                                }
-                            }
+                            eval_between(op, &zx, &bounds, |a, b| cmp_in(ctx, a, b))
                         }
                         Op::IsInList | Op::IsNotInList => {
-                            // x must be a literal...
-                            if zx.literal_type().is_none() {
-                                return Err(MyError::Runtime(
-                                    "[NOT] IN LHS is not a literal value".into(),
-                                ));
-                            }
                             // y must be a list...
                             let list = zy.to_list()?;
-                            // ...and every element must be of same-type as x...
-                            let ok = list.iter().all(|e| Q::same_type(&zx, e));
-                            if !ok {
-                                return Err(MyError::Runtime(
-                                    "Incompatible [NOT] IN predicate types".into(),
-                                ));
-                            }
-                            if matches!(op, Op::IsInList) {
-                                Ok(Q::Bool(zx.contained_by(list)?))
-                            } else {
-                                Ok(Q::Bool(!zx.contained_by(list)?))
-                            }
+                            eval_in_list(op, &zx, list, |a, b| in_list_eq(ctx, a, b))
                         }
                         _ => Err(MyError::Runtime(
                             format!("Unexpected extended comparison ({op:?}). Abort").into(),
@@ -395,27 +1020,13 @@ impl E {
                     }
                 }
             }
-            #[rustfmt::skip]
             E::Dyadic(op, x, y) if op.arithmetic() => {
                 let zx = x.eval(ctx, feature)?;
                 let zy = y.eval(ctx, feature)?;
                 if zx.is_null() || zy.is_null() {
                     Ok(Q::Null)
                 } else {
-                    let a = zx.to_num()?;
-                    let b = zy.to_num()?;
-                    match op {
-                        Op::Plus   => Ok(Q::Num(a + b)),
-                        Op::Minus  => Ok(Q::Num(a - b)),
-                        Op::Mult   => Ok(Q::Num(a * b)),
-                        Op::Div    => Ok(Q::Num(a / b)),
-                        Op::IntDiv => Ok(Q::Num(a.rem_euclid(b))),
-                        Op::Mod    => Ok(Q::Num(a % b)),
-                        Op::Exp    => Ok(Q::Num(a.powf(b).round())),
-                        _ => Err(MyError::Runtime(
-                            format!("Unexpected arithmetic operator: {op:?}. Abort").into(),
-                        )),
-                    }
+                    eval_arithmetic(op, &zx, &zy)
                 }
             }
             #[rustfmt::skip]
@@ -425,17 +1036,35 @@ impl E {
                 if zx.is_null() || zy.is_null() {
                     Ok(Q::Null)
                 } else {
-                    let a = zx.to_geom()?;
-                    let b = zy.to_geom()?;
+                    let mut a = zx.to_geom()?;
+                    let mut b = zy.to_geom()?;
+                    if ctx.antimeridian() {
+                        a = a.split_at_antimeridian()?;
+                        b = b.split_at_antimeridian()?;
+                    }
+                    if ctx.auto_repair() {
+                        a = repaired(a)?;
+                        b = repaired(b)?;
+                    }
+                    // an undefined geometry; e.g. an empty overlay, or one
+                    // that collapsed to nothing when repaired, yields NULL...
+                    if matches!(a, G::Null) || matches!(b, G::Null) {
+                        return Ok(Q::Null);
+                    }
                     match op {
                         Op::SIntersects => Ok(Q::Bool(a.intersects(&b)?)),
-                        Op::SEquals     => Ok(Q::Bool(a.equals(&b)?)),
+                        Op::SEquals     => match ctx.spatial_tolerance() {
+                            Some(t) => Ok(Q::Bool(a.equals_exact(&b, t)?)),
+                            None    => Ok(Q::Bool(a.equals(&b)?)),
+                        },
                         Op::SDisjoint   => Ok(Q::Bool(a.disjoint(&b)?)),
                         Op::STouches    => Ok(Q::Bool(a.touches(&b)?)),
                         Op::SWithin     => Ok(Q::Bool(a.within(&b)?)),
                         Op::SOverlaps   => Ok(Q::Bool(a.overlaps(&b)?)),
                         Op::SCrosses    => Ok(Q::Bool(a.crosses(&b)?)),
                         Op::SContains   => Ok(Q::Bool(a.contains(&b)?)),
+                        Op::SCovers     => Ok(Q::Bool(a.covers(&b)?)),
+                        Op::SCoveredBy  => Ok(Q::Bool(a.covered_by(&b)?)),
                         _ => Err(MyError::Runtime(
                             format!("Unexpected spatial operator: {op:?}. Abort").into(),
                         )),
@@ -448,83 +1077,16 @@ impl E {
                 if zx.is_null() || zy.is_null() {
                     Ok(Q::Null)
                 } else if op.instant_or_interval() {
-                    let it = eval_temporal_fn(op, zx, zy)?;
+                    let it =
+                        eval_temporal_fn(op, zx, zy, ctx.time_zone(), ctx.temporal_tolerance())?;
                     Ok(Q::Bool(it))
                 } else {
                     // expect intervals only...
                     let t1 = zx.to_interval()?;
                     let t2 = zy.to_interval()?;
-                    match op {
-                        Op::TContains => {
-                            // If a proper interval T1 is intervalContains another
-                            // proper interval T2, then the beginning of T1 is before
-                            // the beginning of T2, and the end of T1 is after the
-                            // end of T2.
-                            Ok(Q::Bool((t1.0 < t2.0) && (t1.1 > t2.1)))
-                        }
-                        Op::TDuring => {
-                            // If a proper interval T1 is intervalDuring another
-                            // proper interval T2, then the beginning of T1 is after
-                            // the beginning of T2, and the end of T1 is before the
-                            // end of T2.
-                            Ok(Q::Bool((t1.0 > t2.0) && (t1.1 < t2.1)))
-                        }
-                        Op::TFinishedBy => {
-                            // If a proper interval T1 is intervalFinishedBy another
-                            // proper interval T2, then the beginning of T1 is before
-                            // the beginning of T2, and the end of T1 is coincident
-                            // with the end of T2.
-                            Ok(Q::Bool((t1.0 < t2.0) && (t1.1 == t2.1)))
-                        }
-                        Op::TFinishes => {
-                            // If a proper interval T1 is intervalFinishes another
-                            // proper interval T2, then the beginning of T1 is after
-                            // the beginning of T2, and the end of T1 is coincident
-                            // with the end of T2.
-                            Ok(Q::Bool((t1.0 > t2.0) && (t1.1 == t2.1)))
-                        }
-                        Op::TMeets => {
-                            // If a proper interval T1 is intervalMeets another
-                            // proper interval T2, then the end of T1 is coincident
-                            // with the beginning of T2.
-                            Ok(Q::Bool(t1.1 == t2.0))
-                        }
-                        Op::TMetBy => {
-                            // If a proper interval T1 is intervalMetBy another
-                            // proper interval T2, then the beginning of T1 is
-                            // coincident with the end of T2.
-                            Ok(Q::Bool(t1.0 == t2.1))
-                        }
-                        Op::TOverlappedBy => {
-                            // If a proper interval T1 is intervalOverlappedBy another
-                            // proper interval T2, then the beginning of T1 is after
-                            // the beginning of T2, the beginning of T1 is before the
-                            // end of T2, and the end of T1 is after the end of T2.
-                            Ok(Q::Bool((t1.0 > t2.0) && (t1.0 < t2.1) && (t1.1 > t2.1)))
-                        }
-                        Op::TOverlaps => {
-                            // If a proper interval T1 is intervalOverlaps another
-                            // proper interval T2, then the beginning of T1 is before
-                            // the beginning of T2, the end of T1 is after the
-                            // beginning of T2, and the end of T1 is before the end
-                            // of T2.
-                            Ok(Q::Bool((t1.0 < t2.0) && (t1.1 > t2.0) && (t1.1 < t2.1)))
-                        }
-                        Op::TStartedBy => {
-                            // If a proper interval T1 is intervalStartedBy another
-                            // proper interval T2, then the beginning of T1 is
-                            // coincident with the beginning of T2, and the end of
-                            // T1 is after the end of T2.
-                            Ok(Q::Bool((t1.0 == t2.0) && (t1.1 > t2.1)))
-                        }
-                        Op::TStarts => {
-                            // If a proper interval T1 is intervalStarts another
-                            // proper interval T2, then the beginning of T1 is
-                            // coincident with the beginning of T2, and the end of
-                            // T1 is before the end of T2.
-                            Ok(Q::Bool((t1.0 == t2.0) && (t1.1 < t2.1)))
-                        }
-                        _ => Err(MyError::Runtime(
+                    match eval_interval_fn(op, &t1, &t2) {
+                        Some(x) => Ok(Q::Bool(x)),
+                        None => Err(MyError::Runtime(
                             format!("Unexpected interval operator: {op:?}. Abort").into(),
                         )),
                     }
@@ -540,7 +1102,7 @@ impl E {
                     Op::AEquals      => Ok(Q::Bool(a.eq(&b))),
                     Op::AContains    => Ok(Q::Bool(b.iter().all(|p| a.contains(p)))),
                     Op::AContainedBy => Ok(Q::Bool(a.iter().all(|p| b.contains(p)))),
-                    Op::AOverlaps    => Ok(Q::Bool(a.iter().zip(b).any(|(m, n)| m.eq(&n)))),
+                    Op::AOverlaps    => Ok(Q::Bool(a.iter().any(|p| b.contains(p)))),
                     _ => Err(MyError::Runtime(
                         format!("Unexpected array operator: {op:?}. Abort").into(),
                     )),
@@ -593,11 +1155,13 @@ impl E {
             | E::Unbounded
             | E::Bool(_)
             | E::Num(_)
+            | E::Int(_)
             | E::Str(_)
             | E::Date(_)
             | E::Timestamp(_)
             | E::Spatial(_)
-            | E::Id(_) => {
+            | E::Id(_)
+            | E::Param(_) => {
                 let it = mem::take(e);
                 Ok(it)
             }
@@ -609,11 +1173,14 @@ impl E {
                     Op::Minus => match v {
                         E::Null => Ok(E::Null),
                         E::Num(x) => Ok(E::Num(-x)),
+                        E::Int(x) => Ok(x.checked_neg().map_or(E::Num(-(x as f64)), E::Int)),
                         _ => Ok(E::Monadic(Op::Minus, Box::new(v))),
                     },
                     Op::Neg => match v {
                         E::Null => Ok(E::Null),
                         E::Bool(x) => Ok(E::Bool(!x)),
+                        // a double negation is the predicate itself...
+                        E::Monadic(Op::Neg, z) => Ok(*z),
                         _ => Ok(E::Monadic(Op::Neg, Box::new(v))),
                     },
                     Op::IsNull => match v {
@@ -655,6 +1222,11 @@ impl E {
                     (E::Bool(false), E::Bool(true)) => Ok(E::Bool(false)),
                     (E::Bool(false), E::Bool(false)) => Ok(E::Bool(false)),
 
+                    // TRUE is the identity of AND, while FALSE absorbs it...
+                    (E::Bool(true), _) => Ok(rhs),
+                    (_, E::Bool(true)) => Ok(lhs),
+                    (E::Bool(false), _) | (_, E::Bool(false)) => Ok(E::Bool(false)),
+
                     _ => Ok(E::Dyadic(Op::And, Box::new(lhs), Box::new(rhs))),
                 }
             }
@@ -674,6 +1246,11 @@ impl E {
                     (E::Bool(false), E::Bool(true)) => Ok(E::Bool(true)),
                     (E::Bool(false), E::Bool(false)) => Ok(E::Bool(false)),
 
+                    // FALSE is the identity of OR, while TRUE absorbs it...
+                    (E::Bool(false), _) => Ok(rhs),
+                    (_, E::Bool(false)) => Ok(lhs),
+                    (E::Bool(true), _) | (_, E::Bool(true)) => Ok(E::Bool(true)),
+
                     _ => Ok(E::Dyadic(Op::Or, Box::new(lhs), Box::new(rhs))),
                 }
             }
@@ -687,18 +1264,16 @@ impl E {
                     let u = lhs.as_literal();
                     let v = rhs.as_literal();
                     match (u, v) {
-                        (Some(a), Some(b)) => {
-                            // ...that are similar to each other...
-                            match op {
-                                Op::Eq => Ok(E::Bool(a.eq(&b))),
-                                Op::Neq => Ok(E::Bool(!a.eq(&b))),
-                                Op::Lt => Ok(E::Bool(a.lt(&b))),
-                                Op::Gt => Ok(E::Bool(a.gt(&b))),
-                                Op::Lte => Ok(E::Bool(a.le(&b))),
-                                Op::Gte => Ok(E::Bool(a.ge(&b))),
-                                _ => Ok(E::Dyadic(op.to_owned(), Box::new(lhs), Box::new(rhs))),
-                            }
-                        }
+                        // ...that compare the same way whatever the Context...
+                        (Some(a), Some(b)) if ctx_free(&a, &b) => match op {
+                            Op::Eq => Ok(E::Bool(a.eq(&b))),
+                            Op::Neq => Ok(E::Bool(!a.eq(&b))),
+                            Op::Lt => Ok(E::Bool(a.lt(&b))),
+                            Op::Gt => Ok(E::Bool(a.gt(&b))),
+                            Op::Lte => Ok(E::Bool(a.le(&b))),
+                            Op::Gte => Ok(E::Bool(a.ge(&b))),
+                            _ => Ok(E::Dyadic(op.to_owned(), Box::new(lhs), Box::new(rhs))),
+                        },
                         _ => Ok(E::Dyadic(op.to_owned(), Box::new(lhs), Box::new(rhs))),
                     }
                 }
@@ -713,53 +1288,57 @@ impl E {
                     let v = rhs.as_literal();
                     match (u, v) {
                         (Some(a), Some(b)) => match op {
+                            // NOTE (rsn) 20261016 - the LIKE escape character is
+                            // a Context setting we don't have here; leave it to
+                            // be evaluated, or translated to SQL, later...
                             Op::IsLike | Op::IsNotLike => {
-                                let input = a.to_str()?;
-                                let pattern = b.to_str()?;
-                                if matches!(op, Op::IsLike) {
-                                    Ok(E::Bool(QString::like(&input, &pattern)))
-                                } else {
-                                    Ok(E::Bool(!QString::like(&input, &pattern)))
-                                }
+                                Ok(E::Dyadic(op.to_owned(), Box::new(lhs), Box::new(rhs)))
                             }
+                            // strings are ordered by the Context's collation...
                             Op::IsBetween | Op::IsNotBetween => {
-                                let z = a.to_num()?;
                                 let bounds = b.to_list()?;
-                                let (b0, b1) = (&bounds[0], &bounds[1]);
-                                if b0.is_null() || b1.is_null() {
-                                    Ok(E::Null)
+                                let is_str = |x: &Q| matches!(x, Q::Str(_));
+                                if is_str(&a) || bounds.iter().any(is_str) {
+                                    Ok(E::Dyadic(op.to_owned(), Box::new(lhs), Box::new(rhs)))
                                 } else {
-                                    let lo = b0.to_num()?;
-                                    let hi = b1.to_num()?;
-                                    let range = if lo <= hi { lo..=hi } else { hi..=lo };
-                                    if matches!(op, Op::IsBetween) {
-                                        Ok(E::Bool(range.contains(&z)))
-                                    } else {
-                                        Ok(E::Bool(!range.contains(&z)))
+                                    match eval_between(op, &a, &bounds, Q::partial_cmp)? {
+                                        Q::Bool(x) => Ok(E::Bool(x)),
+                                        _ => Ok(E::Null),
                                     }
                                 }
                             }
+                            // ...and members are matched like w/ `=`...
                             Op::IsInList | Op::IsNotInList => {
-                                if a.literal_type().is_none() {
-                                    return Err(MyError::Runtime(
-                                        "[NOT] IN LHS is not a literal value".into(),
-                                    ));
-                                }
                                 let list = b.to_list()?;
-                                let ok = list.iter().all(|e| Q::same_type(&a, e));
-                                if !ok {
-                                    return Err(MyError::Runtime(
-                                        "Incompatible [NOT] IN predicate types".into(),
-                                    ));
-                                }
-                                if matches!(op, Op::IsInList) {
-                                    Ok(E::Bool(a.contained_by(list)?))
+                                if !list.iter().all(|x| x.is_null() || ctx_free(&a, x)) {
+                                    Ok(E::Dyadic(op.to_owned(), Box::new(lhs), Box::new(rhs)))
                                 } else {
-                                    Ok(E::Bool(!a.contained_by(list)?))
+                                    match eval_in_list(op, &a, list, |x, y| Ok(x.eq(y)))? {
+                                        Q::Bool(x) => Ok(E::Bool(x)),
+                                        _ => Ok(E::Null),
+                                    }
                                 }
                             }
                             _ => Ok(E::Dyadic(op.to_owned(), Box::new(lhs), Box::new(rhs))),
                         },
+                        // NOTE (rsn) 20261016 - `x IN ()` is FALSE, and `x NOT
+                        // IN ()` is TRUE, but only when `x` is not NULL; both
+                        // are NULL otherwise. w/ a non-literal `x` we can't tell
+                        // which, so we keep only the test for NULL...
+                        (u, _) if matches!(&rhs, E::Array(z) if z.is_empty()) => match (u, op) {
+                            (Some(_), Op::IsInList) => Ok(E::Bool(false)),
+                            (Some(_), _) => Ok(E::Bool(true)),
+                            (None, Op::IsInList) => Ok(E::Dyadic(
+                                Op::And,
+                                Box::new(E::Monadic(Op::IsNull, Box::new(lhs))),
+                                Box::new(E::Null),
+                            )),
+                            (None, _) => Ok(E::Dyadic(
+                                Op::Or,
+                                Box::new(E::Monadic(Op::IsNotNull, Box::new(lhs))),
+                                Box::new(E::Null),
+                            )),
+                        },
                         _ => Ok(E::Dyadic(op.to_owned(), Box::new(lhs), Box::new(rhs))),
                     }
                 }
@@ -773,50 +1352,26 @@ impl E {
                     let u = lhs.as_literal();
                     let v = rhs.as_literal();
                     match (u, v) {
-                        (Some(a), Some(b)) => {
-                            let m = a.to_num()?;
-                            let n = b.to_num()?;
-                            match op {
-                                Op::Plus => Ok(E::Num(m + n)),
-                                Op::Minus => Ok(E::Num(m - n)),
-                                Op::Mult => Ok(E::Num(m * n)),
-                                Op::Div => Ok(E::Num(m / n)),
-                                Op::IntDiv => Ok(E::Num(m.rem_euclid(n))),
-                                Op::Mod => Ok(E::Num(m % n)),
-                                Op::Exp => Ok(E::Num(m.powf(n).round())),
-                                _ => Ok(E::Dyadic(op.to_owned(), Box::new(lhs), Box::new(rhs))),
-                            }
-                        }
+                        (Some(a), Some(b)) => match eval_arithmetic(op, &a, &b)? {
+                            Q::Int(x) => Ok(E::Int(x)),
+                            Q::Num(x) => Ok(E::Num(x)),
+                            _ => Ok(E::Dyadic(op.to_owned(), Box::new(lhs), Box::new(rhs))),
+                        },
                         _ => Ok(E::Dyadic(op.to_owned(), Box::new(lhs), Box::new(rhs))),
                     }
                 }
             }
+            // NOTE (rsn) 20261016 - the outcome of a spatial predicate depends
+            // on Context settings we don't have here; e.g. its spatial tolerance,
+            // antimeridian handling, and repair of invalid geometries. leave it
+            // to be evaluated, or translated to SQL, later...
             E::Dyadic(op, x, y) if op.spatial() => {
                 let lhs = E::reduce(x)?;
                 let rhs = E::reduce(y)?;
                 if matches!(lhs, E::Null) || matches!(rhs, E::Null) {
                     Ok(E::Null)
                 } else {
-                    let u = lhs.as_literal();
-                    let v = rhs.as_literal();
-                    match (u, v) {
-                        (Some(a), Some(b)) => {
-                            let m = a.to_geom()?;
-                            let n = b.to_geom()?;
-                            match op {
-                                Op::SIntersects => Ok(E::Bool(m.intersects(&n)?)),
-                                Op::SEquals => Ok(E::Bool(m.equals(&n)?)),
-                                Op::SDisjoint => Ok(E::Bool(m.disjoint(&n)?)),
-                                Op::STouches => Ok(E::Bool(m.touches(&n)?)),
-                                Op::SWithin => Ok(E::Bool(m.within(&n)?)),
-                                Op::SOverlaps => Ok(E::Bool(m.overlaps(&n)?)),
-                                Op::SCrosses => Ok(E::Bool(m.crosses(&n)?)),
-                                Op::SContains => Ok(E::Bool(m.contains(&n)?)),
-                                _ => Ok(E::Dyadic(op.to_owned(), Box::new(lhs), Box::new(rhs))),
-                            }
-                        }
-                        _ => Ok(E::Dyadic(op.to_owned(), Box::new(lhs), Box::new(rhs))),
-                    }
+                    Ok(E::Dyadic(op.to_owned(), Box::new(lhs), Box::new(rhs)))
                 }
             }
             E::Dyadic(op, x, y) if op.temporal() => {
@@ -831,29 +1386,25 @@ impl E {
                         (Some(a), Some(b)) => {
                             if a.is_null() || b.is_null() {
                                 Ok(E::Null)
+                            } else if mixed_instants(&a, &b).is_some()
+                                || matches!(op, Op::TEquals | Op::TIntersects | Op::TDisjoint)
+                            {
+                                // the outcome depends on the Context's time
+                                // zone, or its temporal tolerance; leave it
+                                // for later...
+                                Ok(E::Dyadic(op.to_owned(), Box::new(lhs), Box::new(rhs)))
                             } else if op.instant_or_interval() {
-                                let it = eval_temporal_fn(op, a, b)?;
+                                let it = eval_temporal_fn(op, a, b, &TimeZone::UTC, None)?;
                                 Ok(E::Bool(it))
                             } else {
                                 // expect intervals only...
                                 let t1 = a.to_interval()?;
                                 let t2 = b.to_interval()?;
-                                match op {
-                                    Op::TContains => Ok(E::Bool((t1.0 < t2.0) && (t1.1 > t2.1))),
-                                    Op::TDuring => Ok(E::Bool((t1.0 > t2.0) && (t1.1 < t2.1))),
-                                    Op::TFinishedBy => Ok(E::Bool((t1.0 < t2.0) && (t1.1 == t2.1))),
-                                    Op::TFinishes => Ok(E::Bool((t1.0 > t2.0) && (t1.1 == t2.1))),
-                                    Op::TMeets => Ok(E::Bool(t1.1 == t2.0)),
-                                    Op::TMetBy => Ok(E::Bool(t1.0 == t2.1)),
-                                    Op::TOverlappedBy => {
-                                        Ok(E::Bool((t1.0 > t2.0) && (t1.0 < t2.1) && (t1.1 > t2.1)))
-                                    }
-                                    Op::TOverlaps => {
-                                        Ok(E::Bool((t1.0 < t2.0) && (t1.1 > t2.0) && (t1.1 < t2.1)))
+                                match eval_interval_fn(op, &t1, &t2) {
+                                    Some(x) => Ok(E::Bool(x)),
+                                    None => {
+                                        Ok(E::Dyadic(op.to_owned(), Box::new(lhs), Box::new(rhs)))
                                     }
-                                    Op::TStartedBy => Ok(E::Bool((t1.0 == t2.0) && (t1.1 > t2.1))),
-                                    Op::TStarts => Ok(E::Bool((t1.0 == t2.0) && (t1.1 < t2.1))),
-                                    _ => Ok(E::Dyadic(op.to_owned(), Box::new(lhs), Box::new(rhs))),
                                 }
                             }
                         }
@@ -877,9 +1428,7 @@ impl E {
                                 Op::AEquals => Ok(E::Bool(m.eq(&n))),
                                 Op::AContains => Ok(E::Bool(n.iter().all(|p| m.contains(p)))),
                                 Op::AContainedBy => Ok(E::Bool(m.iter().all(|p| n.contains(p)))),
-                                Op::AOverlaps => {
-                                    Ok(E::Bool(m.iter().zip(n).any(|(i, j)| i.eq(&j))))
-                                }
+                                Op::AOverlaps => Ok(E::Bool(m.iter().any(|p| n.contains(p)))),
                                 _ => Ok(E::Dyadic(op.to_owned(), Box::new(lhs), Box::new(rhs))),
                             }
                         }
@@ -917,15 +1466,41 @@ impl E {
         }
     }
 
-    fn eval_fn_call(ctx: &Context, feature: &Resource, c: &Call) -> Result<Q, MyError> {
+    fn eval_fn_call<R: ResourceRef + ?Sized>(
+        ctx: &Context,
+        feature: &R,
+        c: &Call,
+    ) -> Result<Q, MyError> {
         let fname = &c.name;
+        // NOTE (rsn) 20261016 - array functions take, and may return, values
+        // of any type which registered closures can't express...
+        if ARRAY_FNS.contains(&fname.as_str()) {
+            return Self::eval_array_fn_call(ctx, feature, c);
+        }
+        // ...and so do variadic ones...
+        if fname == CONCAT {
+            return Self::eval_concat(ctx, feature, c);
+        }
+        // ...or return an interval...
+        if fname == INTERVAL_FROM_STR {
+            return Self::eval_interval_from_str(ctx, feature, c);
+        }
+        // ...or take a binary value...
+        if fname == HEX {
+            return Self::eval_hex(ctx, feature, c);
+        }
         if let Some(fn_meta) = ctx.fn_info(fname) {
             // check if number of declared args matches that of call params...
-            if c.params.len() != fn_meta.arg_types.len() {
+            let (min, max) = (fn_meta.required, fn_meta.arg_types.len());
+            if c.params.len() < min || c.params.len() > max {
+                let expected = if min == max {
+                    max.to_string()
+                } else {
+                    format!("{min} to {max}")
+                };
                 let msg = format!(
-                    "Function '{fname}()' has wrong ({}) arguments count; expected {}",
+                    "Function '{fname}()' has wrong ({}) arguments count; expected {expected}",
                     c.params.len(),
-                    fn_meta.arg_types.len(),
                 );
                 error!("Failed: {}", msg);
                 return Err(MyError::Runtime(msg.into()));
@@ -936,6 +1511,23 @@ impl E {
             let args: Result<Vec<Q>, MyError> =
                 c.params.iter().map(|x| x.eval(ctx, feature)).collect();
             let args = args?;
+            // a NULL argument; e.g. an unset property or the empty result of
+            // another function, yields a NULL...
+            if args.iter().any(Q::is_null) {
+                debug!("Invoking '{fname}()' w/ a NULL argument resulted in NULL");
+                return Ok(Q::Null);
+            }
+            // the length of a binary value is its number of bytes...
+            if fname == LENGTH
+                && let [Q::Bytes(x)] = args.as_slice()
+            {
+                return Ok(Q::Int(x.len() as i64));
+            }
+            #[cfg(not(feature = "geos"))]
+            if GEOS_FNS.contains(&fname.as_str()) {
+                error!("Failed invoking '{fname}()' w/o GEOS");
+                return Err(crate::geom::spatial_disabled());
+            }
 
             // Check if each argument type matches the expected one
             let mut z_args: Vec<Box<dyn Any>> = vec![];
@@ -971,7 +1563,11 @@ impl E {
                             .downcast_ref::<f64>()
                             .unwrap_or_else(|| panic!("Expected '{fname}()' to return a float"));
                         debug!("Invoking '{fname}()' resulted in {result}");
-                        Ok(Q::Num(*result))
+                        // an undefined result; e.g. `sqrt(-1)`, is a NULL...
+                        match result.is_nan() {
+                            true => Ok(Q::Null),
+                            false => Ok(Q::Num(*result)),
+                        }
                     }
                     ExtDataType::Bool => {
                         let result = x
@@ -1001,7 +1597,13 @@ impl E {
                             .downcast_ref::<G>()
                             .unwrap_or_else(|| panic!("Expected '{fname}()' to return a Geometry"));
                         debug!("Invoking '{fname}()' resulted in a geometry");
-                        Ok(Q::Geom(result.to_owned()))
+                        // an empty geometry is a NULL unless it's an overlay's
+                        // result; e.g. the intersection of disjoint geometries
+                        // which has an area of 0...
+                        match result {
+                            G::Null if !OVERLAY_FNS.contains(&fname.as_str()) => Ok(Q::Null),
+                            x => Ok(Q::Geom(x.to_owned())),
+                        }
                     }
                 },
                 None => {
@@ -1015,36 +1617,189 @@ impl E {
         }
     }
 
-    #[cfg(test)]
-    pub(crate) fn as_str(&self) -> Option<&QString> {
-        match self {
-            E::Str(x) => Some(x),
-            _ => None,
+    // Evaluate a call to one of the intrinsic array functions...
+    //
+    // * `array_length(x)` returns the number of items in the array `x`.
+    // * `nth(x, n)` returns the `n`th item of the array `x`. Indices are
+    //   1-based, like the positions of characters in SQL strings, so
+    //   `nth(x, 1)` is the first item. An out-of-bounds index is an error.
+    //
+    // Both return NULL if any of their arguments is NULL.
+    fn eval_array_fn_call<R: ResourceRef + ?Sized>(
+        ctx: &Context,
+        feature: &R,
+        c: &Call,
+    ) -> Result<Q, MyError> {
+        let fname = &c.name;
+        let expected = if fname == ARRAY_LENGTH { 1 } else { 2 };
+        if c.params.len() != expected {
+            let msg = format!(
+                "Function '{fname}()' has wrong ({}) arguments count; expected {expected}",
+                c.params.len(),
+            );
+            error!("Failed: {}", msg);
+            return Err(MyError::Runtime(msg.into()));
         }
-    }
 
-    #[cfg(test)]
-    pub(crate) fn as_zoned(&self) -> Option<&Zoned> {
-        match self {
-            E::Date(x) => Some(x),
-            E::Timestamp(x) => Some(x),
-            _ => None,
+        let args: Result<Vec<Q>, MyError> = c.params.iter().map(|x| x.eval(ctx, feature)).collect();
+        let args = args?;
+        if args.iter().any(Q::is_null) {
+            return Ok(Q::Null);
         }
-    }
 
-    #[cfg(test)]
-    pub(crate) fn as_spatial(&self) -> Option<&G> {
-        match self {
-            E::Spatial(x) => Some(x),
-            _ => None,
+        let list = args[0].to_list()?;
+        if fname == ARRAY_LENGTH {
+            return Ok(Q::Int(list.len() as i64));
         }
-    }
 
-    #[cfg(test)]
-    pub(crate) fn as_dyadic(&self) -> Option<(&Op, &E, &E)> {
-        match self {
-            E::Dyadic(op, a, b) => Some((op, a, b)),
-            _ => None,
+        let n = args[1].to_int()?;
+        let ndx = usize::try_from(n)
+            .ok()
+            .filter(|x| (1..=list.len()).contains(x));
+        match ndx {
+            Some(x) => Ok(list[x - 1].to_owned()),
+            None => {
+                let msg = format!(
+                    "Index {n} is out of bounds; expected 1 to {} (inclusive)",
+                    list.len()
+                );
+                error!("Failed: {}", msg);
+                Err(MyError::Runtime(msg.into()))
+            }
+        }
+    }
+
+    // Evaluate a call to the intrinsic `concat(a, b, ...)` function which
+    // returns a plain string made of its 1 or more arguments joined together.
+    // Numbers and booleans are coerced to their display form. Any other type
+    // is an error.
+    //
+    // Returns NULL if any of its arguments is NULL.
+    fn eval_concat<R: ResourceRef + ?Sized>(
+        ctx: &Context,
+        feature: &R,
+        c: &Call,
+    ) -> Result<Q, MyError> {
+        if c.params.is_empty() {
+            let msg = format!("Function '{CONCAT}()' expects at least 1 argument");
+            error!("Failed: {}", msg);
+            return Err(MyError::Runtime(msg.into()));
+        }
+
+        let mut result = String::new();
+        for p in &c.params {
+            match p.eval(ctx, feature)? {
+                Q::Null => return Ok(Q::Null),
+                Q::Str(x) => result.push_str(x.as_str()),
+                Q::Num(x) => result.push_str(&x.to_string()),
+                Q::Int(x) => result.push_str(&x.to_string()),
+                Q::Bool(x) => result.push_str(&x.to_string()),
+                x => {
+                    let msg = format!("Function '{CONCAT}()' can't concatenate {x}");
+                    error!("Failed: {}", msg);
+                    return Err(MyError::Runtime(msg.into()));
+                }
+            }
+        }
+        debug!("Invoking '{CONCAT}()' resulted in {result}");
+        Ok(Q::Str(QString::plain(result)))
+    }
+
+    // Evaluate a call to the intrinsic `interval_from_str(x)` function which
+    // returns the interval represented by the range string `x`; e.g.
+    // `2020-01-01/2021-12-31`, `../2021-12-31` or `2020-01-01/..`. See
+    // `Bound::try_new_range()` for details.
+    //
+    // Returns NULL if its argument is NULL.
+    fn eval_interval_from_str<R: ResourceRef + ?Sized>(
+        ctx: &Context,
+        feature: &R,
+        c: &Call,
+    ) -> Result<Q, MyError> {
+        if c.params.len() != 1 {
+            let msg = format!(
+                "Function '{INTERVAL_FROM_STR}()' has wrong ({}) arguments count; expected 1",
+                c.params.len(),
+            );
+            error!("Failed: {}", msg);
+            return Err(MyError::Runtime(msg.into()));
+        }
+
+        match c.params[0].eval(ctx, feature)? {
+            Q::Null => Ok(Q::Null),
+            Q::Str(x) => {
+                let (start, end) = Bound::try_new_range(x.as_str())?;
+                Ok(Q::Interval(start, end))
+            }
+            x => {
+                let msg = format!("Function '{INTERVAL_FROM_STR}()' expects a string; got {x}");
+                error!("Failed: {}", msg);
+                Err(MyError::Runtime(msg.into()))
+            }
+        }
+    }
+
+    // Evaluate a call to the intrinsic `hex(x)` function which returns the
+    // lowercase hexadecimal representation of the binary value, or the UTF-8
+    // encoded string, `x`.
+    //
+    // Returns NULL if its argument is NULL.
+    fn eval_hex<R: ResourceRef + ?Sized>(
+        ctx: &Context,
+        feature: &R,
+        c: &Call,
+    ) -> Result<Q, MyError> {
+        if c.params.len() != 1 {
+            let msg = format!(
+                "Function '{HEX}()' has wrong ({}) arguments count; expected 1",
+                c.params.len(),
+            );
+            error!("Failed: {}", msg);
+            return Err(MyError::Runtime(msg.into()));
+        }
+
+        match c.params[0].eval(ctx, feature)? {
+            Q::Null => Ok(Q::Null),
+            Q::Bytes(x) => Ok(Q::Str(QString::plain(to_hex(&x)))),
+            Q::Str(x) => Ok(Q::Str(QString::plain(to_hex(x.as_str().as_bytes())))),
+            x => {
+                let msg = format!("Function '{HEX}()' expects a binary value or a string; got {x}");
+                error!("Failed: {}", msg);
+                Err(MyError::Runtime(msg.into()))
+            }
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn as_str(&self) -> Option<&QString> {
+        match self {
+            E::Str(x) => Some(x),
+            _ => None,
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn as_zoned(&self) -> Option<&Zoned> {
+        match self {
+            E::Date(x) => Some(x),
+            E::Timestamp(x) => Some(x),
+            _ => None,
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn as_spatial(&self) -> Option<&G> {
+        match self {
+            E::Spatial(x) => Some(x),
+            _ => None,
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn as_dyadic(&self) -> Option<(&Op, &E, &E)> {
+        match self {
+            E::Dyadic(op, a, b) => Some((op, a, b)),
+            _ => None,
         }
     }
 
@@ -1058,44 +1813,392 @@ impl E {
 }
 
 // arguments are either intervals, or instants.
-fn eval_temporal_fn(op: &Op, t1: Q, t2: Q) -> Result<bool, MyError> {
+fn eval_temporal_fn(
+    op: &Op,
+    t1: Q,
+    t2: Q,
+    tz: &TimeZone,
+    tolerance: Option<Duration>,
+) -> Result<bool, MyError> {
+    if let Some((date_first, day, instant)) = mixed_instants(&t1, &t2) {
+        return eval_day_vs_instant(op, date_first, day, instant, tz);
+    }
+
     let (t1_is_instant, t2_is_instant, b0, b1, b2, b3) = unfold_queryables(&t1, &t2)?;
     match op {
         // start of T1 is after end of T2
         Op::TAfter => match (t1_is_instant, t2_is_instant) {
-            (true, true) => Ok(b0 > b2),
-            (true, false) => Ok(b0 > b3),
-            (false, true) => Ok(b0 > b2),
-            (false, false) => Ok(b0 > b3),
+            (true, true) => Ok(b0.as_start() > b2.as_end()),
+            (true, false) => Ok(b0.as_start() > b3.as_end()),
+            (false, true) => Ok(b0.as_start() > b2.as_end()),
+            (false, false) => Ok(b0.as_start() > b3.as_end()),
         },
         // end of T1 is before start of T2
         Op::TBefore => match (t1_is_instant, t2_is_instant) {
-            (true, true) => Ok(b0 < b2),
-            (true, false) => Ok(b0 < b2),
-            (false, true) => Ok(b1 < b2),
-            (false, false) => Ok(b1 < b2),
+            (true, true) => Ok(b0.as_end() < b2.as_start()),
+            (true, false) => Ok(b0.as_end() < b2.as_start()),
+            (false, true) => Ok(b1.as_end() < b2.as_start()),
+            (false, false) => Ok(b1.as_end() < b2.as_start()),
         },
         // (t1 T_BEFORE t2) OR (t1 T_AFTER t2)
         Op::TDisjoint => match (t1_is_instant, t2_is_instant) {
-            (true, true) => Ok(b0 != b2),
-            (true, false) => Ok((b0 < b2) || (b0 > b3)),
-            (false, true) => Ok((b1 < b2) || (b0 > b2)),
-            (false, false) => Ok((b1 < b2) || (b0 > b3)),
+            (true, true) => Ok(!same_instant(&b0, &b2, tolerance)),
+            (true, false) => Ok(ends_before(&b0, &b2) || starts_after(&b0, &b3)),
+            (false, true) => Ok(ends_before(&b1, &b2) || starts_after(&b0, &b2)),
+            (false, false) => Ok(ends_before(&b1, &b2) || starts_after(&b0, &b3)),
         },
         // Start and end of t1 and t2 coincide
-        Op::TEquals => match (t1_is_instant, t2_is_instant) {
-            (true, true) => Ok(b0 == b2),
-            (true, false) => Ok((b0 == b2) && (b0 == b3)),
-            (false, true) => Ok((b0 == b2) && (b1 == b2)),
-            (false, false) => Ok((b0 == b2) && (b1 == b3)),
-        },
-        // NOT (t1 T_DISJOINT t2)
+        Op::TEquals => {
+            let eq = |a, b| same_instant(a, b, tolerance);
+            match (t1_is_instant, t2_is_instant) {
+                (true, true) => Ok(eq(&b0, &b2)),
+                (true, false) => Ok(eq(&b0, &b2) && eq(&b0, &b3)),
+                (false, true) => Ok(eq(&b0, &b2) && eq(&b1, &b2)),
+                (false, false) => Ok(eq(&b0, &b2) && eq(&b1, &b3)),
+            }
+        }
+        // NOT (t1 T_DISJOINT t2); i.e. equality for 2 instants, containment
+        // for an instant and an interval, and overlap for 2 intervals...
         Op::TIntersects => match (t1_is_instant, t2_is_instant) {
-            (true, true) => Ok(b0 == b2),
-            (true, false) => Ok(!((b0 < b2) || (b0 > b3))),
-            (false, true) => Ok(!((b1 < b2) || (b0 > b2))),
-            (false, false) => Ok(!((b1 < b2) || (b0 > b3))),
+            (true, true) => Ok(same_instant(&b0, &b2, tolerance)),
+            (true, false) => Ok(!(ends_before(&b0, &b2) || starts_after(&b0, &b3))),
+            (false, true) => Ok(!(ends_before(&b1, &b2) || starts_after(&b0, &b2))),
+            (false, false) => Ok(!(ends_before(&b1, &b2) || starts_after(&b0, &b3))),
+        },
+        _ => Err(MyError::Runtime(
+            format!("Unexpected ({op}) temporal operator").into(),
+        )),
+    }
+}
+
+// return TRUE if `x` is NULL, or if it's an empty geometry or array and the
+// Context considers those as NULL...
+fn is_null_in(ctx: &Context, x: &Q) -> bool {
+    match x {
+        Q::Null => true,
+        Q::Geom(g) => ctx.empty_as_null() && g.is_empty(),
+        Q::List(v) => ctx.empty_as_null() && v.is_empty(),
+        _ => false,
+    }
+}
+
+// return TRUE if 2 bounds are equal, or if both are instants at most
+// `tolerance` apart when one is set...
+fn same_instant(a: &Bound, b: &Bound, tolerance: Option<Duration>) -> bool {
+    match (tolerance, a.as_zoned(), b.as_zoned()) {
+        (Some(t), Some(x), Some(y)) => x.duration_until(&y).unsigned_abs() <= t,
+        _ => a == b,
+    }
+}
+
+// IMPORTANT (rsn) 20261016 - `Bound::None` always sorts before any other
+// bound, which is right when it's the start of an interval (-infinity) but
+// wrong when it's the end one (+infinity). comparisons of interval limits
+// below go through `Limit`s which take the role of each bound into account...
+
+// return TRUE if `end`, the end of an interval or an instant, is strictly
+// before `start`, the start of another interval or an instant.
+fn ends_before(end: &Bound, start: &Bound) -> bool {
+    end.as_end() < start.as_start()
+}
+
+// return TRUE if `start`, the start of an interval or an instant, is strictly
+// after `end`, the end of another interval or an instant.
+fn starts_after(start: &Bound, end: &Bound) -> bool {
+    start.as_start() > end.as_end()
+}
+
+// evaluate a temporal operator that only applies to 2 intervals `t1` and
+// `t2`. return `None` if `op` is not one of those.
+fn eval_interval_fn(op: &Op, t1: &(Bound, Bound), t2: &(Bound, Bound)) -> Option<bool> {
+    let (s1, e1) = (t1.0.as_start(), t1.1.as_end());
+    let (s2, e2) = (t2.0.as_start(), t2.1.as_end());
+    let it = match op {
+        // If a proper interval T1 is intervalContains another proper interval
+        // T2, then the beginning of T1 is before the beginning of T2, and the
+        // end of T1 is after the end of T2.
+        Op::TContains => s1 < s2 && e1 > e2,
+        // If a proper interval T1 is intervalDuring another proper interval T2,
+        // then the beginning of T1 is after the beginning of T2, and the end of
+        // T1 is before the end of T2.
+        Op::TDuring => s1 > s2 && e1 < e2,
+        // If a proper interval T1 is intervalFinishedBy another proper interval
+        // T2, then the beginning of T1 is before the beginning of T2, and the
+        // end of T1 is coincident with the end of T2.
+        Op::TFinishedBy => s1 < s2 && e1 == e2,
+        // If a proper interval T1 is intervalFinishes another proper interval
+        // T2, then the beginning of T1 is after the beginning of T2, and the
+        // end of T1 is coincident with the end of T2.
+        Op::TFinishes => s1 > s2 && e1 == e2,
+        // If a proper interval T1 is intervalMeets another proper interval T2,
+        // then the end of T1 is coincident with the beginning of T2.
+        Op::TMeets => e1 == s2,
+        // If a proper interval T1 is intervalMetBy another proper interval T2,
+        // then the beginning of T1 is coincident with the end of T2.
+        Op::TMetBy => s1 == e2,
+        // If a proper interval T1 is intervalOverlappedBy another proper
+        // interval T2, then the beginning of T1 is after the beginning of T2,
+        // the beginning of T1 is before the end of T2, and the end of T1 is
+        // after the end of T2.
+        Op::TOverlappedBy => s1 > s2 && s1 < e2 && e1 > e2,
+        // If a proper interval T1 is intervalOverlaps another proper interval
+        // T2, then the beginning of T1 is before the beginning of T2, the end
+        // of T1 is after the beginning of T2, and the end of T1 is before the
+        // end of T2.
+        Op::TOverlaps => s1 < s2 && e1 > s2 && e1 < e2,
+        // If a proper interval T1 is intervalStartedBy another proper interval
+        // T2, then the beginning of T1 is coincident with the beginning of T2,
+        // and the end of T1 is after the end of T2.
+        Op::TStartedBy => s1 == s2 && e1 > e2,
+        // If a proper interval T1 is intervalStarts another proper interval T2,
+        // then the beginning of T1 is coincident with the beginning of T2, and
+        // the end of T1 is before the end of T2.
+        Op::TStarts => s1 == s2 && e1 < e2,
+        _ => return None,
+    };
+    Some(it)
+}
+
+// compare 2 values in the order set by `ctx`; i.e. strings according to its
+// collation if one was set, and everything else as usual...
+fn cmp_in(ctx: &Context, a: &Q, b: &Q) -> Option<Ordering> {
+    match (a, b, ctx.collator()) {
+        (Q::Str(x), Q::Str(y), Some(collator)) => Some(collator.compare(x.as_str(), y.as_str())),
+        _ => a.partial_cmp(b),
+    }
+}
+
+// return TRUE if comparing `a` to `b` yields the same outcome whatever the
+// Context; i.e. they're of the same type, and it's one that none of the
+// Context settings affects; e.g. its numeric epsilon, string normalization,
+// case folding, and collation, or implicit coercion...
+fn ctx_free(a: &Q, b: &Q) -> bool {
+    matches!(
+        (a, b),
+        (Q::Bool(_), Q::Bool(_)) | (Q::Int(_), Q::Int(_)) | (Q::Instant(_), Q::Instant(_))
+    )
+}
+
+// return TRUE if 2 values of the same type are equal according to `ctx`;
+// i.e. strings w/ its normalization and case folding, and numbers w/in its
+// numeric epsilon if one was set...
+fn eq_in(ctx: &Context, a: &Q, b: &Q) -> Result<bool, MyError> {
+    let it = match (a, b) {
+        (Q::Str(x), Q::Str(y)) => x.eq_with(y, ctx.normalization(), ctx.case_folding()),
+        (Q::Num(x), Q::Num(y)) => match ctx.numeric_epsilon() {
+            Some(eps) => approx_eq(*x, *y, eps),
+            None => x == y,
+        },
+        // integers compare exactly unless mixed w/ a floating point number...
+        (Q::Num(_), Q::Int(_)) | (Q::Int(_), Q::Num(_)) => match ctx.numeric_epsilon() {
+            Some(eps) => approx_eq(a.to_num()?, b.to_num()?, eps),
+            None => a.eq(b),
         },
+        _ => a.eq(b),
+    };
+    Ok(it)
+}
+
+// same as `eq_in` for `x` and a non-NULL member of a [NOT] IN list, coercing
+// them first if `ctx` allows it. like `x = member` it fails if they're not of
+// the same type...
+fn in_list_eq(ctx: &Context, x: &Q, member: &Q) -> Result<bool, MyError> {
+    let coerced;
+    let (a, b) = if ctx.implicit_coercion() && !Q::same_type(x, member) {
+        coerced = Q::coerce(x.to_owned(), member.to_owned())?;
+        (&coerced.0, &coerced.1)
+    } else {
+        (x, member)
+    };
+    if !Q::same_type(a, b) {
+        return Err(MyError::Runtime(
+            "Incompatible [NOT] IN predicate types".into(),
+        ));
+    }
+    eq_in(ctx, a, b)
+}
+
+// evaluate `x [NOT] BETWEEN lo AND hi` where `bounds` is `[lo, hi]`. all 3
+// operands must be numbers, strings, or temporal instants. numbers, incl.
+// mixed integers and floats, are compared as `f64`; the others w/ `cmp`;
+// e.g. in their natural (i.e. lexical or chronological) order, or strings
+// w/ a Context collation. like for numbers, the bounds may be given in any
+// order. the result is NULL if any is NULL.
+fn eval_between<F>(op: &Op, x: &Q, bounds: &[Q], cmp: F) -> Result<Q, MyError>
+where
+    F: Fn(&Q, &Q) -> Option<Ordering>,
+{
+    let (b0, b1) = (&bounds[0], &bounds[1]);
+    if x.is_null() || b0.is_null() || b1.is_null() {
+        return Ok(Q::Null);
+    }
+    let within = match (x, b0, b1) {
+        (Q::Num(_) | Q::Int(_), Q::Num(_) | Q::Int(_), Q::Num(_) | Q::Int(_)) => {
+            let (a, lo, hi) = (x.to_num()?, b0.to_num()?, b1.to_num()?);
+            let range = if lo <= hi { lo..=hi } else { hi..=lo };
+            range.contains(&a)
+        }
+        (Q::Str(_), Q::Str(_), Q::Str(_)) | (Q::Instant(_), Q::Instant(_), Q::Instant(_)) => {
+            let le = |a: &Q, b: &Q| cmp(a, b).is_some_and(Ordering::is_le);
+            let (lo, hi) = if le(b0, b1) { (b0, b1) } else { (b1, b0) };
+            le(lo, x) && le(x, hi)
+        }
+        _ => {
+            let msg = format!("Incompatible [NOT] BETWEEN operands: {x}, {b0}, {b1}");
+            error!("Failed: {}", msg);
+            return Err(MyError::Runtime(msg.into()));
+        }
+    };
+    Ok(Q::Bool(if matches!(op, Op::IsBetween) {
+        within
+    } else {
+        !within
+    }))
+}
+
+// evaluate `x [NOT] IN (list)` w/ SQL-like 3-valued logic. NULL members are
+// skipped, while `eq` compares `x` to every other one, failing if they can't
+// be compared. when `x` matches none of the other members, the result is NULL
+// if there's at least one NULL member, since `x = NULL` is itself NULL; e.g.
+// `1 IN (2, NULL)` is NULL, and so is `1 NOT IN (2, NULL)`.
+fn eval_in_list<F>(op: &Op, x: &Q, list: Vec<Q>, eq: F) -> Result<Q, MyError>
+where
+    F: Fn(&Q, &Q) -> Result<bool, MyError>,
+{
+    // x must be a literal...
+    if x.literal_type().is_none() {
+        return Err(MyError::Runtime(
+            "[NOT] IN LHS is not a literal value".into(),
+        ));
+    }
+    let (mut found, mut nulls) = (false, false);
+    for member in &list {
+        if member.is_null() {
+            nulls = true;
+        } else {
+            // ...and every other member must be comparable to it...
+            found |= eq(x, member)?;
+        }
+    }
+    if !found && nulls {
+        Ok(Q::Null)
+    } else if matches!(op, Op::IsInList) {
+        Ok(Q::Bool(found))
+    } else {
+        Ok(Q::Bool(!found))
+    }
+}
+
+// apply an arithmetic operator to 2 numbers. integers yield an exact integer
+// except when dividing w/ `/`, raising to a negative power, or if the result
+// overflows an `i64`. both are then promoted to `f64` like when mixed w/ a
+// floating point number...
+#[rustfmt::skip]
+fn eval_arithmetic(op: &Op, x: &Q, y: &Q) -> Result<Q, MyError> {
+    if let (Q::Int(a), Q::Int(b)) = (x, y) {
+        let it = match op {
+            Op::Plus   => a.checked_add(*b),
+            Op::Minus  => a.checked_sub(*b),
+            Op::Mult   => a.checked_mul(*b),
+            Op::IntDiv => a.checked_rem_euclid(*b),
+            Op::Mod    => a.checked_rem(*b),
+            Op::Exp    => u32::try_from(*b).ok().and_then(|b| a.checked_pow(b)),
+            _ => None,
+        };
+        if let Some(z) = it {
+            return Ok(Q::Int(z));
+        }
+    }
+    let a = x.to_num()?;
+    let b = y.to_num()?;
+    match op {
+        Op::Plus   => Ok(Q::Num(a + b)),
+        Op::Minus  => Ok(Q::Num(a - b)),
+        Op::Mult   => Ok(Q::Num(a * b)),
+        Op::Div    => Ok(Q::Num(a / b)),
+        Op::IntDiv => Ok(Q::Num(a.rem_euclid(b))),
+        Op::Mod    => Ok(Q::Num(a % b)),
+        Op::Exp    => Ok(Q::Num(pow(a, b))),
+        _ => Err(MyError::Runtime(
+            format!("Unexpected arithmetic operator: {op:?}. Abort").into(),
+        )),
+    }
+}
+
+// raise `a` to the power `b` using repeated multiplications when `b` is a
+// whole number that fits an `i32`; e.g. so `2 ^ 10` is exactly 1024 and
+// `2 ^ -1` is 0.5. otherwise use `powf` as is; e.g. `2 ^ 0.5` is √2...
+fn pow(a: f64, b: f64) -> f64 {
+    if b.fract() == 0.0 && (i32::MIN as f64..=i32::MAX as f64).contains(&b) {
+        a.powi(b as i32)
+    } else {
+        a.powf(b)
+    }
+}
+
+// return TRUE if `a` and `b` are w/in `eps` of each other either in absolute
+// terms or relative to the larger of their magnitudes...
+fn approx_eq(a: f64, b: f64, eps: f64) -> bool {
+    let delta = (a - b).abs();
+    a == b || delta <= eps || delta <= eps * a.abs().max(b.abs())
+}
+
+// the CQL2-JSON name of an operator that has one...
+fn json_op(op: &Op) -> Result<&str, MyError> {
+    op.to_json().ok_or_else(|| {
+        MyError::Runtime(format!("Operator {op:?} has no JSON representation").into())
+    })
+}
+
+// return `g` as is if it's valid, or a repaired copy otherwise...
+fn repaired(g: G) -> Result<G, MyError> {
+    if g.is_valid()? {
+        Ok(g)
+    } else {
+        debug!("Repair invalid {}", g.type_());
+        g.make_valid()
+    }
+}
+
+// if one argument is a DATE instant and the other is a TIMESTAMP one, return
+// a flag indicating whether the DATE came first, its civil day, and the
+// TIMESTAMP value.  return None otherwise.
+fn mixed_instants(a: &Q, b: &Q) -> Option<(bool, Date, Zoned)> {
+    match (a, b) {
+        (Q::Instant(Bound::Date(d)), Q::Instant(Bound::Timestamp(t))) => {
+            Some((true, d.date(), t.to_owned()))
+        }
+        (Q::Instant(Bound::Timestamp(t)), Q::Instant(Bound::Date(d))) => {
+            Some((false, d.date(), t.to_owned()))
+        }
+        _ => None,
+    }
+}
+
+// IMPORTANT (rsn) 20261016 - comparing a DATE to a TIMESTAMP is ambiguous. we
+// expand the DATE to the half-open interval [start, end) covering that whole
+// civil day in the given time zone. a TIMESTAMP falling exactly at midnight
+// is thus considered w/in the day it starts and not the one it ends.
+fn eval_day_vs_instant(
+    op: &Op,
+    date_first: bool,
+    day: Date,
+    instant: Zoned,
+    tz: &TimeZone,
+) -> Result<bool, MyError> {
+    let start = day.to_zoned(tz.to_owned())?.timestamp();
+    let end = day.tomorrow()?.to_zoned(tz.to_owned())?.timestamp();
+    let t = instant.timestamp();
+    let inside = start <= t && t < end;
+    match op {
+        Op::TAfter if date_first => Ok(start > t),
+        Op::TAfter => Ok(t >= end),
+        Op::TBefore if date_first => Ok(end <= t),
+        Op::TBefore => Ok(t < start),
+        Op::TDisjoint => Ok(!inside),
+        Op::TIntersects => Ok(inside),
+        // an instant can never coincide w/ a whole day...
+        Op::TEquals => Ok(false),
         _ => Err(MyError::Runtime(
             format!("Unexpected ({op}) temporal operator").into(),
         )),
@@ -1149,7 +2252,8 @@ impl Call {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::text::cql2;
+    use crate::{CaseFolding, NormalizationForm, Resource, text::cql2};
+    use std::error::Error;
 
     #[test]
     fn test_ex57() {
@@ -1210,4 +2314,1179 @@ AND landsat:wrs_path=203
         let exp = cql2::expression(CQL);
         assert!(exp.is_ok());
     }
+
+    #[test]
+    fn test_date_vs_timestamp() -> Result<(), Box<dyn Error>> {
+        #[rustfmt::skip]
+        const TV: [(&str, bool, bool); 8] = [
+            // expression                                                            UTC    UTC-5
+            ("T_INTERSECTS(DATE('2020-01-01'), TIMESTAMP('2020-01-01T00:00:00Z'))", true,  false),
+            ("T_INTERSECTS(TIMESTAMP('2020-01-01T23:59:59Z'), DATE('2020-01-01'))", true,  true),
+            ("T_INTERSECTS(DATE('2020-01-01'), TIMESTAMP('2020-01-02T00:00:00Z'))", false, true),
+            ("T_DISJOINT(DATE('2020-01-01'), TIMESTAMP('2020-01-02T00:00:00Z'))",   true,  false),
+            ("T_BEFORE(DATE('2020-01-01'), TIMESTAMP('2020-01-02T00:00:00Z'))",     true,  false),
+            ("T_BEFORE(TIMESTAMP('2020-01-01T03:00:00Z'), DATE('2020-01-01'))",     false, true),
+            ("T_AFTER(TIMESTAMP('2020-01-02T00:00:00Z'), DATE('2020-01-01'))",      true,  false),
+            ("T_EQUALS(DATE('2020-01-01'), TIMESTAMP('2020-01-01T00:00:00Z'))",     false, false),
+        ];
+
+        let utc = Context::new();
+        let est = Context::new().with_time_zone(TimeZone::fixed(jiff::tz::offset(-5)));
+        let feat = Resource::new();
+        for (cql, expected_utc, expected_est) in TV {
+            let exp = cql2::expression(cql)?;
+            assert_eq!(exp.eval(&utc, &feat)?, Q::Bool(expected_utc), "UTC: {cql}");
+            assert_eq!(exp.eval(&est, &feat)?, Q::Bool(expected_est), "EST: {cql}");
+            // mixed DATE + TIMESTAMP literals are left as-is when reduced...
+            let mut exp_ = exp.clone();
+            assert_eq!(E::reduce(&mut exp_)?, exp);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_t_intersects_matrix() -> Result<(), Box<dyn Error>> {
+        let ctx = Context::new();
+        let feat = Resource::from([
+            (
+                "t".into(),
+                Q::Instant(Bound::try_new_timestamp("2020-06-15T12:00:00Z")?),
+            ),
+            (
+                "span".into(),
+                Q::Interval(
+                    Bound::try_new_timestamp("2020-01-01T00:00:00Z")?,
+                    Bound::try_new_timestamp("2020-12-31T00:00:00Z")?,
+                ),
+            ),
+            (
+                "since".into(),
+                Q::Interval(
+                    Bound::try_new_timestamp("2020-01-01T00:00:00Z")?,
+                    Bound::None,
+                ),
+            ),
+        ]);
+
+        #[rustfmt::skip]
+        const TV: [(&str, bool); 18] = [
+            // instant x instant...
+            ("T_INTERSECTS(t, TIMESTAMP('2020-06-15T12:00:00Z'))", true),
+            ("T_INTERSECTS(t, TIMESTAMP('2020-06-15T12:00:01Z'))", false),
+            // instant x interval...
+            ("T_INTERSECTS(t, INTERVAL('2020-06-01T00:00:00Z', '2020-07-01T00:00:00Z'))", true),
+            ("T_INTERSECTS(t, INTERVAL('2021-06-01T00:00:00Z', '2021-07-01T00:00:00Z'))", false),
+            // instant x open interval...
+            ("T_INTERSECTS(t, INTERVAL('2020-06-01T00:00:00Z', '..'))", true),
+            ("T_INTERSECTS(t, INTERVAL('..', '2020-06-01T00:00:00Z'))", false),
+            // interval x instant...
+            ("T_INTERSECTS(span, TIMESTAMP('2020-03-01T00:00:00Z'))", true),
+            ("T_INTERSECTS(span, TIMESTAMP('2021-03-01T00:00:00Z'))", false),
+            // interval x interval...
+            ("T_INTERSECTS(span, INTERVAL('2020-12-01T00:00:00Z', '2021-02-01T00:00:00Z'))", true),
+            ("T_INTERSECTS(span, INTERVAL('2021-01-01T00:00:00Z', '2021-02-01T00:00:00Z'))", false),
+            // interval x open interval...
+            ("T_INTERSECTS(span, INTERVAL('..', '2020-01-01T00:00:00Z'))", true),
+            ("T_INTERSECTS(span, INTERVAL('2021-01-01T00:00:00Z', '..'))", false),
+            // open interval x instant...
+            ("T_INTERSECTS(since, TIMESTAMP('2999-01-01T00:00:00Z'))", true),
+            ("T_INTERSECTS(since, TIMESTAMP('2019-01-01T00:00:00Z'))", false),
+            // open interval x interval...
+            ("T_INTERSECTS(since, INTERVAL('2030-01-01T00:00:00Z', '2031-01-01T00:00:00Z'))", true),
+            ("T_INTERSECTS(since, INTERVAL('2019-01-01T00:00:00Z', '2019-02-01T00:00:00Z'))", false),
+            // open interval x open interval...
+            ("T_INTERSECTS(since, INTERVAL('..', '2020-01-01T00:00:00Z'))", true),
+            ("T_INTERSECTS(since, INTERVAL('..', '2019-12-31T00:00:00Z'))", false),
+        ];
+        for (ndx, (src, expected)) in TV.iter().enumerate() {
+            let exp = cql2::expression(src)?;
+            assert_eq!(exp.eval(&ctx, &feat)?, Q::Bool(*expected), "#{ndx}: {src}");
+            // T_DISJOINT is always the opposite...
+            let src = src.replace("T_INTERSECTS", "T_DISJOINT");
+            let exp = cql2::expression(&src)?;
+            assert_eq!(exp.eval(&ctx, &feat)?, Q::Bool(!*expected), "#{ndx}: {src}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_implicit_coercion() -> Result<(), Box<dyn Error>> {
+        let strict = Context::new();
+        let lenient = Context::new().with_implicit_coercion();
+        assert!(!strict.implicit_coercion());
+        assert!(lenient.implicit_coercion());
+
+        let feat = Resource::from([
+            ("population".into(), Q::Num(1500.0)),
+            ("created".into(), Q::new_plain_str("2020-01-01T00:00:00Z")),
+        ]);
+
+        let exp = cql2::expression("population > '1000'")?;
+        assert!(exp.eval(&strict, &feat).is_err());
+        assert_eq!(exp.eval(&lenient, &feat)?, Q::Bool(true));
+
+        let exp = cql2::expression("created < TIMESTAMP('2021-01-01T00:00:00Z')")?;
+        assert!(exp.eval(&strict, &feat).is_err());
+        assert_eq!(exp.eval(&lenient, &feat)?, Q::Bool(true));
+
+        let exp = cql2::expression("population = 'many'")?;
+        assert!(exp.eval(&lenient, &feat).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_spatial_tolerance() -> Result<(), Box<dyn Error>> {
+        let exact = Context::new();
+        let tolerant = Context::new().with_spatial_tolerance(1e-4);
+        assert_eq!(exact.spatial_tolerance(), None);
+        assert_eq!(tolerant.spatial_tolerance(), Some(1e-4));
+        // invalid tolerances are ignored...
+        assert_eq!(
+            Context::new()
+                .with_spatial_tolerance(-1.0)
+                .spatial_tolerance(),
+            None
+        );
+
+        let feat = Resource::from([(
+            "geom".into(),
+            Q::try_from_wkt("POLYGON((0 0, 10.00001 0, 10 10, 0 10, 0 0))")?,
+        )]);
+
+        let exp = cql2::expression("S_EQUALS(geom, POLYGON((0 0, 10 0, 10 10, 0 10, 0 0)))")?;
+        assert_eq!(exp.eval(&exact, &feat)?, Q::Bool(false));
+        assert_eq!(exp.eval(&tolerant, &feat)?, Q::Bool(true));
+
+        // beyond the tolerance...
+        let exp = cql2::expression("S_EQUALS(geom, POLYGON((0 0, 10.1 0, 10 10, 0 10, 0 0)))")?;
+        assert_eq!(exp.eval(&tolerant, &feat)?, Q::Bool(false));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_auto_repair() -> Result<(), Box<dyn Error>> {
+        let ctx = Context::new().with_auto_repair();
+        assert!(ctx.auto_repair());
+        assert!(!Context::new().auto_repair());
+
+        // a bow-tie; i.e. 2 triangles touching at (5 5)...
+        let feat = Resource::from([(
+            "geom".into(),
+            Q::try_from_wkt("POLYGON((0 0, 10 10, 10 0, 0 10, 0 0))")?,
+        )]);
+
+        const TV: [(&str, bool); 4] = [
+            ("S_INTERSECTS(geom, POINT(8 5))", true),
+            ("S_INTERSECTS(geom, POINT(2 5))", true),
+            ("S_INTERSECTS(geom, POINT(5 2))", false),
+            ("S_CONTAINS(geom, POINT(5 8))", false),
+        ];
+        for (src, expected) in TV {
+            let exp = cql2::expression(src)?;
+            assert_eq!(exp.eval(&ctx, &feat)?, Q::Bool(expected), "{src}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_antimeridian() -> Result<(), Box<dyn Error>> {
+        let feat = Resource::from([(
+            "geom".into(),
+            Q::try_from_wkt("POLYGON((178 -16, -179 -16, -179 -17, 178 -17, 178 -16))")?,
+        )]);
+
+        #[rustfmt::skip]
+        const TV: [(&str, bool, bool); 4] = [
+            ("S_INTERSECTS(geom, BBOX(179.5, -16.8, 179.9, -16.2))", false, true),
+            ("S_INTERSECTS(geom, BBOX(179.5, -16.8, -179.5, -16.2))", false, true),
+            ("S_INTERSECTS(geom, POINT(0 -16.5))", true, false),
+            ("S_WITHIN(POINT(-179.5 -16.5), geom)", false, true),
+        ];
+        let ctx1 = Context::new();
+        let ctx2 = Context::new().with_antimeridian();
+        assert!(!ctx1.antimeridian());
+        assert!(ctx2.antimeridian());
+        for (src, as_is, split) in TV {
+            let exp = cql2::expression(src)?;
+            assert_eq!(exp.eval(&ctx1, &feat)?, Q::Bool(as_is), "{src}");
+            assert_eq!(exp.eval(&ctx2, &feat)?, Q::Bool(split), "{src}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_strict_properties() -> Result<(), Box<dyn Error>> {
+        let feat = Resource::from([
+            ("population".into(), Q::Num(1000.0)),
+            ("name".into(), Q::Null),
+        ]);
+        let lax = Context::new();
+        let strict = Context::new().with_strict_properties();
+        assert!(!lax.strict_properties());
+        assert!(strict.strict_properties());
+
+        let exp = cql2::expression("populaton > 10")?;
+        assert_eq!(exp.eval(&lax, &feat)?, Q::Null);
+        assert!(exp.eval(&strict, &feat).is_err());
+
+        // known properties, incl. quoted and NULL ones, are fine...
+        let exp = cql2::expression(r#""population" > 10 AND name IS NULL"#)?;
+        assert_eq!(exp.eval(&lax, &feat)?, Q::Bool(true));
+        assert_eq!(exp.eval(&strict, &feat)?, Q::Bool(true));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_computed_properties() -> Result<(), Box<dyn Error>> {
+        use crate::ResourceExt;
+        use std::sync::{
+            Arc,
+            atomic::{AtomicUsize, Ordering},
+        };
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let calls_ = calls.clone();
+        let ctx = Context::new()
+            .with_computed("double_pop", move |r| {
+                calls_.fetch_add(1, Ordering::SeqCst);
+                match r.get("pop").map(|x| x.to_num()) {
+                    Some(Ok(x)) => Q::Num(2.0 * x),
+                    _ => Q::Null,
+                }
+            })
+            .with_strict_properties();
+        assert!(ctx.computed("double_pop").is_some());
+        assert!(ctx.computed("pop").is_none());
+
+        let feat = Resource::from([("pop".into(), Q::Num(600.0))]);
+        // not referenced; not invoked...
+        let exp = cql2::expression("pop > 500")?;
+        assert_eq!(exp.eval(&ctx, &feat)?, Q::Bool(true));
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+
+        for cql in ["double_pop > 1000", r#""double_pop" = 1200"#] {
+            let exp = cql2::expression(cql)?;
+            assert_eq!(exp.eval(&ctx, &feat)?, Q::Bool(true), "{cql}");
+        }
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+        // a stored property takes precedence...
+        let exp = cql2::expression("double_pop = 1")?;
+        let feat = feat.with("double_pop", Q::Num(1.0));
+        assert_eq!(feat["pop"], Q::Num(600.0));
+        assert_eq!(exp.eval(&ctx, &feat)?, Q::Bool(true));
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+        // an unknown one is still an error when strict...
+        let exp = cql2::expression("triple_pop > 1000")?;
+        assert!(exp.eval(&ctx, &feat).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_unbounded_intervals() -> Result<(), Box<dyn Error>> {
+        const CLOSED: &str = "INTERVAL('2021-01-01', '2021-12-31')";
+
+        let ctx = Context::new();
+        let feat = Resource::new();
+        let cases = [
+            // left-unbounded: (-infinity, 2020-01-01]...
+            ("T_BEFORE(INTERVAL('..', '2020-01-01'), {CLOSED})", true),
+            ("T_AFTER(INTERVAL('..', '2020-01-01'), {CLOSED})", false),
+            ("T_DISJOINT(INTERVAL('..', '2020-01-01'), {CLOSED})", true),
+            ("T_CONTAINS(INTERVAL('..', '2022-01-01'), {CLOSED})", true),
+            ("T_OVERLAPS(INTERVAL('..', '2021-06-01'), {CLOSED})", true),
+            // right-unbounded: [2021-06-01, +infinity)...
+            ("T_BEFORE(INTERVAL('2021-06-01', '..'), {CLOSED})", false),
+            ("T_DURING(INTERVAL('2021-06-01', '..'), {CLOSED})", false),
+            (
+                "T_OVERLAPPEDBY(INTERVAL('2021-06-01', '..'), {CLOSED})",
+                true,
+            ),
+            ("T_AFTER(INTERVAL('2022-06-01', '..'), {CLOSED})", true),
+            // fully unbounded...
+            ("T_CONTAINS(INTERVAL('..', '..'), {CLOSED})", true),
+            ("T_BEFORE(INTERVAL('..', '..'), {CLOSED})", false),
+            (
+                "T_STARTS(INTERVAL('..', '2020-01-01'), INTERVAL('..', '2021-01-01'))",
+                true,
+            ),
+            (
+                "T_FINISHES(INTERVAL('2022-01-01', '..'), INTERVAL('2021-01-01', '..'))",
+                true,
+            ),
+        ];
+        for (cql, expected) in cases {
+            let cql = cql.replace("{CLOSED}", CLOSED);
+            let exp = cql2::expression(&cql)?;
+            assert_eq!(exp.eval(&ctx, &feat)?, Q::Bool(expected), "{cql}");
+            // reducing literals yields the same outcome...
+            let mut exp = exp;
+            let reduced = E::reduce(&mut exp)?;
+            assert_eq!(reduced.eval(&ctx, &feat)?, Q::Bool(expected), "{cql}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_covers() -> Result<(), Box<dyn Error>> {
+        const POLY: &str = "POLYGON((0 0, 4 0, 4 4, 0 4, 0 0))";
+
+        let ctx = Context::new();
+        let feat = Resource::from([("geom".into(), Q::try_from_wkt(POLY)?)]);
+        // boundary points count for S_COVERS and S_COVEREDBY but not for
+        // S_CONTAINS and S_WITHIN...
+        #[rustfmt::skip]
+        let cases = [
+            ("S_CONTAINS(geom, POINT(4 2))",                false),
+            ("S_COVERS(geom, POINT(4 2))",                  true),
+            ("S_WITHIN(POINT(4 2), geom)",                  false),
+            ("S_COVEREDBY(POINT(4 2), geom)",               true),
+            ("S_CONTAINS(geom, LINESTRING(0 0, 4 0))",      false),
+            ("s_covers(geom, LINESTRING(0 0, 4 0))",        true),
+            ("S_COVERS(geom, POINT(2 2))",                  true),
+            ("S_COVERS(geom, POINT(5 5))",                  false),
+            ("S_COVEREDBY(geom, BBOX(0, 0, 4, 4))",         true),
+            ("S_COVEREDBY(geom, BBOX(1, 1, 4, 4))",         false),
+            ("S_COVERS(BBOX(0, 0, 4, 4), GEOMETRYCOLLECTION(POINT(0 0), POINT(4 4)))", true),
+        ];
+        for (cql, expected) in cases {
+            let exp = cql2::expression(cql)?;
+            assert_eq!(exp.eval(&ctx, &feat)?, Q::Bool(expected), "{cql}");
+            // literals are evaluated the same way...
+            let exp = cql2::expression(&cql.replace("geom", POLY))?;
+            assert_eq!(exp.eval(&ctx, &feat)?, Q::Bool(expected), "{cql}");
+        }
+
+        // ...and both are available in CQL2-JSON too...
+        let exp = crate::Expression::try_from_json(
+            r#"{ "op": "s_coveredBy", "args": [
+                { "type": "Point", "coordinates": [4, 2] }, { "property": "geom" }
+            ] }"#,
+        )?;
+        assert_eq!(exp.evaluate(&ctx, &feat)?, crate::Outcome::T);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_spatial_w_collection() -> Result<(), Box<dyn Error>> {
+        const GC: &str =
+            "GEOMETRYCOLLECTION(POINT(1 1), POLYGON((10 10, 12 10, 12 12, 10 12, 10 10)))";
+
+        let ctx = Context::new();
+        let feat = Resource::from([("geom".into(), Q::try_from_wkt(GC)?)]);
+        let cases = [
+            ("S_INTERSECTS(geom, BBOX(0, 0, 2, 2))", true),
+            ("S_INTERSECTS(geom, BBOX(11, 11, 13, 13))", true),
+            ("S_INTERSECTS(BBOX(5, 5, 6, 6), geom)", false),
+            ("S_DISJOINT(geom, BBOX(5, 5, 6, 6))", true),
+            ("S_DISJOINT(geom, BBOX(0, 0, 2, 2))", false),
+            ("S_WITHIN(geom, BBOX(0, 0, 20, 20))", true),
+            ("S_WITHIN(geom, BBOX(0, 0, 2, 2))", false),
+            ("S_CONTAINS(BBOX(0, 0, 20, 20), geom)", true),
+            ("S_CONTAINS(BBOX(9, 9, 13, 13), geom)", false),
+        ];
+        for (cql, expected) in cases {
+            let exp = cql2::expression(cql)?;
+            assert_eq!(exp.eval(&ctx, &feat)?, Q::Bool(expected), "{cql}");
+        }
+
+        // literal collections are evaluated the same way...
+        let exp = cql2::expression(&format!("S_INTERSECTS({GC}, BBOX(0, 0, 2, 2))"))?;
+        assert_eq!(exp.eval(&ctx, &feat)?, Q::Bool(true));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_spatial_property_property() -> Result<(), Box<dyn Error>> {
+        use crate::ResourceExt;
+
+        let ctx = Context::new();
+        let feat = Resource::from_json_object(&serde_json::json!({
+            "geom_a": { "type": "Polygon", "coordinates": [[[0, 0], [4, 0], [4, 4], [0, 4], [0, 0]]] },
+            "geom_b": { "type": "Point", "coordinates": [1, 1] },
+            "name": "a",
+        }))?;
+        let cases = [
+            (r#"S_INTERSECTS("geom_a", "geom_b")"#, Q::Bool(true)),
+            ("S_INTERSECTS(geom_b, geom_a)", Q::Bool(true)),
+            ("S_CONTAINS(geom_a, geom_b)", Q::Bool(true)),
+            ("S_WITHIN(geom_a, geom_b)", Q::Bool(false)),
+            ("S_DISJOINT(geom_a, geom_b)", Q::Bool(false)),
+            ("S_EQUALS(geom_a, geom_a)", Q::Bool(true)),
+            // a missing property evaluates to NULL which propagates...
+            (r#"S_INTERSECTS("geom_a", "geom_c")"#, Q::Null),
+            ("S_WITHIN(geom_c, geom_b)", Q::Null),
+        ];
+        for (cql, expected) in cases {
+            let mut exp = cql2::expression(cql)?;
+            // property-property predicates are left alone when reduced...
+            let reduced = E::reduce(&mut exp)?;
+            assert!(matches!(reduced, E::Dyadic(..)), "{cql}");
+            assert_eq!(reduced.eval(&ctx, &feat)?, expected, "{cql}");
+        }
+
+        // a property that doesn't resolve to a geometry is an error...
+        let exp = cql2::expression("S_INTERSECTS(geom_a, name)")?;
+        assert!(exp.eval(&ctx, &feat).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_array_fns() -> Result<(), Box<dyn Error>> {
+        let ctx = Context::new();
+        let feat = Resource::from([
+            (
+                "tags".into(),
+                Q::List(vec![
+                    Q::new_plain_str("a"),
+                    Q::new_plain_str("b"),
+                    Q::Int(3),
+                    Q::Null,
+                ]),
+            ),
+            ("empty".into(), Q::List(vec![])),
+            ("name".into(), Q::new_plain_str("foo")),
+        ]);
+        let cases = [
+            (r#"array_length("tags") > 3"#, Q::Bool(true)),
+            ("array_length(empty) = 0", Q::Bool(true)),
+            ("array_length(('x', 'y'))", Q::Int(2)),
+            ("array_length(missing)", Q::Null),
+            // indices are 1-based...
+            ("nth(tags, 1) = 'a'", Q::Bool(true)),
+            ("nth(tags, 2)", Q::new_plain_str("b")),
+            ("nth(tags, 1 + 2) * 2 = 6", Q::Bool(true)),
+            ("nth(tags, 4)", Q::Null),
+            ("nth(tags, missing)", Q::Null),
+            ("nth(tags, array_length(tags)) IS NULL", Q::Bool(true)),
+        ];
+        for (cql, expected) in cases {
+            let exp = cql2::expression(cql)?;
+            assert_eq!(exp.eval(&ctx, &feat)?, expected, "{cql}");
+        }
+
+        // out-of-bounds indices, non-arrays and wrong arguments are errors...
+        for cql in [
+            "nth(tags, 0)",
+            "nth(tags, 5)",
+            "nth(empty, 1)",
+            "nth(tags, 1.5)",
+            "nth(name, 1)",
+            "array_length(name)",
+            "array_length(tags, 1)",
+            "nth(tags)",
+        ] {
+            let exp = cql2::expression(cql)?;
+            assert!(exp.eval(&ctx, &feat).is_err(), "{cql}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bytes() -> Result<(), Box<dyn Error>> {
+        let mut ctx = Context::new();
+        ctx.register_builtins();
+        let feat = Resource::from([
+            ("thumbnail".into(), Q::Bytes(vec![0x89, 0x50, 0x4e, 0x47])),
+            ("empty".into(), Q::Bytes(vec![])),
+            ("hash".into(), Q::Bytes(vec![0xca, 0xfe])),
+            ("hash2".into(), Q::Bytes(vec![0xca, 0xfe])),
+            ("geom".into(), Q::try_from_wkt("LINESTRING(0 0, 3 4)")?),
+        ]);
+        #[rustfmt::skip]
+        let cases = [
+            ("length(thumbnail) > 0",   Q::Bool(true)),
+            ("length(thumbnail) = 4",   Q::Bool(true)),
+            ("length(empty) = 0",       Q::Bool(true)),
+            ("length(geom) = 5",        Q::Bool(true)),
+            ("hex(hash) = 'cafe'",      Q::Bool(true)),
+            ("hex('AB') = '4142'",      Q::Bool(true)),
+            ("hash = hash2",            Q::Bool(true)),
+            ("hash < thumbnail",        Q::Bool(false)),
+            ("hex(missing) = 'cafe'",   Q::Null),
+            ("length(missing) > 0",     Q::Null),
+        ];
+        for (cql, expected) in cases {
+            let exp = cql2::expression(cql)?;
+            assert_eq!(exp.eval(&ctx, &feat)?, expected, "{cql}");
+        }
+
+        for cql in ["hex(1) = '01'", "hex(hash, hash) = 'cafe'", "hash = 'cafe'"] {
+            let exp = cql2::expression(cql)?;
+            assert!(exp.eval(&ctx, &feat).is_err(), "{cql}");
+        }
+
+        // binary values can't be bound to parameters...
+        let exp = crate::Expression::try_from_text("hash = ${h}")?;
+        let params = HashMap::from([("h".into(), Q::Bytes(vec![0xca, 0xfe]))]);
+        assert!(exp.bind(&params).is_err());
+        // ...nested or not, but only if they're used...
+        let params = HashMap::from([("h".into(), Q::List(vec![Q::Bytes(vec![0xca])]))]);
+        assert!(exp.bind(&params).is_err());
+        let exp = crate::Expression::try_from_text("hash = ${g}")?;
+        assert!(exp.bind(&params).is_ok());
+        assert!(E::try_from(Q::Bytes(vec![])).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat() -> Result<(), Box<dyn Error>> {
+        let ctx = Context::new();
+        let feat = Resource::from([
+            ("first".into(), Q::new_plain_str("John")),
+            ("last".into(), Q::new_plain_str("Smith")),
+            ("age".into(), Q::Int(42)),
+            ("height".into(), Q::Num(1.85)),
+            ("geom".into(), Q::try_from_wkt("POINT (1 2)")?),
+        ]);
+        let cases = [
+            (
+                r#"concat("first", ' ', "last")"#,
+                Q::new_plain_str("John Smith"),
+            ),
+            ("concat(first, ' ', last) LIKE '%Smith%'", Q::Bool(true)),
+            ("concat(last)", Q::new_plain_str("Smith")),
+            (
+                "concat(first, ' is ', age, ' and ', height)",
+                Q::new_plain_str("John is 42 and 1.85"),
+            ),
+            ("concat('x', 2 + 3, true)", Q::new_plain_str("x5true")),
+            ("concat(first, missing, last)", Q::Null),
+            ("concat(first, missing) IS NULL", Q::Bool(true)),
+        ];
+        for (cql, expected) in cases {
+            let exp = cql2::expression(cql)?;
+            assert_eq!(exp.eval(&ctx, &feat)?, expected, "{cql}");
+        }
+
+        // leading and trailing spaces are kept...
+        let exp = cql2::expression("concat(' ', first, ' ')")?;
+        assert_eq!(exp.eval(&ctx, &feat)?.to_str()?.as_str(), " John ");
+
+        for cql in ["concat()", "concat(first, geom)"] {
+            let exp = cql2::expression(cql)?;
+            assert!(exp.eval(&ctx, &feat).is_err(), "{cql}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_between_non_numeric() -> Result<(), Box<dyn Error>> {
+        let ctx = Context::new();
+        let feat = Resource::from([
+            (
+                "event_date".into(),
+                Q::Instant(Bound::try_new_date("2020-06-15")?),
+            ),
+            (
+                "event_ts".into(),
+                Q::Instant(Bound::try_new_timestamp("2021-01-01T10:00:00Z")?),
+            ),
+            ("name".into(), Q::new_plain_str("Kiwi")),
+            ("pop".into(), Q::Int(42)),
+        ]);
+        #[rustfmt::skip]
+        let cases = [
+            ("event_date BETWEEN DATE('2020-01-01') AND DATE('2020-12-31')",     Q::Bool(true)),
+            ("event_date NOT BETWEEN DATE('2020-01-01') AND DATE('2020-12-31')", Q::Bool(false)),
+            ("event_date BETWEEN DATE('2021-01-01') AND DATE('2021-12-31')",     Q::Bool(false)),
+            // bounds are inclusive, and may be given in any order...
+            ("event_date BETWEEN DATE('2020-06-15') AND DATE('2020-01-01')",     Q::Bool(true)),
+            (
+                "event_ts BETWEEN TIMESTAMP('2021-01-01T00:00:00Z') AND DATE('2021-01-02')",
+                Q::Bool(true),
+            ),
+            ("name BETWEEN 'Apple' AND 'Mango'",                                 Q::Bool(true)),
+            ("name BETWEEN 'Lemon' AND 'Mango'",                                 Q::Bool(false)),
+            ("name NOT BETWEEN 'Lemon' AND 'Mango'",                             Q::Bool(true)),
+            ("'b' BETWEEN 'a' AND 'c'",                                          Q::Bool(true)),
+            ("missing BETWEEN 'a' AND 'c'",                                      Q::Null),
+            ("name BETWEEN missing AND 'Mango'",                                 Q::Null),
+            // numbers still work as before...
+            ("pop BETWEEN 40 AND 42.5",                                          Q::Bool(true)),
+        ];
+        for (cql, expected) in cases {
+            let exp = cql2::expression(cql)?;
+            assert_eq!(exp.eval(&ctx, &feat)?, expected, "{cql}");
+        }
+
+        // operands must be of the same kind...
+        for cql in [
+            "name BETWEEN 1 AND 10",
+            "pop BETWEEN 'a' AND 'z'",
+            "event_date BETWEEN 'a' AND DATE('2020-12-31')",
+        ] {
+            let exp = cql2::expression(cql)?;
+            assert!(exp.eval(&ctx, &feat).is_err(), "{cql}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_interval_from_str() -> Result<(), Box<dyn Error>> {
+        let ctx = Context::new();
+        let feat = Resource::from([
+            ("closed".into(), Q::new_plain_str("2020-01-01/2021-12-31")),
+            ("open_start".into(), Q::new_plain_str("../2021-12-31")),
+            ("open_end".into(), Q::new_plain_str("2020-01-01/..")),
+            ("bad".into(), Q::new_plain_str("2021-12-31/2020-01-01")),
+            ("year".into(), Q::Int(2020)),
+        ]);
+        let cases = [
+            (
+                "T_INTERSECTS(TIMESTAMP('2021-06-01T00:00:00Z'), interval_from_str(closed))",
+                Q::Bool(true),
+            ),
+            (
+                "T_AFTER(DATE('2022-01-01'), interval_from_str(closed))",
+                Q::Bool(true),
+            ),
+            (
+                "T_BEFORE(DATE('1900-01-01'), interval_from_str(open_start))",
+                Q::Bool(false),
+            ),
+            (
+                "T_AFTER(DATE('2999-01-01'), interval_from_str(open_end))",
+                Q::Bool(false),
+            ),
+            (
+                "T_INTERSECTS(interval_from_str(open_start), interval_from_str(open_end))",
+                Q::Bool(true),
+            ),
+            (
+                "T_DURING(interval_from_str(closed), interval_from_str(missing))",
+                Q::Null,
+            ),
+        ];
+        for (cql, expected) in cases {
+            let exp = cql2::expression(cql)?;
+            assert_eq!(exp.eval(&ctx, &feat)?, expected, "{cql}");
+        }
+
+        let exp = cql2::expression("interval_from_str(open_end)")?;
+        let start = Bound::try_new_date("2020-01-01")?;
+        assert_eq!(exp.eval(&ctx, &feat)?, Q::Interval(start, Bound::None));
+
+        for cql in [
+            "interval_from_str(bad)",
+            "interval_from_str(year)",
+            "interval_from_str(closed, open_end)",
+        ] {
+            let exp = cql2::expression(cql)?;
+            assert!(exp.eval(&ctx, &feat).is_err(), "{cql}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_int_exact() -> Result<(), Box<dyn Error>> {
+        let ctx = Context::new();
+        // 2^53 + 1 --not representable as an f64...
+        let feat = Resource::from([
+            ("fid".into(), Q::try_from(9007199254740993_i64)?),
+            ("x".into(), Q::Num(42.0)),
+        ]);
+
+        let exp = cql2::expression("fid = 9007199254740993")?;
+        assert_eq!(exp.eval(&ctx, &feat)?, Q::Bool(true));
+        let exp = cql2::expression("fid = 9007199254740992")?;
+        assert_eq!(exp.eval(&ctx, &feat)?, Q::Bool(false));
+        let exp = cql2::expression("fid > 9007199254740992")?;
+        assert_eq!(exp.eval(&ctx, &feat)?, Q::Bool(true));
+        let exp = cql2::expression("fid IN (1, 9007199254740992)")?;
+        assert_eq!(exp.eval(&ctx, &feat)?, Q::Bool(false));
+
+        // mixed comparisons promote to f64...
+        let exp = cql2::expression("x = 42 AND x < 42.5 AND 42 = 42.0")?;
+        assert_eq!(exp.eval(&ctx, &feat)?, Q::Bool(true));
+
+        // negation and arithmetic...
+        let exp = cql2::expression("-fid < 0 AND x + 1 = 43")?;
+        assert_eq!(exp.eval(&ctx, &feat)?, Q::Bool(true));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_exp() -> Result<(), Box<dyn Error>> {
+        let ctx = Context::new();
+        let feat = Resource::from([("x".into(), Q::Int(2))]);
+        let exp = |b: f64| E::Dyadic(Op::Exp, Box::new(E::Id("x".into())), Box::new(E::Num(b)));
+
+        #[rustfmt::skip]
+        let cases = [
+            (10.0,  1024.0),
+            (0.0,   1.0),
+            (-1.0,  0.5),
+            (-10.0, 1.0 / 1024.0),
+            (0.5,   std::f64::consts::SQRT_2),
+            (-0.5,  std::f64::consts::FRAC_1_SQRT_2),
+        ];
+        for (b, expected) in cases {
+            let actual = exp(b).eval(&ctx, &feat)?.to_num()?;
+            assert!((actual - expected).abs() < 1e-12, "2 ^ {b} = {actual}");
+            // ...and the same when reduced...
+            let mut e = E::Dyadic(Op::Exp, Box::new(E::Num(2.0)), Box::new(E::Num(b)));
+            let reduced = E::reduce(&mut e)?;
+            assert!(
+                matches!(reduced, E::Num(y) if (y - expected).abs() < 1e-12),
+                "{reduced:?}"
+            );
+        }
+
+        let exp = cql2::expression("x ^ 10 = 1024 AND x ^ 0.5 > 1.41 AND x ^ 0.5 < 1.42")?;
+        assert_eq!(exp.eval(&ctx, &feat)?, Q::Bool(true));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_int_arithmetic() -> Result<(), Box<dyn Error>> {
+        let ctx = Context::new();
+        let feat = Resource::from([("fid".into(), Q::Int((1 << 53) + 1))]);
+
+        #[rustfmt::skip]
+        const TV: [(&str, bool); 6] = [
+            ("fid + 0 = 9007199254740993",    true),
+            ("fid + 0 = 9007199254740992",    false),
+            ("fid - 1 = 9007199254740992",    true),
+            ("fid * 1 = 9007199254740993",    true),
+            ("fid % 2 = 1",                   true),
+            ("2 ^ 53 + 1 = 9007199254740993", true),
+        ];
+        for (src, expected) in TV {
+            let exp = cql2::expression(src)?;
+            assert_eq!(exp.eval(&ctx, &feat)?, Q::Bool(expected), "{src}");
+        }
+
+        // integers stay so when reduced, unless the result isn't one...
+        #[rustfmt::skip]
+        let cases = [
+            ("9007199254740992 + 1",    E::Int(9007199254740993)),
+            ("7 div 2",                 E::Int(1)),
+            ("-7 % 2",                  E::Int(-1)),
+            ("7 / 2",                   E::Num(3.5)),
+            ("2 ^ -1",                  E::Num(0.5)),
+            ("2 ^ 62",                  E::Int(1 << 62)),
+            ("2 ^ 64",                  E::Num(2.0_f64.powi(64))),
+            ("9223372036854775807 + 1", E::Num(2.0_f64.powi(63))),
+            ("2 * 1.5",                 E::Num(3.0)),
+        ];
+        for (src, expected) in cases {
+            let mut exp = cql2::expression(&format!("x = {src}"))?;
+            let expected = E::Dyadic(Op::Eq, Box::new(E::Id("x".into())), Box::new(expected));
+            assert_eq!(E::reduce(&mut exp)?, expected, "{src}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_in_list_w_nulls() -> Result<(), Box<dyn Error>> {
+        let ctx = Context::new();
+        let feat = Resource::from([
+            ("x".into(), Q::Num(1.0)),
+            ("name".into(), Q::new_plain_str("foo")),
+            ("tags".into(), Q::List(vec![Q::Num(2.0), Q::Null])),
+        ]);
+
+        // `missing` is unset and evaluates to NULL...
+        #[rustfmt::skip]
+        const TV: [(&str, Q); 10] = [
+            ("x IN (1, missing)",                 Q::Bool(true)),
+            ("x IN (2, missing)",                 Q::Null),
+            ("x NOT IN (1, missing)",             Q::Bool(false)),
+            ("x NOT IN (2, missing)",             Q::Null),
+            ("x IN (2, 3)",                       Q::Bool(false)),
+            ("x NOT IN (2, 3)",                   Q::Bool(true)),
+            ("name IN (missing, 'foo')",          Q::Bool(true)),
+            ("x IN tags",                         Q::Null),
+            ("x IN (2, missing) OR x = 1",        Q::Bool(true)),
+            ("x IN (2, missing) AND x = 1",       Q::Null),
+        ];
+        for (src, expected) in TV {
+            let exp = cql2::expression(src)?;
+            assert_eq!(exp.eval(&ctx, &feat)?, expected, "{src}");
+        }
+
+        // non-NULL members must still be of the same type...
+        let exp = cql2::expression("x IN ('a', missing)")?;
+        assert!(exp.eval(&ctx, &feat).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_numeric_epsilon() -> Result<(), Box<dyn Error>> {
+        let feat = Resource::from([
+            ("ratio".into(), Q::Num(0.09999999999999998)),
+            ("big".into(), Q::Num(1.0e12 + 1.0)),
+        ]);
+
+        #[rustfmt::skip]
+        const TV: [(&str, bool, bool); 6] = [
+            ("ratio = 0.1",         false, true),
+            ("ratio <> 0.1",        true,  false),
+            ("big = 1000000000000", false, true),
+            ("ratio = 0.2",         false, false),
+            // ordering remains strict...
+            ("ratio < 0.1",         true,  true),
+            ("ratio >= 0.1",        false, false),
+        ];
+        let exact = Context::new();
+        let approx = Context::new().with_numeric_epsilon(1e-9);
+        assert_eq!(exact.numeric_epsilon(), None);
+        assert_eq!(approx.numeric_epsilon(), Some(1e-9));
+        // invalid values are ignored...
+        let ignored = Context::new().with_numeric_epsilon(-1.0);
+        assert!(ignored.numeric_epsilon().is_none());
+        for (src, expected_exact, expected_approx) in TV {
+            let exp = cql2::expression(src)?;
+            assert_eq!(exp.eval(&exact, &feat)?, Q::Bool(expected_exact), "{src}");
+            assert_eq!(exp.eval(&approx, &feat)?, Q::Bool(expected_approx), "{src}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_in_list_w_ctx() -> Result<(), Box<dyn Error>> {
+        let feat = Resource::from([
+            ("ratio".into(), Q::Num(0.09999999999999998)),
+            ("code".into(), Q::new_plain_str("10")),
+            ("fid".into(), Q::Int((1 << 53) + 1)),
+        ]);
+
+        #[rustfmt::skip]
+        const TV: [(&str, &str, bool); 5] = [
+            ("ratio", "0.1",              true),
+            ("code",  "10",               true),
+            ("code",  "10.5",             false),
+            // integers are matched exactly...
+            ("fid",   "9007199254740992", false),
+            ("fid",   "9007199254740993", true),
+        ];
+        let strict = Context::new();
+        let lenient = Context::new()
+            .with_numeric_epsilon(1e-9)
+            .with_implicit_coercion();
+        for (x, y, expected) in TV {
+            let eq = cql2::expression(&format!("{x} = {y}"))?;
+            let in_list = cql2::expression(&format!("{x} IN (0.5, {y})"))?;
+            let not_in_list = cql2::expression(&format!("{x} NOT IN (0.5, {y})"))?;
+            // `x IN (..., y)` agrees w/ `x = y`...
+            assert_eq!(eq.eval(&lenient, &feat)?, Q::Bool(expected), "{x} = {y}");
+            assert_eq!(
+                in_list.eval(&lenient, &feat)?,
+                Q::Bool(expected),
+                "{x} IN {y}"
+            );
+            assert_eq!(
+                not_in_list.eval(&lenient, &feat)?,
+                Q::Bool(!expected),
+                "{x}"
+            );
+            // ...incl. failing to compare a string to numbers by default...
+            match eq.eval(&strict, &feat) {
+                Ok(z) => assert_eq!(in_list.eval(&strict, &feat)?, z, "{x} IN {y}"),
+                Err(_) => assert!(in_list.eval(&strict, &feat).is_err(), "{x} IN {y}"),
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_temporal_tolerance() -> Result<(), Box<dyn Error>> {
+        // stored truncated to milliseconds...
+        let feat = Resource::from([(
+            "ts".into(),
+            Q::Instant(Bound::try_new_timestamp("2010-02-10T05:29:20.073Z")?),
+        )]);
+
+        #[rustfmt::skip]
+        const TV: [(&str, bool, bool); 6] = [
+            ("T_EQUALS(ts, TIMESTAMP('2010-02-10T05:29:20.073225Z'))",     false, true),
+            ("T_INTERSECTS(ts, TIMESTAMP('2010-02-10T05:29:20.073225Z'))", false, true),
+            ("T_DISJOINT(ts, TIMESTAMP('2010-02-10T05:29:20.073225Z'))",   true,  false),
+            ("T_EQUALS(ts, TIMESTAMP('2010-02-10T05:29:20.075Z'))",        false, false),
+            // ordering remains strict...
+            ("T_AFTER(TIMESTAMP('2010-02-10T05:29:20.073225Z'), ts)",      true,  true),
+            ("T_BEFORE(ts, TIMESTAMP('2010-02-10T05:29:20.073225Z'))",     true,  true),
+        ];
+        let exact = Context::new();
+        let ms = Duration::from_millis(1);
+        let approx = Context::new().with_temporal_tolerance(ms);
+        assert_eq!(exact.temporal_tolerance(), None);
+        assert_eq!(approx.temporal_tolerance(), Some(ms));
+        for (src, expected_exact, expected_approx) in TV {
+            let exp = cql2::expression(src)?;
+            assert_eq!(exp.eval(&exact, &feat)?, Q::Bool(expected_exact), "{src}");
+            assert_eq!(exp.eval(&approx, &feat)?, Q::Bool(expected_approx), "{src}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_empty_as_null() -> Result<(), Box<dyn Error>> {
+        let feat = Resource::from([
+            ("geom".into(), Q::Geom(G::Null)),
+            ("tags".into(), Q::List(vec![])),
+            ("site".into(), Q::try_from_wkt("POINT(1 2)")?),
+            ("name".into(), Q::new_plain_str("")),
+        ]);
+
+        #[rustfmt::skip]
+        const TV: [(&str, bool, bool); 7] = [
+            ("geom IS NULL",     false, true),
+            ("geom IS NOT NULL", true,  false),
+            ("tags IS NULL",     false, true),
+            ("site IS NULL",     false, false),
+            // empty strings are not...
+            ("name IS NULL",     false, false),
+            ("foo IS NULL",      true,  true),
+            ("foo IS NOT NULL",  false, false),
+        ];
+        let strict = Context::new();
+        let loose = Context::new().with_empty_as_null();
+        assert!(!strict.empty_as_null());
+        assert!(loose.empty_as_null());
+        for (src, expected_strict, expected_loose) in TV {
+            let exp = cql2::expression(src)?;
+            assert_eq!(exp.eval(&strict, &feat)?, Q::Bool(expected_strict), "{src}");
+            assert_eq!(exp.eval(&loose, &feat)?, Q::Bool(expected_loose), "{src}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_array_of_geometries() -> Result<(), Box<dyn Error>> {
+        let ctx = Context::new();
+        // same square as below but its ring starts at a different vertex and
+        // is traversed in the opposite direction...
+        let feat = Resource::from([(
+            "geoms".into(),
+            Q::List(vec![
+                Q::try_from_wkt("POINT(9 9)")?,
+                Q::try_from_wkt("POLYGON((1 1, 1 0, 0 0, 0 1, 1 1))")?,
+            ]),
+        )]);
+
+        #[rustfmt::skip]
+        const TV: [(&str, bool); 4] = [
+            ("A_OVERLAPS((POLYGON((0 0, 1 0, 1 1, 0 1, 0 0))), geoms)", true),
+            ("A_OVERLAPS((POINT(5 5), POLYGON((0 0, 1 0, 1 1, 0 1, 0 0))), geoms)", true),
+            ("A_CONTAINS(geoms, (POLYGON((0 0, 1 0, 1 1, 0 1, 0 0)), POINT(9 9)))", true),
+            ("A_OVERLAPS((POINT(5 5), LINESTRING(0 0, 1 1)), geoms)", false),
+        ];
+        for (src, expected) in TV {
+            let exp = cql2::expression(src)?;
+            assert_eq!(exp.eval(&ctx, &feat)?, Q::Bool(expected), "{src}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_array_of_timestamps() -> Result<(), Box<dyn Error>> {
+        let ctx = Context::new();
+        // 10:00 UTC expressed in a +02:00 time-zone...
+        let z = "2020-01-01T10:00:00Z"
+            .parse::<jiff::Timestamp>()?
+            .to_zoned(TimeZone::fixed(jiff::tz::offset(2)));
+        let feat = Resource::from([(
+            "times".into(),
+            Q::List(vec![
+                Q::Instant(Bound::Timestamp(z)),
+                Q::try_from_date_str("2021-06-30")?,
+            ]),
+        )]);
+
+        #[rustfmt::skip]
+        const TV: [(&str, bool); 4] = [
+            ("A_OVERLAPS((TIMESTAMP('2020-01-01T10:00:00Z')), times)", true),
+            ("A_OVERLAPS((DATE('2019-01-01'), TIMESTAMP('2020-01-01T10:00:00Z')), times)", true),
+            ("A_EQUALS(times, (TIMESTAMP('2020-01-01T10:00:00Z'), DATE('2021-06-30')))", true),
+            ("A_OVERLAPS((TIMESTAMP('2020-01-01T12:00:00Z')), times)", false),
+        ];
+        for (src, expected) in TV {
+            let exp = cql2::expression(src)?;
+            assert_eq!(exp.eval(&ctx, &feat)?, Q::Bool(expected), "{src}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_like_accenti_casei() -> Result<(), Box<dyn Error>> {
+        let ctx = Context::new();
+        let feat = Resource::from([("name".into(), Q::new_plain_str("Chișinău"))]);
+
+        const TV: [(&str, bool); 6] = [
+            ("ACCENTI(CASEI(name)) LIKE accenti(casei('cHis%'))", true),
+            ("CASEI(ACCENTI(name)) LIKE casei(accenti('cHis%'))", true),
+            ("ACCENTI(name) LIKE casei('cHis%')", true),
+            ("name LIKE accenti(casei('cHis%'))", true),
+            ("CASEI(name) LIKE casei('cHis%')", false),
+            ("name NOT LIKE accenti(casei('cHis%'))", false),
+        ];
+        for (ndx, (src, expected)) in TV.iter().enumerate() {
+            let exp = cql2::expression(src)?;
+            assert_eq!(exp.eval(&ctx, &feat)?, Q::Bool(*expected), "#{ndx}: {src}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_normalization_form() -> Result<(), Box<dyn Error>> {
+        // 'ﬁ' is the U+FB01 ligature...
+        let feat = Resource::from([("name".into(), Q::new_plain_str("ﬁle"))]);
+        let eq = cql2::expression("ACCENTI(name) = accenti('file')")?;
+        let like = cql2::expression("ACCENTI(name) LIKE accenti('fi%')")?;
+        let plain = cql2::expression("name = 'file'")?;
+
+        #[rustfmt::skip]
+        const TV: [(NormalizationForm, bool); 4] = [
+            (NormalizationForm::Nfc,  false),
+            (NormalizationForm::Nfd,  false),
+            (NormalizationForm::Nfkc, true),
+            (NormalizationForm::Nfkd, true),
+        ];
+        for (form, expected) in TV {
+            let ctx = Context::new().with_normalization(form);
+            assert_eq!(ctx.normalization(), form);
+            assert_eq!(eq.eval(&ctx, &feat)?, Q::Bool(expected), "{form:?}");
+            assert_eq!(like.eval(&ctx, &feat)?, Q::Bool(expected), "{form:?}");
+            // only accent-insensitive comparisons are affected...
+            assert_eq!(plain.eval(&ctx, &feat)?, Q::Bool(false), "{form:?}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_case_folding() -> Result<(), Box<dyn Error>> {
+        let feat = Resource::from([
+            ("name".into(), Q::new_plain_str("Straße")),
+            ("city".into(), Q::new_plain_str("DİYARBAKIR")),
+            ("town".into(), Q::new_plain_str("ISTANBUL")),
+        ]);
+
+        #[rustfmt::skip]
+        const TV: [(&str, bool, bool); 7] = [
+            // (expression, w/ full folding, w/ turkic folding)
+            (r#"CASEI("name") = casei('STRASSE')"#,                  true,  true),
+            ("CASEI(name) LIKE casei('STRAS%')",                     true,  true),
+            ("ACCENTI(CASEI(name)) = accenti(casei('strasse'))",     true,  true),
+            ("CASEI(city) = casei('diyarbakır')",                    false, true),
+            ("CASEI(city) LIKE casei('diyar%')",                     false, true),
+            ("ACCENTI(CASEI(city)) = accenti(casei('diyarbakır'))",  false, true),
+            ("CASEI(town) = casei('istanbul')",                      true,  false),
+        ];
+        for folding in [CaseFolding::Full, CaseFolding::Turkic] {
+            let ctx = Context::new().with_case_folding(folding);
+            assert_eq!(ctx.case_folding(), folding);
+            for (src, full, turkic) in TV {
+                let expected = match folding {
+                    CaseFolding::Full => full,
+                    CaseFolding::Turkic => turkic,
+                };
+                let exp = cql2::expression(src)?;
+                assert_eq!(
+                    exp.eval(&ctx, &feat)?,
+                    Q::Bool(expected),
+                    "{folding:?}: {src}"
+                );
+            }
+        }
+
+        // full folding is the default...
+        assert_eq!(Context::new().case_folding(), CaseFolding::Full);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_collation() -> Result<(), Box<dyn Error>> {
+        let between = cql2::expression(r#""name" BETWEEN 'a' AND 'ö'"#)?;
+        let before_p = cql2::expression("name < 'p'")?;
+
+        #[rustfmt::skip]
+        const TV: [(&str, Option<&str>, bool, bool); 9] = [
+            // (name, locale, BETWEEN 'a' AND 'ö', < 'p')
+            ("Zorn",   None,       false, true),
+            ("Zorn",   Some("en"), false, false),
+            ("Zorn",   Some("sv"), true,  false),
+            ("oslo",   None,       true,  true),
+            ("oslo",   Some("en"), false, true),
+            ("oslo",   Some("sv"), true,  true),
+            ("örebro", None,       false, false),
+            ("örebro", Some("en"), false, true),
+            ("örebro", Some("sv"), false, false),
+        ];
+        for (name, locale, expected_between, expected_before_p) in TV {
+            let ctx = match locale {
+                Some(x) => Context::new().try_with_collation(x)?,
+                None => Context::new(),
+            };
+            assert_eq!(ctx.collation(), locale);
+            let feat = Resource::from([("name".into(), Q::new_plain_str(name))]);
+            let actual = between.eval(&ctx, &feat)?;
+            assert_eq!(actual, Q::Bool(expected_between), "{name} {locale:?}");
+            let actual = before_p.eval(&ctx, &feat)?;
+            assert_eq!(actual, Q::Bool(expected_before_p), "{name} {locale:?}");
+        }
+
+        assert!(Context::new().try_with_collation("!!").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(not(feature = "geos"))]
+    fn test_spatial_disabled() -> Result<(), Box<dyn Error>> {
+        const TV: [&str; 4] = [
+            "S_INTERSECTS(POINT(1 1), BBOX(0, 0, 2, 2))",
+            "S_WITHIN(geom, POLYGON((0 0, 2 0, 2 2, 0 2, 0 0)))",
+            "area(BBOX(0, 0, 2, 2)) > 1",
+            "bbox_minx(geom) = 1",
+        ];
+
+        let ctx = Context::new();
+        let feat = Resource::from([("geom".into(), Q::Geom(G::try_from("POINT(1 1)")?))]);
+        for cql in TV {
+            let exp = cql2::expression(cql)?;
+            let x = exp.eval(&ctx, &feat).expect_err("Expected an error");
+            assert!(matches!(x, MyError::Unsupported(_)), "{cql}");
+        }
+
+        // ...while NULL operands still yield NULL, and pure ones still work...
+        let exp = cql2::expression("S_INTERSECTS(nope, POINT(1 1))")?;
+        assert_eq!(exp.eval(&ctx, &Resource::new())?, Q::Null);
+        let exp = cql2::expression("num_geometries(MULTIPOINT(0 0, 1 1)) = 2")?;
+        assert_eq!(exp.eval(&ctx, &feat)?, Q::Bool(true));
+
+        Ok(())
+    }
 }
@@ -5,7 +5,7 @@
 //! Expressions evaluation context.
 //!
 
-use crate::{Context, G, GTrait, QString};
+use crate::{BBox, Context, G, GTrait, QString};
 use core::fmt;
 use jiff::{Zoned, tz::TimeZone};
 use std::any::Any;
@@ -39,6 +39,8 @@ type GenericFn = Box<dyn Fn(Vec<Box<dyn Any>>) -> Option<Box<dyn Any>> + Send +
 pub struct FnInfo {
     pub(crate) closure: GenericFn,
     pub(crate) arg_types: Vec<ExtDataType>,
+    // number of leading arguments that must be present in a call...
+    pub(crate) required: usize,
     pub(crate) result_type: ExtDataType,
 }
 
@@ -46,6 +48,7 @@ impl fmt::Debug for FnInfo {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("FnInfo")
             .field("arg_types", &self.arg_types)
+            .field("required", &self.required)
             .field("return_type", &self.result_type)
             .finish()
     }
@@ -142,6 +145,17 @@ pub(crate) fn add_builtins(ctx: &mut Context) {
         },
     );
 
+    let exp = |x: f64| x.exp();
+    ctx.register(
+        "exp",
+        vec![ExtDataType::Num],
+        ExtDataType::Num,
+        move |args| {
+            let x = args.first()?.downcast_ref::<f64>()?;
+            Some(Box::new(exp(*x)))
+        },
+    );
+
     let ln = |x: f64| x.ln();
     ctx.register(
         "ln",
@@ -153,6 +167,38 @@ pub(crate) fn add_builtins(ctx: &mut Context) {
         },
     );
 
+    let log = |x: f64| x.log10();
+    ctx.register(
+        "log",
+        vec![ExtDataType::Num],
+        ExtDataType::Num,
+        move |args| {
+            let x = args.first()?.downcast_ref::<f64>()?;
+            Some(Box::new(log(*x)))
+        },
+    );
+
+    // half-way cases are rounded away from zero. a negative number of digits
+    // rounds to the left of the decimal point; e.g. `round(1250, -2)` is 1300...
+    let round = |x: f64, digits: f64| {
+        let factor = 10_f64.powi(digits.trunc() as i32);
+        (x * factor).round() / factor
+    };
+    ctx.register_with_optional(
+        "round",
+        vec![ExtDataType::Num, ExtDataType::Num],
+        1,
+        ExtDataType::Num,
+        move |args| {
+            let x = args.first()?.downcast_ref::<f64>()?;
+            let digits = match args.get(1) {
+                Some(y) => *y.downcast_ref::<f64>()?,
+                None => 0.0,
+            };
+            Some(Box::new(round(*x, digits)))
+        },
+    );
+
     let sin = |x: f64| x.sin();
     ctx.register(
         "sin",
@@ -303,18 +349,18 @@ pub(crate) fn add_builtins(ctx: &mut Context) {
     });
 
     // spatial builtins...
-    let boundary = |x: &G| x.boundary().expect("Failed finding boundary");
+    let boundary = |x: &G| x.boundary().ok();
     ctx.register(
         "boundary",
         vec![ExtDataType::Geom],
         ExtDataType::Geom,
         move |args| {
             let x = args.first()?.downcast_ref::<G>()?;
-            Some(Box::new(boundary(x)))
+            Some(Box::new(boundary(x)?))
         },
     );
 
-    let buffer = |x: &G, y: &f64| x.buffer(*y, 8).expect("Failed computing buffer");
+    let buffer = |x: &G, y: &f64| x.buffer(*y, 8).ok();
     ctx.register(
         "buffer",
         vec![ExtDataType::Geom, ExtDataType::Num],
@@ -322,73 +368,230 @@ pub(crate) fn add_builtins(ctx: &mut Context) {
         move |args| {
             let x = args.first()?.downcast_ref::<G>()?;
             let y = args.get(1)?.downcast_ref::<f64>()?;
-            Some(Box::new(buffer(x, y)))
+            Some(Box::new(buffer(x, y)?))
         },
     );
 
-    let envelope = |x: &G| x.envelope().expect("Failed finding envelope");
+    let envelope = |x: &G| x.envelope().ok();
     ctx.register(
         "envelope",
         vec![ExtDataType::Geom],
         ExtDataType::Geom,
         move |args| {
             let x = args.first()?.downcast_ref::<G>()?;
-            Some(Box::new(envelope(x)))
+            Some(Box::new(envelope(x)?))
+        },
+    );
+
+    // bounds of a geometry's envelope...
+    type BoundFn = fn(&BBox) -> f64;
+    let bounds: [(&str, BoundFn); 4] = [
+        ("bbox_minx", BBox::min_x),
+        ("bbox_miny", BBox::min_y),
+        ("bbox_maxx", BBox::max_x),
+        ("bbox_maxy", BBox::max_y),
+    ];
+    for (name, bound) in bounds {
+        ctx.register(
+            name,
+            vec![ExtDataType::Geom],
+            ExtDataType::Num,
+            move |args| {
+                let x = args.first()?.downcast_ref::<G>()?;
+                let it = envelope(x)?;
+                Some(Box::new(bound(it.as_bbox()?)))
+            },
+        );
+    }
+
+    // members of collections...
+    ctx.register(
+        "num_geometries",
+        vec![ExtDataType::Geom],
+        ExtDataType::Num,
+        move |args| {
+            let x = args.first()?.downcast_ref::<G>()?;
+            Some(Box::new(x.num_geometries() as f64))
+        },
+    );
+
+    // NOTE (rsn) 20261016 - unlike G::geometry_n(), and like its PostGIS
+    // namesake, `n` is one-based...
+    let geometry_n = |x: &G, n: &f64| {
+        if n.fract() == 0.0 && *n >= 1.0 {
+            x.geometry_n(*n as usize - 1).unwrap_or(G::Null)
+        } else {
+            G::Null
+        }
+    };
+    ctx.register(
+        "geometry_n",
+        vec![ExtDataType::Geom, ExtDataType::Num],
+        ExtDataType::Geom,
+        move |args| {
+            let x = args.first()?.downcast_ref::<G>()?;
+            let n = args.get(1)?.downcast_ref::<f64>()?;
+            Some(Box::new(geometry_n(x, n)))
         },
     );
 
-    let centroid = |x: &G| x.centroid().expect("Failed finding centroid");
+    let centroid = |x: &G| x.centroid().ok();
     ctx.register(
         "centroid",
         vec![ExtDataType::Geom],
         ExtDataType::Geom,
         move |args| {
             let x = args.first()?.downcast_ref::<G>()?;
-            Some(Box::new(centroid(x)))
+            Some(Box::new(centroid(x)?))
         },
     );
 
-    let convex_hull = |x: &G| x.convex_hull().expect("Failed finding convex hull");
+    let convex_hull = |x: &G| x.convex_hull().ok();
     ctx.register(
         "convex_hull",
         vec![ExtDataType::Geom],
         ExtDataType::Geom,
         move |args| {
             let x = args.first()?.downcast_ref::<G>()?;
-            Some(Box::new(convex_hull(x)))
+            Some(Box::new(convex_hull(x)?))
+        },
+    );
+
+    let point_on_surface = |x: &G| x.point_on_surface().ok();
+    ctx.register(
+        "point_on_surface",
+        vec![ExtDataType::Geom],
+        ExtDataType::Geom,
+        move |args| {
+            let x = args.first()?.downcast_ref::<G>()?;
+            Some(Box::new(point_on_surface(x)?))
+        },
+    );
+
+    let simplify = |x: &G, y: &f64| x.simplify(*y).ok();
+    ctx.register(
+        "simplify",
+        vec![ExtDataType::Geom, ExtDataType::Num],
+        ExtDataType::Geom,
+        move |args| {
+            let x = args.first()?.downcast_ref::<G>()?;
+            let y = args.get(1)?.downcast_ref::<f64>()?;
+            Some(Box::new(simplify(x, y)?))
+        },
+    );
+
+    let union = |x: &G, y: &G| x.union(y).ok();
+    ctx.register(
+        "union",
+        vec![ExtDataType::Geom, ExtDataType::Geom],
+        ExtDataType::Geom,
+        move |args| {
+            let x = args.first()?.downcast_ref::<G>()?;
+            let y = args.get(1)?.downcast_ref::<G>()?;
+            Some(Box::new(union(x, y)?))
+        },
+    );
+
+    let intersection = |x: &G, y: &G| x.intersection(y).ok();
+    ctx.register(
+        "intersection",
+        vec![ExtDataType::Geom, ExtDataType::Geom],
+        ExtDataType::Geom,
+        move |args| {
+            let x = args.first()?.downcast_ref::<G>()?;
+            let y = args.get(1)?.downcast_ref::<G>()?;
+            Some(Box::new(intersection(x, y)?))
+        },
+    );
+
+    let difference = |x: &G, y: &G| x.difference(y).ok();
+    ctx.register(
+        "difference",
+        vec![ExtDataType::Geom, ExtDataType::Geom],
+        ExtDataType::Geom,
+        move |args| {
+            let x = args.first()?.downcast_ref::<G>()?;
+            let y = args.get(1)?.downcast_ref::<G>()?;
+            Some(Box::new(difference(x, y)?))
+        },
+    );
+
+    let sym_difference = |x: &G, y: &G| x.sym_difference(y).ok();
+    ctx.register(
+        "sym_difference",
+        vec![ExtDataType::Geom, ExtDataType::Geom],
+        ExtDataType::Geom,
+        move |args| {
+            let x = args.first()?.downcast_ref::<G>()?;
+            let y = args.get(1)?.downcast_ref::<G>()?;
+            Some(Box::new(sym_difference(x, y)?))
         },
     );
 
-    let get_x = |x: &G| x.get_x().expect("Failed isolating X");
+    let get_x = |x: &G| x.get_x().ok();
     ctx.register(
         "get_x",
         vec![ExtDataType::Geom],
         ExtDataType::Num,
         move |args| {
             let x = args.first()?.downcast_ref::<G>()?;
-            Some(Box::new(get_x(x)))
+            Some(Box::new(get_x(x)?))
         },
     );
 
-    let get_y = |x: &G| x.get_y().expect("Failed isolating Y");
+    let get_y = |x: &G| x.get_y().ok();
     ctx.register(
         "get_y",
         vec![ExtDataType::Geom],
         ExtDataType::Num,
         move |args| {
             let x = args.first()?.downcast_ref::<G>()?;
-            Some(Box::new(get_y(x)))
+            Some(Box::new(get_y(x)?))
         },
     );
 
-    let get_z = |x: &G| x.get_z().expect("Failed isolating Z");
+    let get_z = |x: &G| x.get_z().ok();
     ctx.register(
         "get_z",
         vec![ExtDataType::Geom],
         ExtDataType::Num,
         move |args| {
             let x = args.first()?.downcast_ref::<G>()?;
-            Some(Box::new(get_z(x)))
+            Some(Box::new(get_z(x)?))
+        },
+    );
+
+    let area = |x: &G| x.area().ok();
+    ctx.register(
+        "area",
+        vec![ExtDataType::Geom],
+        ExtDataType::Num,
+        move |args| {
+            let x = args.first()?.downcast_ref::<G>()?;
+            Some(Box::new(area(x)?))
+        },
+    );
+
+    let length = |x: &G| x.length().ok();
+    ctx.register(
+        "length",
+        vec![ExtDataType::Geom],
+        ExtDataType::Num,
+        move |args| {
+            let x = args.first()?.downcast_ref::<G>()?;
+            Some(Box::new(length(x)?))
+        },
+    );
+
+    let distance = |x: &G, y: &G| x.distance(y).ok();
+    ctx.register(
+        "distance",
+        vec![ExtDataType::Geom, ExtDataType::Geom],
+        ExtDataType::Num,
+        move |args| {
+            let x = args.first()?.downcast_ref::<G>()?;
+            let y = args.get(1)?.downcast_ref::<G>()?;
+            Some(Box::new(distance(x, y)?))
         },
     );
 
@@ -543,6 +746,56 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_math_builtins() -> Result<(), Box<dyn Error>> {
+        let mut ctx = Context::new();
+        ctx.register_builtins();
+        let shared_ctx = ctx.freeze();
+
+        let feat = Resource::from([
+            ("a".into(), Q::try_from(-4)?),
+            ("b".into(), Q::try_from(1234.5678)?),
+        ]);
+
+        #[rustfmt::skip]
+        const EXPRESSIONS: [(&str, bool); 9] = [
+            ("sqrt(abs(a)) = 2",                    true),
+            ("floor(b / 100) + ceil(0.1) = 13",     true),
+            ("log(1000) = 3 AND exp(0) = 1",        true),
+            ("round(b) = 1235",                     true),
+            ("round(b, 2) = 1234.57",               true),
+            ("round(b, -2) = 1200",                 true),
+            ("round(-2.5) = -3",                    true),
+            ("round(b, 2) = 1234.5678",             false),
+            ("sqrt(abs(a)) ^ 2 + floor(a / 3) = 2", true),
+        ];
+        for (input, expected) in EXPRESSIONS {
+            let mut eval = ExEvaluator::new(shared_ctx.clone());
+            eval.setup(Expression::try_from_text(input)?)?;
+            let res = eval.evaluate(&feat)?;
+            assert_eq!(res, Outcome::new(Some(&expected)), "{input}");
+        }
+
+        // undefined results are NULLs, not NaNs...
+        for input in ["sqrt(a) = 0", "ln(a) < 0", "log(a) IS NULL"] {
+            let mut eval = ExEvaluator::new(shared_ctx.clone());
+            eval.setup(Expression::try_from_text(input)?)?;
+            let res = eval.evaluate(&feat)?;
+            let expected = match input.ends_with("IS NULL") {
+                true => Outcome::T,
+                false => Outcome::N,
+            };
+            assert_eq!(res, expected, "{input}");
+        }
+
+        // too many arguments...
+        let mut eval = ExEvaluator::new(shared_ctx);
+        eval.setup(Expression::try_from_text("round(b, 1, 2) = 0")?)?;
+        assert!(eval.evaluate(&feat).is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn test_geom_builtins() -> Result<(), Box<dyn Error>> {
         // IMPORTANT (rsn) 20250901 - if we rely on Context::new() we leave
@@ -556,7 +809,7 @@ mod tests {
         let expr = Expression::try_from_text(
             "wkt(centroid(envelope(MULTIPOINT(0 90, 90 0))), 0) = 'POINT (45 45)'",
         )?;
-        let mut eval = ExEvaluator::new(shared_ctx);
+        let mut eval = ExEvaluator::new(shared_ctx.clone());
         eval.setup(expr)?;
 
         let feat = Resource::new();
@@ -564,6 +817,152 @@ mod tests {
         let res = eval.evaluate(&feat)?;
         assert!(matches!(res, Outcome::T));
 
+        let expr = Expression::try_from_text(
+            "wkt(envelope(LINESTRING(-10 -5, 20 15, 0 30)), 0) = 'BBOX (-10, -5, 20, 30)'
+            AND bbox_minx(LINESTRING(-10 -5, 20 15, 0 30)) = -10
+            AND bbox_miny(LINESTRING(-10 -5, 20 15, 0 30)) = -5
+            AND bbox_maxx(LINESTRING(-10 -5, 20 15, 0 30)) = 20
+            AND bbox_maxy(LINESTRING(-10 -5, 20 15, 0 30)) = 30
+            AND bbox_maxx(BBOX(170, 0, -170, 10)) = -170",
+        )?;
+        let mut eval = ExEvaluator::new(shared_ctx.clone());
+        eval.setup(expr)?;
+
+        let res = eval.evaluate(&feat)?;
+        assert!(matches!(res, Outcome::T));
+
+        let expr = Expression::try_from_text(
+            "num_geometries(MULTIPOINT(0 0, 1 1, 2 2)) = 3
+            AND num_geometries(POINT(0 0)) = 1
+            AND S_EQUALS(geometry_n(MULTIPOINT(0 0, 1 1, 2 2), 2), POINT(1 1))
+            AND geometry_n(MULTIPOINT(0 0, 1 1, 2 2), 4) IS NULL
+            AND geometry_n(POINT(0 0), 0) IS NULL",
+        )?;
+        let mut eval = ExEvaluator::new(shared_ctx.clone());
+        eval.setup(expr)?;
+
+        let res = eval.evaluate(&feat)?;
+        assert!(matches!(res, Outcome::T));
+
+        // multipart features only...
+        let expr = Expression::try_from_text("num_geometries(geom) > 1")?;
+        let mut eval = ExEvaluator::new(shared_ctx.clone());
+        eval.setup(expr)?;
+        for (wkt, expected) in [
+            ("POLYGON((0 0, 1 0, 1 1, 0 0))", Outcome::F),
+            ("MULTILINESTRING((0 0, 1 1), (2 2, 3 3))", Outcome::T),
+            ("MULTIPOINT(0 0)", Outcome::F),
+        ] {
+            let feat = Resource::from([("geom".into(), Q::try_from_wkt(wkt)?)]);
+            assert_eq!(eval.evaluate(&feat)?, expected, "{wkt}");
+        }
+
+        // western hemisphere features...
+        let expr = Expression::try_from_text("bbox_maxx(geom) < 0")?;
+        let mut eval = ExEvaluator::new(shared_ctx.clone());
+        eval.setup(expr)?;
+        for (wkt, expected) in [
+            ("POINT(-73.98 40.75)", Outcome::T),
+            ("LINESTRING(-10 0, 10 0)", Outcome::F),
+            ("POINT(2.35 48.86)", Outcome::F),
+        ] {
+            let feat = Resource::from([("geom".into(), Q::try_from_wkt(wkt)?)]);
+            assert_eq!(eval.evaluate(&feat)?, expected, "{wkt}");
+        }
+
+        let expr = Expression::try_from_text(
+            "area(POLYGON((0 0, 4 0, 4 3, 0 3, 0 0))) = 12 AND length(LINESTRING(0 0, 3 4)) = 5",
+        )?;
+        let mut eval = ExEvaluator::new(shared_ctx.clone());
+        eval.setup(expr)?;
+
+        let res = eval.evaluate(&feat)?;
+        assert!(matches!(res, Outcome::T));
+
+        // a U-shaped polygon's representative point is inside it unlike its
+        // centroid...
+        let expr = Expression::try_from_text(
+            "S_WITHIN(point_on_surface(POLYGON((0 0, 6 0, 6 6, 4 6, 4 2, 2 2, 2 6, 0 6, 0 0))),
+                POLYGON((0 0, 6 0, 6 6, 4 6, 4 2, 2 2, 2 6, 0 6, 0 0)))
+            AND NOT S_WITHIN(centroid(POLYGON((0 0, 6 0, 6 6, 4 6, 4 2, 2 2, 2 6, 0 6, 0 0))),
+                POLYGON((0 0, 6 0, 6 6, 4 6, 4 2, 2 2, 2 6, 0 6, 0 0)))",
+        )?;
+        let mut eval = ExEvaluator::new(shared_ctx.clone());
+        eval.setup(expr)?;
+
+        let res = eval.evaluate(&feat)?;
+        assert!(matches!(res, Outcome::T));
+
+        let expr = Expression::try_from_text(
+            "wkt(simplify(LINESTRING(0 0, 1 0.01, 2 0), 0.1), 0) = 'LINESTRING (0 0, 2 0)'",
+        )?;
+        let mut eval = ExEvaluator::new(shared_ctx.clone());
+        eval.setup(expr)?;
+
+        let res = eval.evaluate(&feat)?;
+        assert!(matches!(res, Outcome::T));
+
+        let expr = Expression::try_from_text(
+            "distance(POINT(0 0), POINT(3 4)) = 5 AND distance(LINESTRING(0 0, 10 0), POINT(5 2)) < 2.5",
+        )?;
+        let mut eval = ExEvaluator::new(shared_ctx.clone());
+        eval.setup(expr)?;
+
+        let res = eval.evaluate(&feat)?;
+        assert!(matches!(res, Outcome::T));
+
+        // an undefined geometry is at no distance from anything...
+        let expr = Expression::try_from_text("distance(geom, POINT(0 0)) < 5")?;
+        let mut eval = ExEvaluator::new(shared_ctx.clone());
+        eval.setup(expr)?;
+
+        let null_feat = Resource::from([("geom".into(), Q::Geom(G::Null))]);
+        let res = eval.evaluate(&null_feat)?;
+        assert!(matches!(res, Outcome::N));
+
+        let expr = Expression::try_from_text(
+            "area(intersection(POLYGON((0 0, 4 0, 4 4, 0 4, 0 0)), BBOX(2, 2, 6, 6))) = 4 AND \
+            area(union(POLYGON((0 0, 4 0, 4 4, 0 4, 0 0)), BBOX(2, 2, 6, 6))) = 28 AND \
+            area(difference(POLYGON((0 0, 4 0, 4 4, 0 4, 0 0)), BBOX(2, 2, 6, 6))) = 12 AND \
+            area(sym_difference(POLYGON((0 0, 4 0, 4 4, 0 4, 0 0)), BBOX(2, 2, 6, 6))) = 24",
+        )?;
+        let mut eval = ExEvaluator::new(shared_ctx.clone());
+        eval.setup(expr)?;
+
+        let res = eval.evaluate(&feat)?;
+        assert!(matches!(res, Outcome::T));
+
+        // an empty overlay is an empty geometry w/ no area or length, while
+        // spatial predicates involving it are NULL...
+        let expr = Expression::try_from_text(
+            "area(intersection(POLYGON((0 0, 1 0, 1 1, 0 1, 0 0)), POINT(5 5))) = 0
+            AND length(difference(LINESTRING(0 0, 1 0), BBOX(-1, -1, 2, 1))) = 0",
+        )?;
+        let mut eval = ExEvaluator::new(shared_ctx.clone());
+        eval.setup(expr)?;
+
+        let res = eval.evaluate(&feat)?;
+        assert!(matches!(res, Outcome::T));
+
+        let expr = Expression::try_from_text(
+            "S_INTERSECTS(intersection(POLYGON((0 0, 1 0, 1 1, 0 1, 0 0)), POINT(5 5)), POINT(5 5))",
+        )?;
+        let mut eval = ExEvaluator::new(shared_ctx.clone());
+        eval.setup(expr)?;
+
+        let res = eval.evaluate(&feat)?;
+        assert!(matches!(res, Outcome::N));
+
+        // collapsed geometries are NULL...
+        let expr = Expression::try_from_text(
+            "S_INTERSECTS(simplify(POLYGON((0 0, 1 0, 1 1, 0 1, 0 0)), 10), POINT(0 0))",
+        )?;
+        let mut eval = ExEvaluator::new(shared_ctx);
+        eval.setup(expr)?;
+
+        let res = eval.evaluate(&feat)?;
+        assert!(matches!(res, Outcome::N));
+
         Ok(())
     }
 
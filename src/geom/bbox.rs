@@ -6,13 +6,24 @@
 //!
 
 use crate::{
-    CRS, EPSG_4326, GTrait, MyError, Polygon, Polygons, geom::ensure_precision, srid::SRID,
+    CRS, EPSG_4326, GTrait, MyError, Polygon, Polygons, WktOptions, geom::ensure_precision,
+    srid::SRID,
 };
 use core::fmt;
+#[cfg(feature = "geos")]
 use geos::{CoordSeq, Geometry};
-use tracing::{error, warn};
+use serde_json::{Value, json};
+#[cfg(feature = "geos")]
+use tracing::error;
+use tracing::warn;
 
 /// 2D or 3D bounding box.
+///
+/// When used in spatial operations, a bounding box is treated as a 2D polygon
+/// --or a multi-polygon of 2 rectangles when it spans the antimeridian (i.e.
+/// its west bound is greater than its east one). In the 3D (6-coordinate)
+/// form the minimum and maximum elevations are kept and rendered as WKT but
+/// are otherwise ignored; i.e. only the 2D footprint is considered.
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
 pub struct BBox {
     w: f64,             // west bound longitude
@@ -30,24 +41,15 @@ impl GTrait for BBox {
         self.z_min.is_none()
     }
 
-    fn to_wkt_fmt(&self, precision: usize) -> String {
-        if let Some(z_min) = self.z_min {
-            format!(
-                "BBOX ({:.6$}, {:.6$}, {:.6$}, {:.6$}, {:.6$}, {:.6$})",
-                self.w,
-                self.s,
-                z_min,
-                self.e,
-                self.n,
-                self.z_max.unwrap(),
-                precision
-            )
-        } else {
-            format!(
-                "BBOX ({:.4$}, {:.4$}, {:.4$}, {:.4$})",
-                self.w, self.s, self.e, self.n, precision
-            )
+    fn to_wkt_with(&self, opts: &WktOptions) -> String {
+        let xy: Vec<String> = match (self.z_min, self.z_max) {
+            (Some(z_min), Some(z_max)) => vec![self.w, self.s, z_min, self.e, self.n, z_max],
+            _ => vec![self.w, self.s, self.e, self.n],
         }
+        .into_iter()
+        .map(|x| opts.fmt_f64(x))
+        .collect();
+        format!("BBOX ({})", xy.join(", "))
     }
 
     fn check_coordinates(&self, crs: &CRS) -> Result<(), MyError> {
@@ -97,16 +99,17 @@ impl BBox {
         }
     }
 
+    #[cfg(feature = "geos")]
     pub(crate) fn to_geos(&self) -> Result<Geometry, MyError> {
         // convert this to one 2D polygon, or in the case of a box that spans the
-        // antimeridian, a 2D multi-polygon.
+        // antimeridian, a 2D multi-polygon.  elevations if any are ignored.
         let x1 = self.w;
         let y1 = self.s;
         let x2 = self.e;
         let y2 = self.n;
 
         // if x_min is larger than x_max, then the box spans the antimeridian...
-        if x1 < x2 {
+        if x1 <= x2 {
             let cs =
                 CoordSeq::new_from_vec(&[&[x1, y1], &[x2, y1], &[x2, y2], &[x1, y2], &[x1, y1]])
                     .map_err(|x| {
@@ -174,6 +177,80 @@ impl BBox {
         }
     }
 
+    /// Return the west bound of this; i.e. its minimum _X_ (longitude or
+    /// easting) coordinate, unless this spans the antimeridian in which case
+    /// it's greater than [`max_x()`][Self::max_x()].
+    pub fn min_x(&self) -> f64 {
+        self.w
+    }
+
+    /// Return the south bound of this; i.e. its minimum _Y_ (latitude or
+    /// northing) coordinate.
+    pub fn min_y(&self) -> f64 {
+        self.s
+    }
+
+    /// Return the east bound of this; i.e. its maximum _X_ (longitude or
+    /// easting) coordinate, unless this spans the antimeridian in which case
+    /// it's less than [`min_x()`][Self::min_x()].
+    pub fn max_x(&self) -> f64 {
+        self.e
+    }
+
+    /// Return the north bound of this; i.e. its maximum _Y_ (latitude or
+    /// northing) coordinate.
+    pub fn max_y(&self) -> f64 {
+        self.n
+    }
+
+    /// Return the minimum elevation of this if it's a 3D one; `None` otherwise.
+    pub fn min_z(&self) -> Option<f64> {
+        self.z_min
+    }
+
+    /// Return the maximum elevation of this if it's a 3D one; `None` otherwise.
+    pub fn max_z(&self) -> Option<f64> {
+        self.z_max
+    }
+
+    // a copy of this w/o elevations...
+    #[cfg(feature = "geos")]
+    pub(crate) fn to_2d(&self) -> Self {
+        BBox {
+            z_min: None,
+            z_max: None,
+            ..self.clone()
+        }
+    }
+
+    // the 4, or 6, numbers defining this in the order they're written.
+    pub(crate) fn coordinates(&self) -> Vec<f64> {
+        match (self.z_min, self.z_max) {
+            (Some(z1), Some(z2)) => vec![self.w, self.s, z1, self.e, self.n, z2],
+            _ => vec![self.w, self.s, self.e, self.n],
+        }
+    }
+
+    // GeoJSON has no bounding box geometry type. like w/ GEOS, render this as
+    // a 2D polygon, or a multi-polygon if it spans the antimeridian...
+    pub(crate) fn to_geojson(&self) -> Value {
+        let (x1, y1, x2, y2) = (self.w, self.s, self.e, self.n);
+        if x1 <= x2 {
+            json!({
+                "type": "Polygon",
+                "coordinates": [[[x1, y1], [x2, y1], [x2, y2], [x1, y2], [x1, y1]]]
+            })
+        } else {
+            json!({
+                "type": "MultiPolygon",
+                "coordinates": [
+                    [[[x1, y1], [180.0, y1], [180.0, y2], [x1, y2], [x1, y1]]],
+                    [[[x2, y1], [x2, y2], [-180.0, y2], [-180.0, y1], [x2, y1]]]
+                ]
+            })
+        }
+    }
+
     pub(crate) fn set_srid_unchecked(&mut self, srid: &SRID) {
         if self.srid != *srid {
             warn!("Replacing current SRID ({}) w/ {srid}", self.srid);
@@ -191,7 +268,7 @@ impl BBox {
         let y2 = self.n;
 
         // if x_min is larger than x_max, then the box spans the antimeridian...
-        let wkt = if x1 < x2 {
+        let wkt = if x1 <= x2 {
             let p = Polygon::from_xy_and_srid_unchecked(
                 vec![vec![
                     vec![x1, y1],
@@ -276,7 +353,10 @@ impl fmt::Display for BBox {
 #[cfg(test)]
 mod tests {
     use super::*;
+    #[cfg(feature = "geos")]
+    use crate::{Context, Q, Resource};
     use crate::{G, expr::E, text::cql2};
+    #[cfg(feature = "geos")]
     use geos::Geom;
     use std::error::Error;
 
@@ -317,6 +397,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "geos")]
     #[tracing_test::traced_test]
     fn test_to_polygon() {
         const G1: &str = "BBOX(-180,-90,180,90)";
@@ -355,6 +436,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "geos")]
     fn test_antimeridian() -> Result<(), Box<dyn Error>> {
         const WKT: &str = "MULTIPOLYGON (((150 -90, 180 -90, 180 90, 150 90, 150 -90)), ((-150 -90, -150 90, -180 90, -180 -90, -150 -90)))";
 
@@ -390,4 +472,41 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    #[cfg(feature = "geos")]
+    fn test_s_intersects() -> Result<(), Box<dyn Error>> {
+        #[rustfmt::skip]
+        const TV: [(&str, bool); 10] = [
+            ("S_INTERSECTS(POINT(4 53), BBOX(2.54, 51.43, 6.40, 55.77))",                true),
+            ("S_INTERSECTS(BBOX(2.54, 51.43, 6.40, 55.77), POINT(4 53))",                true),
+            ("S_INTERSECTS(BBOX(2.54, 51.43, 6.40, 55.77), POINT(7 53))",                false),
+            ("S_INTERSECTS(BBOX(0, 0, 10, 10), BBOX(5, 5, 15, 15))",                     true),
+            ("S_INTERSECTS(BBOX(0, 0, 10, 10), BBOX(11, 11, 15, 15))",                   false),
+            // antimeridian...
+            ("S_INTERSECTS(POINT(179 10), BBOX(170, -10, -170, 20))",                    true),
+            ("S_INTERSECTS(POINT(-179 10), BBOX(170, -10, -170, 20))",                   true),
+            ("S_INTERSECTS(POINT(0 10), BBOX(170, -10, -170, 20))",                      false),
+            ("S_INTERSECTS(BBOX(175, 0, 178, 5), BBOX(170, -10, -170, 20))",             true),
+            // 3D forms only consider their 2D footprint...
+            ("S_INTERSECTS(POINT(4 53), BBOX(2.54, 51.43, -100, 6.40, 55.77, 100))",    true),
+        ];
+
+        let ctx = Context::new();
+        let feat = Resource::new();
+        for (cql, expected) in TV {
+            let exp = cql2::expression(cql)?;
+            assert_eq!(exp.eval(&ctx, &feat)?, Q::Bool(expected), "{cql}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_coordinates_count() {
+        assert!(cql2::geom_expression("BBOX(0, 0, 10, 10)").is_ok());
+        assert!(cql2::geom_expression("BBOX(0, 0, 0, 10, 10, 10)").is_ok());
+        // neither 2D nor 3D...
+        assert!(cql2::geom_expression("BBOX(0, 0, 0, 10, 10)").is_err());
+    }
 }
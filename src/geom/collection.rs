@@ -5,8 +5,9 @@
 //! Collection of heterogeous geometries.
 //!
 
-use crate::{CRS, G, GTrait, MyError, config::config, srid::SRID};
+use crate::{CRS, G, GTrait, MyError, WktOptions, config::config, srid::SRID};
 use core::fmt;
+#[cfg(feature = "geos")]
 use geos::{ConstGeometry, Geom, Geometry};
 use std::slice::Iter;
 use tracing::{error, warn};
@@ -34,26 +35,26 @@ impl GTrait for Geometries {
         }
     }
 
-    fn to_wkt_fmt(&self, precision: usize) -> String {
+    fn to_wkt_with(&self, opts: &WktOptions) -> String {
         let items: Vec<String> = self
             .items
             .iter()
             .map(|x| match x {
-                G::Point(x) => x.to_wkt_fmt(precision),
-                G::Line(x) => x.to_wkt_fmt(precision),
-                G::Polygon(x) => x.to_wkt_fmt(precision),
-                G::Points(x) => x.to_wkt_fmt(precision),
-                G::Lines(x) => x.to_wkt_fmt(precision),
-                G::Polygons(x) => x.to_wkt_fmt(precision),
-                G::BBox(x) => x.to_wkt_fmt(precision),
+                G::Point(x) => x.to_wkt_with(opts),
+                G::Line(x) => x.to_wkt_with(opts),
+                G::Polygon(x) => x.to_wkt_with(opts),
+                G::Points(x) => x.to_wkt_with(opts),
+                G::Lines(x) => x.to_wkt_with(opts),
+                G::Polygons(x) => x.to_wkt_with(opts),
+                G::BBox(x) => x.to_wkt_with(opts),
                 _ => panic!("Unexpected geometries item"),
             })
             .collect();
-        if self.is_2d() {
-            format!("GEOMETRYCOLLECTION ({})", items.join(", "))
-        } else {
-            format!("GEOMETRYCOLLECTION Z ({})", items.join(", "))
-        }
+        format!(
+            "{} ({})",
+            opts.keyword("GEOMETRYCOLLECTION", self.is_2d()),
+            items.join(", ")
+        )
     }
 
     fn check_coordinates(&self, crs: &CRS) -> Result<(), MyError> {
@@ -105,6 +106,7 @@ impl Geometries {
         Geometries { items, srid }
     }
 
+    #[cfg(feature = "geos")]
     pub(crate) fn to_geos(&self) -> Result<Geometry, MyError> {
         let items: Result<Vec<Geometry>, MyError> = self
             .items
@@ -126,6 +128,7 @@ impl Geometries {
         Ok(g)
     }
 
+    #[cfg(feature = "geos")]
     pub(crate) fn from_geos_xy<T: Geom>(gg: T) -> Result<Vec<G>, MyError> {
         let num_geometries = gg.get_num_geometries()?;
         let mut result = Vec::with_capacity(num_geometries);
@@ -154,6 +157,7 @@ impl fmt::Display for Geometries {
     }
 }
 
+#[cfg(feature = "geos")]
 impl TryFrom<Geometry> for Geometries {
     type Error = MyError;
 
@@ -171,6 +175,7 @@ impl TryFrom<Geometry> for Geometries {
     }
 }
 
+#[cfg(feature = "geos")]
 impl TryFrom<ConstGeometry<'_>> for Geometries {
     type Error = MyError;
 
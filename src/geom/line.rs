@@ -6,15 +6,18 @@
 //!
 
 use crate::{
-    CRS, GTrait, MyError, Point,
+    CRS, GTrait, MyError, Point, WktOptions,
     config::config,
     geom::{XY1V, XY2V},
     srid::SRID,
 };
 use core::fmt;
+#[cfg(feature = "geos")]
 use geos::{ConstGeometry, CoordDimensions, CoordSeq, Geom, Geometry};
 use std::slice::Iter;
-use tracing::{error, warn};
+#[cfg(feature = "geos")]
+use tracing::error;
+use tracing::warn;
 
 /// 2D or 3D line-string geometry.
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
@@ -28,18 +31,12 @@ impl GTrait for Line {
         self.coord[0].len() == 2
     }
 
-    fn to_wkt_fmt(&self, precision: usize) -> String {
-        if self.is_2d() {
-            format!(
-                "LINESTRING {}",
-                Self::coords_with_dp(&self.coord, precision)
-            )
-        } else {
-            format!(
-                "LINESTRING Z {}",
-                Self::coords_with_dp(&self.coord, precision)
-            )
-        }
+    fn to_wkt_with(&self, opts: &WktOptions) -> String {
+        format!(
+            "{} {}",
+            opts.keyword("LINESTRING", self.is_2d()),
+            Self::coords_with_dp(&self.coord, opts)
+        )
     }
 
     fn check_coordinates(&self, crs: &CRS) -> Result<(), MyError> {
@@ -76,7 +73,7 @@ impl Line {
     }
 
     pub(crate) fn coords_as_txt(coord: &[XY1V]) -> String {
-        Self::coords_with_dp(coord, config().default_precision())
+        Self::coords_with_dp(coord, &WktOptions::default())
     }
 
     pub(crate) fn ensure_precision_xy(coord: &[XY1V]) -> XY2V {
@@ -86,18 +83,20 @@ impl Line {
             .collect()
     }
 
-    pub(crate) fn coords_with_dp(coord: &[XY1V], precision: usize) -> String {
+    pub(crate) fn coords_with_dp(coord: &[XY1V], opts: &WktOptions) -> String {
         let points: Vec<String> = coord
             .iter()
-            .map(|x| Point::coords_with_dp(x, precision))
+            .map(|x| Point::coords_with_dp(x, opts))
             .collect();
         format!("({})", points.join(", "))
     }
 
+    #[cfg(feature = "geos")]
     pub(crate) fn to_geos(&self) -> Result<Geometry, MyError> {
         Self::to_geos_xy(&self.coord, &self.srid)
     }
 
+    #[cfg(feature = "geos")]
     pub(crate) fn to_geos_xy(xy: &[XY1V], srid: &SRID) -> Result<Geometry, MyError> {
         let vertices: Vec<&[f64]> = xy.iter().map(|x| x.as_slice()).collect();
         let xy = CoordSeq::new_from_vec(&vertices)?;
@@ -113,6 +112,7 @@ impl Line {
         self.coord.first() == self.coord.last()
     }
 
+    #[cfg(feature = "geos")]
     pub(crate) fn from_geos_xy<T: Geom>(gg: T) -> Result<XY2V, MyError> {
         let cs = gg.get_coord_seq()?;
         let is_3d = match cs.dimensions()? {
@@ -172,6 +172,7 @@ impl fmt::Display for Line {
     }
 }
 
+#[cfg(feature = "geos")]
 impl TryFrom<Geometry> for Line {
     type Error = MyError;
 
@@ -186,6 +187,7 @@ impl TryFrom<Geometry> for Line {
     }
 }
 
+#[cfg(feature = "geos")]
 impl TryFrom<ConstGeometry<'_>> for Line {
     type Error = MyError;
 
@@ -237,6 +239,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "geos")]
     #[should_panic]
     fn test_invalid() {
         let line = Line::from_xy(vec![vec![0.0, 45.0], vec![90.0, 180.0], vec![45.0, 45.0]]);
@@ -6,15 +6,18 @@
 //!
 
 use crate::{
-    CRS, GTrait, Line, MyError,
+    CRS, GTrait, Line, MyError, WktOptions,
     config::config,
     geom::{XY2V, XY3V},
     srid::SRID,
 };
 use core::fmt;
+#[cfg(feature = "geos")]
 use geos::{ConstGeometry, Geom, Geometry};
 use std::slice::Iter;
-use tracing::{error, warn};
+#[cfg(feature = "geos")]
+use tracing::error;
+use tracing::warn;
 
 /// Collection of line-string geometries.
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
@@ -28,18 +31,12 @@ impl GTrait for Lines {
         self.lines[0][0].len() == 2
     }
 
-    fn to_wkt_fmt(&self, precision: usize) -> String {
-        if self.is_2d() {
-            format!(
-                "MULTILINESTRING {}",
-                Self::coords_with_dp(self.lines.as_slice(), precision)
-            )
-        } else {
-            format!(
-                "MULTILINESTRING Z {}",
-                Self::coords_with_dp(self.lines.as_slice(), precision)
-            )
-        }
+    fn to_wkt_with(&self, opts: &WktOptions) -> String {
+        format!(
+            "{} {}",
+            opts.keyword("MULTILINESTRING", self.is_2d()),
+            Self::coords_with_dp(self.lines.as_slice(), opts)
+        )
     }
 
     fn check_coordinates(&self, crs: &CRS) -> Result<(), MyError> {
@@ -82,9 +79,10 @@ impl Lines {
     }
 
     pub(crate) fn coords_as_txt(lines: &[XY2V]) -> String {
-        Self::coords_with_dp(lines, config().default_precision())
+        Self::coords_with_dp(lines, &WktOptions::default())
     }
 
+    #[cfg(feature = "geos")]
     pub(crate) fn to_geos(&self) -> Result<Geometry, MyError> {
         let mut lines: Vec<Geometry> = vec![];
         for l in &self.lines {
@@ -98,6 +96,7 @@ impl Lines {
         Ok(g)
     }
 
+    #[cfg(feature = "geos")]
     pub(crate) fn from_geos_xy<T: Geom>(gg: T) -> Result<XY3V, MyError> {
         let num_lines = gg.get_num_geometries()?;
         let mut result = Vec::with_capacity(num_lines);
@@ -116,10 +115,10 @@ impl Lines {
         }
     }
 
-    fn coords_with_dp(lines: &[XY2V], precision: usize) -> String {
+    fn coords_with_dp(lines: &[XY2V], opts: &WktOptions) -> String {
         let lines: Vec<String> = lines
             .iter()
-            .map(|x| Line::coords_with_dp(x.as_slice(), precision))
+            .map(|x| Line::coords_with_dp(x.as_slice(), opts))
             .collect();
         format!("({})", lines.join(", "))
     }
@@ -131,6 +130,7 @@ impl fmt::Display for Lines {
     }
 }
 
+#[cfg(feature = "geos")]
 impl TryFrom<Geometry> for Lines {
     type Error = MyError;
 
@@ -148,6 +148,7 @@ impl TryFrom<Geometry> for Lines {
     }
 }
 
+#[cfg(feature = "geos")]
 impl TryFrom<ConstGeometry<'_>> for Lines {
     type Error = MyError;
 
@@ -23,9 +23,18 @@ pub use points::*;
 pub use polygon::*;
 pub use polygons::*;
 
-use crate::{MyError, config::config, crs::CRS, srid::SRID, text::cql2::wkt, wkb::*};
+use crate::{
+    EPSG_3857, EPSG_4326, MyError, config::config, crs::CRS, error::SyntaxError, srid::SRID,
+    text::cql2::wkt, wkb::*,
+};
 use core::fmt;
+#[cfg(feature = "geos")]
 use geos::{ConstGeometry, Geom, Geometry, GeometryTypes};
+#[cfg(feature = "geos")]
+use proj::Proj;
+use serde_json::{Value, json};
+use std::{slice::Iter, str::FromStr};
+use tracing::error;
 
 // type aliases to silence clippy + work nicely w/ macros...
 pub(crate) type XY1V = Vec<f64>;
@@ -33,16 +42,118 @@ pub(crate) type XY2V = Vec<Vec<f64>>;
 pub(crate) type XY3V = Vec<Vec<Vec<f64>>>;
 pub(crate) type XY4V = Vec<Vec<Vec<Vec<f64>>>>;
 
+// transform the X and Y of a 2D or 3D coordinate using the given Proj pipeline
+// leaving Z, if any, untouched.
+#[cfg(feature = "geos")]
+fn transform_xy(proj: &Proj, xy: &[f64]) -> Result<XY1V, MyError> {
+    let (x, y) = proj.convert((xy[0], xy[1]))?;
+    if !x.is_finite() || !y.is_finite() {
+        return Err(MyError::Runtime(
+            format!("Failed transforming ({} {})", xy[0], xy[1]).into(),
+        ));
+    }
+    let mut result = vec![x, y];
+    result.extend_from_slice(&xy[2..]);
+    Ok(result)
+}
+
+// the error raised by spatial operations when built w/o the `geos` feature...
+#[cfg(not(feature = "geos"))]
+pub(crate) fn spatial_disabled() -> MyError {
+    MyError::Unsupported("Spatial functions not enabled; build w/ the `geos` feature".into())
+}
+
+// insert between every 2 consecutive vertices of a line as many evenly spaced
+// ones as needed for no segment to be longer than `max` in the X/Y plane. Z,
+// if any, is linearly interpolated too.
+fn densify_xy(xy: Iter<'_, XY1V>, max: f64) -> XY2V {
+    let mut result = vec![];
+    let mut prev: Option<&XY1V> = None;
+    for b in xy {
+        if let Some(a) = prev {
+            let n = ((b[0] - a[0]).hypot(b[1] - a[1]) / max).ceil() as usize;
+            for i in 1..n {
+                let t = i as f64 / n as f64;
+                result.push(a.iter().zip(b).map(|(p, q)| p + (q - p) * t).collect());
+            }
+        }
+        result.push(b.to_owned());
+        prev = Some(b);
+    }
+    result
+}
+
 /// Ensure a float only has a fixed number of decimal digits in its fractional
 /// part.
 fn ensure_precision(x: &f64) -> f64 {
-    let d = 10.0_f64.powi(
-        config()
-            .default_precision()
-            .try_into()
-            .expect("Failed coercing DEFAULT_PRECISION"),
-    );
-    (x * d).round() / d
+    config()
+        .rounding_mode()
+        .unwrap_or_default()
+        .round(*x, config().default_precision())
+}
+
+// Return TRUE if `value` has the shape of a GeoJSON geometry object; FALSE
+// otherwise.
+pub(crate) fn is_geojson(value: &Value) -> bool {
+    match value.get("type").and_then(Value::as_str) {
+        Some("GeometryCollection") => value.get("geometries").is_some_and(Value::is_array),
+        Some(
+            "Point" | "LineString" | "Polygon" | "MultiPoint" | "MultiLineString" | "MultiPolygon",
+        ) => value.get("coordinates").is_some_and(Value::is_array),
+        _ => false,
+    }
+}
+
+// Convert a GeoJSON geometry object to its WKT equivalent so we can reuse the
+// PEG rules to construct the corresponding geometry.
+fn geojson_to_wkt(value: &Value) -> Result<String, MyError> {
+    fn invalid(v: &Value) -> MyError {
+        MyError::Runtime(format!("Invalid GeoJSON geometry: {v}").into())
+    }
+
+    // a position is an array of 2 or 3 numbers...
+    fn position(v: &Value) -> Result<String, MyError> {
+        let xyz = v
+            .as_array()
+            .filter(|a| a.len() == 2 || a.len() == 3)
+            .ok_or_else(|| invalid(v))?;
+        let nums: Option<Vec<String>> = xyz
+            .iter()
+            .map(|n| n.as_f64().map(|x| x.to_string()))
+            .collect();
+        Ok(nums.ok_or_else(|| invalid(v))?.join(" "))
+    }
+
+    // `depth` levels of nested arrays of positions...
+    fn nested(v: &Value, depth: usize) -> Result<String, MyError> {
+        if depth == 0 {
+            return position(v);
+        }
+        let items = v.as_array().ok_or_else(|| invalid(v))?;
+        let parts: Result<Vec<String>, MyError> =
+            items.iter().map(|x| nested(x, depth - 1)).collect();
+        Ok(format!("({})", parts?.join(", ")))
+    }
+
+    if !is_geojson(value) {
+        return Err(invalid(value));
+    }
+    let coords = &value["coordinates"];
+    match value["type"].as_str() {
+        Some("Point") => Ok(format!("POINT ({})", position(coords)?)),
+        Some("LineString") => Ok(format!("LINESTRING {}", nested(coords, 1)?)),
+        Some("Polygon") => Ok(format!("POLYGON {}", nested(coords, 2)?)),
+        Some("MultiPoint") => Ok(format!("MULTIPOINT {}", nested(coords, 1)?)),
+        Some("MultiLineString") => Ok(format!("MULTILINESTRING {}", nested(coords, 2)?)),
+        Some("MultiPolygon") => Ok(format!("MULTIPOLYGON {}", nested(coords, 3)?)),
+        _ => {
+            let items = value["geometries"]
+                .as_array()
+                .ok_or_else(|| invalid(value))?;
+            let parts: Result<Vec<String>, MyError> = items.iter().map(geojson_to_wkt).collect();
+            Ok(format!("GEOMETRYCOLLECTION ({})", parts?.join(", ")))
+        }
+    }
 }
 
 /// Geometry type variants handled by this library.
@@ -70,6 +181,208 @@ pub enum G {
     BBox(BBox),
 }
 
+/// How the dimension of 3D geometries is tagged in WKT output.
+///
+/// Note that only 2D and 3D (`Z`) coordinates are supported by this library;
+/// i.e. there are no `M` or `ZM` geometries.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DimTag {
+    /// Tag separated from the geometry type; e.g. `POINT Z (1 2 3)`. This is
+    /// the default.
+    #[default]
+    Spaced,
+    /// Tag attached to the geometry type; e.g. `POINTZ (1 2 3)`.
+    Attached,
+    /// No tag at all; e.g. `POINT (1 2 3)`. The dimension is then implied by
+    /// the number of ordinates.
+    Omitted,
+}
+
+/// How an undefined geometry (i.e. [`G::Null`]) is rendered in WKT output.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum EmptyWkt {
+    /// An empty string. This is the default.
+    #[default]
+    Blank,
+    /// `POINT EMPTY`.
+    Point,
+    /// `GEOMETRYCOLLECTION EMPTY`.
+    Collection,
+}
+
+impl fmt::Display for EmptyWkt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EmptyWkt::Blank => write!(f, ""),
+            EmptyWkt::Point => write!(f, "POINT EMPTY"),
+            EmptyWkt::Collection => write!(f, "GEOMETRYCOLLECTION EMPTY"),
+        }
+    }
+}
+
+/// How a coordinate is rounded to a fixed number of decimal digits; either
+/// when ingested, or when rendered in WKT output.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Round half-way cases away from `0.0`; e.g. `2.5` becomes `3.0` and
+    /// `-2.5` becomes `-3.0`. This is the default.
+    #[default]
+    HalfAwayFromZero,
+    /// Round half-way cases towards positive infinity; e.g. `2.5` becomes
+    /// `3.0` and `-2.5` becomes `-2.0`.
+    HalfUp,
+    /// Round half-way cases to the nearest even digit; e.g. `2.5` becomes
+    /// `2.0` and `3.5` becomes `4.0`. Aka. _Banker's rounding_.
+    HalfEven,
+    /// Drop the extra digits; e.g. `2.7` becomes `2.0` and `-2.7` becomes
+    /// `-2.0`.
+    Truncate,
+}
+
+impl FromStr for RoundingMode {
+    type Err = MyError;
+    fn from_str(value: &str) -> Result<Self, MyError> {
+        match value.to_lowercase().replace('-', "_").as_str() {
+            "half_away_from_zero" => Ok(Self::HalfAwayFromZero),
+            "half_up" => Ok(Self::HalfUp),
+            "half_even" => Ok(Self::HalfEven),
+            "truncate" => Ok(Self::Truncate),
+            _ => Err(MyError::Runtime(
+                format!("Unknown ({value}) rounding mode").into(),
+            )),
+        }
+    }
+}
+
+impl RoundingMode {
+    /// Return `x` rounded, according to this mode, to `precision` digits after
+    /// the decimal point.
+    ///
+    /// ```rust
+    /// use ogc_cql2::prelude::*;
+    ///
+    /// assert_eq!(RoundingMode::HalfAwayFromZero.round(-2.5, 0), -3.0);
+    /// assert_eq!(RoundingMode::HalfUp.round(-2.5, 0), -2.0);
+    /// assert_eq!(RoundingMode::HalfEven.round(2.5, 0), 2.0);
+    /// assert_eq!(RoundingMode::Truncate.round(2.79, 1), 2.7);
+    /// ```
+    pub fn round(&self, x: f64, precision: usize) -> f64 {
+        let d = 10.0_f64.powi(precision.try_into().expect("Failed coercing precision"));
+        let y = x * d;
+        let y = match self {
+            RoundingMode::HalfAwayFromZero => y.round(),
+            RoundingMode::HalfUp => (y + 0.5).floor(),
+            RoundingMode::HalfEven => y.round_ties_even(),
+            RoundingMode::Truncate => y.trunc(),
+        };
+        y / d
+    }
+}
+
+/// Options controlling the WKT output of [`GTrait::to_wkt_with()`].
+///
+/// The default values produce the same output as [`GTrait::to_wkt()`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct WktOptions {
+    precision: usize,
+    dim_tag: DimTag,
+    parenthesized_points: bool,
+    empty: EmptyWkt,
+    rounding: Option<RoundingMode>,
+}
+
+impl Default for WktOptions {
+    fn default() -> Self {
+        Self {
+            precision: config().default_precision(),
+            dim_tag: DimTag::default(),
+            parenthesized_points: false,
+            empty: EmptyWkt::default(),
+            rounding: config().rounding_mode(),
+        }
+    }
+}
+
+impl WktOptions {
+    /// Set the number of digits to print after the decimal point.
+    pub fn with_precision(mut self, precision: usize) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Set how 3D geometries, incl. every member of a collection, are tagged.
+    pub fn with_dim_tag(mut self, dim_tag: DimTag) -> Self {
+        self.dim_tag = dim_tag;
+        self
+    }
+
+    /// Enclose every MULTIPOINT member in parentheses; e.g.
+    /// `MULTIPOINT ((1 2), (3 4))` instead of `MULTIPOINT (1 2, 3 4)`.
+    pub fn with_parenthesized_points(mut self) -> Self {
+        self.parenthesized_points = true;
+        self
+    }
+
+    /// Set how an undefined geometry is rendered.
+    pub fn with_empty(mut self, empty: EmptyWkt) -> Self {
+        self.empty = empty;
+        self
+    }
+
+    /// Round every coordinate w/ the given `mode` before printing it. When
+    /// unset, and no `ROUNDING_MODE` is configured, the last digit is the one
+    /// produced by Rust's `{:.N}` formatting of the value.
+    pub fn with_rounding(mut self, mode: RoundingMode) -> Self {
+        self.rounding = Some(mode);
+        self
+    }
+
+    /// Return the number of digits printed after the decimal point.
+    pub fn precision(&self) -> usize {
+        self.precision
+    }
+
+    /// Return how 3D geometries are tagged.
+    pub fn dim_tag(&self) -> DimTag {
+        self.dim_tag
+    }
+
+    /// Return TRUE if MULTIPOINT members are enclosed in parentheses. Return
+    /// FALSE otherwise.
+    pub fn parenthesized_points(&self) -> bool {
+        self.parenthesized_points
+    }
+
+    /// Return how an undefined geometry is rendered.
+    pub fn empty(&self) -> EmptyWkt {
+        self.empty
+    }
+
+    /// Return the mode used for rounding coordinates if any.
+    pub fn rounding(&self) -> Option<RoundingMode> {
+        self.rounding
+    }
+
+    // return `x` formatted w/ the configured precision + rounding mode...
+    pub(crate) fn fmt_f64(&self, x: f64) -> String {
+        let x = match self.rounding {
+            Some(mode) => mode.round(x, self.precision),
+            None => x,
+        };
+        format!("{:.1$}", x, self.precision)
+    }
+
+    // return the WKT keyword, incl. the dimension tag if any, of a geometry
+    // type given its `name` + dimension...
+    pub(crate) fn keyword(&self, name: &str, is_2d: bool) -> String {
+        match (is_2d, self.dim_tag) {
+            (true, _) | (false, DimTag::Omitted) => name.to_owned(),
+            (false, DimTag::Spaced) => format!("{name} Z"),
+            (false, DimTag::Attached) => format!("{name}Z"),
+        }
+    }
+}
+
 /// Geometry Trait implemented by all [geometry][G] types in this library.
 pub trait GTrait {
     /// Return TRUE if coordinates are 2D. Return FALSE otherwise.
@@ -103,7 +416,29 @@ pub trait GTrait {
     /// # Ok(())
     /// # }
     /// ```
-    fn to_wkt_fmt(&self, precision: usize) -> String;
+    fn to_wkt_fmt(&self, precision: usize) -> String {
+        self.to_wkt_with(&WktOptions::default().with_precision(precision))
+    }
+
+    /// Generate a WKT string w/ finer control over its layout than what the
+    /// `to_wkt_fmt()` alternative offers. This is useful when interoperating
+    /// w/ strict WKT consumers.
+    ///
+    /// Here's an example...
+    /// ```rust
+    /// use ogc_cql2::prelude::*;
+    /// # use std::error::Error;
+    /// # fn test() -> Result<(), Box<dyn Error>> {
+    ///     let g = G::try_from("MULTIPOINT Z(1 2 3, 4 5 6)")?;
+    ///     let opts = WktOptions::default()
+    ///         .with_precision(0)
+    ///         .with_dim_tag(DimTag::Attached)
+    ///         .with_parenthesized_points();
+    ///     assert_eq!(g.to_wkt_with(&opts), "MULTIPOINTZ ((1 2 3), (4 5 6))");
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn to_wkt_with(&self, opts: &WktOptions) -> String;
 
     /// Check if all geometry coordinates fall w/in a given CRS's Area-of-Use,
     /// aka Extent-of-Validity.
@@ -131,17 +466,17 @@ impl GTrait for G {
         }
     }
 
-    fn to_wkt_fmt(&self, precision: usize) -> String {
+    fn to_wkt_with(&self, opts: &WktOptions) -> String {
         match self {
-            G::Point(x) => x.to_wkt_fmt(precision),
-            G::Line(x) => x.to_wkt_fmt(precision),
-            G::Polygon(x) => x.to_wkt_fmt(precision),
-            G::Points(x) => x.to_wkt_fmt(precision),
-            G::Lines(x) => x.to_wkt_fmt(precision),
-            G::Polygons(x) => x.to_wkt_fmt(precision),
-            G::Vec(x) => x.to_wkt_fmt(precision),
-            G::BBox(x) => x.to_wkt_fmt(precision),
-            _ => unreachable!("N/A for this geometry type"),
+            G::Null => opts.empty().to_string(),
+            G::Point(x) => x.to_wkt_with(opts),
+            G::Line(x) => x.to_wkt_with(opts),
+            G::Polygon(x) => x.to_wkt_with(opts),
+            G::Points(x) => x.to_wkt_with(opts),
+            G::Lines(x) => x.to_wkt_with(opts),
+            G::Polygons(x) => x.to_wkt_with(opts),
+            G::Vec(x) => x.to_wkt_with(opts),
+            G::BBox(x) => x.to_wkt_with(opts),
         }
     }
 
@@ -189,6 +524,32 @@ impl GTrait for G {
 }
 
 impl G {
+    /// Return the _Web Mercator_ (EPSG:3857) polygon covering the slippy-map
+    /// tile at zoom level `z` w/ column `x` and row `y`, counted from the
+    /// top-left (north-west) corner of the world; e.g. to filter the _Features_
+    /// of that tile w/ `S_INTERSECTS("geom", <tile>)`.
+    ///
+    /// Return an error if `z` is greater than 32, or if `x` or `y` are not
+    /// less than `2^z`.
+    pub fn from_tile(z: u8, x: u32, y: u32) -> Result<G, MyError> {
+        // half the width, in meters, of the square Web Mercator world...
+        const HALF: f64 = 20_037_508.342_789_244;
+
+        let n = 1_u64 << z.min(32);
+        if z > 32 || u64::from(x) >= n || u64::from(y) >= n {
+            let msg = format!("Invalid tile {z}/{x}/{y}. Expected z <= 32, and x, y < 2^z");
+            error!("{msg}");
+            return Err(MyError::Runtime(msg.into()));
+        }
+
+        let size = 2.0 * HALF / n as f64;
+        let w = -HALF + f64::from(x) * size;
+        let n = HALF - f64::from(y) * size;
+        let (e, s) = (w + size, n - size);
+        let ring = vec![vec![w, s], vec![e, s], vec![e, n], vec![w, n], vec![w, s]];
+        Ok(G::Polygon(Polygon::from_xy_and_srid(vec![ring], EPSG_3857)))
+    }
+
     /// Return this if it was indeed a Point, `None` otherwise.
     pub fn as_point(&self) -> Option<&Point> {
         match self {
@@ -237,8 +598,117 @@ impl G {
         }
     }
 
+    /// Return this if it was indeed a Bounding Box, `None` otherwise.
+    pub fn as_bbox(&self) -> Option<&BBox> {
+        match self {
+            G::BBox(x) => Some(x),
+            _ => None,
+        }
+    }
+
+    /// Return the number of member geometries of this if it's a collection;
+    /// i.e. a `MULTI...` geometry or a `GEOMETRYCOLLECTION`. Return 1 for any
+    /// other geometry, including a `BBOX`, and 0 for [`G::Null`].
+    pub fn num_geometries(&self) -> usize {
+        match self {
+            G::Null => 0,
+            G::Point(_) | G::Line(_) | G::Polygon(_) | G::BBox(_) => 1,
+            G::Points(x) => x.num_points(),
+            G::Lines(x) => x.num_lines(),
+            G::Polygons(x) => x.num_polygons(),
+            G::Vec(x) => x.num_geometries(),
+        }
+    }
+
+    /// Return TRUE if this has no coordinates; FALSE otherwise. That's the case
+    /// of:
+    /// * [`G::Null`]; e.g. a decoded `POINT EMPTY`, or the result of the
+    ///   intersection of 2 disjoint geometries,
+    /// * a line w/o any points, or a polygon w/o any rings, and
+    /// * a `MULTI...` geometry or a `GEOMETRYCOLLECTION` w/o any members.
+    ///
+    /// Points and bounding boxes are never empty.
+    pub fn is_empty(&self) -> bool {
+        match self {
+            G::Line(x) => x.num_points() == 0,
+            G::Polygon(x) => x.num_rings() == 0,
+            _ => self.num_geometries() == 0,
+        }
+    }
+
+    /// Return a copy of the (zero-based) `n`-th member geometry of this if
+    /// it's a collection, w/ the same SRID; e.g. a `POINT` for a `MULTIPOINT`.
+    /// Any other geometry is its own single member.
+    ///
+    /// Return `None` if `n` is not less than [`num_geometries()`][Self::num_geometries()].
+    pub fn geometry_n(&self, n: usize) -> Option<G> {
+        if matches!(self, G::Null) {
+            return None;
+        }
+        let srid = self.srid();
+        match self {
+            G::Null => None,
+            G::Point(_) | G::Line(_) | G::Polygon(_) | G::BBox(_) => {
+                (n == 0).then(|| self.to_owned())
+            }
+            G::Points(x) => x
+                .points()
+                .nth(n)
+                .map(|xy| G::Point(Point::from_xy_and_srid(xy.to_owned(), srid))),
+            G::Lines(x) => x
+                .lines()
+                .nth(n)
+                .map(|xy| G::Line(Line::from_xy_and_srid(xy.to_owned(), srid))),
+            G::Polygons(x) => x
+                .polygons()
+                .nth(n)
+                .map(|xy| G::Polygon(Polygon::from_xy_and_srid(xy.to_owned(), srid))),
+            G::Vec(x) => x.geometries().nth(n).cloned(),
+        }
+    }
+
+    /// Return an [RFC 7946][1] GeoJSON geometry object representing this.
+    ///
+    /// Z coordinates, if any, are emitted as a 3rd position element. Since
+    /// GeoJSON has no bounding box geometry type, a `BBOX` is rendered as a
+    /// `Polygon`, or a `MultiPolygon` when it spans the antimeridian, ignoring
+    /// its elevations. [`G::Null`] is rendered as JSON `null`.
+    ///
+    /// Note that coordinates are written as is; i.e. they are not transformed
+    /// to WGS 84 if this uses a different CRS.
+    ///
+    /// [1]: https://datatracker.ietf.org/doc/html/rfc7946#section-3.1
+    pub fn to_geojson(&self) -> Value {
+        let object =
+            |type_: &str, coordinates: Value| json!({ "type": type_, "coordinates": coordinates });
+        match self {
+            G::Null => Value::Null,
+            G::Point(x) => object("Point", json!(x.xy())),
+            G::Line(x) => object("LineString", json!(x.points().as_slice())),
+            G::Polygon(x) => object("Polygon", json!(x.rings().as_slice())),
+            G::Points(x) => object("MultiPoint", json!(x.points().as_slice())),
+            G::Lines(x) => object("MultiLineString", json!(x.lines().as_slice())),
+            G::Polygons(x) => object("MultiPolygon", json!(x.polygons().as_slice())),
+            G::Vec(x) => json!({
+                "type": "GeometryCollection",
+                "geometries": x.geometries().map(G::to_geojson).collect::<Vec<_>>()
+            }),
+            G::BBox(x) => x.to_geojson(),
+        }
+    }
+
     // ----- GEOS related methods...
 
+    // return the WKT of the GEOS normalized form of this; i.e. w/ its rings,
+    // members and vertices in a canonical order...
+    #[cfg(feature = "geos")]
+    pub(crate) fn to_normalized_wkt(&self) -> Result<String, MyError> {
+        let mut g = self.to_geos()?;
+        g.normalize()?;
+        Ok(g.to_wkt()?)
+    }
+
+    #[cfg(feature = "geos")]
     pub(crate) fn to_geos(&self) -> Result<Geometry, MyError> {
         match self {
             G::Point(x) => x.to_geos(),
@@ -249,17 +719,36 @@ impl G {
             G::Polygons(x) => x.to_geos(),
             G::Vec(x) => x.to_geos(),
             G::BBox(x) => x.to_geos(),
-            _ => unreachable!("N/A for this geometry type"),
+            G::Null => Err(MyError::Runtime(
+                "An undefined (NULL) geometry has no GEOS equivalent".into(),
+            )),
         }
     }
 
+    // NOTE (rsn) 20261016 - GEOS does not support GeometryCollection operands
+    // in most of its spatial predicates. where the semantics allow it, such
+    // predicates are evaluated against the collection's members instead...
+    #[cfg(feature = "geos")]
     pub(crate) fn intersects(&self, other: &G) -> Result<bool, MyError> {
+        // TRUE if any member intersects the other operand...
+        if let G::Vec(x) = self {
+            for g in x.geometries() {
+                if g.intersects(other)? {
+                    return Ok(true);
+                }
+            }
+            return Ok(false);
+        }
+        if let G::Vec(_) = other {
+            return other.intersects(self);
+        }
         let lhs = self.to_geos()?;
         let rhs = other.to_geos()?;
         let result = lhs.intersects(&rhs)?;
         Ok(result)
     }
 
+    #[cfg(feature = "geos")]
     pub(crate) fn equals(&self, other: &G) -> Result<bool, MyError> {
         let lhs = self.to_geos()?;
         let rhs = other.to_geos()?;
@@ -267,13 +756,30 @@ impl G {
         Ok(result)
     }
 
+    // NOTE (rsn) 20261016 - unlike `equals()` this is not a topological test.
+    // it checks the 2 geometries have the same structure, and that all their
+    // vertices pair-wise are w/in `tolerance` of each other...
+    #[cfg(feature = "geos")]
+    pub(crate) fn equals_exact(&self, other: &G, tolerance: f64) -> Result<bool, MyError> {
+        let lhs = self.to_geos()?;
+        let rhs = other.to_geos()?;
+        let result = lhs.equals_exact(&rhs, tolerance)?;
+        Ok(result)
+    }
+
+    #[cfg(feature = "geos")]
     pub(crate) fn disjoint(&self, other: &G) -> Result<bool, MyError> {
+        // TRUE only if all members are disjoint from the other operand...
+        if matches!(self, G::Vec(_)) || matches!(other, G::Vec(_)) {
+            return Ok(!self.intersects(other)?);
+        }
         let lhs = self.to_geos()?;
         let rhs = other.to_geos()?;
         let result = lhs.disjoint(&rhs)?;
         Ok(result)
     }
 
+    #[cfg(feature = "geos")]
     pub(crate) fn touches(&self, other: &G) -> Result<bool, MyError> {
         let lhs = self.to_geos()?;
         let rhs = other.to_geos()?;
@@ -281,13 +787,27 @@ impl G {
         Ok(result)
     }
 
+    #[cfg(feature = "geos")]
     pub(crate) fn within(&self, other: &G) -> Result<bool, MyError> {
+        // TRUE if every member is w/in the other operand...
+        if let G::Vec(x) = self {
+            if x.num_geometries() == 0 {
+                return Ok(false);
+            }
+            for g in x.geometries() {
+                if !g.within(other)? {
+                    return Ok(false);
+                }
+            }
+            return Ok(true);
+        }
         let lhs = self.to_geos()?;
         let rhs = other.to_geos()?;
         let result = lhs.within(&rhs)?;
         Ok(result)
     }
 
+    #[cfg(feature = "geos")]
     pub(crate) fn overlaps(&self, other: &G) -> Result<bool, MyError> {
         let lhs = self.to_geos()?;
         let rhs = other.to_geos()?;
@@ -295,6 +815,7 @@ impl G {
         Ok(result)
     }
 
+    #[cfg(feature = "geos")]
     pub(crate) fn crosses(&self, other: &G) -> Result<bool, MyError> {
         let lhs = self.to_geos()?;
         let rhs = other.to_geos()?;
@@ -302,15 +823,251 @@ impl G {
         Ok(result)
     }
 
+    #[cfg(feature = "geos")]
     pub(crate) fn contains(&self, other: &G) -> Result<bool, MyError> {
+        // TRUE if every member of the other operand is w/in this...
+        if let G::Vec(_) = other {
+            return other.within(self);
+        }
         let lhs = self.to_geos()?;
         let rhs = other.to_geos()?;
         let result = lhs.contains(&rhs)?;
         Ok(result)
     }
 
+    // unlike `contains`, TRUE even if the other operand lies entirely on the
+    // boundary of this; e.g. a point on a polygon's edge, or the polygon's
+    // own boundary...
+    #[cfg(feature = "geos")]
+    pub(crate) fn covers(&self, other: &G) -> Result<bool, MyError> {
+        if let G::Vec(_) = other {
+            return other.covered_by(self);
+        }
+        let lhs = self.to_geos()?;
+        let rhs = other.to_geos()?;
+        let result = lhs.covers(&rhs)?;
+        Ok(result)
+    }
+
+    #[cfg(feature = "geos")]
+    pub(crate) fn covered_by(&self, other: &G) -> Result<bool, MyError> {
+        // TRUE if every member is covered by the other operand...
+        if let G::Vec(x) = self {
+            if x.num_geometries() == 0 {
+                return Ok(false);
+            }
+            for g in x.geometries() {
+                if !g.covered_by(other)? {
+                    return Ok(false);
+                }
+            }
+            return Ok(true);
+        }
+        let lhs = self.to_geos()?;
+        let rhs = other.to_geos()?;
+        let result = lhs.covered_by(&rhs)?;
+        Ok(result)
+    }
+
+    /// Return TRUE if this geometry is valid in the OGC _Simple Features_
+    /// sense; e.g. its polygons' rings do not self-intersect. Return FALSE
+    /// otherwise. An undefined geometry ([`G::Null`]) is considered valid.
+    #[cfg(feature = "geos")]
+    pub fn is_valid(&self) -> Result<bool, MyError> {
+        if matches!(self, G::Null) {
+            return Ok(true);
+        }
+        let g = self.to_geos()?;
+        let it = g.is_valid()?;
+        Ok(it)
+    }
+
+    /// Return `None` if this geometry is valid (see [`G::is_valid`]). Otherwise
+    /// return the reason why it's not; e.g. `"Self-intersection"`, along w/
+    /// the [`Point`], in the SRID of this, where the problem was detected if
+    /// `GEOS` reports one.
+    ///
+    /// Unlike `is_valid`, this is meant for triaging invalid geometries; e.g.
+    /// when cleaning a dataset, rather than as a cheap check.
+    #[cfg(feature = "geos")]
+    pub fn validity_reason(&self) -> Result<Option<(String, Option<Point>)>, MyError> {
+        if self.is_valid()? {
+            return Ok(None);
+        }
+        let g = self.to_geos()?;
+        let reason = g.is_valid_reason()?;
+        // NOTE (rsn) 20261016 - GEOS reports the location, if any, as a
+        // bracketed suffix; e.g. "Self-intersection[5 5]"...
+        let Some((text, rest)) = reason.split_once('[') else {
+            return Ok(Some((reason.trim().to_owned(), None)));
+        };
+        let xy = rest
+            .trim_end_matches(']')
+            .split_whitespace()
+            .map(str::parse::<f64>)
+            .collect::<Result<Vec<_>, _>>();
+        let location = match xy {
+            Ok(xy) if xy.len() >= 2 => Some(Point::from_xy_and_srid(xy, self.srid())),
+            _ => None,
+        };
+        Ok(Some((text.trim().to_owned(), location)))
+    }
+
+    /// Return a valid version of this geometry w/o losing any of its vertices.
+    /// The result keeps the SRID of this.
+    ///
+    /// Note that the result may be of a different type; e.g. a self-intersecting
+    /// (bow-tie) `POLYGON` becomes a `MULTIPOLYGON`, and a collapsed one may
+    /// become a `LINESTRING` or a `GEOMETRYCOLLECTION`.
+    #[cfg(feature = "geos")]
+    pub fn make_valid(&self) -> Result<Self, MyError> {
+        if matches!(self, G::Null) {
+            return Ok(G::Null);
+        }
+        let g1 = self.to_geos()?;
+        let g2 = g1.make_valid()?;
+        if g2.is_empty()? {
+            return Ok(G::Null);
+        }
+        let mut it = G::try_from(g2)?;
+        it.set_srid_unchecked(&self.srid());
+        Ok(it)
+    }
+
+    /// Return TRUE if any 2 consecutive vertices of this geometry's lines, or
+    /// polygons' rings, are more than 180 degrees of longitude apart; i.e. if
+    /// the shortest path between them crosses the antimeridian.
+    #[cfg(feature = "geos")]
+    pub(crate) fn crosses_antimeridian(&self) -> bool {
+        let jumps = |xy: &[XY1V]| xy.windows(2).any(|w| (w[1][0] - w[0][0]).abs() > 180.0);
+        match self {
+            G::Line(x) => jumps(x.points().as_slice()),
+            G::Polygon(x) => x.rings().any(|r| jumps(r)),
+            G::Lines(x) => x.lines().any(|l| jumps(l)),
+            G::Polygons(x) => x.polygons().flatten().any(|r| jumps(r)),
+            G::Vec(x) => x.geometries().any(G::crosses_antimeridian),
+            // points have no edges, and a BBOX w/ w > e is already handled...
+            _ => false,
+        }
+    }
+
+    /// Return a copy of this split at the antimeridian if it
+    /// [crosses][Self::crosses_antimeridian()] it; or a clone of this otherwise.
+    ///
+    /// The parts east of the antimeridian (w/ negative longitudes) are first
+    /// shifted by +360 degrees so the geometry is contiguous. It's then clipped
+    /// to the [0, 180] and [180, 360] longitude bands, and the latter part is
+    /// shifted back by -360 degrees. The result is a multi-geometry, or a
+    /// collection, whose parts all lie w/in [-180, 180].
+    #[cfg(feature = "geos")]
+    pub(crate) fn split_at_antimeridian(&self) -> Result<Self, MyError> {
+        if !self.crosses_antimeridian() {
+            return Ok(self.clone());
+        }
+
+        let shift = |dx: f64, negative_only: bool| {
+            move |xy: &[f64]| {
+                let mut it = xy.to_vec();
+                if !negative_only || it[0] < 0.0 {
+                    it[0] += dx;
+                }
+                Ok::<_, MyError>(it)
+            }
+        };
+        let g = self.map_xy(&shift(360.0, true))?.to_geos()?;
+        let west_band = BBox::from(vec![0.0, -90.0, 180.0, 90.0]).to_geos()?;
+        let east_band = BBox::from(vec![180.0, -90.0, 360.0, 90.0]).to_geos()?;
+        let west = g.intersection(&west_band)?;
+        let east = g.intersection(&east_band)?;
+        let it = if east.is_empty()? {
+            west
+        } else {
+            let east = G::try_from(east)?.map_xy(&shift(-360.0, false))?;
+            if west.is_empty()? {
+                east.to_geos()?
+            } else {
+                west.union(&east.to_geos()?)?
+            }
+        };
+        let mut result = G::try_from(it)?;
+        result.set_srid_unchecked(&self.srid());
+        Ok(result)
+    }
+
+    /// Return the union of this geometry and `other`; i.e. all the points
+    /// that are in either. The result keeps the SRID of this.
+    ///
+    /// Return [`G::Null`] if the result is empty.
+    #[cfg(feature = "geos")]
+    pub fn union(&self, other: &G) -> Result<Self, MyError> {
+        match (self, other) {
+            (G::Null, G::Null) => Ok(G::Null),
+            (G::Null, x) | (x, G::Null) => Ok(x.clone()),
+            _ => self.overlay(other, |a, b| a.union(b)),
+        }
+    }
+
+    /// Return the intersection of this geometry and `other`; i.e. the points
+    /// they have in common. The result keeps the SRID of this.
+    ///
+    /// Return [`G::Null`] if the result is empty; e.g. when they're disjoint.
+    #[cfg(feature = "geos")]
+    pub fn intersection(&self, other: &G) -> Result<Self, MyError> {
+        match (self, other) {
+            (G::Null, _) | (_, G::Null) => Ok(G::Null),
+            _ => self.overlay(other, |a, b| a.intersection(b)),
+        }
+    }
+
+    /// Return the difference of this geometry and `other`; i.e. the points of
+    /// this that are not in `other`. The result keeps the SRID of this.
+    ///
+    /// Return [`G::Null`] if the result is empty; e.g. when `other` covers
+    /// this.
+    #[cfg(feature = "geos")]
+    pub fn difference(&self, other: &G) -> Result<Self, MyError> {
+        match (self, other) {
+            (G::Null, _) => Ok(G::Null),
+            (x, G::Null) => Ok(x.clone()),
+            _ => self.overlay(other, |a, b| a.difference(b)),
+        }
+    }
+
+    /// Return the symmetric difference of this geometry and `other`; i.e. the
+    /// points that are in either but not in both. The result keeps the SRID of
+    /// this.
+    ///
+    /// Return [`G::Null`] if the result is empty; e.g. when they're equal.
+    #[cfg(feature = "geos")]
+    pub fn sym_difference(&self, other: &G) -> Result<Self, MyError> {
+        match (self, other) {
+            (G::Null, G::Null) => Ok(G::Null),
+            (G::Null, x) | (x, G::Null) => Ok(x.clone()),
+            _ => self.overlay(other, |a, b| a.sym_difference(b)),
+        }
+    }
+
+    // apply the GEOS overlay operation `op` to this and `other` (neither being
+    // `Null`) mapping an empty result to `Null`...
+    #[cfg(feature = "geos")]
+    fn overlay<F>(&self, other: &G, op: F) -> Result<Self, MyError>
+    where
+        F: Fn(&Geometry, &Geometry) -> geos::GResult<Geometry>,
+    {
+        let g1 = self.to_geos()?;
+        let g2 = other.to_geos()?;
+        let g3 = op(&g1, &g2)?;
+        if g3.is_empty()? {
+            return Ok(G::Null);
+        }
+        let mut it = G::try_from(g3)?;
+        it.set_srid_unchecked(&self.srid());
+        Ok(it)
+    }
+
     // ----- methods exposed for use by Functions...
 
+    #[cfg(feature = "geos")]
     pub(crate) fn boundary(&self) -> Result<Self, MyError> {
         let g1 = self.to_geos()?;
         let g2 = g1.boundary()?;
@@ -318,6 +1075,7 @@ impl G {
         Ok(it)
     }
 
+    #[cfg(feature = "geos")]
     pub(crate) fn buffer(&self, width: f64, quadsegs: i32) -> Result<Self, MyError> {
         let g1 = self.to_geos()?;
         let g2 = g1.buffer(width, quadsegs)?;
@@ -325,13 +1083,32 @@ impl G {
         Ok(it)
     }
 
-    pub(crate) fn envelope(&self) -> Result<Self, MyError> {
-        let g1 = self.to_geos()?;
-        let g2 = g1.envelope()?;
-        let it = G::try_from(g2)?;
-        Ok(it)
+    /// Return the 2D minimum bounding box of this as a [`G::BBox`] w/ the
+    /// same SRID. Elevations, if any, are ignored.
+    ///
+    /// A bounding box is returned as is; i.e. even if it spans the antimeridian.
+    /// Return [`G::Null`] if this is `G::Null`, and an error if this is empty.
+    #[cfg(feature = "geos")]
+    pub fn envelope(&self) -> Result<Self, MyError> {
+        match self {
+            G::Null => Ok(G::Null),
+            G::BBox(x) => Ok(G::BBox(x.to_2d())),
+            _ => {
+                let g = self.to_geos()?;
+                let xy = vec![
+                    g.get_x_min()?,
+                    g.get_y_min()?,
+                    g.get_x_max()?,
+                    g.get_y_max()?,
+                ];
+                let mut it = BBox::from(xy);
+                it.set_srid_unchecked(&self.srid());
+                Ok(G::BBox(it))
+            }
+        }
     }
 
+    #[cfg(feature = "geos")]
     pub(crate) fn centroid(&self) -> Result<Self, MyError> {
         let g1 = self.to_geos()?;
         let g2 = g1.get_centroid()?;
@@ -339,6 +1116,7 @@ impl G {
         Ok(it)
     }
 
+    #[cfg(feature = "geos")]
     pub(crate) fn convex_hull(&self) -> Result<Self, MyError> {
         let g1 = self.to_geos()?;
         let g2 = g1.convex_hull()?;
@@ -346,6 +1124,102 @@ impl G {
         Ok(it)
     }
 
+    /// Return a [`G::Point`], w/ the SRID of this, guaranteed to lie on this
+    /// geometry; e.g. in the interior of a polygon, or on a line. Unlike the
+    /// centroid, it never falls outside of a concave polygon which makes it
+    /// suitable as a _representative point_; e.g. for labeling.
+    ///
+    /// Return [`G::Null`] if this is `G::Null`.
+    #[cfg(feature = "geos")]
+    pub fn point_on_surface(&self) -> Result<Self, MyError> {
+        if matches!(self, G::Null) {
+            return Ok(G::Null);
+        }
+
+        let g1 = self.to_geos()?;
+        let g2 = g1.point_on_surface()?;
+        if g2.is_empty()? {
+            return Ok(G::Null);
+        }
+        let mut it = G::try_from(g2)?;
+        it.set_srid_unchecked(&self.srid());
+        Ok(it)
+    }
+
+    /// Return a simplified version of this geometry using the _Douglas-Peucker_
+    /// algorithm w/ the given `tolerance` (in units of its CRS). The result
+    /// keeps the SRID of this.
+    ///
+    /// When the tolerance is large enough to collapse this to an empty
+    /// geometry, [`G::Null`] is returned rather than an error.
+    #[cfg(feature = "geos")]
+    pub fn simplify(&self, tolerance: f64) -> Result<Self, MyError> {
+        if tolerance.is_nan() || tolerance < 0.0 {
+            return Err(MyError::Runtime(
+                format!("Invalid ({tolerance}) simplification tolerance").into(),
+            ));
+        }
+        if matches!(self, G::Null) {
+            return Ok(G::Null);
+        }
+
+        let g1 = self.to_geos()?;
+        let g2 = g1.simplify(tolerance)?;
+        if g2.is_empty()? {
+            return Ok(G::Null);
+        }
+        let mut it = G::try_from(g2)?;
+        it.set_srid_unchecked(&self.srid());
+        Ok(it)
+    }
+
+    /// Return a copy of this geometry w/ intermediate vertices inserted along
+    /// its lines and polygon rings so that no segment is longer than
+    /// `max_segment_length` (in units of its CRS). The result keeps the SRID
+    /// of this, and Z coordinates, if any, are linearly interpolated.
+    ///
+    /// Points are left as they are, while a `BBOX` is densified as the
+    /// (multi-)polygon it stands for. See also [`transform_densified()`][1].
+    ///
+    /// [1]: Self::transform_densified()
+    pub fn densify(&self, max_segment_length: f64) -> Result<G, MyError> {
+        if !max_segment_length.is_finite() || max_segment_length <= 0.0 {
+            return Err(MyError::Runtime(
+                format!("Invalid ({max_segment_length}) maximum segment length").into(),
+            ));
+        }
+
+        let line = |xy: Iter<'_, XY1V>| densify_xy(xy, max_segment_length);
+        let rings = |xy: Iter<'_, XY2V>| xy.map(|x| line(x.iter())).collect::<XY3V>();
+        let srid = self.srid();
+        let it = match self {
+            G::Null | G::Point(_) | G::Points(_) => self.to_owned(),
+            G::Line(x) => G::Line(Line::from_xy_and_srid(line(x.points()), srid)),
+            G::Polygon(x) => G::Polygon(Polygon::from_xy_and_srid(rings(x.rings()), srid)),
+            G::Lines(x) => G::Lines(Lines::from_xy_and_srid(rings(x.lines()), srid)),
+            G::Polygons(x) => {
+                let xy = x.polygons().map(|p| rings(p.iter())).collect::<XY4V>();
+                G::Polygons(Polygons::from_xy_and_srid(xy, srid))
+            }
+            G::Vec(x) => {
+                let items = x
+                    .geometries()
+                    .map(|g| g.densify(max_segment_length))
+                    .collect::<Result<Vec<_>, _>>()?;
+                G::Vec(Geometries::from_items_and_srid(items, srid))
+            }
+            #[cfg(feature = "geos")]
+            G::BBox(x) => {
+                let mut g = G::try_from(x.to_geos()?)?;
+                g.set_srid_unchecked(&srid);
+                g.densify(max_segment_length)?
+            }
+            #[cfg(not(feature = "geos"))]
+            G::BBox(_) => return Err(spatial_disabled()),
+        };
+        Ok(it)
+    }
+
     pub(crate) fn get_x(&self) -> Result<f64, MyError> {
         if let Some(pt) = self.as_point() {
             Ok(pt.x())
@@ -374,6 +1248,161 @@ impl G {
         }
     }
 
+    /// Return the area of this geometry in units of its CRS.
+    ///
+    /// Non-areal geometries (i.e. points and lines) have no area; for those,
+    /// as well as for [`G::Null`], `0.0` is returned rather than an error.
+    #[cfg(feature = "geos")]
+    pub fn area(&self) -> Result<f64, MyError> {
+        if matches!(self, G::Null) {
+            return Ok(0.0);
+        }
+        let g = self.to_geos()?;
+        let it = g.area()?;
+        Ok(it)
+    }
+
+    /// Return the length of this geometry in units of its CRS.
+    ///
+    /// For polygons it's the length of their rings (i.e. their perimeter),
+    /// while for points, and [`G::Null`], it is `0.0`.
+    #[cfg(feature = "geos")]
+    pub fn length(&self) -> Result<f64, MyError> {
+        if matches!(self, G::Null) {
+            return Ok(0.0);
+        }
+        let g = self.to_geos()?;
+        let it = g.length()?;
+        Ok(it)
+    }
+
+    /// Return the minimum distance between this and `other` geometries in
+    /// units of their CRS.
+    ///
+    /// For geographic coordinates, such as those of `EPSG:4326`, that means
+    /// degrees and not metres unless both geometries are first reprojected to
+    /// a projected CRS (see [`transform()`][Self::transform()]).
+    #[cfg(feature = "geos")]
+    pub fn distance(&self, other: &G) -> Result<f64, MyError> {
+        let (lhs, rhs) = Self::to_geos_pair(self, other)?;
+        let it = lhs.distance(&rhs)?;
+        Ok(it)
+    }
+
+    /// Return the pair of points, the 1<sup>st</sup> on this and the 2<sup>nd</sup>
+    /// on `other`, that are nearest to each other; i.e. whose distance is the
+    /// [`distance()`][Self::distance()] between the two geometries.
+    ///
+    /// Both points are 2D and have the SRID of this.
+    #[cfg(feature = "geos")]
+    pub fn nearest_points(&self, other: &G) -> Result<(Point, Point), MyError> {
+        let (lhs, rhs) = Self::to_geos_pair(self, other)?;
+        let cs = lhs.nearest_points(&rhs)?;
+        let srid = self.srid();
+        let a = Point::from_xy_and_srid(vec![cs.get_x(0)?, cs.get_y(0)?], srid);
+        let b = Point::from_xy_and_srid(vec![cs.get_x(1)?, cs.get_y(1)?], srid);
+        Ok((a, b))
+    }
+
+    // convert both geometries to their GEOS equivalent raising an error if
+    // either is NULL...
+    #[cfg(feature = "geos")]
+    fn to_geos_pair(a: &G, b: &G) -> Result<(Geometry, Geometry), MyError> {
+        if matches!(a, G::Null) || matches!(b, G::Null) {
+            return Err(MyError::Runtime("Expected 2 non-NULL geometries".into()));
+        }
+        Ok((a.to_geos()?, b.to_geos()?))
+    }
+
+    /// Return a copy of this geometry w/ its coordinates transformed from the
+    /// `from` CRS to the `to` one. The result has the SRID of `to`.
+    ///
+    /// Z coordinates, if any, are passed through unchanged. Return an error
+    /// if `to` is not an EPSG code, if Proj fails transforming a coordinate,
+    /// or if the result falls outside the area-of-use of `to`.
+    ///
+    /// A `BBOX` is transformed as the (multi-)polygon it stands for.
+    #[cfg(feature = "geos")]
+    pub fn transform(&self, from: &CRS, to: &CRS) -> Result<G, MyError> {
+        if matches!(self, G::Null) {
+            return Ok(G::Null);
+        }
+
+        let srid = to.srid()?;
+        let proj = from.pipeline_to(to)?;
+        let mut result = self.map_xy(&|xy| transform_xy(&proj, xy))?;
+        result.set_srid_unchecked(&srid);
+
+        // NOTE (rsn) 20261016 - a CRS area-of-use is expressed in geographic
+        // (WGS 84) degrees. check the transformed geometry in those units...
+        let wgs84 = CRS::new("EPSG:4326")?;
+        let geographic = if from.definition() == wgs84.definition() {
+            self.map_xy(&|xy| Ok(xy.to_vec()))?
+        } else {
+            let proj = from.pipeline_to(&wgs84)?;
+            self.map_xy(&|xy| transform_xy(&proj, xy))?
+        };
+        geographic.check_coordinates(to).inspect_err(|x| {
+            error!("Transformed geometry is outside '{to}' extent: {x}");
+        })?;
+
+        Ok(result)
+    }
+
+    /// Similar to [`transform()`][Self::transform()] except that this is first
+    /// [densified][Self::densify()] so that its straight edges, no longer than
+    /// `max_segment_length` in units of `from`, follow the curves they map to
+    /// in `to`; e.g. the parallels bounding a box reprojected to a polar CRS.
+    #[cfg(feature = "geos")]
+    pub fn transform_densified(
+        &self,
+        from: &CRS,
+        to: &CRS,
+        max_segment_length: f64,
+    ) -> Result<G, MyError> {
+        self.densify(max_segment_length)?.transform(from, to)
+    }
+
+    // return a copy of this w/ every coordinate replaced by the result of
+    // applying `f` to it. the SRID is kept as is...
+    #[cfg(feature = "geos")]
+    fn map_xy<F>(&self, f: &F) -> Result<G, MyError>
+    where
+        F: Fn(&[f64]) -> Result<XY1V, MyError>,
+    {
+        let line = |xy: Iter<'_, XY1V>| xy.map(|x| f(x)).collect::<Result<XY2V, _>>();
+        let rings = |xy: Iter<'_, XY2V>| xy.map(|x| line(x.iter())).collect::<Result<XY3V, _>>();
+        let srid = self.srid();
+        let it = match self {
+            G::Null => G::Null,
+            G::Point(x) => G::Point(Point::from_xy_and_srid(f(x.xy())?, srid)),
+            G::Line(x) => G::Line(Line::from_xy_and_srid(line(x.points())?, srid)),
+            G::Polygon(x) => G::Polygon(Polygon::from_xy_and_srid(rings(x.rings())?, srid)),
+            G::Points(x) => G::Points(Points::from_xy_and_srid(line(x.points())?, srid)),
+            G::Lines(x) => G::Lines(Lines::from_xy_and_srid(rings(x.lines())?, srid)),
+            G::Polygons(x) => {
+                let xy = x
+                    .polygons()
+                    .map(|p| rings(p.iter()))
+                    .collect::<Result<XY4V, _>>()?;
+                G::Polygons(Polygons::from_xy_and_srid(xy, srid))
+            }
+            G::Vec(x) => {
+                let items = x
+                    .geometries()
+                    .map(|g| g.map_xy(f))
+                    .collect::<Result<Vec<_>, _>>()?;
+                G::Vec(Geometries::from_items_and_srid(items, srid))
+            }
+            G::BBox(x) => {
+                let mut g = G::try_from(x.to_geos()?)?;
+                g.set_srid_unchecked(&srid);
+                g.map_xy(f)?
+            }
+        };
+        Ok(it)
+    }
+
     // ----- methods used to accommodate GeoPackage related ops...
 
     pub(crate) fn to_sql(&self) -> Result<String, MyError> {
@@ -404,6 +1433,59 @@ impl G {
     }
 }
 
+// generate stand-ins, w/ the same signatures, for the GEOS backed methods of
+// `G` that always fail when built w/o the `geos` feature...
+#[cfg(not(feature = "geos"))]
+macro_rules! disabled_ops {
+    ($($vis:vis fn $name:ident(&self $(, $arg:ident: $ty:ty)*) -> $ret:ty;)+) => {
+        $(
+            /// Not available w/o the `geos` feature; always return an error.
+            $vis fn $name(&self $(, $arg: $ty)*) -> Result<$ret, MyError> {
+                Err(spatial_disabled())
+            }
+        )+
+    };
+}
+
+#[cfg(not(feature = "geos"))]
+impl G {
+    disabled_ops! {
+        pub(crate) fn to_normalized_wkt(&self) -> String;
+        pub(crate) fn intersects(&self, _other: &G) -> bool;
+        pub(crate) fn equals(&self, _other: &G) -> bool;
+        pub(crate) fn equals_exact(&self, _other: &G, _tolerance: f64) -> bool;
+        pub(crate) fn disjoint(&self, _other: &G) -> bool;
+        pub(crate) fn touches(&self, _other: &G) -> bool;
+        pub(crate) fn within(&self, _other: &G) -> bool;
+        pub(crate) fn overlaps(&self, _other: &G) -> bool;
+        pub(crate) fn crosses(&self, _other: &G) -> bool;
+        pub(crate) fn contains(&self, _other: &G) -> bool;
+        pub(crate) fn covers(&self, _other: &G) -> bool;
+        pub(crate) fn covered_by(&self, _other: &G) -> bool;
+        pub fn is_valid(&self) -> bool;
+        pub fn validity_reason(&self) -> Option<(String, Option<Point>)>;
+        pub fn make_valid(&self) -> Self;
+        pub(crate) fn split_at_antimeridian(&self) -> Self;
+        pub fn union(&self, _other: &G) -> Self;
+        pub fn intersection(&self, _other: &G) -> Self;
+        pub fn difference(&self, _other: &G) -> Self;
+        pub fn sym_difference(&self, _other: &G) -> Self;
+        pub(crate) fn boundary(&self) -> Self;
+        pub(crate) fn buffer(&self, _width: f64, _quadsegs: i32) -> Self;
+        pub fn envelope(&self) -> Self;
+        pub(crate) fn centroid(&self) -> Self;
+        pub(crate) fn convex_hull(&self) -> Self;
+        pub fn point_on_surface(&self) -> Self;
+        pub fn simplify(&self, _tolerance: f64) -> Self;
+        pub fn area(&self) -> f64;
+        pub fn length(&self) -> f64;
+        pub fn distance(&self, _other: &G) -> f64;
+        pub fn nearest_points(&self, _other: &G) -> (Point, Point);
+        pub fn transform(&self, _from: &CRS, _to: &CRS) -> G;
+        pub fn transform_densified(&self, _from: &CRS, _to: &CRS, _max_segment_length: f64) -> G;
+    }
+}
+
 impl fmt::Display for G {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -420,15 +1502,53 @@ impl fmt::Display for G {
     }
 }
 
-// Construct new instance from WKT string...
+// Split an EWKT string; e.g. `SRID=4326;POINT(1 2)` as output by PostGIS's
+// `ST_AsEWKT`, into its SRID and the plain WKT that follows. a plain WKT
+// string is returned as is w/o an SRID...
+fn split_ewkt(value: &str) -> Result<(Option<SRID>, &str), MyError> {
+    let s = value.trim_start();
+    match s.get(..5) {
+        Some(prefix) if prefix.eq_ignore_ascii_case("SRID=") => {
+            let Some((code, wkt)) = s[5..].split_once(';') else {
+                let msg = format!("Missing ';' after EWKT SRID in '{value}'");
+                error!("Failed: {}", msg);
+                return Err(MyError::Runtime(msg.into()));
+            };
+            let code = code.trim().parse::<i32>().map_err(|_| {
+                let msg = format!("Invalid ({code}) EWKT SRID");
+                error!("Failed: {}", msg);
+                MyError::Runtime(msg.into())
+            })?;
+            Ok((Some(SRID::try_from(code)?), wkt))
+        }
+        _ => Ok((None, value)),
+    }
+}
+
+// Construct new instance from WKT, or EWKT, string...
 impl TryFrom<&str> for G {
     type Error = MyError;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        let mut g = wkt(value).map_err(MyError::Text)?;
+        let (srid, value) = split_ewkt(value)?;
+        let mut g = wkt(value).map_err(|x| MyError::Text(SyntaxError::new(value, x)))?;
         // NOTE (rsn) 20251023 - WKT does not encode SRIDs.  assign configured
-        // global default set in .env...
-        g.set_srid_unchecked(config().default_srid());
+        // global default set in .env unless it's an EWKT w/ its own...
+        g.set_srid_unchecked(srid.as_ref().unwrap_or(config().default_srid()));
+
+        Ok(g)
+    }
+}
+
+// Construct new instance from a GeoJSON (RFC 7946) geometry object...
+impl TryFrom<&Value> for G {
+    type Error = MyError;
+
+    fn try_from(value: &Value) -> Result<Self, Self::Error> {
+        let txt = geojson_to_wkt(value)?;
+        let mut g = wkt(&txt).map_err(|x| MyError::Text(SyntaxError::new(&txt, x)))?;
+        // NOTE (rsn) 20261016 - RFC 7946 mandates WGS 84 coordinates...
+        g.set_srid_unchecked(&EPSG_4326);
 
         Ok(g)
     }
@@ -445,6 +1565,7 @@ impl TryFrom<&[u8]> for G {
 }
 
 // Construct new instance from GEOS Geometry instance...
+#[cfg(feature = "geos")]
 impl TryFrom<Geometry> for G {
     type Error = MyError;
 
@@ -489,6 +1610,7 @@ impl TryFrom<Geometry> for G {
 }
 
 // Construct new instance from GEOS ConstGeometry instance...
+#[cfg(feature = "geos")]
 impl TryFrom<ConstGeometry<'_>> for G {
     type Error = MyError;
 
@@ -536,6 +1658,7 @@ impl TryFrom<ConstGeometry<'_>> for G {
 mod tests {
     use super::*;
     use crate::{expr::E, text::cql2};
+    #[cfg(feature = "geos")]
     use geos::Geom;
     use std::error::Error;
 
@@ -571,6 +1694,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "geos")]
     #[tracing_test::traced_test]
     fn test_to_geos() -> Result<(), Box<dyn Error>> {
         let g = G::try_from("POINT(17.03 45.87)")?;
@@ -694,6 +1818,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "geos")]
     #[tracing_test::traced_test]
     fn test_geos() -> Result<(), Box<dyn Error>> {
         const G: &str = r#"MultiLineString(
@@ -722,13 +1847,14 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "geos")]
     #[tracing_test::traced_test]
     fn test_new_from_wkt() -> Result<(), Box<dyn Error>> {
         const PT: &str = "POINT (-46.03556 -7.5325)";
         const LS: &str = "LINESTRING (-180 -45, 0 -45)";
         const P: &str = "POLYGON ((-180 -90, -90 -90, -90 90, -180 90, -180 -90), (-120 -50, -100 -50, -100 -40, -120 -40, -120 -50))";
         const MPT: &str = "MULTIPOINT ((7.02 49.92), (90 180))";
-        // const MPT2: &str = "MULTIPOINT (7.02 49.92, 90 180)";
+        const MPT2: &str = "MULTIPOINT (7.02 49.92, 90 180)";
         const MLS: &str = "MULTILINESTRING ((-180 -45, 0 -45), (0 45, 180 45))";
         const MP: &str = r#"MULTIPOLYGON(
             ((-180 -90, -90 -90, -90 90, -180 90, -180 -90),
@@ -758,6 +1884,12 @@ mod tests {
         assert!(points.is_ok());
         // tracing::debug!("points = {}", points?.to_wkt()?);
         assert_eq!(points?.to_wkt()?, MPT);
+        // GEOS accepts the bare form too but outputs the parenthesized one...
+        let points = Geometry::new_from_wkt(MPT2);
+        assert!(points.is_ok());
+        assert_eq!(points?.to_wkt()?, MPT);
+        // ...and so do we...
+        assert_eq!(G::try_from(MPT2)?, G::try_from(MPT)?);
 
         let lines = Geometry::new_from_wkt(MLS);
         assert!(lines.is_ok());
@@ -777,6 +1909,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "geos")]
     fn test_point_in_polygon() -> Result<(), Box<dyn Error>> {
         const WKT1: &str = "POINT(-46.03556 -7.5325)";
         const WKT2: &str =
@@ -855,6 +1988,560 @@ mod tests {
     }
 
     #[test]
+    fn test_to_wkt_with() -> Result<(), Box<dyn Error>> {
+        // defaults match `to_wkt_fmt()`...
+        let g = G::try_from("MULTIPOINT(1 2, 3 4)")?;
+        let opts = WktOptions::default().with_precision(1);
+        assert_eq!(g.to_wkt_with(&opts), g.to_wkt_fmt(1));
+
+        let opts = opts.with_parenthesized_points();
+        assert_eq!(g.to_wkt_with(&opts), "MULTIPOINT ((1.0 2.0), (3.0 4.0))");
+
+        // tags are applied consistently to every 3D collection member...
+        let g = G::try_from("GEOMETRYCOLLECTION Z(POINT Z(1 2 3), MULTIPOINT Z(4 5 6, 7 8 9))")?;
+        let opts = WktOptions::default().with_precision(0);
+        assert_eq!(
+            g.to_wkt_with(&opts),
+            "GEOMETRYCOLLECTION Z (POINT Z (1 2 3), MULTIPOINT Z (4 5 6, 7 8 9))"
+        );
+        let opts = opts.with_dim_tag(DimTag::Attached);
+        assert_eq!(
+            g.to_wkt_with(&opts),
+            "GEOMETRYCOLLECTIONZ (POINTZ (1 2 3), MULTIPOINTZ (4 5 6, 7 8 9))"
+        );
+        let opts = opts.with_dim_tag(DimTag::Omitted);
+        assert_eq!(
+            g.to_wkt_with(&opts),
+            "GEOMETRYCOLLECTION (POINT (1 2 3), MULTIPOINT (4 5 6, 7 8 9))"
+        );
+
+        // undefined geometries...
+        let opts = WktOptions::default();
+        assert_eq!(G::Null.to_wkt_with(&opts), "");
+        let opts = opts.with_empty(EmptyWkt::Point);
+        assert_eq!(G::Null.to_wkt_with(&opts), "POINT EMPTY");
+        let opts = opts.with_empty(EmptyWkt::Collection);
+        assert_eq!(G::Null.to_wkt_with(&opts), "GEOMETRYCOLLECTION EMPTY");
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "geos")]
+    fn test_try_from_geojson() -> Result<(), Box<dyn Error>> {
+        let pt = serde_json::json!({ "type": "Point", "coordinates": [1.5, -3.0] });
+        let g = G::try_from(&pt)?;
+        assert_eq!(g.to_wkt_fmt(1), "POINT (1.5 -3.0)");
+        assert_eq!(g.srid(), EPSG_4326);
+
+        let poly = serde_json::json!({
+            "type": "Polygon",
+            "coordinates": [[[0, 0], [4, 0], [4, 3], [0, 3], [0, 0]]]
+        });
+        let g = G::try_from(&poly)?;
+        assert_eq!(g.type_(), "Polygon");
+        assert_eq!(g.area()?, 12.0);
+
+        let mpt = serde_json::json!({ "type": "MultiPoint", "coordinates": [[0, 0], [1, 1, 1]] });
+        assert_eq!(G::try_from(&mpt)?.type_(), "MultiPoint");
+
+        let gc = serde_json::json!({
+            "type": "GeometryCollection",
+            "geometries": [pt, { "type": "LineString", "coordinates": [[0, 0], [1, 1]] }]
+        });
+        let g = G::try_from(&gc)?;
+        assert_eq!(g.type_(), "GeometryCollection");
+
+        // not a geometry...
+        let feature = serde_json::json!({ "type": "Feature", "properties": {} });
+        assert!(!is_geojson(&feature));
+        assert!(G::try_from(&feature).is_err());
+        let bad = serde_json::json!({ "type": "Point", "coordinates": [1] });
+        assert!(G::try_from(&bad).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "geos")]
+    fn test_to_geojson() -> Result<(), Box<dyn Error>> {
+        const TV: [&str; 7] = [
+            "POINT (1.5 -3)",
+            "LINESTRING Z (0 0 1, 1 1 2)",
+            "POLYGON ((0 0, 4 0, 4 3, 0 3, 0 0), (1 1, 2 1, 2 2, 1 1))",
+            "MULTIPOINT (0 0, 1 1)",
+            "MULTILINESTRING ((0 0, 1 1), (2 2, 3 3))",
+            "MULTIPOLYGON (((0 0, 1 0, 1 1, 0 0)), ((5 5, 6 5, 6 6, 5 5)))",
+            "GEOMETRYCOLLECTION (POINT (1 2), LINESTRING (0 0, 1 1))",
+        ];
+        for wkt in TV {
+            let g = G::try_from(wkt)?;
+            let json = g.to_geojson();
+            // round-trip...
+            assert_eq!(G::try_from(&json)?, g, "{wkt}");
+        }
+
+        let g = G::try_from("LINESTRING Z (0 0 1, 1 1 2)")?;
+        let expected = serde_json::json!({
+            "type": "LineString",
+            "coordinates": [[0.0, 0.0, 1.0], [1.0, 1.0, 2.0]]
+        });
+        assert_eq!(g.to_geojson(), expected);
+
+        let g = G::try_from("BBOX(0, 0, 4, 3)")?;
+        let json = g.to_geojson();
+        assert_eq!(json["type"], "Polygon");
+        assert_eq!(G::try_from(&json)?.area()?, 12.0);
+        // spanning the antimeridian...
+        let g = G::try_from("BBOX(170, 0, -170, 10)")?;
+        let json = g.to_geojson();
+        assert_eq!(json["type"], "MultiPolygon");
+        assert_eq!(G::try_from(&json)?.area()?, 200.0);
+
+        assert!(G::Null.to_geojson().is_null());
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "geos")]
+    fn test_simplify() -> Result<(), Box<dyn Error>> {
+        let line = G::try_from("LINESTRING (0 0, 1 0.01, 2 0, 3 0.01, 4 0)")?;
+        let simple = line.simplify(0.1)?;
+        assert_eq!(simple.to_wkt_fmt(0), "LINESTRING (0 0, 4 0)");
+        assert_eq!(simple.srid(), line.srid());
+
+        // nothing to remove...
+        let poly = G::try_from("POLYGON ((0 0, 4 0, 4 3, 0 3, 0 0))")?;
+        assert_eq!(poly.simplify(0.1)?.area()?, 12.0);
+
+        // a tolerance that collapses the polygon...
+        let tiny = G::try_from("POLYGON ((0 0, 1 0, 1 1, 0 1, 0 0))")?;
+        assert!(matches!(tiny.simplify(10.0)?, G::Null));
+
+        assert!(poly.simplify(-1.0).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_rounding_mode() -> Result<(), Box<dyn Error>> {
+        // 0.125 and -0.125 are exact in binary so they're true half-way cases
+        // when rendered w/ 2 decimal digits...
+        let g = G::try_from("LINESTRING(0.125 -0.125, 2.75 -2.75)")?;
+        let wkt =
+            |mode| g.to_wkt_with(&WktOptions::default().with_precision(2).with_rounding(mode));
+
+        let cases = [
+            (RoundingMode::HalfAwayFromZero, "0.13 -0.13"),
+            (RoundingMode::HalfUp, "0.13 -0.12"),
+            (RoundingMode::HalfEven, "0.12 -0.12"),
+            (RoundingMode::Truncate, "0.12 -0.12"),
+        ];
+        for (mode, expected) in cases {
+            assert_eq!(wkt(mode), format!("LINESTRING ({expected}, 2.75 -2.75)"));
+        }
+
+        let wkt = g.to_wkt_with(
+            &WktOptions::default()
+                .with_precision(1)
+                .with_rounding(RoundingMode::Truncate),
+        );
+        assert_eq!(wkt, "LINESTRING (0.1 -0.1, 2.7 -2.7)");
+
+        let bbox = G::try_from("BBOX(-0.125, -0.125, 0.125, 0.125)")?;
+        let wkt = bbox.to_wkt_with(
+            &WktOptions::default()
+                .with_precision(2)
+                .with_rounding(RoundingMode::HalfUp),
+        );
+        assert_eq!(wkt, "BBOX (-0.12, -0.12, 0.13, 0.13)");
+
+        assert_eq!("half_even".parse::<RoundingMode>()?, RoundingMode::HalfEven);
+        assert_eq!("Half-Up".parse::<RoundingMode>()?, RoundingMode::HalfUp);
+        assert!("ceiling".parse::<RoundingMode>().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_ewkt() -> Result<(), Box<dyn Error>> {
+        let g = G::try_from("SRID=3857;POINT(1 2)")?;
+        assert_eq!(g.srid(), SRID::try_from(3857)?);
+        assert_eq!(g.to_wkt_fmt(0), "POINT (1 2)");
+
+        // the prefix is case-insensitive and may be padded...
+        let g = G::try_from(" srid=32632 ; LINESTRING(0 0, 1 1)")?;
+        assert_eq!(g.srid(), SRID::try_from(32632)?);
+        assert_eq!(g.type_(), "LineString");
+
+        // plain WKT gets the configured default...
+        let g = G::try_from("POINT(1 2)")?;
+        assert_eq!(g.srid(), *config().default_srid());
+
+        for s in [
+            "SRID=3857 POINT(1 2)",
+            "SRID=foo;POINT(1 2)",
+            "SRID=999999;POINT(1 2)",
+        ] {
+            assert!(G::try_from(s).is_err(), "{s}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "geos")]
+    fn test_point_on_surface() -> Result<(), Box<dyn Error>> {
+        // a U-shaped polygon whose centroid falls in its (empty) middle...
+        let mut u = G::try_from("POLYGON ((0 0, 6 0, 6 6, 4 6, 4 2, 2 2, 2 6, 0 6, 0 0))")?;
+        u.set_srid_unchecked(&SRID::try_from(3857)?);
+        assert!(!u.centroid()?.within(&u)?);
+
+        let pt = u.point_on_surface()?;
+        assert!(pt.as_point().is_some());
+        assert!(pt.within(&u)?);
+        assert_eq!(pt.srid(), u.srid());
+
+        let line = G::try_from("LINESTRING (0 0, 5 0, 5 5)")?;
+        assert!(line.point_on_surface()?.intersects(&line)?);
+
+        assert_eq!(G::Null.point_on_surface()?, G::Null);
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "geos")]
+    fn test_overlays() -> Result<(), Box<dyn Error>> {
+        let mut a = G::try_from("POLYGON ((0 0, 4 0, 4 4, 0 4, 0 0))")?;
+        a.set_srid_unchecked(&SRID::try_from(3857)?);
+        let b = G::try_from("POLYGON ((2 2, 6 2, 6 6, 2 6, 2 2))")?;
+        let far = G::try_from("POLYGON ((10 10, 11 10, 11 11, 10 11, 10 10))")?;
+
+        let union = a.union(&b)?;
+        assert_eq!(union.area()?, 28.0);
+        assert_eq!(union.srid(), a.srid());
+
+        let intersection = a.intersection(&b)?;
+        assert_eq!(intersection.area()?, 4.0);
+        assert_eq!(intersection.srid(), a.srid());
+
+        let difference = a.difference(&b)?;
+        assert_eq!(difference.area()?, 12.0);
+        assert_eq!(difference.srid(), a.srid());
+
+        let sym_difference = a.sym_difference(&b)?;
+        assert_eq!(sym_difference.area()?, 24.0);
+        assert_eq!(sym_difference.srid(), a.srid());
+
+        // empty results are NULL...
+        assert!(matches!(a.intersection(&far)?, G::Null));
+        assert!(matches!(a.difference(&a)?, G::Null));
+        assert!(matches!(a.sym_difference(&a)?, G::Null));
+
+        // ...and so are NULL operands as appropriate...
+        assert_eq!(a.union(&G::Null)?, a);
+        assert_eq!(G::Null.difference(&a)?, G::Null);
+        assert_eq!(a.difference(&G::Null)?, a);
+        assert!(matches!(G::Null.intersection(&a)?, G::Null));
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "geos")]
+    fn test_area_and_length() -> Result<(), Box<dyn Error>> {
+        let pt = G::try_from("POINT (1 3)")?;
+        assert_eq!(pt.area()?, 0.0);
+        assert_eq!(pt.length()?, 0.0);
+
+        let line = G::try_from("LINESTRING (0 0, 3 4)")?;
+        assert_eq!(line.area()?, 0.0);
+        assert_eq!(line.length()?, 5.0);
+
+        let poly = G::try_from("POLYGON ((0 0, 4 0, 4 3, 0 3, 0 0))")?;
+        assert_eq!(poly.area()?, 12.0);
+        assert_eq!(poly.length()?, 14.0);
+
+        assert_eq!(G::Null.area()?, 0.0);
+        assert_eq!(G::Null.length()?, 0.0);
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "geos")]
+    fn test_distance() -> Result<(), Box<dyn Error>> {
+        let pt = G::try_from("POINT (0 0)")?;
+        let line = G::try_from("LINESTRING (-1 3, 5 3, 5 -1)")?;
+        assert_eq!(pt.distance(&line)?, 3.0);
+        assert_eq!(line.distance(&pt)?, 3.0);
+
+        let (a, b) = pt.nearest_points(&line)?;
+        assert_eq!(a.xy(), &vec![0.0, 0.0]);
+        assert_eq!(b.xy(), &vec![0.0, 3.0]);
+        assert_eq!(b.srid(), pt.srid());
+
+        // intersecting geometries are 0 apart...
+        let poly = G::try_from("POLYGON ((-1 -1, 1 -1, 1 1, -1 1, -1 -1))")?;
+        assert_eq!(pt.distance(&poly)?, 0.0);
+
+        assert!(pt.distance(&G::Null).is_err());
+        assert!(G::Null.nearest_points(&pt).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "geos")]
+    fn test_make_valid() -> Result<(), Box<dyn Error>> {
+        // a bow-tie...
+        let mut g = G::try_from("POLYGON((0 0, 10 10, 10 0, 0 10, 0 0))")?;
+        g.set_srid_unchecked(&SRID::try_from(3857)?);
+        assert!(!g.is_valid()?);
+
+        let repaired = g.make_valid()?;
+        assert!(repaired.is_valid()?);
+        assert_eq!(repaired.type_(), "MultiPolygon");
+        let polygons = repaired.as_polygons().expect("Expected polygons");
+        assert_eq!(polygons.num_polygons(), 2);
+        assert_eq!(repaired.area()?, 50.0);
+        assert_eq!(repaired.srid(), g.srid());
+
+        let g = G::try_from("POLYGON((0 0, 10 0, 10 10, 0 10, 0 0))")?;
+        assert!(g.is_valid()?);
+        assert_eq!(g.make_valid()?, g);
+
+        assert!(G::Null.is_valid()?);
+        assert_eq!(G::Null.make_valid()?, G::Null);
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "geos")]
+    fn test_validity_reason() -> Result<(), Box<dyn Error>> {
+        // a bow-tie...
+        let mut g = G::try_from("POLYGON((0 0, 10 10, 10 0, 0 10, 0 0))")?;
+        g.set_srid_unchecked(&SRID::try_from(3857)?);
+        let (reason, location) = g.validity_reason()?.expect("Expected a reason");
+        assert!(reason.contains("Self-intersection"), "{reason}");
+        let location = location.expect("Expected a location");
+        assert_eq!(location.xy(), &vec![5.0, 5.0]);
+        assert_eq!(location.srid(), g.srid());
+
+        let g = G::try_from("POLYGON((0 0, 10 0, 10 10, 0 10, 0 0))")?;
+        assert!(g.validity_reason()?.is_none());
+        assert!(G::Null.validity_reason()?.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "geos")]
+    fn test_transform() -> Result<(), Box<dyn Error>> {
+        let wgs84 = CRS::new("EPSG:4326")?;
+        let mercator = CRS::new("EPSG:3857")?;
+
+        let pt = G::try_from("POINT (10 50)")?;
+        let xy = pt.transform(&wgs84, &mercator)?;
+        assert_eq!(xy.srid(), SRID::try_from(3857_usize)?);
+        assert!((xy.get_x()? - 1113194.9079327).abs() < 1e-3);
+        assert!((xy.get_y()? - 6446275.8410172).abs() < 1e-3);
+
+        // and back...
+        let pt_ = xy.transform(&mercator, &wgs84)?;
+        assert_eq!(pt_.srid(), EPSG_4326);
+        assert!((pt_.get_x()? - 10.0).abs() < 1e-6);
+        assert!((pt_.get_y()? - 50.0).abs() < 1e-6);
+
+        // Z is passed through unchanged...
+        let pt = G::try_from("POINT Z (10 50 123.5)")?;
+        let xyz = pt.transform(&wgs84, &mercator)?;
+        assert_eq!(xyz.get_z()?, 123.5);
+
+        // every coordinate of a polygon is transformed...
+        let poly = G::try_from("POLYGON ((0 0, 10 0, 10 10, 0 10, 0 0))")?;
+        let poly_ = poly.transform(&wgs84, &mercator)?;
+        assert_eq!(poly_.type_(), "Polygon");
+        assert!(poly_.area()? > 1e12);
+
+        // w/in and outside the area-of-use of UTM zone 32N...
+        let utm = CRS::new("EPSG:32632")?;
+        assert!(G::try_from("POINT (9 45)")?.transform(&wgs84, &utm).is_ok());
+        assert!(
+            G::try_from("POINT (100 10)")?
+                .transform(&wgs84, &utm)
+                .is_err()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "geos")]
+    fn test_densify() -> Result<(), Box<dyn Error>> {
+        let line = G::try_from("LINESTRING (0 0, 10 0)")?;
+        let dense = line.densify(3.0)?;
+        assert_eq!(dense.srid(), line.srid());
+        assert_eq!(
+            dense,
+            G::try_from("LINESTRING (0 0, 2.5 0, 5 0, 7.5 0, 10 0)")?
+        );
+        // short enough segments are kept as is...
+        assert_eq!(line.densify(10.0)?, line);
+
+        // Z is interpolated...
+        let line = G::try_from("LINESTRING Z (0 0 0, 0 4 8)")?;
+        let dense = line.densify(1.0)?;
+        assert_eq!(
+            dense,
+            G::try_from("LINESTRING Z (0 0 0, 0 1 2, 0 2 4, 0 3 6, 0 4 8)")?
+        );
+
+        // rings stay closed...
+        let poly = G::try_from("SRID=3857;POLYGON ((0 0, 2 0, 2 2, 0 2, 0 0))")?;
+        let dense = poly.densify(1.0)?;
+        assert_eq!(dense.srid(), SRID::try_from(3857_usize)?);
+        let rings: Vec<_> = dense.as_polygon().expect("Not a polygon").rings().collect();
+        assert_eq!(rings[0].len(), 9);
+        assert_eq!(rings[0].first(), rings[0].last());
+        assert_eq!(dense.area()?, 4.0);
+
+        // points are left alone...
+        let pts = G::try_from("MULTIPOINT (0 0, 10 10)")?;
+        assert_eq!(pts.densify(1.0)?, pts);
+
+        for x in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            assert!(poly.densify(x).is_err());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "geos")]
+    fn test_transform_densified() -> Result<(), Box<dyn Error>> {
+        let wgs84 = CRS::new("EPSG:4326")?;
+        let polar = CRS::new("EPSG:3413")?;
+
+        let bbox = G::try_from("POLYGON ((-40 60, 40 60, 40 80, -40 80, -40 60))")?;
+        let plain = bbox.transform(&wgs84, &polar)?;
+        let dense = bbox.transform_densified(&wgs84, &polar, 1.0)?;
+        assert_eq!(dense.srid(), SRID::try_from(3413_usize)?);
+
+        let rings: Vec<_> = dense.as_polygon().expect("Not a polygon").rings().collect();
+        assert_eq!(rings[0].len(), 201);
+        // the southern edge bulges away from the pole more than the northern
+        // one bulges towards it...
+        assert!(dense.area()? > plain.area()?);
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "geos")]
+    fn test_envelope() -> Result<(), Box<dyn Error>> {
+        let g = G::try_from("SRID=3857;POLYGON Z((0 0 1, 4 0 2, 4 3 3, 0 0 1))")?;
+        let envelope = g.envelope()?;
+        assert_eq!(envelope.type_(), "BBox");
+        assert_eq!(envelope.srid(), g.srid());
+        let bbox = envelope.as_bbox().expect("Expected a BBox");
+        assert_eq!(
+            (bbox.min_x(), bbox.min_y(), bbox.max_x(), bbox.max_y()),
+            (0.0, 0.0, 4.0, 3.0)
+        );
+        assert!(bbox.min_z().is_none());
+
+        // bounding boxes are kept as is; albeit in 2D...
+        let g = G::try_from("BBOX(170, -10, 0, -170, 10, 100)")?;
+        let bbox = g.envelope()?;
+        assert_eq!(bbox.to_wkt_fmt(0), "BBOX (170, -10, -170, 10)");
+        let g = G::try_from("BBOX(170, -10, 0, -170, 10, 100)")?;
+        assert_eq!(g.as_bbox().and_then(BBox::max_z), Some(100.0));
+
+        assert!(matches!(G::Null.envelope()?, G::Null));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_geometry_n() -> Result<(), Box<dyn Error>> {
+        #[rustfmt::skip]
+        const TV: [(&str, usize, &str); 6] = [
+            ("POINT(1 2)",                                          1, "POINT (1 2)"),
+            ("MULTIPOINT(1 2, 3 4, 5 6)",                           3, "POINT (3 4)"),
+            ("MULTILINESTRING((0 0, 1 1), (2 2, 3 3))",             2, "LINESTRING (2 2, 3 3)"),
+            ("MULTIPOLYGON(((0 0, 1 0, 1 1, 0 0)), ((5 5, 6 5, 6 6, 5 5)))",
+                                                                    2, "POLYGON ((5 5, 6 5, 6 6, 5 5))"),
+            ("GEOMETRYCOLLECTION(POINT(1 2), LINESTRING(0 0, 1 1))", 2, "LINESTRING (0 0, 1 1)"),
+            ("BBOX(0, 0, 1, 1)",                                    1, "BBOX (0, 0, 1, 1)"),
+        ];
+        for (wkt, count, second_or_first) in TV {
+            let g = G::try_from(wkt)?;
+            assert_eq!(g.num_geometries(), count, "{wkt}");
+            let member = g.geometry_n(count.min(2) - 1).expect("Missing member");
+            assert_eq!(member.to_wkt_fmt(0), second_or_first, "{wkt}");
+            assert_eq!(member.srid(), g.srid());
+            assert!(g.geometry_n(count).is_none(), "{wkt}");
+        }
+
+        assert_eq!(G::Null.num_geometries(), 0);
+        assert!(G::Null.geometry_n(0).is_none());
+        assert!(G::Null.is_empty());
+        assert!(!G::try_from("POINT(1 2)")?.is_empty());
+        assert!(G::Vec(Geometries::from_items(vec![])).is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "geos")]
+    fn test_from_tile() -> Result<(), Box<dyn Error>> {
+        const HALF: f64 = 20_037_508.342_789_2;
+
+        #[rustfmt::skip]
+        const TV: [(u8, u32, u32, [f64; 4]); 4] = [
+            (0, 0, 0, [-HALF, -HALF, HALF, HALF]),
+            (1, 0, 0, [-HALF, 0.0,   0.0,  HALF]),
+            (1, 1, 1, [0.0,   -HALF, HALF, 0.0]),
+            (2, 3, 0, [HALF / 2.0, HALF / 2.0, HALF, HALF]),
+        ];
+        for (z, x, y, [w, s, e, n]) in TV {
+            let g = G::from_tile(z, x, y)?;
+            assert_eq!(g.type_(), "Polygon");
+            assert_eq!(g.srid(), EPSG_3857);
+            let g = g.to_geos()?;
+            let actual = [
+                g.get_x_min()?,
+                g.get_y_min()?,
+                g.get_x_max()?,
+                g.get_y_max()?,
+            ];
+            for (a, b) in actual.iter().zip([w, s, e, n]) {
+                assert!((a - b).abs() < 0.01, "{z}/{x}/{y}: {a} != {b}");
+            }
+        }
+
+        // Paris is in tile 10/518/352...
+        let paris = G::try_from("POINT(2.35 48.86)")?;
+        let paris = paris.transform(&CRS::new("EPSG:4326")?, &CRS::new("EPSG:3857")?)?;
+        let tile = G::from_tile(10, 518, 352)?;
+        assert!(tile.to_geos()?.contains(&paris.to_geos()?)?);
+        let tile = G::from_tile(10, 519, 352)?;
+        assert!(!tile.to_geos()?.intersects(&paris.to_geos()?)?);
+
+        assert!(G::from_tile(1, 2, 0).is_err());
+        assert!(G::from_tile(3, 0, 8).is_err());
+        assert!(G::from_tile(32, u32::MAX, 0).is_ok());
+        assert!(G::from_tile(33, 0, 0).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "geos")]
     #[tracing_test::traced_test]
     fn test_geos_envelope() -> Result<(), Box<dyn Error>> {
         let mut geom = geos::Geometry::new_from_wkt("LINESTRING(0 0, 1 3)")?;
@@ -869,6 +2556,41 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "geos")]
+    fn test_split_at_antimeridian() -> Result<(), Box<dyn Error>> {
+        let line = G::try_from("LINESTRING(170 0, -170 0)")?;
+        assert!(line.crosses_antimeridian());
+        let split = line.split_at_antimeridian()?;
+        assert_eq!(split.type_(), "MultiLineString");
+        assert!(split.equals(&G::try_from(
+            "MULTILINESTRING((170 0, 180 0), (-180 0, -170 0))"
+        )?)?);
+        assert_eq!(split.srid(), line.srid());
+
+        // a Fiji-like polygon w/ vertices on both sides of the antimeridian...
+        let poly = G::try_from("POLYGON((178 -16, -179 -16, -179 -17, 178 -17, 178 -16))")?;
+        assert!(poly.crosses_antimeridian());
+        let split = poly.split_at_antimeridian()?;
+        assert_eq!(split.type_(), "MultiPolygon");
+        assert!((split.area()? - 3.0).abs() < 1e-9);
+        let east = G::try_from("POINT(-179.5 -16.5)")?;
+        let greenwich = G::try_from("POINT(0 -16.5)")?;
+        assert!(split.intersects(&east)?);
+        assert!(!split.intersects(&greenwich)?);
+        // w/o splitting, GEOS sees a polygon spanning most of the globe...
+        assert!(!poly.intersects(&east)?);
+        assert!(poly.intersects(&greenwich)?);
+
+        // geometries that do not cross it are returned as is...
+        let poly = G::try_from("POLYGON((0 0, 10 0, 10 10, 0 10, 0 0))")?;
+        assert!(!poly.crosses_antimeridian());
+        assert_eq!(poly.split_at_antimeridian()?, poly);
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "geos")]
     #[ignore = "GEOS possible bug"]
     fn test_geos_wkt() -> Result<(), Box<dyn Error>> {
         let expected = "POINT (1.0 3.0)";
@@ -881,6 +2603,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "geos")]
     #[ignore = "GEOS possible bug"]
     fn test_geos_wkt_writer() -> Result<(), Box<dyn Error>> {
         let expected = "POINT (1.00 3.00)";
@@ -893,4 +2616,26 @@ mod tests {
         assert_eq!(actual, expected);
         Ok(())
     }
+
+    #[test]
+    #[cfg(not(feature = "geos"))]
+    fn test_spatial_disabled() -> Result<(), Box<dyn Error>> {
+        let a = G::try_from("POLYGON ((0 0, 4 0, 4 3, 0 3, 0 0))")?;
+        let b = G::try_from("POINT (1 1)")?;
+        assert!(matches!(a.area(), Err(MyError::Unsupported(_))));
+        assert!(matches!(a.intersects(&b), Err(MyError::Unsupported(_))));
+        assert!(matches!(a.union(&b), Err(MyError::Unsupported(_))));
+        let crs = CRS::new("EPSG:3857")?;
+        let x = b.transform(&crs, &crs);
+        assert!(matches!(x, Err(MyError::Unsupported(_))));
+        let bbox = G::try_from("BBOX(0, 0, 4, 3)")?;
+        assert!(matches!(bbox.densify(1.0), Err(MyError::Unsupported(_))));
+
+        // parsing, and GEOS-free methods, still work...
+        assert_eq!(a.to_wkt_fmt(0), "POLYGON ((0 0, 4 0, 4 3, 0 3, 0 0))");
+        let dense = a.densify(2.0)?;
+        assert_eq!(dense.as_polygon().map(|x| x.num_rings()), Some(1));
+
+        Ok(())
+    }
 }
@@ -6,14 +6,17 @@
 //!
 
 use crate::{
-    CRS, GTrait, MyError,
+    CRS, GTrait, MyError, WktOptions,
     config::config,
     geom::{XY1V, ensure_precision},
     srid::SRID,
 };
 use core::fmt;
+#[cfg(feature = "geos")]
 use geos::{ConstGeometry, CoordSeq, Geom, Geometry};
-use tracing::{error, warn};
+#[cfg(feature = "geos")]
+use tracing::error;
+use tracing::warn;
 
 /// 2D or 3D point geometry.
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
@@ -27,15 +30,12 @@ impl GTrait for Point {
         self.coord.len() == 2
     }
 
-    fn to_wkt_fmt(&self, precision: usize) -> String {
-        if self.is_2d() {
-            format!("POINT ({})", Self::coords_with_dp(self.as_2d(), precision))
-        } else {
-            format!(
-                "POINT Z ({})",
-                Self::coords_with_dp(self.as_3d(), precision)
-            )
-        }
+    fn to_wkt_with(&self, opts: &WktOptions) -> String {
+        format!(
+            "{} ({})",
+            opts.keyword("POINT", self.is_2d()),
+            Self::coords_with_dp(&self.coord, opts)
+        )
     }
 
     fn check_coordinates(&self, crs: &CRS) -> Result<(), MyError> {
@@ -71,28 +71,24 @@ impl Point {
 
     // Output given coordinates sequentially seperated by a space.
     pub(crate) fn coords_as_txt(coord: &[f64]) -> String {
-        Self::coords_with_dp(coord, config().default_precision())
+        Self::coords_with_dp(coord, &WktOptions::default())
     }
 
     pub(crate) fn ensure_precision_xy(coord: &[f64]) -> XY1V {
         coord.iter().map(ensure_precision).collect()
     }
 
-    pub(crate) fn coords_with_dp(coord: &[f64], precision: usize) -> String {
-        if coord.len() == 2 {
-            format!("{:.2$} {:.2$}", coord[0], coord[1], precision)
-        } else {
-            format!(
-                "{:.3$} {:.3$} {:.3$}",
-                coord[0], coord[1], coord[2], precision
-            )
-        }
+    pub(crate) fn coords_with_dp(coord: &[f64], opts: &WktOptions) -> String {
+        let xy: Vec<String> = coord.iter().take(3).map(|x| opts.fmt_f64(*x)).collect();
+        xy.join(" ")
     }
 
+    #[cfg(feature = "geos")]
     pub(crate) fn to_geos(&self) -> Result<Geometry, MyError> {
         Self::to_geos_xy(&self.coord, &self.srid)
     }
 
+    #[cfg(feature = "geos")]
     pub(crate) fn to_geos_xy(xy: &[f64], srid: &SRID) -> Result<Geometry, MyError> {
         let xy = CoordSeq::new_from_vec(&[xy])?;
         let mut g = Geometry::create_point(xy)?;
@@ -121,6 +117,7 @@ impl Point {
         }
     }
 
+    #[cfg(feature = "geos")]
     pub(crate) fn from_geos_xy<T: Geom>(gg: T) -> Result<XY1V, MyError> {
         let result = if gg.has_z()? {
             vec![gg.get_x()?, gg.get_y()?, gg.get_z()?]
@@ -136,22 +133,6 @@ impl Point {
             self.srid = srid.to_owned();
         }
     }
-
-    // Return the 2D coordinates of this point.
-    fn as_2d(&self) -> &[f64; 2] {
-        self.coord
-            .as_slice()
-            .try_into()
-            .expect("Failed coercing Point to 2D")
-    }
-
-    // Return the 3D coordinates of this point.
-    fn as_3d(&self) -> &[f64; 3] {
-        self.coord
-            .as_slice()
-            .try_into()
-            .expect("Failed coercing Point to 3D")
-    }
 }
 
 impl fmt::Display for Point {
@@ -160,6 +141,7 @@ impl fmt::Display for Point {
     }
 }
 
+#[cfg(feature = "geos")]
 impl TryFrom<Geometry> for Point {
     type Error = MyError;
 
@@ -174,6 +156,7 @@ impl TryFrom<Geometry> for Point {
     }
 }
 
+#[cfg(feature = "geos")]
 impl TryFrom<ConstGeometry<'_>> for Point {
     type Error = MyError;
 
@@ -265,6 +248,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "geos")]
     #[should_panic]
     fn test_invalid() {
         let pt = Point::from_xy(vec![90.0, 180.0]);
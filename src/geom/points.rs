@@ -6,15 +6,18 @@
 //!
 
 use crate::{
-    CRS, GTrait, MyError, Point,
+    CRS, GTrait, MyError, Point, WktOptions,
     config::config,
     geom::{XY1V, XY2V},
     srid::SRID,
 };
 use core::fmt;
+#[cfg(feature = "geos")]
 use geos::{ConstGeometry, Geom, Geometry};
 use std::slice::Iter;
-use tracing::{error, warn};
+#[cfg(feature = "geos")]
+use tracing::error;
+use tracing::warn;
 
 /// Collection of point geometries.
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
@@ -28,18 +31,13 @@ impl GTrait for Points {
         self.points[0].len() == 2
     }
 
-    fn to_wkt_fmt(&self, precision: usize) -> String {
-        if self.is_2d() {
-            format!(
-                "MULTIPOINT {}",
-                Self::coords_with_dp(&self.points, precision)
-            )
+    fn to_wkt_with(&self, opts: &WktOptions) -> String {
+        let coords = if opts.parenthesized_points() {
+            Self::nested_coords_with_dp(&self.points, opts)
         } else {
-            format!(
-                "MULTIPOINT Z {}",
-                Self::coords_with_dp(&self.points, precision)
-            )
-        }
+            Self::coords_with_dp(&self.points, opts)
+        };
+        format!("{} {coords}", opts.keyword("MULTIPOINT", self.is_2d()))
     }
 
     fn check_coordinates(&self, crs: &CRS) -> Result<(), MyError> {
@@ -85,9 +83,10 @@ impl Points {
     }
 
     pub(crate) fn coords_as_txt(points: &[XY1V]) -> String {
-        Self::coords_with_dp(points, config().default_precision())
+        Self::coords_with_dp(points, &WktOptions::default())
     }
 
+    #[cfg(feature = "geos")]
     pub(crate) fn to_geos(&self) -> Result<Geometry, MyError> {
         let mut points: Vec<Geometry> = vec![];
         for p in &self.points {
@@ -101,6 +100,7 @@ impl Points {
         Ok(g)
     }
 
+    #[cfg(feature = "geos")]
     pub(crate) fn from_geos_xy<T: Geom>(gg: T) -> Result<XY2V, MyError> {
         let num_points = gg.get_num_geometries()?;
         let mut result = Vec::with_capacity(num_points);
@@ -123,10 +123,19 @@ impl Points {
         }
     }
 
-    fn coords_with_dp(points: &[XY1V], precision: usize) -> String {
+    fn coords_with_dp(points: &[XY1V], opts: &WktOptions) -> String {
+        let points: Vec<String> = points
+            .iter()
+            .map(|x| Point::coords_with_dp(x, opts))
+            .collect();
+        format!("({})", points.join(", "))
+    }
+
+    // same as `coords_with_dp` but w/ every point enclosed in parentheses...
+    fn nested_coords_with_dp(points: &[XY1V], opts: &WktOptions) -> String {
         let points: Vec<String> = points
             .iter()
-            .map(|x| Point::coords_with_dp(x, precision))
+            .map(|x| format!("({})", Point::coords_with_dp(x, opts)))
             .collect();
         format!("({})", points.join(", "))
     }
@@ -138,6 +147,7 @@ impl fmt::Display for Points {
     }
 }
 
+#[cfg(feature = "geos")]
 impl TryFrom<Geometry> for Points {
     type Error = MyError;
 
@@ -155,6 +165,7 @@ impl TryFrom<Geometry> for Points {
     }
 }
 
+#[cfg(feature = "geos")]
 impl TryFrom<ConstGeometry<'_>> for Points {
     type Error = MyError;
 
@@ -6,15 +6,18 @@
 //!
 
 use crate::{
-    CRS, GTrait, Line, MyError,
+    CRS, GTrait, Line, MyError, WktOptions,
     config::config,
     geom::{XY2V, XY3V},
     srid::SRID,
 };
 use core::fmt;
+#[cfg(feature = "geos")]
 use geos::{ConstGeometry, CoordSeq, Geom, Geometry};
 use std::slice::Iter;
-use tracing::{error, warn};
+#[cfg(feature = "geos")]
+use tracing::error;
+use tracing::warn;
 
 /// 2D or 3D polygon geometry.
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
@@ -28,12 +31,12 @@ impl GTrait for Polygon {
         self.rings[0][0].len() == 2
     }
 
-    fn to_wkt_fmt(&self, precision: usize) -> String {
-        if self.is_2d() {
-            format!("POLYGON {}", Self::coords_with_dp(&self.rings, precision))
-        } else {
-            format!("POLYGON Z {}", Self::coords_with_dp(&self.rings, precision))
-        }
+    fn to_wkt_with(&self, opts: &WktOptions) -> String {
+        format!(
+            "{} {}",
+            opts.keyword("POLYGON", self.is_2d()),
+            Self::coords_with_dp(&self.rings, opts)
+        )
     }
 
     fn check_coordinates(&self, crs: &CRS) -> Result<(), MyError> {
@@ -74,25 +77,27 @@ impl Polygon {
     }
 
     pub(crate) fn coords_as_txt(rings: &[XY2V]) -> String {
-        Self::coords_with_dp(rings, config().default_precision())
+        Self::coords_with_dp(rings, &WktOptions::default())
     }
 
     pub(crate) fn ensure_precision_xy(rings: &[XY2V]) -> XY3V {
         rings.iter().map(|r| Line::ensure_precision_xy(r)).collect()
     }
 
-    pub(crate) fn coords_with_dp(rings: &[XY2V], precision: usize) -> String {
+    pub(crate) fn coords_with_dp(rings: &[XY2V], opts: &WktOptions) -> String {
         let rings: Vec<String> = rings
             .iter()
-            .map(|x| Line::coords_with_dp(x, precision))
+            .map(|x| Line::coords_with_dp(x, opts))
             .collect();
         format!("({})", rings.join(", "))
     }
 
+    #[cfg(feature = "geos")]
     pub(crate) fn to_geos(&self) -> Result<Geometry, MyError> {
         Self::to_geos_xy(&self.rings, &self.srid)
     }
 
+    #[cfg(feature = "geos")]
     pub(crate) fn to_geos_xy(rings: &[XY2V], srid: &SRID) -> Result<Geometry, MyError> {
         let vertices: Vec<&[f64]> = rings[0].iter().map(|x| x.as_slice()).collect();
         let xy = CoordSeq::new_from_vec(&vertices)?;
@@ -115,6 +120,7 @@ impl Polygon {
         Ok(g)
     }
 
+    #[cfg(feature = "geos")]
     pub(crate) fn from_geos_xy<T: Geom>(gg: T) -> Result<XY3V, MyError> {
         let num_inners = gg.get_num_interior_rings()?;
         let mut result = Vec::with_capacity(num_inners + 1);
@@ -164,6 +170,7 @@ impl fmt::Display for Polygon {
     }
 }
 
+#[cfg(feature = "geos")]
 impl TryFrom<Geometry> for Polygon {
     type Error = MyError;
 
@@ -181,6 +188,7 @@ impl TryFrom<Geometry> for Polygon {
     }
 }
 
+#[cfg(feature = "geos")]
 impl TryFrom<ConstGeometry<'_>> for Polygon {
     type Error = MyError;
 
@@ -202,7 +210,9 @@ impl TryFrom<ConstGeometry<'_>> for Polygon {
 mod tests {
     use super::*;
     use crate::{G, expr::E, text::cql2};
+    #[cfg(feature = "geos")]
     use geos::Geom;
+    #[cfg(feature = "geos")]
     use std::error::Error;
 
     #[test]
@@ -254,6 +264,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "geos")]
     #[tracing_test::traced_test]
     fn test_touches() -> Result<(), Box<dyn Error>> {
         const WKT1: &str = "POLYGON ((0 -90, 0 0, 180 0, 180 -90, 0 -90))";
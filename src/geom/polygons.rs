@@ -6,15 +6,18 @@
 //!
 
 use crate::{
-    CRS, GTrait, MyError, Polygon,
+    CRS, GTrait, MyError, Polygon, WktOptions,
     config::config,
     geom::{XY3V, XY4V},
     srid::SRID,
 };
 use core::fmt;
+#[cfg(feature = "geos")]
 use geos::{ConstGeometry, Geom, Geometry};
 use std::slice::Iter;
-use tracing::{error, warn};
+#[cfg(feature = "geos")]
+use tracing::error;
+use tracing::warn;
 
 /// Collection of polygon geometries.
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
@@ -28,18 +31,12 @@ impl GTrait for Polygons {
         self.polygons[0][0][0].len() == 2
     }
 
-    fn to_wkt_fmt(&self, precision: usize) -> String {
-        if self.is_2d() {
-            format!(
-                "MULTIPOLYGON {}",
-                Self::coords_with_dp(self.polygons.as_slice(), precision)
-            )
-        } else {
-            format!(
-                "MULTIPOLYGON Z {}",
-                Self::coords_with_dp(self.polygons.as_slice(), precision)
-            )
-        }
+    fn to_wkt_with(&self, opts: &WktOptions) -> String {
+        format!(
+            "{} {}",
+            opts.keyword("MULTIPOLYGON", self.is_2d()),
+            Self::coords_with_dp(self.polygons.as_slice(), opts)
+        )
     }
 
     fn check_coordinates(&self, crs: &CRS) -> Result<(), MyError> {
@@ -89,9 +86,10 @@ impl Polygons {
     }
 
     pub(crate) fn coords_as_txt(polygons: &[XY3V]) -> String {
-        Self::coords_with_dp(polygons, config().default_precision())
+        Self::coords_with_dp(polygons, &WktOptions::default())
     }
 
+    #[cfg(feature = "geos")]
     pub(crate) fn to_geos(&self) -> Result<Geometry, MyError> {
         let mut polygons: Vec<Geometry> = vec![];
         for p in &self.polygons {
@@ -105,6 +103,7 @@ impl Polygons {
         Ok(g)
     }
 
+    #[cfg(feature = "geos")]
     pub(crate) fn from_geos_xy<T: Geom>(gg: T) -> Result<XY4V, MyError> {
         let num_polygons = gg.get_num_geometries()?;
         let mut result = Vec::with_capacity(num_polygons);
@@ -123,10 +122,10 @@ impl Polygons {
         }
     }
 
-    fn coords_with_dp(polygons: &[XY3V], precision: usize) -> String {
+    fn coords_with_dp(polygons: &[XY3V], opts: &WktOptions) -> String {
         let polygons: Vec<String> = polygons
             .iter()
-            .map(|x| Polygon::coords_with_dp(x.as_slice(), precision))
+            .map(|x| Polygon::coords_with_dp(x.as_slice(), opts))
             .collect();
         format!("({})", polygons.join(", "))
     }
@@ -138,6 +137,7 @@ impl fmt::Display for Polygons {
     }
 }
 
+#[cfg(feature = "geos")]
 impl TryFrom<Geometry> for Polygons {
     type Error = MyError;
 
@@ -155,6 +155,7 @@ impl TryFrom<Geometry> for Polygons {
     }
 }
 
+#[cfg(feature = "geos")]
 impl TryFrom<ConstGeometry<'_>> for Polygons {
     type Error = MyError;
 
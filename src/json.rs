@@ -271,7 +271,11 @@ impl fmt::Display for AndOrExpression {
             .iter()
             .map(|x| {
                 let y: Expression = serde_json::from_value(x.clone()).expect("Expected expression");
-                y.to_string()
+                // keep nested connectives grouped; e.g. `(a or b) and c`...
+                match y {
+                    Expression::AndOrExpression(_) => format!("({y})"),
+                    _ => y.to_string(),
+                }
             })
             .collect();
         let zop = format!(" {} ", self.op);
@@ -563,7 +567,8 @@ pub(crate) enum ArithmeticOperandsItem {
     Variant0(Box<ArithmeticExpression>),
     Variant1(PropertyRef),
     Variant2(FunctionRef),
-    Variant3(f64),
+    Variant3(i64),
+    Variant4(f64),
 }
 
 impl fmt::Display for ArithmeticOperandsItem {
@@ -573,6 +578,7 @@ impl fmt::Display for ArithmeticOperandsItem {
             ArithmeticOperandsItem::Variant1(x) => write!(f, "{x}"),
             ArithmeticOperandsItem::Variant2(x) => write!(f, "{x}"),
             ArithmeticOperandsItem::Variant3(x) => write!(f, "{x}"),
+            ArithmeticOperandsItem::Variant4(x) => write!(f, "{x}"),
         }
     }
 }
@@ -597,9 +603,14 @@ impl From<FunctionRef> for ArithmeticOperandsItem {
         Self::Variant2(value)
     }
 }
+impl From<i64> for ArithmeticOperandsItem {
+    fn from(value: i64) -> Self {
+        Self::Variant3(value)
+    }
+}
 impl From<f64> for ArithmeticOperandsItem {
     fn from(value: f64) -> Self {
-        Self::Variant3(value)
+        Self::Variant4(value)
     }
 }
 #[doc = "`Array`"]
@@ -1683,6 +1694,8 @@ impl<'de> ::serde::Deserialize<'de> for DateString {
 #[doc = "          \"casei\","]
 #[doc = "          \"accenti\","]
 #[doc = "          \"s_contains\","]
+#[doc = "          \"s_coveredBy\","]
+#[doc = "          \"s_covers\","]
 #[doc = "          \"s_crosses\","]
 #[doc = "          \"s_disjoint\","]
 #[doc = "          \"s_equals\","]
@@ -1868,6 +1881,8 @@ impl From<PropertyRef> for FunctionRefArgsItem {
 #[doc = "      \"casei\","]
 #[doc = "      \"accenti\","]
 #[doc = "      \"s_contains\","]
+#[doc = "      \"s_coveredBy\","]
+#[doc = "      \"s_covers\","]
 #[doc = "      \"s_crosses\","]
 #[doc = "      \"s_disjoint\","]
 #[doc = "      \"s_equals\","]
@@ -1945,6 +1960,8 @@ impl TryFrom<String> for FunctionRefOp {
             "casei".to_string(),
             "accenti".to_string(),
             "s_contains".to_string(),
+            "s_coveredBy".to_string(),
+            "s_covers".to_string(),
             "s_crosses".to_string(),
             "s_disjoint".to_string(),
             "s_equals".to_string(),
@@ -4046,7 +4063,8 @@ impl TryFrom<String> for NotExpressionOp {
 #[serde(untagged)]
 pub(crate) enum NumericExpression {
     Variant0(ArithmeticExpression),
-    Variant1(f64),
+    Variant1(i64),
+    Variant2(f64),
 }
 
 impl fmt::Display for NumericExpression {
@@ -4054,6 +4072,7 @@ impl fmt::Display for NumericExpression {
         match self {
             NumericExpression::Variant0(x) => write!(f, "{x}"),
             NumericExpression::Variant1(x) => write!(f, "{x}"),
+            NumericExpression::Variant2(x) => write!(f, "{x}"),
         }
     }
 }
@@ -4068,9 +4087,14 @@ impl From<ArithmeticExpression> for NumericExpression {
         Self::Variant0(value)
     }
 }
+impl From<i64> for NumericExpression {
+    fn from(value: i64) -> Self {
+        Self::Variant1(value)
+    }
+}
 impl From<f64> for NumericExpression {
     fn from(value: f64) -> Self {
-        Self::Variant1(value)
+        Self::Variant2(value)
     }
 }
 #[doc = "`PatternExpression`"]
@@ -4846,6 +4870,8 @@ impl From<FunctionRef> for SpatialOperandsItem {
 #[doc = "      \"type\": \"string\","]
 #[doc = "      \"enum\": ["]
 #[doc = "        \"s_contains\","]
+#[doc = "        \"s_coveredBy\","]
+#[doc = "        \"s_covers\","]
 #[doc = "        \"s_crosses\","]
 #[doc = "        \"s_disjoint\","]
 #[doc = "        \"s_equals\","]
@@ -4886,6 +4912,8 @@ impl From<&SpatialPredicate> for SpatialPredicate {
 #[doc = "  \"type\": \"string\","]
 #[doc = "  \"enum\": ["]
 #[doc = "    \"s_contains\","]
+#[doc = "    \"s_coveredBy\","]
+#[doc = "    \"s_covers\","]
 #[doc = "    \"s_crosses\","]
 #[doc = "    \"s_disjoint\","]
 #[doc = "    \"s_equals\","]
@@ -4901,6 +4929,10 @@ impl From<&SpatialPredicate> for SpatialPredicate {
 pub(crate) enum SpatialPredicateOp {
     #[serde(rename = "s_contains")]
     SContains,
+    #[serde(rename = "s_coveredBy")]
+    SCoveredBy,
+    #[serde(rename = "s_covers")]
+    SCovers,
     #[serde(rename = "s_crosses")]
     SCrosses,
     #[serde(rename = "s_disjoint")]
@@ -4925,6 +4957,8 @@ impl fmt::Display for SpatialPredicateOp {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
             Self::SContains => write!(f, "s_contains"),
+            Self::SCoveredBy => write!(f, "s_coveredBy"),
+            Self::SCovers => write!(f, "s_covers"),
             Self::SCrosses => write!(f, "s_crosses"),
             Self::SDisjoint => write!(f, "s_disjoint"),
             Self::SEquals => write!(f, "s_equals"),
@@ -4940,6 +4974,8 @@ impl FromStr for SpatialPredicateOp {
     fn from_str(value: &str) -> Result<Self, MyError> {
         match value {
             "s_contains" => Ok(Self::SContains),
+            "s_coveredBy" => Ok(Self::SCoveredBy),
+            "s_covers" => Ok(Self::SCovers),
             "s_crosses" => Ok(Self::SCrosses),
             "s_disjoint" => Ok(Self::SDisjoint),
             "s_equals" => Ok(Self::SEquals),
@@ -37,6 +37,12 @@
 //! ```
 //! An `Ok` result implies a syntactically correct parsed expression!
 //!
+//! Beside the spatial predicates mandated by the specs, two extensions backed
+//! by `GEOS` are also recognized: `S_COVERS` (`s_covers` in CQL2-JSON) and
+//! `S_COVEREDBY` (`s_coveredBy`). They differ from `S_CONTAINS` and `S_WITHIN`
+//! only at the boundaries: a polygon _covers_ a point on one of its edges, or
+//! its own boundary, but does not _contain_ it.
+//!
 //! For convenience, a standalone tool is included that can be used from the
 //! command line to quickly test the vailidity of candidate expressions.
 //!
@@ -79,8 +85,11 @@
 //! by the library providing two macros: [gen_csv_ds!] and [gen_gpkg_ds!]. The
 //! first for the _CSV_ variety while the second for the _GeoPackage_ one.
 //!
-//! I intend to provide two additional implementations: one for [ESRI
-//! Shapefiles][shapefile] and another for [PostGIS enabled tables][pgis].
+//! Two additional implementations are provided: one for [ESRI Shapefiles][shapefile],
+//! w/ its [gen_shp_ds!] macro, and another for [PostGIS enabled tables][pgis].
+//!
+//! When the `geoparquet` feature is enabled, a read-only `GeoParquetDataSource`
+//! for [GeoParquet][geoparquet] files is available as well.
 //!
 //! # Features and Resources
 //!
@@ -161,7 +170,10 @@
 //! attribute are embodied by the [Queryable][Q] enumeration.
 //!
 //! Note though that this _resource_ genericity is too expensive in terms of
-//! performance.
+//! performance. When that matters, implement the [`ResourceRef`] trait for
+//! a _Feature_ type instead, and have it only materialize the properties an
+//! [`Expression`] actually references; [evaluators][Evaluator] can work w/
+//! either.
 //!
 //! # Iterable and Streamable
 //!
@@ -286,6 +298,10 @@
 //!    * [proj][9]: for coordinate transformation via bindings to the [PROJ][10]
 //!      API.
 //!
+//! Both [geos][1] and [proj][9] are only needed by the `geos` feature, which is
+//! enabled by default. W/o it, evaluating spatial operators, or _Functions_
+//! backed by [GEOS][2], fails w/ a [`MyError::Unsupported`] error.
+//!
 //!
 //! [1]: https://crates.io/crates/geos
 //! [2]: https://libgeos.org/
@@ -301,6 +317,7 @@
 //! [gpkg]: https://www.geopackage.org/spec140/index.html
 //! [shapefile]: https://en.wikipedia.org/wiki/Shapefile
 //! [pgis]: https://en.wikipedia.org/wiki/PostGIS
+//! [geoparquet]: https://geoparquet.org/
 //! [sqlx]: https://crates.io/crates/sqlx
 //! [readme]: https://crates.io/crates/xapi-rs
 //!
@@ -333,18 +350,25 @@ pub use ds::*;
 pub use evaluator::*;
 pub use function::*;
 pub use geom::*;
-pub use qstring::QString;
+pub use qstring::{CaseFolding, NormalizationForm, QString};
 pub use queryable::*;
 pub use srid::*;
 
 pub mod prelude;
 
-use crate::{expr::E, text::cql2::expression};
+use crate::{config::config, expr::E, op::Op, text::cql2::expression};
 use core::fmt;
-pub use error::MyError;
+use core::hash::{Hash, Hasher};
+pub use error::{MyError, SyntaxError};
+use serde::Deserialize;
+use std::{
+    collections::{BTreeSet, HashMap, HashSet},
+    sync::OnceLock,
+};
+use tracing::error;
 
 /// An instance of an OGC CQL2 filter.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub enum Expression {
     /// Instance generated from a successfully parsed text-encoded input string.
     Text(TextEncoded),
@@ -354,15 +378,75 @@ pub enum Expression {
 
 impl Expression {
     /// Try to construct from a text-encoded string.
+    ///
+    /// Input nested deeper than the configured `MAX_NESTING` (128 by default)
+    /// is rejected w/o being parsed; e.g. thousands of nested parentheses
+    /// that would otherwise exhaust the stack.
     pub fn try_from_text(s: &str) -> Result<Self, MyError> {
-        let x = expression(s).map_err(MyError::Text)?;
-        Ok(Expression::Text(TextEncoded(x)))
+        check_nesting(text_nesting(s), config().max_nesting())?;
+        Self::parse_text(s)
     }
 
     /// Try to construct from a JSON-encoded string.
+    ///
+    /// This is a shorthand for parsing `s` into a [`serde_json::Value`] and
+    /// calling [`try_from_json_value()`][Self::try_from_json_value()].
     pub fn try_from_json(s: &str) -> Result<Self, MyError> {
-        let x = serde_json::from_str::<json::Expression>(s).map_err(MyError::Json)?;
-        Ok(Expression::Json(Box::new(JsonEncoded(x))))
+        let value = serde_json::from_str::<serde_json::Value>(s).map_err(MyError::Json)?;
+        Self::try_from_json_value(&value)
+    }
+
+    /// Try to construct from an already parsed JSON-encoded value; e.g. the
+    /// body of a request deserialized by a web framework. The value is used
+    /// as is w/o being serialized back to a string and parsed again.
+    ///
+    /// Like w/ [`try_from_text()`][Self::try_from_text()], values nested too
+    /// deeply are rejected. Each level of a CQL2 JSON expression is both an
+    /// object and its `args` array, hence the limit here is twice the one of
+    /// text-encoded input.
+    pub fn try_from_json_value(value: &serde_json::Value) -> Result<Self, MyError> {
+        check_nesting(json_nesting(value), 2 * config().max_nesting())?;
+        let x = json::Expression::deserialize(value).map_err(MyError::Json)?;
+        Ok(Expression::Json(Box::new(JsonEncoded::new(x))))
+    }
+
+    /// Try to construct from a JSON-encoded string of an _OGC API - Features_
+    /// request object wrapping the filter; e.g.
+    ///
+    /// ```json
+    /// { "filter": { "op": "=", "args": [{ "property": "id" }, 1] }, "filter-lang": "cql2-json" }
+    /// ```
+    ///
+    /// The `filter-lang` member is optional but when present must be
+    /// `cql2-json`. Other members, if any, are ignored.
+    pub fn try_from_ogc_json(s: &str) -> Result<Self, MyError> {
+        let mut obj = match serde_json::from_str::<serde_json::Value>(s)? {
+            serde_json::Value::Object(x) => x,
+            x => {
+                let msg = format!("Expected a JSON object w/ a 'filter' member but found {x}");
+                error!("{msg}");
+                return Err(MyError::Runtime(msg.into()));
+            }
+        };
+        match obj.get("filter-lang").map(|x| x.as_str()) {
+            None | Some(Some("cql2-json")) => (),
+            Some(Some("cql2-text")) => {
+                let msg = "Filter language is 'cql2-text'. Use try_from_text() instead";
+                error!("{msg}");
+                return Err(MyError::Runtime(msg.into()));
+            }
+            Some(_) => {
+                let msg = format!("Unsupported filter language: {}", obj["filter-lang"]);
+                error!("{msg}");
+                return Err(MyError::Runtime(msg.into()));
+            }
+        }
+        let Some(filter) = obj.remove("filter") else {
+            let msg = "Missing 'filter' member";
+            error!("{msg}");
+            return Err(MyError::Runtime(msg.into()));
+        };
+        Self::try_from_json_value(&filter)
     }
 
     /// Return a reference to the text-encoded variant as an `Option`.
@@ -373,22 +457,338 @@ impl Expression {
         }
     }
 
+    /// Return a new text-encoded instance w/ every named parameter (such as
+    /// `${minpop}`) replaced by its value from `params`.
+    ///
+    /// This allows parsing a filter _template_ once and cheaply instantiating
+    /// it w/ different literals. Parameters w/o a bound value are kept as-is
+    /// and will cause an error when evaluated, or translated to SQL.
+    ///
+    /// Return an error if the value of a parameter used by this is, or
+    /// contains, a binary value ([`Q::Bytes`]) which has no CQL2 literal
+    /// representation. Unused parameters are ignored.
+    pub fn bind(&self, params: &HashMap<String, Q>) -> Result<Expression, MyError> {
+        let exp = self.to_inner()?.bind(params)?;
+        Ok(Expression::Text(TextEncoded::new(exp)))
+    }
+
+    /// Return a semantically equivalent text-encoded copy of this w/ its
+    /// constant sub-expressions folded. Specifically...
+    ///
+    /// * literal-only sub-expressions are evaluated; e.g. `2 + 3` becomes `5`,
+    ///   and `x IN ()` becomes FALSE when `x` is a (non-NULL) literal. those
+    ///   whose outcome depends on [Context] settings are kept as is; e.g.
+    ///   comparisons of strings, or of floating point numbers, as well as
+    ///   spatial predicates, and `T_EQUALS`, `T_INTERSECTS` and `T_DISJOINT`.
+    /// * `p IN ()`, and `p NOT IN ()`, where `p` is a property or any other
+    ///   non-literal, become `p IS NULL AND NULL`, and `p IS NOT NULL OR NULL`
+    ///   respectively; i.e. FALSE, and TRUE, unless `p` is NULL in which case
+    ///   they're NULL.
+    /// * TRUE operands of AND, and FALSE ones of OR, are dropped; e.g. `TRUE
+    ///   AND (x > 1)` becomes `x > 1`, while `FALSE AND p` becomes FALSE, and
+    ///   `TRUE OR p` becomes TRUE.
+    /// * double negations are collapsed; e.g. `NOT (NOT p)` becomes `p`.
+    ///
+    /// Evaluators, and the SQL translators of data sources, already apply the
+    /// same folding to the expressions they work w/.
+    pub fn simplify(&self) -> Result<Expression, MyError> {
+        let mut exp = self.to_inner()?;
+        let it = E::reduce(&mut exp)?;
+        Ok(Expression::Text(TextEncoded::new(it)))
+    }
+
+    /// Return a text-encoded instance that is the logical complement of this;
+    /// e.g. to select the _Resources_ this filter excludes.
+    ///
+    /// Where cheap, the negation is pushed inward instead of simply wrapping
+    /// this in a `NOT`. Specifically...
+    ///
+    /// * De Morgan's laws are applied to AND and OR; e.g. `NOT (a AND b)`
+    ///   becomes `NOT a OR NOT b`.
+    /// * comparison operators are flipped; e.g. `=` becomes `<>`, and `<`
+    ///   becomes `>=`.
+    /// * LIKE, BETWEEN, IN and IS NULL become their NOT counterparts, and
+    ///   vice-versa.
+    /// * double negations are collapsed; e.g. `NOT (NOT p)` becomes `p`.
+    ///
+    /// IMPORTANT - The result is the complement of this under two-valued logic
+    /// only. A predicate over a NULL, or missing, property evaluates to NULL,
+    /// and so does its negation; i.e. such a _Resource_ is neither matched by
+    /// this nor by its negation. Use `IS NULL` to capture those explicitly.
+    pub fn negate(&self) -> Result<Expression, MyError> {
+        let exp = self.to_inner()?;
+        Ok(Expression::Text(TextEncoded::new(exp.negate())))
+    }
+
+    /// Parse, reduce and check this once w/in the given [Context] returning a
+    /// [CompiledExpression] that can then be evaluated against any number of
+    /// _Resources_ w/o repeating that work.
+    ///
+    /// Return an error if this is invalid; e.g. when one of its literal
+    /// geometries has coordinates outside the area-of-use of the context CRS.
+    pub fn compile<'a>(&self, ctx: &'a Context) -> Result<CompiledExpression<'a>, MyError> {
+        CompiledExpression::new(ctx, self)
+    }
+
+    /// Evaluate this once, w/in the given [Context], against a [Resource], or
+    /// any other type that provides access to its properties by name.
+    ///
+    /// This is handy for one-off checks; e.g. in scripts and tests. Note
+    /// though that every call converts, reduces and checks this all over
+    /// again, which for JSON-encoded instances means walking the parsed JSON
+    /// tree each time. When evaluating many _Resources_ use [`compile()`][Self::compile()],
+    /// or an [`Evaluator`], instead.
+    ///
+    /// ```rust
+    /// use ogc_cql2::prelude::*;
+    /// # use std::error::Error;
+    /// # fn test() -> Result<(), Box<dyn Error>> {
+    ///     let exp = Expression::try_from_text("pop > 1000 AND name LIKE 'P%'")?;
+    ///     let paris = Resource::from([
+    ///         ("pop".into(), Q::Int(2_100_000)),
+    ///         ("name".into(), Q::new_plain_str("Paris")),
+    ///     ]);
+    ///     assert_eq!(exp.evaluate(&Context::new(), &paris)?, Outcome::T);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn evaluate<R: ResourceRef + ?Sized>(
+        &self,
+        ctx: &Context,
+        resource: &R,
+    ) -> Result<Outcome, MyError> {
+        self.compile(ctx)?.evaluate(resource)
+    }
+
+    /// Return the CQL2-JSON encoding of this.
+    ///
+    /// Negated predicates, such as `"name" NOT LIKE 'foo%'`, are encoded as a
+    /// `not` wrapping the positive one; e.g. `{"op": "not", "args": [{"op":
+    /// "like", ...}]}`. Parsing that back yields the same negated predicate.
+    ///
+    /// Return an error if this contains a construct w/ no JSON representation
+    /// such as an unbound named parameter (e.g. `${minpop}`).
+    pub fn to_json(&self) -> Result<serde_json::Value, MyError> {
+        match self {
+            Expression::Text(x) => x.0.to_json(),
+            Expression::Json(x) => serde_json::to_value(&x.0).map_err(MyError::Json),
+        }
+    }
+
+    /// Return the names of all the properties (aka queryables) this references,
+    /// incl. those only referenced as function arguments; e.g. `name` in
+    /// `CASEI(name) = casei('foo')`. Quoted names are returned w/o their
+    /// surrounding double-quotes.
+    ///
+    /// This is useful to only fetch from a data source the columns needed to
+    /// evaluate this.
+    pub fn properties(&self) -> BTreeSet<String> {
+        let mut result = BTreeSet::new();
+        match self.to_inner() {
+            Ok(x) => x.collect_properties(&mut result),
+            // should not happen...
+            Err(x) => error!("Failed converting JSON-encoded expression: {x}"),
+        }
+        result
+    }
+
+    /// Return the names of the properties this references, as returned by
+    /// [`properties()`][Self::properties()], that are not in `known`; e.g. the
+    /// names of the queryables of a collection.
+    ///
+    /// Use this to catch misspelled property names when setting up a filter
+    /// rather than get no matches when evaluating it, since an unknown property
+    /// evaluates to `NULL`. An empty set means every reference is known.
+    pub fn validate_against_schema(&self, known: &HashSet<String>) -> BTreeSet<String> {
+        self.properties()
+            .into_iter()
+            .filter(|x| !known.contains(x))
+            .collect()
+    }
+
+    /// Return TRUE if this uses at least one spatial operator (such as
+    /// `S_INTERSECTS`); FALSE otherwise.
+    pub fn is_spatial(&self) -> bool {
+        self.has(|x| x.has_op(Op::spatial))
+    }
+
+    /// Return TRUE if this uses at least one temporal operator (such as
+    /// `T_DURING`); FALSE otherwise.
+    pub fn is_temporal(&self) -> bool {
+        self.has(|x| x.has_op(Op::temporal))
+    }
+
+    /// Return TRUE if this uses at least one arithmetic operator (such as
+    /// `+` or `div`); FALSE otherwise.
+    pub fn is_arithmetic(&self) -> bool {
+        self.has(|x| x.has_op(Op::arithmetic))
+    }
+
+    /// Return TRUE if this calls at least one function (such as a built-in
+    /// `abs` or a user-defined one); FALSE otherwise. Note that `CASEI` and
+    /// `ACCENTI` are treated as operators and not as functions.
+    pub fn has_functions(&self) -> bool {
+        self.has(|x| x.any(&|y| matches!(y, E::Function(_))))
+    }
+
+    /// Return the nesting depth of this; i.e. the number of nodes on the
+    /// longest path from its root to a literal or a property name. For
+    /// example `a = 1` has a depth of 2, while `a = 1 AND (b = 2 OR c = 3)`
+    /// has a depth of 4.
+    ///
+    /// See [`Context::with_max_depth()`] to reject over-deep expressions.
+    pub fn depth(&self) -> usize {
+        match self.to_inner() {
+            Ok(x) => x.depth(),
+            // should not happen...
+            Err(x) => {
+                error!("Failed converting JSON-encoded expression: {x}");
+                0
+            }
+        }
+    }
+
+    /// Return a multi-line canonical text-encoded form of this, more suited
+    /// for logging and debugging deeply nested filters than the dense output
+    /// of `Display`. The operands of every `AND` / `OR` chain are placed on
+    /// separate lines, indented by `indent` spaces per nesting level.
+    ///
+    /// The result can be parsed back w/ [`try_from_text`][Self::try_from_text]
+    /// into an equal expression.
+    pub fn to_pretty_string(&self, indent: usize) -> String {
+        match self.to_inner() {
+            Ok(x) => x.to_pretty(indent),
+            // should not happen...
+            Err(x) => {
+                error!("Failed converting JSON-encoded expression: {x}");
+                self.to_string()
+            }
+        }
+    }
+
+    // apply `f` to the `E` form of this. FALSE if the conversion fails...
+    fn has(&self, f: impl Fn(&E) -> bool) -> bool {
+        match self.to_inner() {
+            Ok(x) => f(&x),
+            // should not happen...
+            Err(x) => {
+                error!("Failed converting JSON-encoded expression: {x}");
+                false
+            }
+        }
+    }
+
     // convert both variants to the common `E` intermediary form.
     pub(crate) fn to_inner(&self) -> Result<E, MyError> {
         match self {
             Expression::Text(x) => Ok(x.0.to_owned()),
             Expression::Json(x) => {
                 let s = &x.0.to_string();
-                let te = Self::try_from_text(s)?;
+                // NOTE (rsn) 20261016 - a JSON-encoded expression was already
+                // checked for nesting; its text rendering, w/ parentheses per
+                // level, is parsed unchecked...
+                let te = Self::parse_text(s)?;
                 let it = te
                     .as_text_encoded()
                     .ok_or_else(|| MyError::Runtime("Failed converting to TE".into()))?;
-                Ok(it.0.to_owned())
+                // JSON has no negated operators such as `NOT LIKE`...
+                Ok(it.0.to_owned().fold_not())
             }
         }
     }
 }
 
+impl Expression {
+    fn parse_text(s: &str) -> Result<Self, MyError> {
+        let x = expression(s).map_err(|x| MyError::Text(SyntaxError::new(s, x)))?;
+        Ok(Expression::Text(TextEncoded::new(x)))
+    }
+
+    // NOTE (rsn) 20261016 - both variants are compared, and hashed, using the
+    // same canonical form; i.e. the rendering of their common `E` form, which
+    // ignores whitespace, letter case of keywords, the encoding, and how an
+    // integral number is written, but keeps string literals, and their
+    // CASEI/ACCENTI flags, verbatim. should that conversion fail, we fall back
+    // to the original text. it's computed once, on first use...
+    fn canonical(&self) -> &str {
+        let cache = match self {
+            Expression::Text(x) => &x.1,
+            Expression::Json(x) => &x.1,
+        };
+        cache.get_or_init(|| match self.to_inner() {
+            Ok(x) => x.fold_not().int_numbers().to_string(),
+            Err(_) => self.to_string(),
+        })
+    }
+}
+
+// raise an error if `nesting` exceeds `max`...
+fn check_nesting(nesting: usize, max: usize) -> Result<(), MyError> {
+    if nesting > max {
+        let msg = format!("Input nesting ({nesting}) exceeds maximum ({max})");
+        error!("Failed: {msg}");
+        return Err(MyError::Runtime(msg.into()));
+    }
+    Ok(())
+}
+
+// return, w/o recursing, how deep the parser would recurse to parse the CQL2
+// text `s`; i.e. the nesting of its brackets, incl. those of function calls.
+// `AND` / `OR` chains are parsed iteratively and don't add to it. brackets
+// w/in quoted literals and identifiers are ignored...
+fn text_nesting(s: &str) -> usize {
+    let mut result = 0;
+    let mut depth = 0_usize;
+    let mut quote = None;
+    for c in s.chars() {
+        if let Some(q) = quote {
+            if c == q {
+                quote = None;
+            }
+            continue;
+        }
+        match c {
+            '\'' | '"' => quote = Some(c),
+            '(' | '[' => {
+                depth += 1;
+                result = result.max(depth);
+            }
+            ')' | ']' => depth = depth.saturating_sub(1),
+            _ => (),
+        }
+    }
+    result
+}
+
+// return, w/o recursing, the nesting of JSON objects and arrays in `value`...
+fn json_nesting(value: &serde_json::Value) -> usize {
+    let mut result = 0;
+    let mut stack = vec![(value, 0)];
+    while let Some((x, level)) = stack.pop() {
+        result = result.max(level);
+        match x {
+            serde_json::Value::Object(y) => stack.extend(y.values().map(|z| (z, level + 1))),
+            serde_json::Value::Array(y) => stack.extend(y.iter().map(|z| (z, level + 1))),
+            _ => (),
+        }
+    }
+    result
+}
+
+impl PartialEq for Expression {
+    fn eq(&self, other: &Self) -> bool {
+        self.canonical() == other.canonical()
+    }
+}
+
+impl Eq for Expression {}
+
+impl Hash for Expression {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.canonical().hash(state)
+    }
+}
+
 impl fmt::Display for Expression {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -399,12 +799,31 @@ impl fmt::Display for Expression {
 }
 
 /// Text-encoded CQL2 [`Expression`].
-#[derive(Debug, PartialEq)]
-pub struct TextEncoded(expr::E);
+#[derive(Clone, Debug)]
+pub struct TextEncoded(expr::E, OnceLock<String>);
+
+impl TextEncoded {
+    fn new(x: expr::E) -> Self {
+        Self(x, OnceLock::new())
+    }
+}
+
+// ignore the cached canonical form...
+impl PartialEq for TextEncoded {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
 
 /// JSON-encoded CQL2 [`Expression`].
-#[derive(Debug)]
-pub struct JsonEncoded(json::Expression);
+#[derive(Clone, Debug)]
+pub struct JsonEncoded(json::Expression, OnceLock<String>);
+
+impl JsonEncoded {
+    fn new(x: json::Expression) -> Self {
+        Self(x, OnceLock::new())
+    }
+}
 
 /// Possible outcome values when evaluating an [`Expression`] against an
 /// individual [`Resource`] from a collection.
@@ -433,7 +852,7 @@ pub struct JsonEncoded(json::Expression);
 /// +-----+-----+---------+---------+
 /// ```
 /// [1]: https://docs.ogc.org/is/21-065r2/21-065r2.html
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Outcome {
     /// The input satisfies the [Expression] and should be marked as being in
     /// the result set.
@@ -466,4 +885,527 @@ impl Outcome {
             None => Self::N,
         }
     }
+
+    /// Return the logical conjunction of `self` and `other` according to the
+    /// truth table above.
+    #[rustfmt::skip]
+    pub fn and(self, other: Outcome) -> Outcome {
+        match (self, other) {
+            (Outcome::F, _) | (_, Outcome::F) => Outcome::F,
+            (Outcome::T, Outcome::T)          => Outcome::T,
+            _                                 => Outcome::N,
+        }
+    }
+
+    /// Return the logical disjunction of `self` and `other` according to the
+    /// truth table above.
+    #[rustfmt::skip]
+    pub fn or(self, other: Outcome) -> Outcome {
+        match (self, other) {
+            (Outcome::T, _) | (_, Outcome::T) => Outcome::T,
+            (Outcome::F, Outcome::F)          => Outcome::F,
+            _                                 => Outcome::N,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_properties() -> Result<(), MyError> {
+        let exp = Expression::try_from_text(
+            r#"CASEI("name") = casei('foo') AND pop > 10 AND S_INTERSECTS(geom, BBOX(0, 0, 1, 1))
+            AND T_DURING(INTERVAL(start, '2020-01-01'), INTERVAL('2019-01-01', '..')) AND pop IN (1, x)"#,
+        )?;
+        let expected = ["geom", "name", "pop", "start", "x"];
+        assert!(exp.properties().iter().eq(expected.iter()));
+
+        let exp = Expression::try_from_json(
+            r#"{
+                "op": "and",
+                "args": [
+                    { "op": "a_contains", "args": [{ "property": "tags" }, ["a", "b"]] },
+                    { "op": "=", "args": [{ "op": "casei", "args": [{ "property": "name" }] }, "x"] }
+                ]
+            }"#,
+        )?;
+        assert!(exp.properties().iter().eq(["name", "tags"].iter()));
+
+        // no properties...
+        let exp = Expression::try_from_text("1 < 2")?;
+        assert!(exp.properties().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_classifiers() -> Result<(), MyError> {
+        #[rustfmt::skip]
+        const TV: [(&str, bool, bool, bool, bool); 7] = [
+            // expression                                                  spatial temporal arith. fn
+            ("pop > 10",                                                   false, false, false, false),
+            ("pop > 10 AND S_INTERSECTS(geom, BBOX(0, 0, 1, 1))",          true,  false, false, false),
+            ("NOT T_DURING(INTERVAL(start, '..'), INTERVAL('2019-01-01', '2020-01-01'))",
+                                                                           false, true,  false, false),
+            ("pop * 2 > 10",                                               false, false, true,  false),
+            // CASEI and ACCENTI are operators...
+            ("CASEI(name) = casei('foo')",                                 false, false, false, false),
+            // function arguments...
+            ("abs(pop * 2) > 10 OR S_WITHIN(geom, POINT(1 2))",            true,  false, true,  true),
+            // interval bounds...
+            ("T_AFTER(INTERVAL(begin(x), '..'), DATE('2020-01-01'))",      false, true,  false, true),
+        ];
+        for (src, spatial, temporal, arithmetic, functions) in TV {
+            let exp = Expression::try_from_text(src)?;
+            assert_eq!(exp.is_spatial(), spatial, "{src}");
+            assert_eq!(exp.is_temporal(), temporal, "{src}");
+            assert_eq!(exp.is_arithmetic(), arithmetic, "{src}");
+            assert_eq!(exp.has_functions(), functions, "{src}");
+        }
+
+        let exp = Expression::try_from_json(
+            r#"{ "op": "s_intersects", "args": [{ "property": "geom" }, { "bbox": [0, 0, 1, 1] }] }"#,
+        )?;
+        assert!(exp.is_spatial());
+        assert!(!exp.is_temporal());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_from_json_value() -> Result<(), MyError> {
+        const FILTER: &str = r#"{ "op": "=", "args": [{ "property": "id" }, 1] }"#;
+
+        let value = serde_json::json!({ "op": "=", "args": [{ "property": "id" }, 1] });
+        let exp = Expression::try_from_json_value(&value)?;
+        let expected = Expression::try_from_json(FILTER)?.to_string();
+        assert_eq!(exp.to_string(), expected);
+        let feature = Resource::from([("id".into(), Q::Int(1))]);
+        assert_eq!(exp.evaluate(&Context::new(), &feature)?, Outcome::T);
+
+        let value = serde_json::json!({ "op": 42, "args": {} });
+        let err = Expression::try_from_json_value(&value).expect_err("Expected an error");
+        assert!(matches!(err, MyError::Json(_)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_eq_hash() -> Result<(), MyError> {
+        let a = Expression::try_from_text("name='Paris' and pop>10")?;
+        let b = Expression::try_from_text("name = 'Paris'   AND pop > 10")?;
+        let c = Expression::try_from_json(
+            r#"{ "op": "and", "args": [
+                { "op": "=", "args": [{ "property": "name" }, "Paris"] },
+                { "op": ">", "args": [{ "property": "pop" }, 10] }
+            ] }"#,
+        )?;
+        let d = Expression::try_from_text("name = 'Paris' AND pop >= 10")?;
+
+        assert_eq!(a, b);
+        assert_eq!(a, c);
+        assert_ne!(a, d);
+
+        let cache = HashMap::from([(a, 1), (d, 2)]);
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.get(&b), Some(&1));
+        assert_eq!(cache.get(&c), Some(&1));
+
+        // string literals are compared verbatim, not w/ the lenient equality
+        // used when evaluating...
+        let e = Expression::try_from_text("name = 'Paris'")?;
+        let f = Expression::try_from_text("name = casei('paris')")?;
+        let g = Expression::try_from_text("name = CASEI('paris')")?;
+        assert_ne!(e, f);
+        assert_eq!(f, g);
+        let hash = |x: &Expression| {
+            let mut h = std::hash::DefaultHasher::new();
+            x.hash(&mut h);
+            h.finish()
+        };
+        assert_ne!(hash(&e), hash(&f));
+        assert_eq!(hash(&f), hash(&g));
+
+        // integral numbers are compared by value...
+        let h = Expression::try_from_text("pop > 10.0")?;
+        let i =
+            Expression::try_from_json(r#"{ "op": ">", "args": [{ "property": "pop" }, 1e1] }"#)?;
+        let j = Expression::try_from_text("pop > 10")?;
+        assert_eq!(h, j);
+        assert_eq!(i, j);
+        assert_eq!(hash(&h), hash(&j));
+        assert_eq!(hash(&i), hash(&j));
+        assert_ne!(h, Expression::try_from_text("pop > 10.5")?);
+        // ...and so are zeroes...
+        let k = Expression::try_from_text("pop > -0.0")?;
+        assert_eq!(k, Expression::try_from_text("pop > 0")?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_from_ogc_json() -> Result<(), MyError> {
+        const FILTER: &str = r#"{ "op": "=", "args": [{ "property": "id" }, 1] }"#;
+
+        let expected = Expression::try_from_json(FILTER)?.to_string();
+        let exp = Expression::try_from_ogc_json(&format!(
+            r#"{{ "filter": {FILTER}, "filter-lang": "cql2-json", "limit": 10 }}"#
+        ))?;
+        assert_eq!(exp.to_string(), expected);
+        // filter-lang is optional...
+        let exp = Expression::try_from_ogc_json(&format!(r#"{{ "filter": {FILTER} }}"#))?;
+        assert_eq!(exp.to_string(), expected);
+
+        let text = r#"{ "filter": "id = 1", "filter-lang": "cql2-text" }"#;
+        let err = Expression::try_from_ogc_json(text).expect_err("Expected an error");
+        assert!(err.to_string().contains("try_from_text()"));
+
+        let sql = r#"{ "filter": true, "filter-lang": "sql" }"#;
+        assert!(Expression::try_from_ogc_json(sql).is_err());
+        assert!(Expression::try_from_ogc_json(r#"{ "filter-lang": "cql2-json" }"#).is_err());
+        assert!(Expression::try_from_ogc_json(FILTER).is_err());
+        assert!(Expression::try_from_ogc_json("[]").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_against_schema() -> Result<(), MyError> {
+        let known: HashSet<String> = ["population", "name", "geom"].map(String::from).into();
+        let exp = Expression::try_from_text(
+            r#"populaton > 10 AND "name" LIKE 'A%' AND S_INTERSECTS(geom, BBOX(0, 0, 1, 1))"#,
+        )?;
+        let unknown = exp.validate_against_schema(&known);
+        assert_eq!(unknown, BTreeSet::from(["populaton".to_string()]));
+
+        let exp = Expression::try_from_json(
+            r#"{ "op": "=", "args": [{ "property": "population" }, 10] }"#,
+        )?;
+        assert!(exp.validate_against_schema(&known).is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pretty_string() -> Result<(), MyError> {
+        const CQL: [&str; 5] = [
+            "pop > 10 AND name LIKE 'O''Hare%' AND (a = 1 OR NOT (b <> 2)) AND x IS NOT NULL",
+            "(a + b) * 2 - c / (d - 1) > 2 ^ -e OR depth NOT BETWEEN 100.5 AND 150",
+            r#"S_INTERSECTS(geom, POLYGON((0 0, 1 0, 1 1, 0 1, 0 0))) AND T_DURING(INTERVAL(start, '..'),
+            INTERVAL('2019-01-01', '2020-01-01T10:20:30Z'))"#,
+            r#"A_CONTAINS(tags, ('a', 'b')) AND cat IN (1, 2, 3) AND code NOT IN allowed
+            AND CASEI(name) = casei('Foo') AND d > DATE('2020-01-01')
+            AND t <= TIMESTAMP('2020-01-01T00:00:00Z')"#,
+            "${minpop} < pop OR abs(x - 1) >= 3 OR S_WITHIN(geom, BBOX(-10, -20, 10, 20))",
+        ];
+        for src in CQL {
+            let exp = Expression::try_from_text(src)?;
+            let pretty = exp.to_pretty_string(2);
+            let actual = Expression::try_from_text(&pretty)?;
+            assert_eq!(actual.to_inner()?, exp.to_inner()?, "{pretty}");
+        }
+
+        let exp = Expression::try_from_text(CQL[0])?;
+        assert_eq!(
+            exp.to_pretty_string(2),
+            r#"pop > 10
+  AND name LIKE 'O''Hare%'
+  AND (a = 1
+    OR NOT (b <> 2))
+  AND x IS NOT NULL"#
+        );
+
+        let exp = Expression::try_from_json(
+            r#"{
+                "op": "or",
+                "args": [
+                    { "op": ">", "args": [{ "property": "pop" }, 10] },
+                    { "op": "=", "args": [{ "property": "name" }, "x"] }
+                ]
+            }"#,
+        )?;
+        assert_eq!(exp.to_pretty_string(4), "pop > 10\n    OR name = 'x'");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_json() -> Result<(), MyError> {
+        const CQL: [&str; 12] = [
+            r#""name" NOT LIKE 'foo%'"#,
+            "pop NOT IN (1, 2, 3)",
+            "pop NOT BETWEEN 1 AND 10",
+            "name IS NOT NULL",
+            "a = 1 AND b < 2 AND NOT (c > 3)",
+            "S_INTERSECTS(geom, BBOX(0, 0, 1, 1))",
+            "S_WITHIN(geom, POLYGON((0 0, 1 0, 1 1, 0 0)))",
+            "T_DURING(INTERVAL(starts_at, ends_at), INTERVAL('2005-01-10', '..'))",
+            "CASEI(name) = casei('Foo')",
+            "pop + 2 * x > -5",
+            "ts > TIMESTAMP('2020-01-01T00:00:00Z') OR d = DATE('2020-01-01')",
+            "A_CONTAINS(tags, ('a', 'b'))",
+        ];
+
+        for src in CQL {
+            let exp = Expression::try_from_text(src)?;
+            let json = exp.to_json()?;
+            // must be valid CQL2-JSON that encodes the same expression...
+            let exp2 = Expression::try_from_json(&json.to_string())?;
+            assert_eq!(exp2.to_inner()?.to_json()?, json, "{src}");
+        }
+
+        let exp = Expression::try_from_text(r#""name" NOT LIKE 'foo%'"#)?;
+        let json = exp.to_json()?;
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "op": "not",
+                "args": [{ "op": "like", "args": [{ "property": "name" }, "foo%"] }]
+            })
+        );
+        // and back to the negated operator...
+        let exp = Expression::try_from_json(&json.to_string())?;
+        assert!(matches!(exp.to_inner()?, E::Dyadic(Op::IsNotLike, _, _)));
+
+        let json = r#"{
+            "op": "not",
+            "args": [{ "op": "in", "args": [{ "property": "pop" }, [1, 2]] }]
+        }"#;
+        let exp = Expression::try_from_json(json)?;
+        assert!(matches!(exp.to_inner()?, E::Dyadic(Op::IsNotInList, _, _)));
+        // a JSON-encoded instance is returned as is...
+        let expected: serde_json::Value = serde_json::from_str(json)?;
+        assert_eq!(exp.to_json()?, expected);
+
+        // parameters have no JSON representation...
+        let exp = Expression::try_from_text("pop > ${minpop}")?;
+        assert!(exp.to_json().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_evaluate() -> Result<(), Box<dyn std::error::Error>> {
+        let ctx = Context::new();
+        let feat = Resource::from([
+            ("name".into(), Q::new_plain_str("Paris")),
+            ("pop".into(), Q::Int(2_100_000)),
+            ("geom".into(), Q::try_from_wkt("POINT(2.35 48.86)")?),
+        ]);
+
+        let exp =
+            Expression::try_from_text("pop > 1000 AND S_INTERSECTS(geom, BBOX(2, 48, 3, 49))")?;
+        assert_eq!(exp.evaluate(&ctx, &feat)?, Outcome::T);
+        let exp = Expression::try_from_text("name = 'London'")?;
+        assert_eq!(exp.evaluate(&ctx, &feat)?, Outcome::F);
+        let exp = Expression::try_from_text("missing > 1")?;
+        assert_eq!(exp.evaluate(&ctx, &feat)?, Outcome::N);
+
+        // JSON-encoded ones too...
+        let exp = Expression::try_from_json(
+            r#"{ "op": "=", "args": [{ "property": "name" }, "Paris"] }"#,
+        )?;
+        assert_eq!(exp.evaluate(&ctx, &feat)?, Outcome::T);
+
+        // and the same checks as when compiling apply...
+        let exp = Expression::try_from_text("S_INTERSECTS(geom, POINT(200 0))")?;
+        assert!(exp.evaluate(&ctx, &feat).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_negate() -> Result<(), Box<dyn std::error::Error>> {
+        #[rustfmt::skip]
+        const TV: [(&str, &str); 12] = [
+            ("x = 1",                           "x <> 1"),
+            ("x <> 1",                          "x = 1"),
+            ("x < 1",                           "x >= 1"),
+            ("x >= 1",                          "x < 1"),
+            ("x > 1",                           "x <= 1"),
+            ("name LIKE 'Fo%'",                 "name NOT LIKE 'Fo%'"),
+            ("x NOT BETWEEN 1 AND 5",           "x BETWEEN 1 AND 5"),
+            ("x IN (1, 2)",                     "x NOT IN (1, 2)"),
+            ("x IS NULL",                       "x IS NOT NULL"),
+            ("NOT (x > 1)",                     "x > 1"),
+            ("x > 1 AND (y = 2 OR z < 3)",      "x <= 1 OR (y <> 2 AND z >= 3)"),
+            ("S_INTERSECTS(geom, POINT(0 0))",  "NOT S_INTERSECTS(geom, POINT(0 0))"),
+        ];
+        for (src, expected) in TV {
+            let actual = Expression::try_from_text(src)?.negate()?;
+            let expected = Expression::try_from_text(expected)?;
+            assert_eq!(actual, expected, "{src}");
+        }
+
+        // a JSON-encoded instance is negated too...
+        let exp = Expression::try_from_json(
+            r#"{ "op": "not", "args": [{ "op": "like", "args": [{ "property": "s" }, "a%"] }] }"#,
+        )?;
+        assert_eq!(exp.negate()?, Expression::try_from_text("s LIKE 'a%'")?);
+
+        // for non-NULL values the negation selects exactly what the original
+        // excludes, while NULL ones are excluded by both...
+        let ctx = Context::new();
+        let exp = Expression::try_from_text("x > 1 AND NOT (x IN (3, 4))")?;
+        let negated = exp.negate()?;
+        for (x, expected) in [(0, Outcome::T), (2, Outcome::F), (3, Outcome::T)] {
+            let feat = Resource::from([("x".into(), Q::Int(x))]);
+            assert_eq!(negated.evaluate(&ctx, &feat)?, expected, "{x}");
+            assert_ne!(exp.evaluate(&ctx, &feat)?, expected, "{x}");
+        }
+        let feat = Resource::from([("x".into(), Q::Null)]);
+        assert_eq!(exp.evaluate(&ctx, &feat)?, Outcome::N);
+        assert_eq!(negated.evaluate(&ctx, &feat)?, Outcome::N);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_simplify() -> Result<(), Box<dyn std::error::Error>> {
+        #[rustfmt::skip]
+        const TV: [(&str, &str); 9] = [
+            ("TRUE AND (x > 1)",                        "x > 1"),
+            ("(x > 1) AND TRUE",                        "x > 1"),
+            ("FALSE OR (x > 1)",                        "x > 1"),
+            ("NOT (NOT (x > 1))",                       "x > 1"),
+            ("NOT (NOT (NOT (x > 1)))",                 "NOT (x > 1)"),
+            ("x > 2 + 3",                               "x > 5"),
+            ("(x > 1 AND 1 = 1) OR (y < 2 AND 2 = 3)",  "x > 1"),
+            ("FALSE AND (x > 1)",                       "FALSE"),
+            ("(x > 1) OR (2 > 1)",                      "TRUE"),
+        ];
+        for (src, expected) in TV {
+            let actual = Expression::try_from_text(src)?.simplify()?;
+            let expected = Expression::try_from_text(expected)?;
+            assert_eq!(
+                actual.as_text_encoded(),
+                expected.as_text_encoded(),
+                "{src}"
+            );
+        }
+
+        // a JSON-encoded instance is simplified too...
+        let exp = Expression::try_from_json(
+            r#"{ "op": "and", "args": [true, { "op": ">", "args": [{ "property": "x" }, 1] }] }"#,
+        )?;
+        let expected = Expression::try_from_text("x > 1")?;
+        assert_eq!(
+            exp.simplify()?.as_text_encoded(),
+            expected.as_text_encoded()
+        );
+
+        // the simplified expression is semantically equivalent...
+        let ctx = Context::new().freeze();
+        let src = "NOT (NOT (x > 1 AND TRUE)) OR (y IN (1, 2) AND FALSE)";
+        let exp = Expression::try_from_text(src)?;
+        let simplified = exp.simplify()?;
+        for x in [Q::Int(0), Q::Int(2), Q::Null] {
+            let feat = Resource::from([("x".into(), x)]);
+            let mut e1 = ExEvaluator::new(ctx.clone());
+            e1.setup(exp.clone())?;
+            let mut e2 = ExEvaluator::new(ctx.clone());
+            e2.setup(simplified.clone())?;
+            assert_eq!(e1.evaluate(&feat)?, e2.evaluate(&feat)?, "{feat:?}");
+        }
+
+        // an empty list only tests a property for NULL...
+        #[rustfmt::skip]
+        const EMPTY: [(&str, &str); 5] = [
+            ("x IN ${ids}",             "x IS NULL && (NULL)"),
+            ("x NOT IN ${ids}",         "x IS NOT NULL || (NULL)"),
+            ("NOT (x IN ${ids})",       "!(x IS NULL && (NULL))"),
+            ("1 IN ${ids}",             "FALSE"),
+            ("1 NOT IN ${ids}",         "TRUE"),
+        ];
+        let params = HashMap::from([("ids".into(), Q::List(vec![]))]);
+        for (src, expected) in EMPTY {
+            let exp = Expression::try_from_text(src)?.bind(&params)?;
+            let simplified = exp.simplify()?;
+            assert_eq!(simplified.to_string(), expected, "{src}");
+            for x in [Q::Int(0), Q::Null] {
+                let feat = Resource::from([("x".into(), x)]);
+                let mut e1 = ExEvaluator::new(ctx.clone());
+                e1.setup(exp.clone())?;
+                let mut e2 = ExEvaluator::new(ctx.clone());
+                e2.setup(simplified.clone())?;
+                assert_eq!(e1.evaluate(&feat)?, e2.evaluate(&feat)?, "{src} {feat:?}");
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bound_literals_w_ctx() -> Result<(), Box<dyn std::error::Error>> {
+        let params: Resource = HashMap::from([
+            ("name".into(), Q::new_plain_str("Zorn")),
+            ("ratio".into(), Q::Num(0.09999999999999998)),
+            ("code".into(), Q::new_plain_str("10")),
+            ("pt".into(), Q::try_from_wkt("POINT(1 1)")?),
+            (
+                "ts".into(),
+                Q::Instant(Bound::try_new_timestamp("2010-02-10T05:29:20.073Z")?),
+            ),
+        ]);
+        let ctx = Context::new()
+            .try_with_collation("sv")?
+            .with_numeric_epsilon(1e-9)
+            .with_implicit_coercion()
+            .with_spatial_tolerance(0.01)
+            .with_temporal_tolerance(std::time::Duration::from_millis(1));
+
+        // all hold w/ the settings above, but not w/ the default ones...
+        #[rustfmt::skip]
+        const TV: [&str; 7] = [
+            "${name} BETWEEN 'a' AND 'ö'",
+            "${ratio} = 0.1",
+            "${ratio} IN (0.5, 0.1)",
+            "${code} = 10",
+            "${code} IN (5, 10)",
+            "S_EQUALS(${pt}, POINT(1.001 1))",
+            "T_EQUALS(${ts}, TIMESTAMP('2010-02-10T05:29:20.073225Z'))",
+        ];
+        // the parameters double as the properties of a Resource...
+        let feat = &params;
+        for src in TV {
+            let exp = Expression::try_from_text(src)?.bind(&params)?;
+            // a bound expression, once compiled, yields the same outcome as
+            // when evaluated as is...
+            assert_eq!(exp.to_inner()?.eval(&ctx, feat)?, Q::Bool(true), "{src}");
+            assert_eq!(exp.evaluate(&ctx, feat)?, Outcome::T, "{src}");
+            // ...and as its unbound form over a Resource w/ the same values...
+            let exp = Expression::try_from_text(&src.replace("${", "").replace('}', ""))?;
+            assert_eq!(exp.evaluate(&ctx, feat)?, Outcome::T, "{src}");
+            // ...while the defaults differ...
+            let exp = Expression::try_from_text(src)?.bind(&params)?;
+            let actual = exp.evaluate(&Context::new(), feat);
+            assert!(!matches!(actual, Ok(Outcome::T)), "{src}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn test_outcome_truth_table() {
+        const TT: [(Outcome, Outcome, Outcome, Outcome); 9] = [
+            // P1       P2          P1 & P2     P1 | P2
+            (Outcome::T, Outcome::T, Outcome::T, Outcome::T),
+            (Outcome::T, Outcome::F, Outcome::F, Outcome::T),
+            (Outcome::F, Outcome::T, Outcome::F, Outcome::T),
+            (Outcome::F, Outcome::F, Outcome::F, Outcome::F),
+            (Outcome::T, Outcome::N, Outcome::N, Outcome::T),
+            (Outcome::F, Outcome::N, Outcome::F, Outcome::N),
+            (Outcome::N, Outcome::T, Outcome::N, Outcome::T),
+            (Outcome::N, Outcome::F, Outcome::F, Outcome::N),
+            (Outcome::N, Outcome::N, Outcome::N, Outcome::N),
+        ];
+
+        for (p1, p2, and, or) in TT {
+            assert_eq!(p1.and(p2), and, "{p1} & {p2}");
+            assert_eq!(p1.or(p2), or, "{p1} | {p2}");
+        }
+    }
 }
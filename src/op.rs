@@ -40,6 +40,8 @@ pub(crate) enum Op {
     SOverlaps,
     SCrosses,
     SContains,
+    SCovers,    // not in CQL2; like S_CONTAINS but boundary points count
+    SCoveredBy, // not in CQL2; like S_WITHIN but boundary points count
     // temporal stuff...
     TAfter,
     TBefore,
@@ -116,6 +118,8 @@ impl Op {
             Op::SIntersects
             // other...
             | Op::SContains
+                | Op::SCovers
+                | Op::SCoveredBy
                 | Op::SCrosses
                 | Op::SDisjoint
                 | Op::SEquals
@@ -188,6 +192,8 @@ impl Op {
             Op::SOverlaps   => "ST_Overlaps",
             Op::SCrosses    => "ST_Crosses",
             Op::SContains   => "ST_Contains",
+            Op::SCovers     => "ST_Covers",
+            Op::SCoveredBy  => "ST_CoveredBy",
 
             // NOTE (rsn) 20251202 - SQLite, unlike PostgreSQL, doesn't support
             // Array operators.
@@ -208,6 +214,108 @@ impl Op {
             x => unreachable!("Operator {x:?} has NO direct SQL representation")
         }
     }
+
+    // Return the name of this operator in the CQL2-JSON encoding. negated
+    // operators (such as `NOT LIKE`) have none; they're encoded as a `not`
+    // wrapping their positive counterpart...
+    #[rustfmt::skip]
+    pub(crate) fn to_json(&self) -> Option<&str> {
+        let it = match self {
+            Op::Plus   => "+",
+            Op::Minus  => "-",
+            Op::Mult   => "*",
+            Op::Div    => "/",
+            Op::IntDiv => "div",
+            Op::Mod    => "%",
+            Op::Exp    => "^",
+
+            Op::Neg => "not",
+            Op::Eq  => "=",
+            Op::Neq => "<>",
+            Op::Lt  => "<",
+            Op::Gt  => ">",
+            Op::Lte => "<=",
+            Op::Gte => ">=",
+            Op::And => "and",
+            Op::Or  => "or",
+
+            Op::CaseI   => "casei",
+            Op::AccentI => "accenti",
+
+            Op::SIntersects => "s_intersects",
+            Op::SEquals     => "s_equals",
+            Op::SDisjoint   => "s_disjoint",
+            Op::STouches    => "s_touches",
+            Op::SWithin     => "s_within",
+            Op::SOverlaps   => "s_overlaps",
+            Op::SCrosses    => "s_crosses",
+            Op::SContains   => "s_contains",
+            Op::SCovers     => "s_covers",
+            Op::SCoveredBy  => "s_coveredBy",
+
+            Op::TAfter        => "t_after",
+            Op::TBefore       => "t_before",
+            Op::TContains     => "t_contains",
+            Op::TDisjoint     => "t_disjoint",
+            Op::TDuring       => "t_during",
+            Op::TEquals       => "t_equals",
+            Op::TFinishedBy   => "t_finishedBy",
+            Op::TFinishes     => "t_finishes",
+            Op::TIntersects   => "t_intersects",
+            Op::TMeets        => "t_meets",
+            Op::TMetBy        => "t_metBy",
+            Op::TOverlappedBy => "t_overlappedBy",
+            Op::TOverlaps     => "t_overlaps",
+            Op::TStartedBy    => "t_startedBy",
+            Op::TStarts       => "t_starts",
+
+            Op::AEquals      => "a_equals",
+            Op::AContains    => "a_contains",
+            Op::AContainedBy => "a_containedBy",
+            Op::AOverlaps    => "a_overlaps",
+
+            Op::IsLike    => "like",
+            Op::IsBetween => "between",
+            Op::IsInList  => "in",
+            Op::IsNull    => "isNull",
+
+            Op::IsNotLike | Op::IsNotBetween | Op::IsNotInList | Op::IsNotNull => return None,
+        };
+        Some(it)
+    }
+
+    // Return the positive counterpart of a negated operator; `None` otherwise.
+    pub(crate) fn negated(&self) -> Option<Op> {
+        match self {
+            Op::IsNotLike => Some(Op::IsLike),
+            Op::IsNotBetween => Some(Op::IsBetween),
+            Op::IsNotInList => Some(Op::IsInList),
+            Op::IsNotNull => Some(Op::IsNull),
+            _ => None,
+        }
+    }
+
+    // Return the operator whose outcome, for the same non-NULL operands, is
+    // the logical complement of this one; `None` if there's no such operator.
+    pub(crate) fn complement(&self) -> Option<Op> {
+        match self {
+            Op::Eq => Some(Op::Neq),
+            Op::Neq => Some(Op::Eq),
+            Op::Lt => Some(Op::Gte),
+            Op::Gte => Some(Op::Lt),
+            Op::Gt => Some(Op::Lte),
+            Op::Lte => Some(Op::Gt),
+            Op::IsLike => Some(Op::IsNotLike),
+            Op::IsNotLike => Some(Op::IsLike),
+            Op::IsBetween => Some(Op::IsNotBetween),
+            Op::IsNotBetween => Some(Op::IsBetween),
+            Op::IsInList => Some(Op::IsNotInList),
+            Op::IsNotInList => Some(Op::IsInList),
+            Op::IsNull => Some(Op::IsNotNull),
+            Op::IsNotNull => Some(Op::IsNull),
+            _ => None,
+        }
+    }
 }
 
 #[rustfmt::skip]
@@ -244,6 +352,8 @@ impl fmt::Display for Op {
             Op::SOverlaps   => write!(f, "S_OVERLAPS"),
             Op::SCrosses    => write!(f, "S_CROSSES"),
             Op::SContains   => write!(f, "S_CONTAINS"),
+            Op::SCovers     => write!(f, "S_COVERS"),
+            Op::SCoveredBy  => write!(f, "S_COVEREDBY"),
 
             Op::TAfter        => write!(f, "T_AFTER"),
             Op::TBefore       => write!(f, "T_BEFORE"),
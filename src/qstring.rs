@@ -6,8 +6,9 @@
 //! be used as-is or in a case-insensitive way.
 //!
 
+use crate::MyError;
 use core::fmt;
-use std::ops;
+use std::{borrow::Cow, ops};
 use unicase::UniCase;
 use unicode_normalization::{UnicodeNormalization, char::is_combining_mark};
 
@@ -49,6 +50,85 @@ impl ops::BitOr for Ignoring {
     }
 }
 
+/// Unicode normalization forms to use when folding accents away from strings
+/// compared, or matched, in an accent-insensitive way.
+///
+/// The canonical forms (NFC and NFD) treat precomposed and decomposed accented
+/// characters alike, while the compatibility ones (NFKC and NFKD) also unfold
+/// ligatures such as `ﬁ`, or full-width letters, to their plain equivalents.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum NormalizationForm {
+    /// Canonical decomposition followed by canonical composition. This is
+    /// the default.
+    #[default]
+    Nfc,
+    /// Canonical decomposition.
+    Nfd,
+    /// Compatibility decomposition followed by canonical composition.
+    Nfkc,
+    /// Compatibility decomposition.
+    Nfkd,
+}
+
+impl NormalizationForm {
+    /// Return `s` normalized according to this form.
+    pub fn apply(&self, s: &str) -> String {
+        match self {
+            NormalizationForm::Nfc => s.nfc().collect(),
+            NormalizationForm::Nfd => s.nfd().collect(),
+            NormalizationForm::Nfkc => s.nfkc().collect(),
+            NormalizationForm::Nfkd => s.nfkd().collect(),
+        }
+    }
+
+    // Return TRUE if this is a compatibility form; FALSE otherwise.
+    fn is_compatibility(&self) -> bool {
+        matches!(self, NormalizationForm::Nfkc | NormalizationForm::Nfkd)
+    }
+}
+
+/// Unicode case folding variants to use when comparing, or matching, strings
+/// in a case-insensitive way.
+///
+/// Both apply the _full_ case folding mappings of the Unicode Character
+/// Database; e.g. `ß` and `ẞ` fold to `ss` so that `Straße` and `STRASSE` are
+/// equal ignoring case. They only differ in how they fold the letter I.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CaseFolding {
+    /// Language-neutral folding; e.g. `I` folds to `i`, while `İ` (capital I
+    /// w/ dot above) folds to `i` followed by a combining dot above. This is
+    /// the default.
+    #[default]
+    Full,
+    /// Same as `Full` but w/ the Turkic mappings of dotted and dotless I; i.e.
+    /// `I` folds to `ı`, and `İ` to `i`. Suitable for Turkish and Azerbaijani.
+    Turkic,
+}
+
+impl CaseFolding {
+    /// Return `s` case-folded according to this variant.
+    pub fn apply(&self, s: &str) -> String {
+        UniCase::unicode(self.prepare(s)).to_folded_case()
+    }
+
+    // Return `s` w/ the characters this variant folds differently than the
+    // default replaced w/ their folded form...
+    fn prepare<'a>(&self, s: &'a str) -> Cow<'a, str> {
+        match self {
+            CaseFolding::Turkic if s.contains(['I', 'İ']) => Cow::Owned(
+                s.chars()
+                    .map(|c| match c {
+                        'I' => 'ı',
+                        'İ' => 'i',
+                        x => x,
+                    })
+                    .collect(),
+            ),
+            _ => Cow::Borrowed(s),
+        }
+    }
+}
+
 /// String based type used by [`Queryable`s][crate::Q] to represent a plain string, and
 /// a set of flags to indicate how to use it in case and/or accent insensitive
 /// contexts.
@@ -68,17 +148,7 @@ impl fmt::Display for QString {
 
 impl PartialEq for QString {
     fn eq(&self, other: &Self) -> bool {
-        let to_icase = self.is_icase() || other.is_icase();
-        let to_iaccent = self.is_iaccent() || other.is_iaccent();
-        match (to_icase, to_iaccent) {
-            (true, true) => {
-                UniCase::new(QString::unaccent(&self.inner))
-                    == UniCase::new(QString::unaccent(&other.inner))
-            }
-            (true, false) => UniCase::new(&self.inner) == UniCase::new(&other.inner),
-            (false, true) => QString::unaccent(&self.inner) == QString::unaccent(&other.inner),
-            (false, false) => self.inner == other.inner,
-        }
+        self.eq_with(other, NormalizationForm::default(), CaseFolding::default())
     }
 }
 
@@ -116,15 +186,62 @@ impl QString {
         &self.inner
     }
 
+    /// Return a new instance w/ the same flags as `self` but w/ its inner string
+    /// normalized according to `form`.
+    pub fn normalize_with(&self, form: NormalizationForm) -> Self {
+        Self {
+            inner: form.apply(&self.inner),
+            flags: self.flags.clone(),
+        }
+    }
+
     /// Return a new string from the given argument w/ all Unicode 'Mn' (Combining
     /// Mark) codepoints removed.
     pub fn unaccent(s: &str) -> String {
+        Self::unaccent_with(s, NormalizationForm::default())
+    }
+
+    /// Similar to [`unaccent()`][Self::unaccent()] but the result is normalized
+    /// according to `form`. W/ a compatibility form, compatibility characters
+    /// such as ligatures are also decomposed before removing the accents.
+    pub fn unaccent_with(s: &str, form: NormalizationForm) -> String {
         if s.is_empty() {
             return "".into();
         }
 
-        let result: String = s.nfd().filter(|x| !is_combining_mark(*x)).nfc().collect();
-        result
+        let stripped: String = if form.is_compatibility() {
+            s.nfkd().filter(|x| !is_combining_mark(*x)).collect()
+        } else {
+            s.nfd().filter(|x| !is_combining_mark(*x)).collect()
+        };
+        form.apply(&stripped)
+    }
+
+    // same as `eq` except that accents, if they're to be ignored, are removed
+    // using the given normalization form, and case, if it's to be ignored, is
+    // folded w/ the given case folding...
+    pub(crate) fn eq_with(
+        &self,
+        other: &Self,
+        form: NormalizationForm,
+        folding: CaseFolding,
+    ) -> bool {
+        let to_icase = self.is_icase() || other.is_icase();
+        let to_iaccent = self.is_iaccent() || other.is_iaccent();
+        let unaccent = |x: &str| QString::unaccent_with(x, form);
+        let icase = |x: &str| UniCase::new(folding.prepare(x).into_owned());
+        match (to_icase, to_iaccent) {
+            // NOTE (rsn) 20261016 - Turkic mappings are applied before removing
+            // accents otherwise 'İ' would end up as a dotless 'ı'...
+            (true, true) => {
+                let a = unaccent(&folding.prepare(&self.inner));
+                let b = unaccent(&folding.prepare(&other.inner));
+                UniCase::new(a) == UniCase::new(b)
+            }
+            (true, false) => icase(&self.inner) == icase(&other.inner),
+            (false, true) => unaccent(&self.inner) == unaccent(&other.inner),
+            (false, false) => self.inner == other.inner,
+        }
     }
 
     /// Return a this [`Ignoring`] flags as a byte.
@@ -153,48 +270,79 @@ impl QString {
         self.flags.0 >= 2
     }
 
-    /// Whether `input` matches the LIKE `pattern`.
-    pub(crate) fn like(input: &Self, pattern: &Self) -> bool {
+    /// Whether `input` matches the LIKE `pattern` where `escape` is the
+    /// character that makes the next wildcard (or itself) a literal.
+    ///
+    /// Return an error if `pattern` ends w/ an unescaped `escape` character.
+    pub(crate) fn like(input: &Self, pattern: &Self, escape: char) -> Result<bool, MyError> {
+        Self::like_with(
+            input,
+            pattern,
+            escape,
+            NormalizationForm::default(),
+            CaseFolding::default(),
+        )
+    }
+
+    /// Same as [`like()`][Self::like()] except that accents, if they're to be
+    /// ignored, are removed using the given normalization `form`, and case, if
+    /// it's to be ignored, is folded w/ the given case `folding`.
+    pub(crate) fn like_with(
+        input: &Self,
+        pattern: &Self,
+        escape: char,
+        form: NormalizationForm,
+        folding: CaseFolding,
+    ) -> Result<bool, MyError> {
         const WC: char = '%';
 
         // recursively compare 2 sub-strings, 1 char at a time...
-        fn recursive(input: &[char], pattern: &[char]) -> bool {
+        fn recursive(input: &[char], pattern: &[char], escape: char) -> bool {
             // w/ an empty pattern, only empty input matches...
             if pattern.is_empty() {
                 return input.is_empty();
             }
 
             if input.is_empty() {
-                return pattern.iter().all(|&x| x == '%');
+                return pattern.iter().all(|&x| x == WC);
             }
 
-            if pattern[0] == '\\' && pattern.len() > 1 {
-                let escaped = pattern[1];
-                return recursive(&input[1..], &pattern[2..])
-                    || (input[0] == escaped) && recursive(&input[1..], &pattern[2..]);
+            // NOTE (rsn) 20261016 - a dangling escape is caught when reducing
+            // the pattern so we're guaranteed a following character here...
+            if pattern[0] == escape {
+                return input[0] == pattern[1] && recursive(&input[1..], &pattern[2..], escape);
             }
 
-            if pattern[0] == '%' {
-                return recursive(&input[1..], pattern) || recursive(input, &pattern[1..]);
+            if pattern[0] == WC {
+                return recursive(&input[1..], pattern, escape)
+                    || recursive(input, &pattern[1..], escape);
             }
 
             if pattern[0] == '_' {
-                return recursive(&input[1..], &pattern[1..]);
+                return recursive(&input[1..], &pattern[1..], escape);
             }
 
-            (input[0] == pattern[0]) && recursive(&input[1..], &pattern[1..])
+            (input[0] == pattern[0]) && recursive(&input[1..], &pattern[1..], escape)
         }
 
         // reduce multiple occurences of unescaped wildcards (uwc) to just one.
-        fn reduce_wildcards(pattern: &str) -> Vec<char> {
+        fn reduce_wildcards(pattern: &str, escape: char) -> Result<Vec<char>, MyError> {
             let mut result: Vec<char> = Vec::with_capacity(pattern.len());
             let mut chars = pattern.chars();
             let mut saw_uwc = false;
             while let Some(c) = chars.next() {
-                let state = if c == '\\' {
-                    result.push('\\');
-                    if let Some(n) = chars.next() {
-                        result.push(n);
+                let state = if c == escape {
+                    match chars.next() {
+                        Some(n) => {
+                            result.push(escape);
+                            result.push(n);
+                        }
+                        None => {
+                            return Err(MyError::Runtime(
+                                format!("LIKE pattern '{pattern}' ends w/ escape '{escape}'")
+                                    .into(),
+                            ));
+                        }
                     }
                     false
                 } else if c == WC {
@@ -208,7 +356,7 @@ impl QString {
                 };
                 saw_uwc = state;
             }
-            result
+            Ok(result)
         }
 
         // case-insensitive mode kicks in when either arguments is unicase.
@@ -220,33 +368,41 @@ impl QString {
         let pattern_iaccent = pattern.is_iaccent();
         let iaccent = input_iaccent || pattern_iaccent;
 
-        let folded_input: Vec<char> = match (icase, iaccent) {
-            (true, true) => UniCase::unicode(QString::unaccent(&input.inner))
-                .to_folded_case()
-                .chars()
-                .collect(),
-            (true, false) => UniCase::unicode(input.inner.as_str())
-                .to_folded_case()
-                .chars()
-                .collect(),
-            (false, true) => QString::unaccent(&input.inner).as_str().chars().collect(),
-            (false, false) => input.inner.chars().collect(),
-        };
-
-        let binding1 = UniCase::unicode(QString::unaccent(&pattern.inner)).to_folded_case();
-        let binding2 = UniCase::unicode(&pattern.inner).to_folded_case();
-        let binding3 = QString::unaccent(&pattern.inner);
-        let folded_pattern = match (icase, iaccent) {
-            (true, true) => binding1.as_str(),
-            (true, false) => binding2.as_str(),
-            (false, true) => binding3.as_str(),
-            (false, false) => pattern.inner.as_str(),
-        };
+        // NOTE (rsn) 20261016 - both input and pattern are folded the same
+        // way, and normalized, before matching so that we do not depend on
+        // how each was composed; e.g. `ACCENTI(CASEI(name))` vs. `CASEI(name)`,
+        // or precomposed vs. decomposed accented characters...
+        let folding = icase.then_some(folding);
+        let fold = |x: &str| QString::fold(x, folding, iaccent, form);
+        let folded_input: Vec<char> = fold(&input.inner).chars().collect();
+        let folded_pattern = fold(&pattern.inner);
 
         // replace repeated wildcards w/ one. mind escaped instances.
-        let reduced_pattern = reduce_wildcards(folded_pattern);
+        let reduced_pattern = reduce_wildcards(&folded_pattern, escape)?;
 
-        recursive(&folded_input, &reduced_pattern)
+        Ok(recursive(&folded_input, &reduced_pattern, escape))
+    }
+
+    /// Return `s` case-folded, w/ `folding` if it's not `None`, and/or stripped
+    /// of its accents if `iaccent` is TRUE. The result is always normalized so canonically
+    /// equivalent sequences end up the same; to `form` when accents are
+    /// stripped, or NFC otherwise.
+    pub(crate) fn fold(
+        s: &str,
+        folding: Option<CaseFolding>,
+        iaccent: bool,
+        form: NormalizationForm,
+    ) -> String {
+        // fold case first since it may introduce combining marks; e.g. 'İ'...
+        let folded = match folding {
+            Some(x) => x.apply(s),
+            None => s.to_owned(),
+        };
+        if iaccent {
+            QString::unaccent_with(&folded, form)
+        } else {
+            folded.nfc().collect()
+        }
     }
 
     /// Constructor for an accent-insensitive instance.
@@ -280,6 +436,29 @@ mod tests {
     };
     use tracing::debug;
 
+    #[test]
+    fn test_normalize_with() {
+        let nfc = QString::plain("Chi\u{0219}in\u{0103}u").and_iaccent();
+        let nfd = nfc.normalize_with(NormalizationForm::Nfd);
+        assert_ne!(nfc.as_str(), nfd.as_str());
+        assert!(nfd.is_iaccent());
+        assert_eq!(
+            nfd.normalize_with(NormalizationForm::Nfc).as_str(),
+            nfc.as_str()
+        );
+        // canonical forms keep the ligature; compatibility ones do not...
+        assert_eq!(NormalizationForm::Nfc.apply("\u{FB01}le"), "\u{FB01}le");
+        assert_eq!(NormalizationForm::Nfkc.apply("\u{FB01}le"), "file");
+        assert_eq!(
+            QString::unaccent_with("\u{FB01}l\u{00E9}", NormalizationForm::Nfd),
+            "\u{FB01}le"
+        );
+        assert_eq!(
+            QString::unaccent_with("\u{FB01}l\u{00E9}", NormalizationForm::Nfkc),
+            "file"
+        );
+    }
+
     #[test]
     fn test_display() {
         const S1: &str = "/chișinău/_";
@@ -398,7 +577,7 @@ mod tests {
             let s = starts_with_foo();
             if s.starts_with("Foo") {
                 let input = QString::icase(&s);
-                let result = QString::like(&input, &pattern);
+                let result = QString::like(&input, &pattern, '\\').expect("Failed LIKE");
                 if !result {
                     panic!("Ooops! Was expecting '{s}' to succeed")
                 }
@@ -413,7 +592,7 @@ mod tests {
             let s = starts_with_foo();
             if s.starts_with("Foo") {
                 let input = QString::icase(&s);
-                let result = QString::like(&input, &pattern);
+                let result = QString::like(&input, &pattern, '\\').expect("Failed LIKE");
                 if !result {
                     panic!("Ooops! Was expecting '{s}' to succeed")
                 }
@@ -421,6 +600,64 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_like_escape() {
+        let input = QString::plain("100%");
+        // default escape...
+        let pattern = QString::plain("100\\%");
+        assert!(QString::like(&input, &pattern, '\\').unwrap());
+        let pattern = QString::plain("10\\%");
+        assert!(!QString::like(&input, &pattern, '\\').unwrap());
+        // escaped escape...
+        let pattern = QString::plain("a\\\\%");
+        assert!(QString::like(&QString::plain("a\\bc"), &pattern, '\\').unwrap());
+        // custom escape...
+        let pattern = QString::plain("100!%");
+        assert!(QString::like(&input, &pattern, '!').unwrap());
+        assert!(!QString::like(&QString::plain("1000"), &pattern, '!').unwrap());
+        let pattern = QString::plain("!_%");
+        assert!(QString::like(&QString::plain("_foo"), &pattern, '!').unwrap());
+        assert!(!QString::like(&QString::plain("foo"), &pattern, '!').unwrap());
+        // dangling escape is an error...
+        let pattern = QString::plain("100\\");
+        assert!(QString::like(&input, &pattern, '\\').is_err());
+        let pattern = QString::plain("100!");
+        assert!(QString::like(&input, &pattern, '!').is_err());
+    }
+
+    #[test]
+    fn test_case_folding() {
+        assert_eq!(CaseFolding::Full.apply("Straße"), "strasse");
+        assert_eq!(CaseFolding::Turkic.apply("Straße"), "strasse");
+        assert_eq!(CaseFolding::Full.apply("IİI"), "ii\u{307}i");
+        assert_eq!(CaseFolding::Turkic.apply("IİI"), "ıiı");
+    }
+
+    #[test]
+    fn test_like_folding() {
+        const NFC: &str = "Chi\u{0219}in\u{0103}u";
+        const NFD: &str = "Chis\u{0326}ina\u{0306}u";
+
+        let pattern = QString::plain("cHis%").and_icase().and_iaccent();
+        for s in [NFC, NFD] {
+            // flags set on either side, or both, are honoured...
+            let input = QString::plain(s).and_iaccent().and_icase();
+            assert!(QString::like(&input, &pattern, '\\').unwrap());
+            assert!(QString::like(&QString::plain(s), &pattern, '\\').unwrap());
+            let input = QString::plain(s).and_icase();
+            assert!(QString::like(&input, &QString::plain("cHis%").and_iaccent(), '\\').unwrap());
+        }
+
+        // canonically equivalent strings match w/o any flag...
+        let pattern = QString::plain("Chi\u{0219}%");
+        assert!(QString::like(&QString::plain(NFD), &pattern, '\\').unwrap());
+        // but accents still matter...
+        let pattern = QString::plain("Chis%");
+        assert!(!QString::like(&QString::plain(NFC), &pattern, '\\').unwrap());
+        let pattern = QString::plain("chi\u{0219}%").and_icase();
+        assert!(QString::like(&QString::plain(NFD), &pattern, '\\').unwrap());
+    }
+
     #[test]
     fn test_nfkd() {
         const S: &str = "ἄbc";
@@ -457,7 +694,7 @@ mod tests {
             let ricotta = UniCase::unicode(&cooked).to_folded_case();
             let expected = ricotta.starts_with('a');
             let input = QString::plain(&raw).and_icase().and_iaccent();
-            let actual = QString::like(&input, &pattern);
+            let actual = QString::like(&input, &pattern, '\\').expect("Failed LIKE");
             if actual != expected {
                 debug!("    raw: '{raw}' {}", raw.escape_unicode());
                 debug!("  cotta: '{cooked}' {}", cooked.escape_unicode());
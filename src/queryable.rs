@@ -12,17 +12,17 @@
 use crate::{
     MyError,
     bound::Bound,
-    geom::{G, GTrait},
-    qstring::QString,
+    geom::{G, GTrait, is_geojson},
+    qstring::{CaseFolding, NormalizationForm, QString},
     wkb::PostGisBinary,
 };
 use core::fmt;
 use jiff::{Timestamp, Zoned, civil::Date, tz::TimeZone};
+use serde_json::Value;
 use std::{cmp::Ordering, mem};
-use tracing::error;
 
 /// [Queryable][Q] type variants.
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum DataType {
     /// A Unicode UTF-8 string.
     Str,
@@ -56,6 +56,9 @@ pub enum Q {
     Bool(bool),
     /// A known numeric literal.
     Num(f64),
+    /// A known integer literal; e.g. a 64-bit identifier too large to be
+    /// accurately represented as a `Num`.
+    Int(i64),
     /// Either a known UTF8 character string literal, or one that when used in
     /// comparisons, should be used ignoring its case and/or accent(s).
     Str(QString),
@@ -67,6 +70,9 @@ pub enum Q {
     Interval(Bound, Bound),
     /// A list of other [Queryables][Q].
     List(Vec<Q>),
+    /// A known binary value; e.g. a _BLOB_ attribute, such as a thumbnail or
+    /// a hash, that is not a geometry. Compared byte by byte.
+    Bytes(Vec<u8>),
 }
 
 impl fmt::Debug for Q {
@@ -75,6 +81,7 @@ impl fmt::Debug for Q {
             Self::Null => write!(f, "Null"),
             Self::Bool(arg0) => f.debug_tuple("Bool").field(arg0).finish(),
             Self::Num(arg0) => f.debug_tuple("Num").field(arg0).finish(),
+            Self::Int(arg0) => f.debug_tuple("Int").field(arg0).finish(),
             Self::Str(arg0) => f.debug_tuple("Str").field(arg0).finish(),
             Self::Geom(x) => write!(f, "Geom({})", x.to_wkt()),
             Self::Instant(arg0) => f.debug_tuple("Instant").field(arg0).finish(),
@@ -82,6 +89,7 @@ impl fmt::Debug for Q {
                 f.debug_tuple("Interval").field(arg0).field(arg1).finish()
             }
             Self::List(arg0) => f.debug_tuple("List").field(arg0).finish(),
+            Self::Bytes(x) => write!(f, "Bytes(0x{})", to_hex(x)),
         }
     }
 }
@@ -91,11 +99,21 @@ impl PartialEq for Q {
         match (self, other) {
             (Self::Bool(l0), Self::Bool(r0)) => l0 == r0,
             (Self::Num(l0), Self::Num(r0)) => l0 == r0,
+            (Self::Int(l0), Self::Int(r0)) => l0 == r0,
+            // mixed integer and float comparisons promote to f64...
+            (Self::Int(l0), Self::Num(r0)) | (Self::Num(r0), Self::Int(l0)) => *l0 as f64 == *r0,
             (Self::Str(l0), Self::Str(r0)) => l0 == r0,
-            (Self::Geom(l0), Self::Geom(r0)) => l0 == r0,
+            // NOTE (rsn) 20261016 - geometries are equal when they're so
+            // topologically; e.g. the same polygon w/ its ring starting at a
+            // different vertex. an undefined geometry is only equal to
+            // another undefined one...
+            (Self::Geom(G::Null), Self::Geom(r0)) => matches!(r0, G::Null),
+            (Self::Geom(l0), Self::Geom(G::Null)) => matches!(l0, G::Null),
+            (Self::Geom(l0), Self::Geom(r0)) => l0 == r0 || l0.equals(r0).unwrap_or(false),
             (Self::Instant(l0), Self::Instant(r0)) => l0 == r0,
             (Self::Interval(l0, l1), Self::Interval(r0, r1)) => l0 == r0 && l1 == r1,
             (Self::List(l0), Self::List(r0)) => l0 == r0,
+            (Self::Bytes(l0), Self::Bytes(r0)) => l0 == r0,
             _ => mem::discriminant(self) == mem::discriminant(other),
         }
     }
@@ -109,6 +127,9 @@ impl PartialOrd for Q {
             (Q::Null, Q::Null) => Some(Ordering::Equal),
             (Q::Bool(a), Q::Bool(b)) => a.partial_cmp(b),
             (Q::Num(a), Q::Num(b)) => a.partial_cmp(b),
+            (Q::Int(a), Q::Int(b)) => a.partial_cmp(b),
+            (Q::Int(a), Q::Num(b)) => (*a as f64).partial_cmp(b),
+            (Q::Num(a), Q::Int(b)) => a.partial_cmp(&(*b as f64)),
             (Q::Str(a), Q::Str(b)) => a.partial_cmp(b),
             (Q::Instant(a), Q::Instant(b)) => a.partial_cmp(b),
             (Q::Interval(a0, a1), Q::Interval(b0, b1)) => match a0.partial_cmp(b0) {
@@ -121,6 +142,7 @@ impl PartialOrd for Q {
                 x => x,
             },
             (Q::List(a), Q::List(b)) => a.partial_cmp(b),
+            (Q::Bytes(a), Q::Bytes(b)) => a.partial_cmp(b),
             // anything else, incl. geometries are incomparable...
             _ => None,
         }
@@ -133,6 +155,7 @@ impl fmt::Display for Q {
             Q::Null => write!(f, "Null"),
             Q::Bool(x) => write!(f, "{x}"),
             Q::Num(x) => write!(f, "{x}"),
+            Q::Int(x) => write!(f, "{x}"),
             Q::Str(x) => write!(f, "{x}"),
             Q::Geom(x) => write!(f, "{}", x.to_wkt()),
             Q::Instant(x) => write!(f, "{x}"),
@@ -141,6 +164,7 @@ impl fmt::Display for Q {
             Q::Interval(x, Bound::None) => write!(f, "[{x}..]"),
             Q::Interval(x, y) => write!(f, "[{x}..{y}]"),
             Q::List(x) => write!(f, "{x:?}"),
+            Q::Bytes(x) => write!(f, "0x{}", to_hex(x)),
         }
     }
 }
@@ -205,6 +229,32 @@ impl Q {
         Ok(Q::Geom(g))
     }
 
+    /// Try creating a new instance from a JSON value.
+    ///
+    /// Numbers, strings, booleans and `null` map to their obvious variants,
+    /// arrays to lists, and objects shaped like a GeoJSON geometry to
+    /// geometries. Any other object is rejected.
+    pub fn try_from_json(value: &Value) -> Result<Self, MyError> {
+        match value {
+            Value::Null => Ok(Q::Null),
+            Value::Bool(x) => Ok(Q::Bool(*x)),
+            Value::Number(x) => x
+                .as_i64()
+                .map(Q::Int)
+                .or_else(|| x.as_f64().map(Q::Num))
+                .ok_or_else(|| MyError::Runtime(format!("{x} is not a valid f64").into())),
+            Value::String(x) => Ok(Q::Str(QString::plain(x))),
+            Value::Array(x) => {
+                let items: Result<Vec<Q>, MyError> = x.iter().map(Q::try_from_json).collect();
+                Ok(Q::List(items?))
+            }
+            Value::Object(_) if is_geojson(value) => Ok(Q::Geom(G::try_from(value)?)),
+            Value::Object(_) => Err(MyError::Runtime(
+                "Nested JSON objects are not supported".into(),
+            )),
+        }
+    }
+
     /// Try creating a new instance from a _GeoPackage Well Known Binary_
     /// encoded geometry.
     pub fn try_from_wkb(value: &[u8]) -> Result<Self, MyError> {
@@ -212,6 +262,22 @@ impl Q {
         Ok(Q::Geom(g))
     }
 
+    /// Try creating a new instance from the value of a _GeoPackage_ BLOB
+    /// column. A value starting w/ the `GP` magic bytes of a _GeoPackage
+    /// Binary_ header is decoded as a geometry, like [`Q::try_from_wkb()`]
+    /// does, and it's an error if that fails. Any other value becomes
+    /// [`Q::Bytes`].
+    ///
+    /// Use `Q::Bytes` directly for columns known to never hold geometries;
+    /// e.g. hashes that may start w/ those same 2 bytes.
+    pub fn try_from_blob(value: &[u8]) -> Result<Self, MyError> {
+        if value.starts_with(b"GP") {
+            Q::try_from_wkb(value)
+        } else {
+            Ok(Q::Bytes(value.to_vec()))
+        }
+    }
+
     /// Try creating a new instance from a _PostGIS Extended Well Known Binary_
     /// encoded geometry.
     pub fn try_from_ewkb(value: &[u8]) -> Result<Self, MyError> {
@@ -246,14 +312,26 @@ impl Q {
         }
     }
 
-    /// Return the current value of this if it's a number value.
+    /// Return the current value of this if it's a number value. Integers are
+    /// promoted to `f64`.
     pub fn to_num(&self) -> Result<f64, MyError> {
         match self {
             Q::Num(x) => Ok(*x),
+            Q::Int(x) => Ok(*x as f64),
             _ => Err(MyError::Runtime(format!("{self} is not a number").into())),
         }
     }
 
+    /// Return the current value of this if it's an integer value, or a number
+    /// w/o a fractional part that fits in an `i64`.
+    pub fn to_int(&self) -> Result<i64, MyError> {
+        match self {
+            Q::Int(x) => Ok(*x),
+            Q::Num(x) if x.fract() == 0.0 && x.abs() < i64::MAX as f64 => Ok(*x as i64),
+            _ => Err(MyError::Runtime(format!("{self} is not an integer").into())),
+        }
+    }
+
     /// Return the current value of this if it's a [Geometry][G] value.
     pub fn to_geom(&self) -> Result<G, MyError> {
         match self {
@@ -283,6 +361,16 @@ impl Q {
         }
     }
 
+    /// Return the current value of this if it's a binary value.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, MyError> {
+        match self {
+            Q::Bytes(x) => Ok(x.to_owned()),
+            _ => Err(MyError::Runtime(
+                format!("{self} is not a binary value").into(),
+            )),
+        }
+    }
+
     /// Return the current value of this if it's a collection.
     pub fn to_list(&self) -> Result<Vec<Q>, MyError> {
         match self {
@@ -291,16 +379,97 @@ impl Q {
         }
     }
 
+    /// Return a JSON value representing this suitable for use as a GeoJSON
+    /// _Feature_ geometry, or property, value.
+    ///
+    /// Geometries become [RFC 7946 geometry objects][G::to_geojson()], dates
+    /// `YYYY-MM-DD` strings, timestamps RFC 3339 strings in UTC, unbounded
+    /// instants `".."`, intervals 2-element arrays, lists arrays, and binary
+    /// values lowercase hexadecimal strings. Non finite numbers, like `Null`,
+    /// become JSON `null`.
+    pub fn to_geojson(&self) -> Value {
+        match self {
+            Q::Null => Value::Null,
+            Q::Bool(x) => Value::Bool(*x),
+            Q::Num(x) => serde_json::Number::from_f64(*x).map_or(Value::Null, Value::Number),
+            Q::Int(x) => Value::from(*x),
+            Q::Str(x) => Value::String(x.as_str().to_owned()),
+            Q::Geom(x) => x.to_geojson(),
+            Q::Instant(x) => bound_to_json(x),
+            Q::Interval(x, y) => Value::Array(vec![bound_to_json(x), bound_to_json(y)]),
+            Q::List(x) => Value::Array(x.iter().map(Q::to_geojson).collect()),
+            Q::Bytes(x) => Value::String(to_hex(x)),
+        }
+    }
+
+    /// Return a canonical string form of this suitable for use as the key of a
+    /// `HashSet` or a `HashMap`; e.g. to deduplicate _Resources_ streamed from
+    /// a data source by their `fid`, or to index them by a property value.
+    ///
+    /// `Q` does not implement `Hash` b/c its equality is not structural; e.g.
+    /// geometries are equal when they're topologically so. Keys are instead
+    /// made so that equal values share one in all but the edge cases below.
+    ///
+    /// Numbers are canonicalized as follows:
+    /// * integers, and floats w/ no fractional part exactly representable as
+    ///   an `i64`, are keyed by their decimal value; e.g. `Int(1)` and
+    ///   `Num(1.0)` share the same key. Note that an integer beyond ±2<sup>53</sup>
+    ///   may be equal to a float w/o sharing its key.
+    /// * `-0.0` and `0.0` share the key of `0`, and all NaNs share a single
+    ///   key even though NaN is not equal to itself.
+    /// * any other float, incl. infinities, is keyed by the bit pattern of its
+    ///   IEEE 754 binary representation.
+    ///
+    /// Geometries are keyed by their SRID and the WKT of their GEOS normalized
+    /// form; i.e. regardless of the order of their rings, members or starting
+    /// vertices. Strings are keyed by their value after removing accents and
+    /// folding case if they're to be ignored, instants by their date or UTC
+    /// timestamp, and binary values by their hexadecimal form.
+    pub fn hash_key(&self) -> String {
+        match self {
+            Q::Null => "Z".into(),
+            Q::Bool(x) => format!("B:{x}"),
+            Q::Num(x) => num_key(*x),
+            Q::Int(x) => format!("N:{x}"),
+            Q::Str(x) => {
+                let mut s = x.as_str().to_owned();
+                if x.is_iaccent() {
+                    s = QString::unaccent_with(&s, NormalizationForm::default());
+                }
+                if x.is_icase() {
+                    s = CaseFolding::default().apply(&s);
+                }
+                format!("S:{s:?}")
+            }
+            Q::Geom(G::Null) => "G:".into(),
+            Q::Geom(x) => {
+                let wkt = x.to_normalized_wkt().unwrap_or_else(|_| x.to_wkt());
+                format!("G:{}:{wkt}", x.srid().into_inner())
+            }
+            Q::Instant(x) => format!("T:{}", bound_key(x)),
+            Q::Interval(x, y) => format!("I:{}/{}", bound_key(x), bound_key(y)),
+            Q::List(x) => {
+                let keys: Vec<_> = x.iter().map(Q::hash_key).collect();
+                format!("L:[{}]", keys.join(", "))
+            }
+            Q::Bytes(x) => format!("X:{}", to_hex(x)),
+        }
+    }
+
     /// Return TRUE if both arguments are of the same type; FALSE otherwise.
+    /// Integers and floating point numbers are considered of the same type.
     pub(crate) fn same_type(this: &Self, that: &Self) -> bool {
-        mem::discriminant(this) == mem::discriminant(that)
+        match (this, that) {
+            (Q::Num(_) | Q::Int(_), Q::Num(_) | Q::Int(_)) => true,
+            _ => mem::discriminant(this) == mem::discriminant(that),
+        }
     }
 
     // Return the optional literal data type of this.
     pub(crate) fn literal_type(&self) -> Option<DataType> {
         match self {
             Q::Bool(_) => Some(DataType::Bool),
-            Q::Num(_) => Some(DataType::Num),
+            Q::Num(_) | Q::Int(_) => Some(DataType::Num),
             Q::Str(_) => Some(DataType::Str),
             Q::Geom(_) => Some(DataType::Geom),
             Q::Instant(x) => match x {
@@ -313,50 +482,85 @@ impl Q {
         }
     }
 
-    pub(crate) fn contained_by(&self, list: Vec<Self>) -> Result<bool, MyError> {
-        if list.is_empty() {
-            return Ok(false);
+    /// Try converting this to a [Queryable][Q] of the given `target` type.
+    ///
+    /// Besides the identity, the supported conversions are:
+    /// * string to number, boolean, date, timestamp, and geometry (from WKT),
+    /// * number and boolean to string, and
+    /// * date to timestamp (start of that day).
+    ///
+    /// `Null` is always cast to `Null`. Return an error otherwise.
+    pub fn cast(&self, target: DataType) -> Result<Q, MyError> {
+        if self.is_null() || self.literal_type() == Some(target) {
+            return Ok(self.to_owned());
         }
 
-        if let Some(z_type) = self.literal_type() {
-            if matches!(z_type, DataType::Bool) {
-                let lhs = self.to_bool()?;
-                let rhs: Result<Vec<bool>, MyError> = list.iter().map(|e| e.to_bool()).collect();
-                let rhs = rhs?;
-                Ok(rhs.contains(&lhs))
-            } else if matches!(z_type, DataType::Num) {
-                let lhs = self.to_num()?;
-                let rhs: Result<Vec<f64>, MyError> = list.iter().map(|e| e.to_num()).collect();
-                let rhs = rhs?;
-                Ok(rhs.contains(&lhs))
-            } else if matches!(z_type, DataType::Str) {
-                let lhs = &self.to_str()?;
-                let rhs: Result<Vec<QString>, MyError> = list.iter().map(|e| e.to_str()).collect();
-                let rhs = rhs?;
-                Ok(rhs.contains(lhs))
-            } else if matches!(z_type, DataType::Date) || matches!(z_type, DataType::Timestamp) {
-                let lhs = self.to_bound()?.as_zoned().unwrap();
-                let rhs: Result<Vec<Zoned>, MyError> = list
-                    .iter()
-                    .map(|e| e.to_bound().and_then(|b| b.to_zoned()))
-                    .collect();
-                let rhs = rhs?;
-                Ok(rhs.contains(&lhs))
-            } else if matches!(z_type, DataType::Geom) {
-                let lhs = self.to_geom()?;
-                let rhs: Result<Vec<G>, MyError> = list.iter().map(|e| e.to_geom()).collect();
-                let rhs = rhs?;
-                Ok(rhs.contains(&lhs))
-            } else {
-                error!("Failed. self = {self:?}; list = {list:?}");
-                Ok(false)
+        match (self, target) {
+            (Q::Str(x), DataType::Num) => {
+                let s = x.inner().trim();
+                if let Ok(n) = s.parse::<i64>() {
+                    Ok(Q::Int(n))
+                } else if let Ok(n) = s.parse::<f64>() {
+                    Ok(Q::Num(n))
+                } else {
+                    Err(MyError::Runtime(
+                        format!("Cannot cast '{}' to a number", x.inner()).into(),
+                    ))
+                }
             }
-        } else {
-            Ok(false)
+            (Q::Str(x), DataType::Bool) => match x.inner().trim() {
+                b if b.eq_ignore_ascii_case("true") => Ok(Q::Bool(true)),
+                b if b.eq_ignore_ascii_case("false") => Ok(Q::Bool(false)),
+                _ => Err(MyError::Runtime(
+                    format!("Cannot cast '{}' to a boolean", x.inner()).into(),
+                )),
+            },
+            (Q::Str(x), DataType::Timestamp) => Q::try_from_timestamp_str(x.inner().trim()),
+            (Q::Str(x), DataType::Date) => Q::try_from_date_str(x.inner().trim()),
+            (Q::Str(x), DataType::Geom) => Q::try_from_wkt(x.inner()),
+            (Q::Num(x), DataType::Str) => Ok(Q::new_plain_str(&x.to_string())),
+            (Q::Int(x), DataType::Str) => Ok(Q::new_plain_str(&x.to_string())),
+            (Q::Bool(x), DataType::Str) => Ok(Q::new_plain_str(&x.to_string())),
+            (Q::Instant(Bound::Date(z)), DataType::Timestamp) => {
+                Ok(Q::Instant(Bound::Timestamp(z.to_owned())))
+            }
+            _ => Err(MyError::Runtime(
+                format!("Cannot cast {self:?} to {target:?}").into(),
+            )),
+        }
+    }
+
+    /// When exactly one of the arguments is a string and the other is a
+    /// literal of a different type, cast the string to that type. Otherwise
+    /// return both arguments unchanged.
+    pub(crate) fn coerce(a: Q, b: Q) -> Result<(Q, Q), MyError> {
+        if Q::same_type(&a, &b) {
+            return Ok((a, b));
+        }
+
+        match (&a, &b) {
+            (Q::Str(_), _) => match b.literal_type() {
+                Some(t) => Ok((a.cast(t)?, b)),
+                None => Ok((a, b)),
+            },
+            (_, Q::Str(_)) => match a.literal_type() {
+                Some(t) => {
+                    let b = b.cast(t)?;
+                    Ok((a, b))
+                }
+                None => Ok((a, b)),
+            },
+            _ => Ok((a, b)),
         }
     }
 }
 
+impl From<Vec<u8>> for Q {
+    fn from(value: Vec<u8>) -> Self {
+        Q::Bytes(value)
+    }
+}
+
 impl From<bool> for Q {
     fn from(value: bool) -> Self {
         Q::Bool(value)
@@ -456,24 +660,51 @@ impl TryToF64<isize> for isize {
     }
 }
 
-// generate TryFrom<x> implementetation to Q...
+// generate TryFrom<x> implementetation to Q for integer types that always
+// fit in an i64...
+macro_rules! impl_from_small_int {
+    ($($t:ty),*) => {
+        $(
+            impl TryFrom<$t> for $crate::Q {
+                type Error = MyError;
+                fn try_from(value: $t) -> Result<Self, $crate::MyError> {
+                    Ok(Q::Int(i64::from(value)))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_small_int!(u8, u16, u32, i8, i16, i32);
+
+impl TryFrom<i64> for Q {
+    type Error = MyError;
+
+    fn try_from(value: i64) -> Result<Self, Self::Error> {
+        Ok(Q::Int(value))
+    }
+}
+
+// NOTE (rsn) 20261016 - wider integers are kept exact when they fit in an i64
+// and only fall back to an f64 --and the precision checks that go w/ it--
+// otherwise...
 macro_rules! impl_try_from_int {
     ($($t:ty),*) => {
         $(
             impl TryFrom<$t> for $crate::Q {
                 type Error = MyError;
                 fn try_from(value: $t) -> Result<Self, $crate::MyError> {
-                    let x = value.try_to_f64()?;
-                    Ok(Q::Num(x))
+                    match i64::try_from(value) {
+                        Ok(x) => Ok(Q::Int(x)),
+                        Err(_) => Ok(Q::Num(value.try_to_f64()?)),
+                    }
                 }
             }
         )*
     };
 }
 
-impl_try_from_int!(
-    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
-);
+impl_try_from_int!(u64, u128, usize, i128, isize);
 
 impl From<f64> for Q {
     fn from(value: f64) -> Self {
@@ -503,10 +734,199 @@ impl From<Bound> for Q {
     }
 }
 
+// Render given bytes as a lowercase hexadecimal string w/o a prefix...
+pub(crate) fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+// see `Q::hash_key()` for how numbers are canonicalized...
+fn num_key(x: f64) -> String {
+    const I64_LIMIT: f64 = 9_223_372_036_854_775_808.0; // 2^63
+    if x.is_nan() {
+        "N:NaN".into()
+    } else if x.fract() == 0.0 && (-I64_LIMIT..I64_LIMIT).contains(&x) {
+        // also maps -0.0 to 0...
+        format!("N:{}", x as i64)
+    } else {
+        format!("F:{:016x}", x.to_bits())
+    }
+}
+
+fn bound_key(value: &Bound) -> String {
+    match value {
+        Bound::None => "..".into(),
+        Bound::Date(x) => x.date().to_string(),
+        Bound::Timestamp(x) => x.timestamp().to_string(),
+    }
+}
+
+fn bound_to_json(value: &Bound) -> Value {
+    match value {
+        Bound::None => Value::String("..".into()),
+        Bound::Date(x) => Value::String(x.date().to_string()),
+        Bound::Timestamp(x) => Value::String(x.timestamp().to_string()),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use rand::RngExt;
+    use serde_json::json;
+    use std::{collections::HashSet, error::Error};
+
+    #[test]
+    fn test_hash_key() -> Result<(), Box<dyn Error>> {
+        #[rustfmt::skip]
+        let same = [
+            (Q::Int(1),                       Q::Num(1.0)),
+            (Q::Num(-0.0),                    Q::Int(0)),
+            (Q::Num(f64::NAN),                Q::Num(-f64::NAN)),
+            (Q::Num(0.1 + 0.2),               Q::Num(0.30000000000000004)),
+            (Q::new_plain_str("Paris"),       Q::new_plain_str("Paris")),
+            (Q::new_plain_str("paris"),       Q::Str(QString::plain("PARIS").and_icase())),
+            (Q::try_from_wkt("POLYGON ((0 0, 1 0, 1 1, 0 0))")?,
+                                              Q::try_from_wkt("POLYGON ((1 0, 1 1, 0 0, 1 0))")?),
+            (Q::try_from_date_str("2024-01-31")?,
+                                              Q::try_from_date_str("2024-01-31")?),
+            (Q::List(vec![Q::Int(1), Q::new_plain_str("a")]),
+                                              Q::List(vec![Q::Num(1.0), Q::new_plain_str("a")])),
+            (Q::Bytes(vec![0xca, 0xfe]),      Q::Bytes(vec![0xca, 0xfe])),
+        ];
+        for (a, b) in same {
+            assert_eq!(a.hash_key(), b.hash_key(), "{a:?} vs {b:?}");
+        }
+
+        #[rustfmt::skip]
+        let different = [
+            (Q::Int(1),                       Q::Num(1.5)),
+            (Q::Num(f64::INFINITY),           Q::Num(f64::NEG_INFINITY)),
+            (Q::Int(1),                       Q::new_plain_str("1")),
+            (Q::new_plain_str("Paris"),       Q::new_plain_str("paris")),
+            (Q::Bool(true),                   Q::new_plain_str("true")),
+            (Q::Null,                         Q::Bool(false)),
+            (Q::try_from_wkt("POINT (1 2)")?, Q::try_from_wkt("POINT (2 1)")?),
+            (Q::Bytes(vec![0x01]),            Q::Int(1)),
+        ];
+        for (a, b) in different {
+            assert_ne!(a.hash_key(), b.hash_key(), "{a:?} vs {b:?}");
+        }
+
+        // deduplicate by fid...
+        let fids = [Q::Int(3), Q::Num(1.0), Q::Int(3), Q::Int(1), Q::Int(2)];
+        let unique: HashSet<_> = fids.iter().map(Q::hash_key).collect();
+        assert_eq!(unique.len(), 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bytes() -> Result<(), Box<dyn Error>> {
+        #[rustfmt::skip]
+        const POINT: [u8; 29] = [
+            0x47, 0x50, 0x00, 0x01, 0xe6, 0x10, 0x00, 0x00,
+            0x01, 0x01, 0x00, 0x00, 0x00,
+            0x54, 0xe5, 0x7b, 0x46, 0x22, 0xe8, 0x28, 0x40,
+            0x8b, 0x07, 0x4a, 0xc0, 0x9e, 0xf3, 0x44, 0x40,
+        ];
+
+        let x = Q::from(vec![0x00, 0xff]);
+        assert_eq!(x, Q::Bytes(vec![0x00, 0xff]));
+        assert_ne!(x, Q::Bytes(vec![0x00]));
+        assert!(x > Q::Bytes(vec![0x00]));
+        assert!(x < Q::Bytes(vec![0x01]));
+        assert_eq!(x.partial_cmp(&Q::new_plain_str("00ff")), None);
+        assert_eq!(x.to_string(), "0x00ff");
+        assert_eq!(x.to_geojson(), json!("00ff"));
+        assert_eq!(x.to_bytes()?, vec![0x00, 0xff]);
+        assert!(Q::Num(1.0).to_bytes().is_err());
+
+        // geometries are told apart by their magic...
+        assert_eq!(Q::try_from_blob(&[0xca, 0xfe])?, Q::Bytes(vec![0xca, 0xfe]));
+        assert_eq!(Q::try_from_blob(&[])?, Q::Bytes(vec![]));
+        let g = Q::try_from_blob(&POINT)?;
+        assert_eq!(g.to_geom()?.type_(), "Point");
+        assert!(Q::try_from_blob(b"GP-not-a-geometry").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_geojson() -> Result<(), Box<dyn Error>> {
+        let interval = Q::Interval(Bound::try_new_date("2020-01-01")?, Bound::None);
+        let list = Q::List(vec![
+            Q::Num(1.5),
+            Q::Bool(true),
+            Q::new_plain_str("x"),
+            Q::try_from_timestamp_str("2020-01-01T10:00:00+02:00")?,
+            interval,
+            Q::Num(f64::NAN),
+        ]);
+        let expected = json!([
+            1.5,
+            true,
+            "x",
+            "2020-01-01T08:00:00Z",
+            ["2020-01-01", ".."],
+            null
+        ]);
+        assert_eq!(list.to_geojson(), expected);
+
+        let pt = Q::try_from_wkt("POINT Z (1 2 3)")?;
+        let expected = json!({ "type": "Point", "coordinates": [1.0, 2.0, 3.0] });
+        assert_eq!(pt.to_geojson(), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_cast() -> Result<(), Box<dyn Error>> {
+        let s = Q::new_plain_str(" 1000 ");
+        assert_eq!(s.cast(DataType::Num)?.to_num()?, 1000.0);
+        assert_eq!(
+            Q::Num(1000.0).cast(DataType::Str)?.to_str()?.inner(),
+            "1000"
+        );
+        assert_eq!(Q::Num(2.5).cast(DataType::Str)?.to_str()?.inner(), "2.5");
+        assert!(Q::new_plain_str("TRUE").cast(DataType::Bool)?.to_bool()?);
+
+        let ts = Q::new_plain_str("2026-10-16T12:34:56Z").cast(DataType::Timestamp)?;
+        assert_eq!(ts.literal_type(), Some(DataType::Timestamp));
+        let d = Q::new_plain_str("2026-10-16").cast(DataType::Date)?;
+        assert_eq!(d.literal_type(), Some(DataType::Date));
+        let ts = d.cast(DataType::Timestamp)?;
+        assert_eq!(ts.literal_type(), Some(DataType::Timestamp));
+
+        // identity and null...
+        assert_eq!(Q::Num(1.0).cast(DataType::Num)?.to_num()?, 1.0);
+        assert!(Q::Null.cast(DataType::Num)?.is_null());
+
+        // failures...
+        assert!(Q::new_plain_str("abc").cast(DataType::Num).is_err());
+        assert!(Q::new_plain_str("abc").cast(DataType::Timestamp).is_err());
+        assert!(Q::Num(1.0).cast(DataType::Timestamp).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_coerce() -> Result<(), Box<dyn Error>> {
+        let (a, b) = Q::coerce(Q::Num(42.0), Q::new_plain_str("1000"))?;
+        assert_eq!(a.to_num()?, 42.0);
+        assert_eq!(b.to_num()?, 1000.0);
+
+        let (a, b) = Q::coerce(Q::new_plain_str("1000"), Q::Num(42.0))?;
+        assert_eq!(a.to_num()?, 1000.0);
+        assert_eq!(b.to_num()?, 42.0);
+
+        // same types are left alone...
+        let (a, _) = Q::coerce(Q::new_plain_str("1"), Q::new_plain_str("2"))?;
+        assert!(a.to_str().is_ok());
+
+        assert!(Q::coerce(Q::Num(1.0), Q::new_plain_str("x")).is_err());
+
+        Ok(())
+    }
 
     #[test]
     fn test_usize_max() {
@@ -645,7 +1065,7 @@ mod tests {
                 expected += 1;
             }
             // else as f64 is ok...
-            match Q::try_from(x) {
+            match x.try_to_f64() {
                 Ok(_) => (), // cool
                 Err(MyError::PrecisionLoss(_)) => actual += 1,
                 Err(x) => panic!("Unexpected {x}"),
@@ -675,7 +1095,7 @@ mod tests {
                 expected += 1;
             }
             // else as f64 is ok...
-            match Q::try_from(x) {
+            match x.try_to_f64() {
                 Ok(_) => (), // cool
                 Err(MyError::PrecisionLoss(_)) => actual += 1,
                 Err(x) => panic!("Unexpected {x}"),
@@ -685,84 +1105,127 @@ mod tests {
         assert_eq!(expected, actual)
     }
 
+    #[test]
+    fn test_int_exact() -> Result<(), Box<dyn Error>> {
+        // 2^53 + 1 cannot be represented accurately as an f64...
+        let big: i64 = (1 << 53) + 1;
+        let q = Q::try_from(big)?;
+        assert!(matches!(q, Q::Int(x) if x == big));
+        assert_ne!(q, Q::try_from(big - 1)?);
+        assert_eq!(q.to_int()?, big);
+
+        // wider integers that fit in an i64 are kept exact; others aren't...
+        assert!(matches!(Q::try_from(u64::MAX >> 1)?, Q::Int(_)));
+        assert!(Q::try_from(u64::MAX).is_err());
+
+        // mixed comparisons promote to f64...
+        assert_eq!(Q::Int(42), Q::Num(42.0));
+        assert_eq!(Q::Num(42.0), Q::Int(42));
+        assert!(Q::Int(42) < Q::Num(42.5));
+        assert!(Q::Num(-1.5) < Q::Int(-1));
+        assert!(Q::same_type(&Q::Int(1), &Q::Num(1.0)));
+
+        // JSON and string numbers w/o a fraction become integers...
+        let q = Q::try_from_json(&json!(9007199254740993_i64))?;
+        assert!(matches!(q, Q::Int(9007199254740993)));
+        assert!(matches!(Q::try_from_json(&json!(1.5))?, Q::Num(_)));
+        let q = Q::new_plain_str("9007199254740993").cast(DataType::Num)?;
+        assert!(matches!(q, Q::Int(9007199254740993)));
+        assert_eq!(Q::Int(-7).cast(DataType::Str)?.to_str()?.inner(), "-7");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_null_geom_eq() -> Result<(), Box<dyn Error>> {
+        let null = Q::Geom(G::Null);
+        let pt = Q::try_from_wkt("POINT(0 0)")?;
+        assert_ne!(null, pt);
+        assert_ne!(pt, null);
+        assert_eq!(null, Q::Geom(G::Null));
+        assert_eq!(Q::List(vec![pt.clone()]), Q::List(vec![pt]));
+
+        Ok(())
+    }
+
     #[test]
     fn test_like() {
         // plain input and pattern.  no wildcards...
         let input = QString::plain("hello");
         let pattern = QString::plain("h%o");
-        let r1 = QString::like(&input, &pattern);
+        let r1 = QString::like(&input, &pattern, '\\').expect("Failed LIKE");
         assert!(r1);
 
         // case-insensitive input, plain pattern.  multi wildcard...
         let input = QString::icase("HELLO");
         let pattern = QString::plain("h%o");
-        let r2 = QString::like(&input, &pattern);
+        let r2 = QString::like(&input, &pattern, '\\').expect("Failed LIKE");
         assert!(r2);
         let input = QString::icase("HELLODOLLY");
         let pattern = QString::plain("h%odo%y");
-        let r2p = QString::like(&input, &pattern);
+        let r2p = QString::like(&input, &pattern, '\\').expect("Failed LIKE");
         assert!(r2p);
 
         // plain input, case-insensitive pattern.  single wildcard...
         let input = QString::plain("hello");
         let pattern = QString::icase("h__lo");
-        let r3 = QString::like(&input, &pattern);
+        let r3 = QString::like(&input, &pattern, '\\').expect("Failed LIKE");
         assert!(r3);
         // multi wildcard...
         let pattern = QString::icase("h%lo");
-        let r3p = QString::like(&input, &pattern);
+        let r3p = QString::like(&input, &pattern, '\\').expect("Failed LIKE");
         assert!(r3p);
 
         // plain input and pattern.  escaped multi wildcard...
         let input = QString::plain("hello");
         let pattern = QString::plain("h\\%o");
-        let r4 = QString::like(&input, &pattern);
+        let r4 = QString::like(&input, &pattern, '\\').expect("Failed LIKE");
         assert!(!r4);
 
         let input = QString::plain("h%llo");
         let pattern = QString::plain("h\\%llo");
-        let r5 = QString::like(&input, &pattern);
+        let r5 = QString::like(&input, &pattern, '\\').expect("Failed LIKE");
         assert!(r5);
 
         // empty input and multi wildcard pattern should match
         let input = QString::plain("");
         let pattern = QString::plain("%");
-        let r6 = QString::like(&input, &pattern);
+        let r6 = QString::like(&input, &pattern, '\\').expect("Failed LIKE");
         assert!(r6);
 
         // non-empty input and empty pattern should fail
         let input = QString::plain("abc");
         let pattern = QString::plain("");
-        let r7 = QString::like(&input, &pattern);
+        let r7 = QString::like(&input, &pattern, '\\').expect("Failed LIKE");
         assert!(!r7);
 
         // w/ unicode... case-insensitive input and no wildcards...
 
         let input = QString::icase("ß"); // small sharp s
         let pattern = QString::icase("ẞ"); // capital sharp s
-        let u1 = QString::like(&input, &pattern);
+        let u1 = QString::like(&input, &pattern, '\\').expect("Failed LIKE");
         assert!(u1);
 
         let input = QString::icase("Σ");
         let pattern = QString::plain("σ");
-        let u2 = QString::like(&input, &pattern);
+        let u2 = QString::like(&input, &pattern, '\\').expect("Failed LIKE");
         assert!(u2);
 
         // unicase bug?  Turkish dotted i
         // let input = QString::plain("İ"); // capital dotted I
         // let pattern = QString::icase("i"); // small dotted i
-        // let u3 = QString::like(&input, &pattern);
+        // let u3 = QString::like(&input, &pattern, '\\').expect("Failed LIKE");
         // assert!(u3);
 
         // w/ unicode + wildcard...
 
         let input = QString::plain("こんにちは");
         let pattern = QString::plain("こ%は");
-        let u4 = QString::like(&input, &pattern);
+        let u4 = QString::like(&input, &pattern, '\\').expect("Failed LIKE");
         assert!(u4);
 
         let pattern = QString::icase("こ_にちは");
-        let u5 = QString::like(&input, &pattern);
+        let u5 = QString::like(&input, &pattern, '\\').expect("Failed LIKE");
         assert!(u5);
     }
 }
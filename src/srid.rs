@@ -13,6 +13,10 @@ use std::num::NonZero;
 /// The constant representing the ubiquitous `EPSG:4326` or `WGS'84` SRID.
 pub const EPSG_4326: SRID = SRID(4326);
 
+/// The constant representing the `EPSG:3857` or _Web Mercator_ SRID used by
+/// slippy-map tiles.
+pub const EPSG_3857: SRID = SRID(3857);
+
 /// Representation of a Spatial Reference IDentifier. For now the Authority
 /// is implied to be EPSG.
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
@@ -37,7 +37,17 @@ peg::parser! {
         {? if input.eq_ignore_ascii_case(literal) { Ok(()) } else { Err(literal) } }
 
         // ===== whitespace ===================================================
-        rule _ = quiet! { [
+        // NOTE (rsn) 20261016 - SQL-style comments are skipped wherever white
+        // space is allowed. block comments do not nest, and an unterminated
+        // one is reported at its opener...
+        rule _ = quiet! { (ws() / line_comment() / block_comment())* }
+            (!"/*" / expected!("a terminated block comment (/* ... */)"))
+
+        rule line_comment() = "--" (!['\n' | '\r'] [_])*
+
+        rule block_comment() = "/*" (!"*/" [_])* "*/"
+
+        rule ws() = [
             '\t'
             | '\u{0009}'      // Character tabulation
             | '\n'
@@ -66,33 +76,22 @@ peg::parser! {
             | '\u{202F}'   // Narrow no-break space
             | '\u{205F}'   // Medium mathematical space
             | '\u{3000}'   // Ideographic space
-        ]* }
+        ]
 
         // ignore whitespaces and EOL + EOF...
         pub rule expression() -> E = _ x:boolean_expression() _ ![_] { x }
 
+        // NOTE (rsn) 20261016 - `AND` / `OR` chains are matched iteratively,
+        // w/ `AND` binding tighter than `OR`, and built as balanced trees so
+        // neither parsing nor evaluating a long flat chain nests deeper than
+        // its brackets...
         #[cache_left_rec]
         rule boolean_expression() -> E
-        = x:boolean_term() _ y:or_term()* {
-            match y.len() {
-                0 => x,
-                1 => E::Dyadic(Op::Or, Box::new(x), Box::new(y[0].clone())),
-                _ => E::Dyadic(Op::Or, Box::new(x), Box::new(E::Array(y))),
-            }
-        }
-
-        rule or_term() -> E = i("OR") _ x:boolean_expression()  { x }
+        = x:boolean_term() y:(_ i("OR") _ z:boolean_term() { z })* { E::chain(Op::Or, x, y) }
 
         #[cache_left_rec]
-        rule boolean_term() -> E = x:boolean_factor() _ y:and_term()* {
-            match y.len() {
-                0 => x,
-                1 => E::Dyadic(Op::And, Box::new(x), Box::new(y[0].clone())),
-                _ => E::Dyadic(Op::And, Box::new(x), Box::new(E::Array(y))),
-            }
-        }
-
-        rule and_term() -> E = i("AND") _ y:boolean_expression() { y }
+        rule boolean_term() -> E
+        = x:boolean_factor() y:(_ i("AND") _ z:boolean_factor() { z })* { E::chain(Op::And, x, y) }
 
         #[cache_left_rec]
         rule boolean_factor() -> E = n:(i("NOT") _)? x:boolean_primary() {
@@ -184,9 +183,12 @@ peg::parser! {
         = i("CASEI") _ "(" _ x:pattern_expression() _ ")"   { E::Monadic(Op::CaseI, Box::new(x)) }
         / i("ACCENTI") _ "(" _ x:pattern_expression() _ ")" { E::Monadic(Op::AccentI, Box::new(x)) }
         / x:character_literal()                             { x }
+        / x:parameter()                                     { x }
 
+        // NOTE (rsn) 20261016 - besides numbers, also accept strings and
+        // temporal instants; e.g. `d BETWEEN DATE('2020-01-01') AND DATE('2020-12-31')`...
         rule is_between_predicate() -> E
-        = x:numeric_expression() _ n:(i("NOT") _)? i("BETWEEN") _ a:numeric_expression() _ i("AND") _ b:numeric_expression() {
+        = x:scalar_expression() _ n:(i("NOT") _)? i("BETWEEN") _ a:scalar_expression() _ i("AND") _ b:scalar_expression() {
             let op = if n.is_none() { Op::IsBetween } else { Op::IsNotBetween };
             E::Dyadic(op, Box::new(x), Box::new(E::Array(vec![a, b])))
         }
@@ -203,6 +205,11 @@ peg::parser! {
             let op = if n.is_none() { Op::IsInList } else { Op::IsNotInList };
             E::Dyadic(op, Box::new(x), Box::new(E::Array(y)))
         }
+        // an array-valued property; e.g. a per-feature whitelist...
+        / x:scalar_expression() _ n:(i("NOT") _)? i("IN") _ y:property_name() {
+            let op = if n.is_none() { Op::IsInList } else { Op::IsNotInList };
+            E::Dyadic(op, Box::new(x), Box::new(y))
+        }
 
         rule in_list() -> Vec<E> = x:(scalar_expression() ++ (_ "," _)) { x }
 
@@ -220,6 +227,9 @@ peg::parser! {
         / i("S_OVERLAPS")   { Op::SOverlaps }
         / i("S_CROSSES")    { Op::SCrosses }
         / i("S_CONTAINS")   { Op::SContains }
+        // NOTE (rsn) 20261016 - extensions; not part of the CQL2 spec...
+        / i("S_COVERS")     { Op::SCovers }
+        / i("S_COVEREDBY")  { Op::SCoveredBy }
 
         #[cache]
         pub(crate) rule geom_expression() -> E
@@ -351,11 +361,16 @@ peg::parser! {
         / x:function()        { x }
         / x:property_name()   { x }
 
+        // NOTE (rsn) 20261016 - named parameters (placeholders) are allowed
+        // wherever a property name is...
         #[cache]
         pub(crate) rule property_name() -> E
-        = a:$("\"" (!"\"" [_])* "\"") { E::Id(a.into()) }
+        = p:parameter()               { p }
+        / a:$("\"" (!"\"" [_])* "\"") { E::Id(a.into()) }
         / b:ident()                   { E::Id(b.into()) }
 
+        pub(crate) rule parameter() -> E = "${" _ n:ident() _ "}" { E::Param(n.into()) }
+
         #[cache]
         rule function() -> E
         = name:ident() _ "(" _ params:argument_list() _ ")" { E::Function(Call::from(name, params)) }
@@ -398,8 +413,14 @@ peg::parser! {
 
         rule not_a_quote() -> char = !"'" c:[_] { c }
 
+        // integers w/o a fraction or exponent that fit in an i64 are kept
+        // exact; anything else becomes a floating point number...
         #[cache]
-        rule numeric_literal() -> E = n:(unsigned_num() / signed_num()) { E::Num(n) }
+        rule numeric_literal() -> E
+        = n:$(['+' | '-']? ['0'..='9']+) !['0'..='9' | '.' | 'e' | 'E'] {?
+            n.parse::<i64>().map(E::Int).or(Err("i64"))
+        }
+        / n:(unsigned_num() / signed_num()) { E::Num(n) }
 
         #[cache]
         rule signed_num() -> f64 = s:['+' | '-']? n:unsigned_num() {
@@ -550,7 +571,9 @@ peg::parser! {
 
         rule bbox_tagd_txt() -> G = i("BBOX") _ x:bbox_txt() { G::BBox(BBox::from(x)) }
 
-        rule bbox_txt() -> Vec<f64> = "(" _ x:(signed_num() **<4,6> (_ "," _)) _ ")" { x }
+        rule bbox_txt() -> Vec<f64> = "(" _ x:(signed_num() **<4,6> (_ "," _)) _ ")" {?
+            if x.len() == 5 { Err("4 or 6 BBOX coordinates") } else { Ok(x) }
+        }
 
         #[cache]
         rule full_date() -> Zoned
@@ -571,7 +594,8 @@ mod tests {
         Context, Resource,
         text::cql2::{
             character_expression, expression, geom_expression, is_like_predicate,
-            numeric_expression, property_name, temporal_expression, temporal_predicate,
+            numeric_expression, parameter, property_name, temporal_expression, temporal_predicate,
+            wkt,
         },
     };
     use jiff::civil::DateTime;
@@ -604,6 +628,14 @@ mod tests {
         );
         assert_eq!(numeric_expression("1.0E-2"), Ok(E::Num(0.01)));
         assert_eq!(numeric_expression("1.0e-0"), Ok(E::Num(1.)));
+        // integers...
+        assert_eq!(numeric_expression("42"), Ok(E::Int(42)));
+        assert_eq!(numeric_expression("+42"), Ok(E::Int(42)));
+        assert_eq!(
+            numeric_expression("9007199254740993"),
+            Ok(E::Int(9007199254740993))
+        );
+        assert_eq!(numeric_expression("99999999999999999999"), Ok(E::Num(1e20)));
     }
 
     #[test]
@@ -619,6 +651,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_in_property() {
+        let exp = expression(r#""tag" IN "allowed_tags""#).expect("Failed parsing");
+        assert!(matches!(exp, E::Dyadic(Op::IsInList, _, ref b) if b.is_id()));
+        let exp = expression("tag NOT IN allowed_tags").expect("Failed parsing");
+        assert!(matches!(exp, E::Dyadic(Op::IsNotInList, _, ref b) if b.is_id()));
+        // literal lists are unaffected...
+        let exp = expression("tag IN ('a', 'b')").expect("Failed parsing");
+        assert!(matches!(exp, E::Dyadic(Op::IsInList, _, ref b) if matches!(**b, E::Array(_))));
+    }
+
+    #[test]
+    fn test_parameter() {
+        assert_eq!(property_name("${minpop}"), Ok(E::Param("minpop".into())));
+        assert_eq!(parameter("${ min_pop }"), Ok(E::Param("min_pop".into())));
+        assert!(parameter("${}").is_err());
+        assert!(parameter("$minpop").is_err());
+
+        let exp = expression("pop > ${minpop} AND name LIKE ${pattern}");
+        assert!(exp.is_ok());
+    }
+
     #[test]
     fn test_date() {
         const T: &str = "Date('2010-02-10')";
@@ -976,6 +1030,38 @@ def'"#,
             _ => panic!("Expected a multi-point geometry. Abort"),
         }
 
+        // both forms produce the same geometry...
+        assert_eq!(e1, e2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_multipoint_forms() -> Result<(), Box<dyn Error>> {
+        #[rustfmt::skip]
+        let cases = [
+            ("MULTIPOINT ((7.02 49.92), (90 180))", "MULTIPOINT (7.02 49.92, 90 180)"),
+            ("multipoint((1 2),(3 4),(5 6))",       "MULTIPOINT(  1 2 ,3 4,  5 6 )"),
+            ("MULTIPOINT ((1 2), 3 4)",             "MULTIPOINT (1 2, (3 4))"),
+            ("MULTIPOINT Z ((1 2 3), (4 5 6))",     "MULTIPOINT Z (1 2 3, 4 5 6)"),
+            ("MULTIPOINT ((1 2))",                  "MULTIPOINT (1 2)"),
+        ];
+        for (parenthesized, bare) in cases {
+            let g1 = wkt(parenthesized)?;
+            let g2 = wkt(bare)?;
+            assert!(matches!(g1, G::Points(_)), "{parenthesized}");
+            assert_eq!(g1, g2, "{bare}");
+        }
+
+        // unbalanced parentheses are still errors...
+        for s in [
+            "MULTIPOINT ((1 2), (3 4)",
+            "MULTIPOINT (1 2), 3 4)",
+            "MULTIPOINT ()",
+        ] {
+            assert!(wkt(s).is_err(), "{s}");
+        }
+
         Ok(())
     }
 
@@ -992,4 +1078,32 @@ def'"#,
 
         Ok(())
     }
+
+    #[test]
+    fn test_comments() -> Result<(), Box<dyn Error>> {
+        const PLAIN: &str = "name LIKE 'A%' AND pop * 2 > 1000 OR S_INTERSECTS(geom, POINT(1 2))";
+        const COMMENTED: &str = "-- cities of interest...
+            /* leading */ name LIKE 'A%' -- by name
+            AND pop/* inline */*/**/2 > 1000
+            OR S_INTERSECTS(geom, /* where? */ POINT(1 2)) /* trailing */
+            -- the end";
+
+        let expected = expression(PLAIN)?;
+        assert_eq!(expression(COMMENTED)?, expected);
+        // comment markers w/in string literals are not comments...
+        assert_eq!(
+            expression("name = '-- /* x */'")?,
+            expression("name /* x */ = '-- /* x */'")?
+        );
+
+        // nested block comments are rejected...
+        assert!(expression("pop /* a /* b */ c */ > 1000").is_err());
+
+        // unterminated block comments are reported at their opener...
+        let x = expression("pop > 1000 /* no end").expect_err("Expected a parse error");
+        assert_eq!(x.location.column, 12);
+        assert!(x.expected.tokens().any(|t| t.contains("block comment")));
+
+        Ok(())
+    }
 }
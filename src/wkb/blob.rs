@@ -11,7 +11,7 @@ use crate::{
     srid::SRID,
     wkb::{
         ByteOrder, Envelope, GeoPackageBinaryHeader, line, lines, point, points, polygon, polygons,
-        unsigned,
+        split_type, unsigned,
     },
 };
 use tracing::error;
@@ -64,59 +64,63 @@ fn wkb_geometry(
     header: &GeoPackageBinaryHeader,
 ) -> Result<(G, usize), MyError> {
     let mut pos = start;
-    let byte_order = &ByteOrder::from(ba[pos]);
+    let byte_order = &ByteOrder::from_wkb(ba, pos)?;
     pos += 1;
     let geom_type = unsigned(byte_order, ba, pos)?;
     pos += 4;
-    match geom_type {
+    let (base, dims) = split_type(geom_type)?;
+    let (g, span) = match base {
         1 => {
-            let (xy, span) = point(byte_order, ba, pos)?;
+            let (xy, span) = point(byte_order, ba, pos, dims)?;
             header.check_point(&xy)?;
             let g = Point::from_xy_and_srid(xy, header.srid().to_owned());
-            Ok((G::Point(g), span))
+            (G::Point(g), span)
         }
         2 => {
-            let (xy, span) = line(byte_order, ba, pos)?;
+            let (xy, span) = line(byte_order, ba, pos, dims)?;
             header.check_line(&xy)?;
             let g = Line::from_xy_and_srid(xy, header.srid().to_owned());
-            Ok((G::Line(g), span))
+            (G::Line(g), span)
         }
         3 => {
-            let (xy, span) = polygon(byte_order, ba, pos)?;
+            let (xy, span) = polygon(byte_order, ba, pos, dims)?;
             header.check_polygon(&xy)?;
             let g = Polygon::from_xy_and_srid(xy, header.srid().to_owned());
-            Ok((G::Polygon(g), span))
+            (G::Polygon(g), span)
         }
         4 => {
             let (xy, span) = points(byte_order, ba, pos)?;
             header.check_points(&xy)?;
             let g = Points::from_xy_and_srid(xy, header.srid().to_owned());
-            Ok((G::Points(g), span))
+            (G::Points(g), span)
         }
         5 => {
             let (xy, span) = lines(byte_order, ba, pos)?;
             header.check_lines(&xy)?;
             let g = Lines::from_xy_and_srid(xy, header.srid().to_owned());
-            Ok((G::Lines(g), span))
+            (G::Lines(g), span)
         }
         6 => {
             let (xy, span) = polygons(byte_order, ba, pos)?;
             header.check_polygons(&xy)?;
             let g = Polygons::from_xy_and_srid(xy, header.srid().to_owned());
-            Ok((G::Polygons(g), span))
+            (G::Polygons(g), span)
         }
         7 => {
             let srs_id = header.srid();
             let (xy, span) = collection(byte_order, ba, pos, header)?;
             let g = Geometries::from_items_and_srid(xy, srs_id.to_owned());
-            Ok((G::Vec(g), span))
+            (G::Vec(g), span)
         }
-        x => {
-            let msg = format!("Unsupported ({x}) geometry type");
+        _ => {
+            let msg = format!("Unsupported ({geom_type}) geometry type");
             error!("{msg}");
-            Err(MyError::Runtime(msg.into()))
+            return Err(MyError::Runtime(msg.into()));
         }
-    }
+    };
+    // NOTE (rsn) 20261016 - include the byte order and type bytes in the span
+    // so the next member of a collection is parsed from the right position...
+    Ok((g, 5 + span))
 }
 
 fn collection(
@@ -145,6 +149,155 @@ mod tests {
 
     const TOLERANCE: f64 = 1.0E-3;
 
+    // minimal WKB encoder for hand-crafting blobs w/ a given byte order for
+    // each (sub-)geometry...
+    fn u32_bytes(le: bool, x: u32) -> [u8; 4] {
+        if le { x.to_le_bytes() } else { x.to_be_bytes() }
+    }
+
+    fn f64_bytes(le: bool, x: f64) -> [u8; 8] {
+        if le { x.to_le_bytes() } else { x.to_be_bytes() }
+    }
+
+    fn wkb_start(le: bool, geom_type: u32) -> Vec<u8> {
+        let mut result = vec![u8::from(le)];
+        result.extend(u32_bytes(le, geom_type));
+        result
+    }
+
+    fn wkb_point(le: bool, xy: [f64; 2]) -> Vec<u8> {
+        let mut result = wkb_start(le, 1);
+        xy.iter().for_each(|x| result.extend(f64_bytes(le, *x)));
+        result
+    }
+
+    fn wkb_polygon(le: bool, rings: &[&[[f64; 2]]]) -> Vec<u8> {
+        let mut result = wkb_start(le, 3);
+        result.extend(u32_bytes(le, rings.len() as u32));
+        for ring in rings {
+            result.extend(u32_bytes(le, ring.len() as u32));
+            ring.iter()
+                .flatten()
+                .for_each(|x| result.extend(f64_bytes(le, *x)));
+        }
+        result
+    }
+
+    fn wkb_multi(le: bool, geom_type: u32, parts: &[Vec<u8>]) -> Vec<u8> {
+        let mut result = wkb_start(le, geom_type);
+        result.extend(u32_bytes(le, parts.len() as u32));
+        parts.iter().for_each(|x| result.extend(x));
+        result
+    }
+
+    // prepend a little-endian, envelope-less, EPSG:4326 GeoPackage header...
+    fn gpkg_blob(wkb: Vec<u8>) -> Vec<u8> {
+        let mut result = vec![0x47, 0x50, 0x00, 0x01, 0xe6, 0x10, 0x00, 0x00];
+        result.extend(wkb);
+        result
+    }
+
+    #[test]
+    fn test_mixed_byte_order() -> Result<(), Box<dyn Error>> {
+        const SHELL: [[f64; 2]; 5] = [
+            [0.0, 0.0],
+            [10.0, 0.0],
+            [10.0, 10.0],
+            [0.0, 10.0],
+            [0.0, 0.0],
+        ];
+        const HOLE: [[f64; 2]; 4] = [[2.0, 2.0], [4.0, 2.0], [2.0, 4.0], [2.0, 2.0]];
+        const OTHER: [[f64; 2]; 4] = [[20.0, 20.0], [30.0, 20.0], [20.0, 30.0], [20.0, 20.0]];
+
+        // big-endian multi-polygon w/ little-endian polygons; one w/ a hole...
+        let parts = [
+            wkb_polygon(true, &[&SHELL, &HOLE]),
+            wkb_polygon(true, &[&OTHER]),
+        ];
+        let bytes = gpkg_blob(wkb_multi(false, 6, &parts));
+        let g = WKB::try_from(bytes.as_slice())?.geom();
+        assert_eq!(g.type_(), "MultiPolygon");
+        assert_eq!(
+            g.to_wkt_fmt(0),
+            "MULTIPOLYGON (((0 0, 10 0, 10 10, 0 10, 0 0), (2 2, 4 2, 2 4, 2 2)), ((20 20, 30 20, 20 30, 20 20)))"
+        );
+
+        // and the other way around, w/ mixed parts...
+        let parts = [
+            wkb_polygon(false, &[&SHELL, &HOLE]),
+            wkb_polygon(true, &[&OTHER]),
+        ];
+        let bytes = gpkg_blob(wkb_multi(true, 6, &parts));
+        let g2 = WKB::try_from(bytes.as_slice())?.geom();
+        assert_eq!(g2.to_wkt_fmt(0), g.to_wkt_fmt(0));
+
+        // each member of a collection must be parsed from its own start...
+        let parts = [
+            wkb_point(false, [1.0, 2.0]),
+            wkb_polygon(true, &[&OTHER]),
+            wkb_point(true, [3.0, 4.0]),
+        ];
+        let bytes = gpkg_blob(wkb_multi(false, 7, &parts));
+        let g = WKB::try_from(bytes.as_slice())?.geom();
+        assert_eq!(
+            g.to_wkt_fmt(0),
+            "GEOMETRYCOLLECTION (POINT (1 2), POLYGON ((20 20, 30 20, 20 30, 20 20)), POINT (3 4))"
+        );
+
+        // a byte order other than 0 or 1 is invalid...
+        let mut bytes = gpkg_blob(wkb_multi(true, 6, &parts[1..2]));
+        bytes[17] = 0x02;
+        assert!(WKB::try_from(bytes.as_slice()).is_err());
+        // nor is a truncated blob...
+        let bytes = gpkg_blob(wkb_multi(true, 6, &[]));
+        assert!(WKB::try_from(&bytes[..8]).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_z() -> Result<(), Box<dyn Error>> {
+        fn wkb_coords(le: bool, geom_type: u32, coords: &[f64]) -> Vec<u8> {
+            let mut result = wkb_start(le, geom_type);
+            coords.iter().for_each(|x| result.extend(f64_bytes(le, *x)));
+            result
+        }
+
+        // ISO WKB codes of Z variants are the 2D ones + 1000...
+        let bytes = gpkg_blob(wkb_coords(true, 1001, &[10.0, 20.0, 30.5]));
+        let g = WKB::try_from(bytes.as_slice())?.geom();
+        assert_eq!(g.type_(), "Point");
+        assert_relative_eq!(g.get_z()?, 30.5, epsilon = TOLERANCE);
+        assert_eq!(g.to_wkt_fmt(1), "POINT Z (10.0 20.0 30.5)");
+
+        let mut wkb = wkb_start(false, 1002);
+        wkb.extend(u32_bytes(false, 2));
+        [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]
+            .iter()
+            .for_each(|x| wkb.extend(f64_bytes(false, *x)));
+        let g = WKB::try_from(gpkg_blob(wkb).as_slice())?.geom();
+        assert_eq!(g.to_wkt_fmt(0), "LINESTRING Z (1 2 3, 4 5 6)");
+
+        // each member of a multi-geometry tells its own dimensions...
+        let parts = [
+            wkb_coords(true, 1001, &[1.0, 2.0, 3.0]),
+            wkb_coords(false, 1001, &[4.0, 5.0, 6.0]),
+        ];
+        let bytes = gpkg_blob(wkb_multi(true, 1004, &parts));
+        let g = WKB::try_from(bytes.as_slice())?.geom();
+        assert_eq!(g.to_wkt_fmt(0), "MULTIPOINT Z (1 2 3, 4 5 6)");
+
+        // M ordinates are skipped; w/ or w/o a Z one...
+        let bytes = gpkg_blob(wkb_coords(true, 2001, &[10.0, 20.0, 99.0]));
+        let g = WKB::try_from(bytes.as_slice())?.geom();
+        assert_eq!(g.to_wkt_fmt(0), "POINT (10 20)");
+        let bytes = gpkg_blob(wkb_coords(true, 3001, &[10.0, 20.0, 30.0, 99.0]));
+        let g = WKB::try_from(bytes.as_slice())?.geom();
+        assert_eq!(g.to_wkt_fmt(0), "POINT Z (10 20 30)");
+
+        Ok(())
+    }
+
     #[test]
     fn test_point() -> Result<(), Box<dyn Error>> {
         const X: f64 = 12.4533865;
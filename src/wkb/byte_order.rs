@@ -5,6 +5,9 @@
 //! Representation of WKB blob data endianess indicator.
 //!
 
+use crate::MyError;
+use tracing::error;
+
 /// How to interpret bytes representing signed and unsigned 32-bit integers and
 /// 64-bit doubles.
 #[derive(Debug)]
@@ -18,6 +21,27 @@ impl ByteOrder {
     pub(crate) fn is_le(&self) -> bool {
         self.0
     }
+
+    /// Return the byte order indicated by the byte at index `pos` of a WKB
+    /// encoded geometry; i.e. `0` for BE (XDR), and `1` for LE (NDR).
+    ///
+    /// Raise an error if that byte is missing or is neither.
+    pub(crate) fn from_wkb(ba: &[u8], pos: usize) -> Result<Self, MyError> {
+        match ba.get(pos) {
+            Some(0) => Ok(Self(false)),
+            Some(1) => Ok(Self(true)),
+            Some(x) => {
+                let msg = format!("Invalid ({x}) WKB byte order at #{pos}");
+                error!("{msg}");
+                Err(MyError::Runtime(msg.into()))
+            }
+            None => {
+                let msg = format!("Missing WKB byte order at #{pos}");
+                error!("{msg}");
+                Err(MyError::Runtime(msg.into()))
+            }
+        }
+    }
 }
 
 impl From<u8> for ByteOrder {
@@ -7,7 +7,7 @@
 
 use crate::{
     G, Line, Lines, MyError, Point, Points, Polygon, Polygons, SRID,
-    wkb::{ByteOrder, line, lines, point, points, polygon, polygons, signed, unsigned},
+    wkb::{ByteOrder, Dims, line, lines, point, points, polygon, polygons, signed, unsigned},
 };
 
 /// PostGIS Extended Well Known Binary encoded geometry.
@@ -73,17 +73,17 @@ fn parse_ewkb(
 ) -> Result<(G, usize), MyError> {
     match gt {
         1 => {
-            let (xy, span) = point(bo, ba, pos)?;
+            let (xy, span) = point(bo, ba, pos, Dims::XY)?;
             let g = Point::from_xy_and_srid(xy, srid);
             Ok((G::Point(g), span))
         }
         2 => {
-            let (xy, span) = line(bo, ba, pos)?;
+            let (xy, span) = line(bo, ba, pos, Dims::XY)?;
             let g = Line::from_xy_and_srid(xy, srid);
             Ok((G::Line(g), span))
         }
         3 => {
-            let (xy, span) = polygon(bo, ba, pos)?;
+            let (xy, span) = polygon(bo, ba, pos, Dims::XY)?;
             let g = Polygon::from_xy_and_srid(xy, srid);
             Ok((G::Polygon(g), span))
         }
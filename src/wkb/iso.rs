@@ -0,0 +1,143 @@
+// SPDX-License-Identifier: Apache-2.0
+
+#![warn(missing_docs)]
+
+//! Representation of a plain (ISO / OGC) WKB encoded geometry binary; i.e.
+//! w/o a _GeoPackage_ header, nor an embedded SRID.
+//!
+
+use crate::{
+    G, Geometries, Line, Lines, MyError, Point, Points, Polygon, Polygons, SRID,
+    wkb::{ByteOrder, line, lines, point, points, polygon, polygons, split_type, unsigned},
+};
+use tracing::error;
+
+/// Well Known Binary encoded geometry.
+pub(crate) struct WellKnownBinary {
+    geom: G,
+}
+
+impl WellKnownBinary {
+    /// Try parsing the given bytes assigning the given SRID to the resulting
+    /// geometry since plain WKB does not carry one.
+    pub(crate) fn try_from_bytes(ba: &[u8], srid: SRID) -> Result<Self, MyError> {
+        let (geom, _span) = wkb_geometry(ba, 0, srid)?;
+        Ok(Self { geom })
+    }
+
+    /// Return the inner geometry object.
+    pub(crate) fn geom(self) -> G {
+        self.geom
+    }
+}
+
+// 1-byte byte order, followed by a 4-byte geometry type identifier, followed
+// by the elements of that geometry type.
+fn wkb_geometry(ba: &[u8], start: usize, srid: SRID) -> Result<(G, usize), MyError> {
+    let mut pos = start;
+    let bo = &ByteOrder::from_wkb(ba, pos)?;
+    pos += 1;
+    let gt = unsigned(bo, ba, pos)?;
+    pos += 4;
+    let (base, dims) = split_type(gt)?;
+    let (g, span) = match base {
+        1 => {
+            let (xy, span) = point(bo, ba, pos, dims)?;
+            (G::Point(Point::from_xy_and_srid(xy, srid)), span)
+        }
+        2 => {
+            let (xy, span) = line(bo, ba, pos, dims)?;
+            (G::Line(Line::from_xy_and_srid(xy, srid)), span)
+        }
+        3 => {
+            let (xy, span) = polygon(bo, ba, pos, dims)?;
+            (G::Polygon(Polygon::from_xy_and_srid(xy, srid)), span)
+        }
+        4 => {
+            let (xy, span) = points(bo, ba, pos)?;
+            (G::Points(Points::from_xy_and_srid(xy, srid)), span)
+        }
+        5 => {
+            let (xy, span) = lines(bo, ba, pos)?;
+            (G::Lines(Lines::from_xy_and_srid(xy, srid)), span)
+        }
+        6 => {
+            let (xy, span) = polygons(bo, ba, pos)?;
+            (G::Polygons(Polygons::from_xy_and_srid(xy, srid)), span)
+        }
+        7 => {
+            let num_geometries = unsigned(bo, ba, pos)?;
+            let mut span = 4;
+            let mut items = Vec::with_capacity(usize::try_from(num_geometries)?);
+            for _ in 0..num_geometries {
+                let (g, offset) = wkb_geometry(ba, pos + span, srid)?;
+                items.push(g);
+                span += offset;
+            }
+            let g = Geometries::from_items_and_srid(items, srid);
+            (G::Vec(g), span)
+        }
+        _ => {
+            let msg = format!("Unsupported ({gt}) geometry type");
+            error!("{msg}");
+            return Err(MyError::Runtime(msg.into()));
+        }
+    };
+    Ok((g, 5 + span))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{EPSG_4326, GTrait};
+    use std::error::Error;
+
+    #[test]
+    fn test_iso_wkb() -> Result<(), Box<dyn Error>> {
+        #[rustfmt::skip]
+        let bytes: &[u8] = &[
+            0x01,
+            0x01, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x24, 0x40,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x34, 0xc0,
+        ];
+        let g = WellKnownBinary::try_from_bytes(bytes, EPSG_4326)?.geom();
+        assert_eq!(g.type_(), "Point");
+        assert_eq!(g.srid(), EPSG_4326);
+        assert_eq!(g.to_wkt_fmt(0), "POINT (10 -20)");
+
+        // big-endian collection of 2 points...
+        #[rustfmt::skip]
+        let bytes: &[u8] = &[
+            0x00,
+            0x00, 0x00, 0x00, 0x07,
+            0x00, 0x00, 0x00, 0x02,
+            0x00,
+            0x00, 0x00, 0x00, 0x01,
+            0x3f, 0xf0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x01,
+            0x01, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x40,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x40,
+        ];
+        let g = WellKnownBinary::try_from_bytes(bytes, EPSG_4326)?.geom();
+        assert_eq!(g.type_(), "GeometryCollection");
+        assert_eq!(
+            g.to_wkt_fmt(0),
+            "GEOMETRYCOLLECTION (POINT (1 2), POINT (3 4))"
+        );
+
+        // POINT Z...
+        let mut bytes = bytes[9..30].to_vec();
+        bytes[1..5].copy_from_slice(&1001_u32.to_be_bytes());
+        bytes.extend(5_f64.to_be_bytes());
+        let g = WellKnownBinary::try_from_bytes(&bytes, EPSG_4326)?.geom();
+        assert_eq!(g.to_wkt_fmt(0), "POINT Z (1 2 5)");
+        // ...while an unknown dimension is not supported...
+        bytes[1..5].copy_from_slice(&4001_u32.to_be_bytes());
+        assert!(WellKnownBinary::try_from_bytes(&bytes, EPSG_4326).is_err());
+
+        Ok(())
+    }
+}
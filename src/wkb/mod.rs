@@ -2,7 +2,7 @@
 
 #![warn(missing_docs)]
 
-//! Parse encoded GeoPackage WKB, and PostGIS EWKB binary values.
+//! Parse encoded GeoPackage WKB, plain WKB, and PostGIS EWKB binary values.
 //!
 //! See [BLOB Format][1] section of the _OGC® GeoPackage Encoding Standard_ in
 //! general, and the [OpenGIS® Implementation Standard for Geographic information
@@ -22,14 +22,19 @@ mod byte_order;
 mod envelope;
 mod ewkb;
 mod header;
+#[cfg_attr(not(feature = "geoparquet"), allow(dead_code))]
+mod iso;
 
 pub(crate) use blob::GeoPackageBinary;
 pub(crate) use byte_order::ByteOrder;
 pub(crate) use envelope::Envelope;
 pub(crate) use ewkb::PostGisBinary;
 pub(crate) use header::GeoPackageBinaryHeader;
+#[cfg_attr(not(feature = "geoparquet"), allow(unused_imports))]
+pub(crate) use iso::WellKnownBinary;
 
 use crate::{MyError, XY1V, XY2V, XY3V, XY4V};
+use tracing::error;
 
 // Parse 4 bytes, starting at index `start`, as LE or BE and return an `i32`.
 fn signed(bo: &ByteOrder, buffer: &[u8], start: usize) -> Result<i32, MyError> {
@@ -70,22 +75,65 @@ fn double(bo: &ByteOrder, buffer: &[u8], start: usize) -> Result<f64, MyError> {
     Ok(it)
 }
 
+// Which of the optional Z and M ordinates follow the X and Y ones of every
+// coordinate in a WKB geometry...
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Dims {
+    z: bool,
+    m: bool,
+}
+
+impl Dims {
+    /// 2D coordinates; i.e. w/ neither Z nor M ordinates.
+    pub(crate) const XY: Dims = Dims { z: false, m: false };
+
+    // number of ordinates, hence of doubles, per coordinate...
+    fn len(&self) -> usize {
+        2 + usize::from(self.z) + usize::from(self.m)
+    }
+}
+
+// Split an ISO WKB geometry type code into its (2D) base type and the [Dims]
+// of its coordinates; e.g. 1003 into 3 (a Polygon) w/ Z ordinates.
+//
+// NOTE (rsn) 20261016 - GeoPackage blobs use the same codes. the envelope
+// content indicator in their header says nothing about the dimensions of the
+// geometry itself...
+pub(crate) fn split_type(code: u32) -> Result<(u32, Dims), MyError> {
+    let dims = match code / 1000 {
+        0 => Dims::XY,
+        1 => Dims { z: true, m: false },
+        2 => Dims { z: false, m: true },
+        3 => Dims { z: true, m: true },
+        _ => {
+            let msg = format!("Unsupported ({code}) geometry type");
+            error!("{msg}");
+            return Err(MyError::Runtime(msg.into()));
+        }
+    };
+    Ok((code % 1000, dims))
+}
+
 // a macro to generate blob parsing for the 3 basic geometry types.
 macro_rules! gen_wkb_functions {
     ($suffix:expr, $wkb_type:expr) => {
         paste::paste! {
             pub(crate) fn [<wkb_ $suffix>](ba: &[u8], start: usize) -> Result<([<XY $wkb_type V>], usize), MyError> {
                 let mut pos = start;
-                let byte_order = &ByteOrder::from(ba[pos]);
+                // each (sub-)geometry carries its own byte order...
+                let byte_order = &ByteOrder::from_wkb(ba, pos)?;
                 pos += 1;
                 let geom_type = unsigned(byte_order, ba, pos)?;
                 pos += 4;
                 let type_ = $wkb_type;
-                assert_eq!(
-                    geom_type, $wkb_type,
-                    "Expected wkbType to be {type_} but found {geom_type}"
-                );
-                let (xy, offset) = [<$suffix>](byte_order, ba, pos)?;
+                // a member's own code tells the dimensions of its coordinates...
+                let (base, dims) = split_type(geom_type)?;
+                if base != type_ {
+                    let msg = format!("Expected wkbType to be {type_} but found {geom_type}");
+                    error!("{msg}");
+                    return Err(MyError::Runtime(msg.into()));
+                }
+                let (xy, offset) = [<$suffix>](byte_order, ba, pos, dims)?;
                 Ok((xy, 5 + offset))
             }
         }
@@ -96,24 +144,31 @@ gen_wkb_functions!("point", 1);
 gen_wkb_functions!("line", 2);
 gen_wkb_functions!("polygon", 3);
 
-// Parse and return a pair of x y (double) coordinates.
-fn point(bo: &ByteOrder, ba: &[u8], start: usize) -> Result<(Vec<f64>, usize), MyError> {
+// Parse and return a pair of x y (double) coordinates followed by the z one
+// if `dims` has it. an M ordinate, if any, is consumed but discarded...
+fn point(
+    bo: &ByteOrder,
+    ba: &[u8],
+    start: usize,
+    dims: Dims,
+) -> Result<(Vec<f64>, usize), MyError> {
     let x = double(bo, ba, start)?;
-    let mut span = 8;
-    let y = double(bo, ba, start + span)?;
-    span += 8;
-    let xy = vec![x, y];
-    Ok((xy, span))
+    let y = double(bo, ba, start + 8)?;
+    let mut xy = vec![x, y];
+    if dims.z {
+        xy.push(double(bo, ba, start + 16)?);
+    }
+    Ok((xy, 8 * dims.len()))
 }
 
 // Parse and return an unsigned `numPoints` followed by as many pairs of x, y
 // coordinates.
-fn line(bo: &ByteOrder, ba: &[u8], start: usize) -> Result<(XY2V, usize), MyError> {
+fn line(bo: &ByteOrder, ba: &[u8], start: usize, dims: Dims) -> Result<(XY2V, usize), MyError> {
     let num_points = unsigned(bo, ba, start)?;
     let mut span = 4;
     let mut xy: XY2V = Vec::with_capacity(usize::try_from(num_points)?);
     for _ in 0..num_points {
-        let (coord, offset) = point(bo, ba, start + span)?;
+        let (coord, offset) = point(bo, ba, start + span, dims)?;
         xy.push(coord);
         span += offset;
     }
@@ -122,12 +177,12 @@ fn line(bo: &ByteOrder, ba: &[u8], start: usize) -> Result<(XY2V, usize), MyErro
 
 // An unsigned `numRings` followed by as many linear-rings. For our purposes a
 // linear ring is structurally the same as a `line`.
-fn polygon(bo: &ByteOrder, ba: &[u8], start: usize) -> Result<(XY3V, usize), MyError> {
+fn polygon(bo: &ByteOrder, ba: &[u8], start: usize, dims: Dims) -> Result<(XY3V, usize), MyError> {
     let num_rings = unsigned(bo, ba, start)?;
     let mut span = 4;
     let mut xy: XY3V = Vec::with_capacity(usize::try_from(num_rings)?);
     for _ in 0..num_rings {
-        let (coord, offset) = line(bo, ba, start + span)?;
+        let (coord, offset) = line(bo, ba, start + span, dims)?;
         xy.push(coord);
         span += offset;
     }
@@ -193,12 +193,14 @@ async fn test_outcome_3_gpkg() -> Result<(), Box<dyn Error>> {
 
 #[test]
 fn test_outcome_4() -> Result<(), Box<dyn Error>> {
-    const TV: [(&str, bool); 5] = [
+    const TV: [(&str, bool); 7] = [
         (r#"abc\%def"#, false),
         (r#"abc%def"#, false),
-        (r#"%d"#, true),
-        (r#"_d"#, true),
+        (r#"%d"#, false),
+        (r#"_d"#, false),
         (r#"_"#, false),
+        (r#"%_"#, true),
+        (r#"%%"#, false),
     ];
 
     let shared_ctx = Context::new().freeze();
@@ -226,3 +228,47 @@ fn test_outcome_4() -> Result<(), Box<dyn Error>> {
     assert_eq!(failures, 0);
     Ok(())
 }
+
+#[test]
+fn test_outcome_5() -> Result<(), Box<dyn Error>> {
+    const TV: [(&str, Option<bool>); 4] = [
+        (r#"100%"#, Some(true)),
+        (r#"1000"#, Some(false)),
+        (r#"100!%"#, Some(false)),
+        (r#"100"#, Some(false)),
+    ];
+
+    assert_eq!(Context::new().like_escape(), '\\');
+    assert!(Context::new().try_with_like_escape('%').is_err());
+    let ctx = Context::new().try_with_like_escape('!')?;
+    let shared_ctx = ctx.freeze();
+    let mut evaluator = ExEvaluator::new(shared_ctx.clone());
+    let exp = Expression::try_from_text("NAME LIKE '100!%'")?;
+
+    evaluator.setup(exp)?;
+
+    let mut failures = 0;
+    for (ndx, (name, flag)) in TV.iter().enumerate() {
+        let expected = Outcome::new(flag.as_ref());
+        let feat = Resource::from([
+            ("fid".into(), Q::try_from(ndx)?),
+            ("NAME".into(), Q::new_plain_str(&name)),
+        ]);
+
+        let actual = evaluator.evaluate(&feat)?;
+        if actual != expected {
+            error!("Failed #{ndx}, name = \"{name}\"");
+            failures += 1
+        }
+    }
+    assert_eq!(failures, 0);
+
+    // a dangling escape character is an error...
+    let mut evaluator = ExEvaluator::new(shared_ctx);
+    let exp = Expression::try_from_text("NAME LIKE '100!'")?;
+    evaluator.setup(exp)?;
+    let feat = Resource::from([("NAME".into(), Q::new_plain_str("100!"))]);
+    assert!(evaluator.evaluate(&feat).is_err());
+
+    Ok(())
+}
@@ -13,6 +13,8 @@
 //!
 
 use crate::utils::{PlaceCSV, PlaceGPkg, PlacePG, harness, harness_gpkg, harness_sql};
+use futures::TryStreamExt;
+use ogc_cql2::prelude::*;
 use std::error::Error;
 
 #[rustfmt::skip]
@@ -56,3 +58,92 @@ async fn test_pg_sql() -> Result<(), Box<dyn Error>> {
     let ds = PlacePG::new().await?;
     harness_sql(ds, &PREDICATES).await
 }
+
+#[tokio::test]
+async fn test_gpkg_limit() -> Result<(), Box<dyn Error>> {
+    let ds = PlaceGPkg::new().await?;
+    // 240 matches...
+    let exp = Expression::try_from_text(PREDICATES[1].0)?;
+
+    let first: Vec<_> = ds
+        .stream_where_limit(&exp, 10, 0)
+        .await?
+        .try_collect()
+        .await?;
+    assert_eq!(first.len(), 10);
+    let second: Vec<_> = ds
+        .stream_where_limit(&exp, 10, 10)
+        .await?
+        .try_collect()
+        .await?;
+    assert_eq!(second.len(), 10);
+    // pages do not overlap...
+    assert!(
+        first
+            .iter()
+            .all(|x| !second.iter().any(|y| y["fid"] == x["fid"]))
+    );
+
+    let last: Vec<_> = ds
+        .fetch_where_limit(&exp, 10, 235)
+        .await?
+        .try_collect()
+        .await?;
+    assert_eq!(last.len(), 5);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_gpkg_ordered() -> Result<(), Box<dyn Error>> {
+    let ds = PlaceGPkg::new().await?;
+    let exp = Expression::try_from_text(PREDICATES[2].0)?;
+    let order_by = [
+        ("pop_other".to_owned(), SortDir::Desc),
+        ("fid".to_owned(), SortDir::Asc),
+    ];
+
+    // pushed down to the database...
+    let ordered: Vec<_> = ds
+        .stream_where_ordered(&exp, &order_by)
+        .await?
+        .try_collect()
+        .await?;
+    assert_eq!(ordered.len(), 75);
+
+    // ...and sorted in-process...
+    let mut expected: Vec<_> = ds.stream_where(&exp).await?.try_collect().await?;
+    sort_resources(&mut expected, &order_by);
+    let fids = |x: &[Resource]| x.iter().map(|r| r["fid"].clone()).collect::<Vec<_>>();
+    assert_eq!(fids(&ordered), fids(&expected));
+    assert!(ordered[0]["pop_other"] >= ordered[74]["pop_other"]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_gpkg_stream_with() -> Result<(), Box<dyn Error>> {
+    let ds = PlaceGPkg::new().await?;
+    let fids = |x: &[Resource]| {
+        let mut it: Vec<_> = x
+            .iter()
+            .map(|r| r["fid"].to_num().expect("Not a number"))
+            .collect();
+        it.sort_by(f64::total_cmp);
+        it
+    };
+
+    let expected: Vec<_> = ds.stream().await?.try_collect().await?;
+    // chunks neither overlap nor skip rows...
+    let cfg = StreamConfig::default().with_chunk_size(7).with_prefetch(3);
+    let actual: Vec<_> = ds.stream_with(&cfg).await?.try_collect().await?;
+    assert_eq!(actual.len(), expected.len());
+    assert_eq!(fids(&actual), fids(&expected));
+
+    // a chunk size that is a multiple of the row count...
+    let cfg = StreamConfig::default().with_chunk_size(expected.len());
+    let actual: Vec<_> = ds.stream_with(&cfg).await?.try_collect().await?;
+    assert_eq!(actual.len(), expected.len());
+
+    Ok(())
+}
@@ -55,3 +55,19 @@ async fn test_pg_sql() -> Result<(), Box<dyn Error>> {
     let ds = PlacePG::new().await?;
     harness_sql(ds, &PREDICATES).await
 }
+
+// the operands of these comparisons fold differently. the folding of either
+// side applies to both...
+#[rustfmt::skip]
+const MIXED_PREDICATES: [(&str, u32); 3] = [
+    (r#"CASEI(name)=accenti('sao paulo')"#,      1),
+    (r#"name=accenti(casei('CHISINAU'))"#,       1),
+    (r#"ACCENTI(name)<>casei('REYKJAVIK')"#,   242),
+];
+
+#[tokio::test]
+async fn test_mixed_in_process_vs_sql() -> Result<(), Box<dyn Error>> {
+    harness(PlaceCSV::new(), &MIXED_PREDICATES)?;
+    harness_gpkg(PlaceGPkg::new().await?, &MIXED_PREDICATES).await?;
+    harness_sql(PlaceGPkg::new().await?, &MIXED_PREDICATES).await
+}
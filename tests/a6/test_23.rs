@@ -14,6 +14,8 @@
 //!
 
 use crate::utils::{PlaceCSV, PlaceGPkg, PlacePG, harness, harness_gpkg, harness_sql};
+use futures::TryStreamExt;
+use ogc_cql2::prelude::*;
 use std::error::Error;
 
 #[rustfmt::skip]
@@ -50,34 +52,28 @@ async fn test_gpkg() -> Result<(), Box<dyn Error>> {
 
 #[tokio::test]
 async fn test_sql() -> Result<(), Box<dyn Error>> {
-    // IMPORTANT (rsn) 20251112 - predicate #9 is NOT yielding the expected
-    // result.  LIKE w/ AccentI+CaseI cases seem to work OK in other cases
-    // (e.g. predicate #8), and tests, which make me assume the problem is
-    // not in the collating functions, nor is it in the SQL generation, but
-    // elsewhere...
-    #[rustfmt::skip]
-    const PREDICATES: [(&str, u32); 10] = [
-        (r#"ACCENTI(name)=accenti('Chișinău')"#,                 1),  // 0
-        (r#"ACCENTI(name)=accenti('Chisinau')"#,                 1),
-        (r#"ACCENTI(name)=accenti('Kiev')"#,                     1),
-        (r#"ACCENTI(CASEI(name))=accenti(casei('chișinău'))"#,   1),
-        (r#"ACCENTI(CASEI(name))=accenti(casei('chisinau'))"#,   1),
-        (r#"ACCENTI(CASEI(name))=accenti(casei('CHISINAU'))"#,   1),  // 5
-        (r#"ACCENTI(CASEI(name))=accenti(casei('CHIȘINĂU'))"#,   1),
-
-        // IMPORTANT: see https://github.com/opengeospatial/ogcapi-features/issues/1011
-        (r#"ACCENTI(name) LIKE accenti('Ch%')"#,                 3),  // 7; was 2
-        (r#"ACCENTI(CASEI(name)) LIKE accenti(casei('Chiș%'))"#, 1),  // 8; likewise
+    // NOTE (rsn) 20261016 - predicate #9 used to fail b/c SQLite's LIKE
+    // ignores collations. it's now rewritten to use the same one on both the
+    // column and the pattern...
+    let ds = PlaceGPkg::new().await?;
+    harness_sql(ds, &PREDICATES).await
+}
 
-        // (r#"ACCENTI(CASEI(name)) LIKE accenti(casei('cHis%'))"#, 1),  // 9; likewise
+#[tokio::test]
+async fn test_sql_like_collation() -> Result<(), Box<dyn Error>> {
+    let ds = PlaceGPkg::new().await?;
+    let exp = Expression::try_from_text(r#"ACCENTI(CASEI("name")) LIKE accenti(casei('chis%'))"#)?;
+    let places: Vec<_> = ds.stream_where(&exp).await?.try_collect().await?;
+    assert_eq!(places.len(), 1);
+    assert!(places[0]["name"] == Q::new_plain_str("Chișinău"));
 
-        (r#"ACCENTI(CASEI(name)) IN (accenti(casei('Kiev')),
-        accenti(casei('chișinău')), accenti(casei('Berlin')),
-        accenti(casei('athens')), accenti(casei('foo')))"#,     4)  // 10
-    ];
+    // same w/ NOT LIKE...
+    let exp =
+        Expression::try_from_text(r#"ACCENTI(CASEI("name")) NOT LIKE accenti(casei('chis%'))"#)?;
+    let places: Vec<_> = ds.stream_where(&exp).await?.try_collect().await?;
+    assert_eq!(places.len(), 242);
 
-    let ds = PlaceGPkg::new().await?;
-    harness_sql(ds, &PREDICATES).await
+    Ok(())
 }
 
 #[tokio::test]
@@ -1,6 +1,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use ogc_cql2::Expression;
+use ogc_cql2::{Context, Evaluator, ExEvaluator, Expression, Outcome, Q, Resource};
+use std::{collections::HashMap, error::Error};
 
 #[test]
 fn test_ex13() {
@@ -96,3 +97,85 @@ fn test_ex85_alt02() {
     let exp = Expression::try_from_text(CQL);
     assert!(exp.is_ok());
 }
+
+#[test]
+fn test_bind_parameters() -> Result<(), Box<dyn Error>> {
+    const CQL: &str = r#"pop >= ${minpop} AND name LIKE ${pattern}"#;
+
+    let template = Expression::try_from_text(CQL)?;
+    let feat = Resource::from([
+        ("pop".into(), Q::Num(1500.0)),
+        ("name".into(), Q::new_plain_str("Berlin")),
+    ]);
+
+    let shared_ctx = Context::new().freeze();
+    // unbound parameters fail at evaluation time...
+    let mut evaluator = ExEvaluator::new(shared_ctx.clone());
+    evaluator.setup(template.bind(&HashMap::new())?)?;
+    assert!(evaluator.evaluate(&feat).is_err());
+
+    for (minpop, expected) in [(1000.0, Outcome::T), (2000.0, Outcome::F)] {
+        let params = HashMap::from([
+            ("minpop".into(), Q::Num(minpop)),
+            ("pattern".into(), Q::new_plain_str("B%")),
+        ]);
+        let exp = template.bind(&params)?;
+        assert_eq!(
+            exp.to_string(),
+            format!("pop >= {minpop} && (name LIKE '/B%/_')")
+        );
+
+        let mut evaluator = ExEvaluator::new(shared_ctx.clone());
+        evaluator.setup(exp)?;
+        assert_eq!(evaluator.evaluate(&feat)?, expected);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_in_array_property() -> Result<(), Box<dyn Error>> {
+    let feat = Resource::from([
+        ("category".into(), Q::new_plain_str("park")),
+        ("rank".into(), Q::Num(3.0)),
+        (
+            "allowed_tags".into(),
+            Q::List(vec![Q::new_plain_str("park"), Q::new_plain_str("river")]),
+        ),
+        ("ranks".into(), Q::List(vec![Q::Num(1.0), Q::Num(2.0)])),
+        (
+            "mixed".into(),
+            Q::List(vec![Q::new_plain_str("park"), Q::Num(1.0)]),
+        ),
+    ]);
+
+    #[rustfmt::skip]
+    let tv = [
+        ("category IN allowed_tags",        Some(Outcome::T)),
+        ("category NOT IN allowed_tags",    Some(Outcome::F)),
+        (r#""rank" IN "ranks""#,            Some(Outcome::F)),
+        ("rank NOT IN ranks",               Some(Outcome::T)),
+        ("missing IN allowed_tags",         Some(Outcome::N)),
+        // elements must be of the same type as the LHS...
+        ("category IN mixed",               None),
+        ("rank IN allowed_tags",            None),
+    ];
+
+    let shared_ctx = Context::new().freeze();
+    for (cql, expected) in tv {
+        let exp = Expression::try_from_text(cql)?;
+        let mut evaluator = ExEvaluator::new(shared_ctx.clone());
+        evaluator.setup(exp)?;
+        match expected {
+            Some(x) => assert_eq!(evaluator.evaluate(&feat)?, x, "{cql}"),
+            None => assert!(evaluator.evaluate(&feat).is_err(), "{cql}"),
+        }
+    }
+
+    // round-trip through Display...
+    let exp = Expression::try_from_text("category IN allowed_tags")?;
+    let exp2 = Expression::try_from_text(&exp.to_string())?;
+    assert_eq!(exp.to_string(), exp2.to_string());
+
+    Ok(())
+}
@@ -14,8 +14,8 @@ pub(crate) use river::{RiverCSV, RiverGPkg, RiverPG};
 
 use futures::TryStreamExt;
 use ogc_cql2::{
-    Context, Evaluator, ExEvaluator, Expression, IterableDS, MyError, Outcome, Resource,
-    StreamableDS,
+    Context, Evaluator, ExEvaluator, Expression, IterableDS, MyError, Outcome, StreamableDS,
+    tally_with,
 };
 use rand::{
     RngExt,
@@ -38,14 +38,7 @@ const PG_DB_NAME: &str = "cql2";
 pub(crate) fn harness<T: IterableDS<Err = MyError>>(
     ds: T,
     predicates: &[(&str, u32)],
-) -> Result<(), Box<dyn Error>>
-where
-    Resource: TryFrom<<T as IterableDS>::Item>,
-{
-    let mut evaluators = vec![];
-    let mut expected = vec![];
-    let mut actual = vec![];
-
+) -> Result<(), Box<dyn Error>> {
     // IMPORTANT (rsn) 20250901 - as mentioned often in the comments + docs,
     // some conformance tests that expect a failure due to "invalid coordinate"
     // assume that the _implicit_ CRS against which coordinates are checked is
@@ -54,33 +47,16 @@ where
     // a `Context` that takes this into account, the test(s) may fail.
     let shared_ctx = Context::try_with_crs("epsg:4326")?.freeze();
 
-    for (input, success_count) in predicates {
-        let mut evaluator = ExEvaluator::new(shared_ctx.clone());
-        let expr = Expression::try_from_text(input)?;
-        evaluator.setup(expr)?;
-        evaluators.push(evaluator);
-        expected.push(success_count);
-        actual.push(0);
-    }
-
-    for x in ds.iter()? {
-        let feature = x?;
-        let resource = Resource::try_from(feature)
-            .map_err(|_| MyError::Runtime("Failed converting iterable item to Resource".into()))?;
-        for (p_ndx, evaluator) in evaluators.iter().enumerate() {
-            let res = evaluator
-                .evaluate(&resource)
-                .expect(&format!("Failed evaluating resource"));
-            if matches!(res, Outcome::T) {
-                actual[p_ndx] += 1;
-            }
-        }
+    let mut exprs = vec![];
+    for (input, _) in predicates {
+        exprs.push(Expression::try_from_text(input)?);
     }
+    let actual = tally_with(&ds, &exprs, shared_ctx)?;
 
     let mut failures = 0;
     for (ndx, count) in actual.iter().enumerate() {
-        let n = expected[ndx];
-        if count != n {
+        let n = u64::from(predicates[ndx].1);
+        if *count != n {
             tracing::error!("Failed predicate #{ndx} - actual/expected: {count} / {n}");
             failures += 1;
         }
@@ -176,6 +176,43 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_discovered_srid() -> Result<(), Box<dyn Error>> {
+        install_default_drivers();
+
+        let gpkg = RiverGPkg::new().await?;
+        // SRS is discovered from the `gpkg_geometry_columns` table...
+        assert_eq!(gpkg.inner().geom_column(), Some("geom"));
+        assert_eq!(gpkg.inner().srid(), Some(4326));
+
+        let mut stream = gpkg.stream().await?;
+        while let Some(r) = stream.try_next().await? {
+            let g = r.get("geom").expect("Missing 'geom'").to_geom()?;
+            assert_eq!(g.srid().to_string(), "EPSG:4326");
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_to_sql_where() -> Result<(), Box<dyn Error>> {
+        install_default_drivers();
+
+        let gpkg = RiverGPkg::new().await?;
+        let exp = Expression::try_from_text("fid > 3")?;
+        let sql = gpkg.inner().to_sql_where(&exp)?;
+        assert_eq!(
+            sql,
+            r#"SELECT * FROM "vgpkg_ne_110m_rivers_lake_centerlines" WHERE fid > 3"#
+        );
+
+        // the WHERE clause is the translated predicate...
+        let exp = Expression::try_from_text("name = 'Nile' AND fid > 3")?;
+        let sql = gpkg.inner().to_sql_where(&exp)?;
+        let where_clause = gpkg.inner().to_sql(&exp)?;
+        assert!(sql.ends_with(&format!(" WHERE {where_clause}")));
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_pg() -> Result<(), Box<dyn Error>> {
         let mut count = 0;